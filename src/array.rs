@@ -1,5 +1,6 @@
 //! Utility functions related to arrays.
 
+/// The first `N` items of `iterator`, with `I::default()` filling in when it is shorter
 pub fn array_from_iterator<I: Copy + Default, T: IntoIterator<Item = I>, const N: usize>(
     iterator: T,
 ) -> [I; N] {
@@ -13,3 +14,20 @@ pub fn array_from_iterator<I: Copy + Default, T: IntoIterator<Item = I>, const N
         });
     output
 }
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use crate::array::array_from_iterator;
+
+    proptest! {
+        #[test]
+        fn array_from_iterator_truncates_and_pads(values in prop::collection::vec(1..u32::MAX, 0..16)) {
+            let array: [u32; 8] = array_from_iterator(values.iter().copied());
+            for (index, value) in array.iter().enumerate() {
+                prop_assert_eq!(*value, values.get(index).copied().unwrap_or_default());
+            }
+        }
+    }
+}
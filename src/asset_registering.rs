@@ -1,28 +1,80 @@
 //! Utility functions related to loading assets, in this case materials and textures.
 
-use std::ffi::CStr;
+use std::path::{Path, PathBuf};
 
 use game_asset::{
     ecs_module::{GpuInterface, TextAssetManager},
-    resource_managers::material_manager::materials::MaterialType,
+    resource_managers::material_manager::materials::{Material, MaterialType},
+};
+use void_public::{
+    AssetPath, Engine, EventWriter, bundle,
+    event::graphics::{NewText, NewTexture},
+    graphics::TextureId,
+    material::MaterialId,
+    text::TextId,
 };
-use void_public::{AssetPath, Engine, EventWriter, bundle, event::graphics::NewText, text::TextId};
 
 use crate::{
-    MaterialTest, MaterialTestId, MaterialTestIdHolder, MaterialTextAsset, MaybeLoadedMaterial,
+    MaterialTest, MaterialTestId, MaterialTestIdHolder, MaterialTextAsset, MaterialTextureAsset,
+    MaybeLoadedMaterial,
+    local_error::{Result, TestModuleError},
+    test_definition::{MaterialTestSystems, register_test_systems},
 };
 
-#[allow(clippy::too_many_arguments)]
-pub fn register_material(
+/// The directory [`AssetPath`]s are relative to, where the engine's asset loader reads them from
+pub const ASSETS_DIRECTORY: &str = "assets";
+
+/// The file an asset path like `toml_materials/sprite/fire.toml` is loaded from, for reading an
+/// asset's source directly
+pub fn asset_file_path(asset_path: &str) -> PathBuf {
+    Path::new(ASSETS_DIRECTORY).join(asset_path)
+}
+
+/// Loads a texture without holding up loading until it's ready, so a texture the platform can't
+/// create only affects the test showing it
+pub fn load_optional_texture(
+    texture_path: &str,
+    insert_in_atlas: bool,
+    gpu_interface: &mut GpuInterface,
+    event_writer: &EventWriter<NewTexture>,
+) -> Result<TextureId> {
+    let pending_texture = gpu_interface
+        .texture_asset_manager
+        .load_texture(
+            &PathBuf::from(texture_path).into(),
+            insert_in_atlas,
+            event_writer,
+        )
+        .map_err(|error| TestModuleError::AssetNotLoaded {
+            asset_path: texture_path.to_string(),
+            reason: format!("{error:?}"),
+        })?;
+
+    Ok(pending_texture.id())
+}
+
+pub fn register_texture(
+    texture_path: &str,
+    insert_in_atlas: bool,
+    gpu_interface: &mut GpuInterface,
+    event_writer: &EventWriter<NewTexture>,
+) -> Result<()> {
+    let texture_id =
+        load_optional_texture(texture_path, insert_in_atlas, gpu_interface, event_writer)?;
+    Engine::spawn(bundle!(&MaterialTextureAsset::new(texture_id)));
+
+    Ok(())
+}
+
+/// Loads a material without a [`MaterialTest`] of its own, for tests made of several materials
+pub fn load_material(
     name: &str,
     material_type: MaterialType,
     material_definition_path: &AssetPath,
-    startup_system: &CStr,
     gpu_interface: &mut GpuInterface,
-    material_test_id_holder: &mut MaterialTestIdHolder,
     event_writer: &EventWriter<NewText<'_>>,
     text_asset_manager: &mut TextAssetManager,
-) -> (TextId, MaterialTestId) {
+) -> Result<TextId> {
     let pending_text = gpu_interface
         .material_manager
         .load_material_from_path(
@@ -33,16 +85,84 @@ pub fn register_material(
             event_writer,
             text_asset_manager,
         )
-        .unwrap();
-    let material_test = &MaterialTest::new(
+        .map_err(|error| TestModuleError::AssetNotLoaded {
+            asset_path: format!("{material_definition_path:?}"),
+            reason: format!("{error:?}"),
+        })?;
+    Engine::spawn(bundle!(&MaterialTextAsset::new(pending_text.id())));
+
+    Ok(pending_text.id())
+}
+
+/// Registers the [`MaterialTest`] and records `T`'s systems, see [`register_test_systems`]. The
+/// test keeps `material_definition_path` so its TOML can be read again, e.g. for its
+/// `[uniform_ranges]`.
+pub fn register_material<T: MaterialTestSystems>(
+    name: &str,
+    material_type: MaterialType,
+    material_definition_path: &str,
+    gpu_interface: &mut GpuInterface,
+    material_test_id_holder: &mut MaterialTestIdHolder,
+    event_writer: &EventWriter<NewText<'_>>,
+    text_asset_manager: &mut TextAssetManager,
+) -> Result<(TextId, MaterialTestId)> {
+    // Recorded before anything can fail, the systems of a test that didn't load still have to
+    // be turned off
+    register_test_systems::<T>();
+    let text_id = load_material(
         name,
-        startup_system,
-        &[MaybeLoadedMaterial::new(material_type, pending_text.id())],
+        material_type,
+        &material_definition_path.into(),
+        gpu_interface,
+        event_writer,
+        text_asset_manager,
+    )?;
+    let mut material_test = MaterialTest::new(
+        name,
+        T::STARTUP_SYSTEM,
+        &[MaybeLoadedMaterial::new(material_type, text_id)],
         &material_type,
         material_test_id_holder,
-    );
+    )
+    .with_material_definition_path(material_definition_path);
+    if let Some(teardown_system) = T::TEARDOWN_SYSTEM {
+        material_test = material_test.with_teardown_system(teardown_system);
+    }
+    let material_test = &material_test;
     Engine::spawn(bundle!(material_test));
-    Engine::spawn(bundle!(&MaterialTextAsset::new(pending_text.id())));
 
-    (pending_text.id(), material_test.id())
+    Ok((text_id, material_test.id()))
+}
+
+/// The id of a texture loaded by `materials_setup`, or an error if it hasn't finished loading
+pub fn require_texture(gpu_interface: &GpuInterface, texture_path: &str) -> Result<TextureId> {
+    gpu_interface
+        .texture_asset_manager
+        .get_texture_by_path(&texture_path.into())
+        .map(|texture| texture.id())
+        .ok_or_else(|| TestModuleError::AssetNotLoaded {
+            asset_path: texture_path.to_string(),
+            reason: "the texture was not found in the texture asset manager".to_string(),
+        })
+}
+
+/// The first material of `material_test`, which is the one every single material test uses
+pub fn require_material<'a>(
+    gpu_interface: &'a GpuInterface,
+    material_test: &MaterialTest,
+) -> Result<(MaterialId, &'a Material)> {
+    let Some(Some(material_id)) = material_test.material_id_iter().next() else {
+        return Err(TestModuleError::MaterialMissing {
+            test_name: material_test.name().to_string(),
+        });
+    };
+    let material = gpu_interface
+        .material_manager
+        .get_material(material_id)
+        .ok_or_else(|| TestModuleError::AssetNotLoaded {
+            asset_path: format!("material {material_id:?} of {}", material_test.name()),
+            reason: "the material was not found in the material manager".to_string(),
+        })?;
+
+    Ok((material_id, material))
 }
@@ -0,0 +1,137 @@
+//! Backgrounds drawn behind material tests, chosen per test. Alpha sensitive shaders like
+//! outlines, dissolves and drop shadows read very differently on dark, light, busy and
+//! photographic backgrounds, so the background key cycles through solid colors, a generated
+//! checkerboard and a photographic texture.
+
+/// The solid backgrounds' names and colors
+pub const BACKGROUND_COLORS: [(&str, [f32; 4]); 3] = [
+    ("black", [0., 0., 0., 1.]),
+    ("gray", [0.5, 0.5, 0.5, 1.]),
+    ("white", [1., 1., 1., 1.]),
+];
+/// The solid backgrounds tint this texture
+pub const BACKGROUND_SOLID_TEXTURE_PATH: &str = "textures/white.png";
+/// The busiest photographic texture the module ships
+pub const BACKGROUND_PHOTO_TEXTURE_PATH: &str = "textures/star_map_with_mask.png";
+pub const BACKGROUND_CHECKER_TEXTURE_PATH: &str = "procedural/background_checker";
+/// The checkerboard texture is this many pixels wide and high
+pub const BACKGROUND_CHECKER_SIZE: usize = 256;
+/// Pixels per checkerboard cell
+const BACKGROUND_CHECKER_CELL_SIZE: usize = 16;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Background {
+    /// The test draws over the clear color
+    #[default]
+    None,
+    /// An index into [`BACKGROUND_COLORS`]
+    Solid(usize),
+    Checker,
+    Photo,
+}
+
+impl Background {
+    pub fn next(self) -> Self {
+        match self {
+            Background::None => Background::Solid(0),
+            Background::Solid(index) if index + 1 < BACKGROUND_COLORS.len() => {
+                Background::Solid(index + 1)
+            }
+            Background::Solid(_) => Background::Checker,
+            Background::Checker => Background::Photo,
+            Background::Photo => Background::None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Background::None => "none",
+            Background::Solid(index) => BACKGROUND_COLORS[index].0,
+            Background::Checker => "checker",
+            Background::Photo => "photo",
+        }
+    }
+}
+
+/// A `size` by `size` RGBA8 checkerboard of light and dark gray, like image editors draw behind
+/// transparency
+pub fn checker_pixels(size: usize) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(size * size * 4);
+    for y in 0..size {
+        for x in 0..size {
+            let light =
+                (x / BACKGROUND_CHECKER_CELL_SIZE + y / BACKGROUND_CHECKER_CELL_SIZE) % 2 == 0;
+            let value = if light { 0xcc } else { 0x66 };
+            pixels.extend([value, value, value, 0xff]);
+        }
+    }
+    pixels
+}
+
+/// The background chosen for each test, tests that were never cycled have none
+#[derive(Debug, Default)]
+pub struct TestBackgrounds {
+    backgrounds: Vec<(String, Background)>,
+}
+
+impl TestBackgrounds {
+    pub fn get(&self, test_name: &str) -> Background {
+        self.backgrounds
+            .iter()
+            .find(|(name, _)| name == test_name)
+            .map_or(Background::None, |(_, background)| *background)
+    }
+
+    /// Moves `test_name` on to the next background and returns it
+    pub fn cycle(&mut self, test_name: &str) -> Background {
+        let background = self.get(test_name).next();
+        match self
+            .backgrounds
+            .iter_mut()
+            .find(|(name, _)| name == test_name)
+        {
+            Some((_, test_background)) => *test_background = background,
+            None => self.backgrounds.push((test_name.to_string(), background)),
+        }
+        background
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::background::{
+        BACKGROUND_CHECKER_SIZE, BACKGROUND_COLORS, Background, TestBackgrounds, checker_pixels,
+    };
+
+    #[test]
+    fn backgrounds_cycle_per_test() {
+        let mut background = Background::None;
+        let mut names = vec![];
+        for _ in 0..BACKGROUND_COLORS.len() + 3 {
+            background = background.next();
+            names.push(background.name());
+        }
+        assert_eq!(
+            names,
+            ["black", "gray", "white", "checker", "photo", "none"]
+        );
+
+        let mut test_backgrounds = TestBackgrounds::default();
+        assert_eq!(test_backgrounds.cycle("warp"), Background::Solid(0));
+        assert_eq!(test_backgrounds.cycle("warp"), Background::Solid(1));
+        assert_eq!(test_backgrounds.get("warp"), Background::Solid(1));
+        assert_eq!(test_backgrounds.get("fire"), Background::None);
+    }
+
+    #[test]
+    fn checker_alternates_cells() {
+        let pixels = checker_pixels(BACKGROUND_CHECKER_SIZE);
+        assert_eq!(
+            pixels.len(),
+            BACKGROUND_CHECKER_SIZE * BACKGROUND_CHECKER_SIZE * 4
+        );
+        assert_eq!(pixels[..4], [0xcc, 0xcc, 0xcc, 0xff]);
+        // The pixel right of the first cell is dark
+        assert_eq!(pixels[16 * 4], 0x66);
+    }
+}
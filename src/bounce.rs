@@ -0,0 +1,70 @@
+//! Helpers for keeping moving sprites inside the screen.
+
+use void_public::{Vec2, Vec3};
+
+/// Reflects `direction` off any screen edge that `position` has crossed while still moving
+/// outwards. `half_extents` is half of the screen size, since the screen is centered on the origin.
+pub fn reflect_at_screen_edges(position: Vec3, direction: Vec3, half_extents: Vec2) -> Vec3 {
+    let mut direction = direction;
+
+    if position.x < -half_extents.x && direction.x < 0.
+        || position.x > half_extents.x && direction.x > 0.
+    {
+        direction.x = -direction.x;
+    }
+
+    if position.y < -half_extents.y && direction.y < 0.
+        || position.y > half_extents.y && direction.y > 0.
+    {
+        direction.y = -direction.y;
+    }
+
+    direction
+}
+
+#[cfg(test)]
+mod test {
+    use void_public::{Vec2, Vec3};
+
+    use crate::bounce::reflect_at_screen_edges;
+
+    #[test]
+    fn reflects_at_all_four_edges() {
+        let half_extents = Vec2::new(100., 50.);
+
+        let right = reflect_at_screen_edges(
+            Vec3::new(101., 0., 0.),
+            Vec3::new(1., -1., 0.),
+            half_extents,
+        );
+        assert_eq!(right, Vec3::new(-1., -1., 0.));
+
+        let left = reflect_at_screen_edges(
+            Vec3::new(-101., 0., 0.),
+            Vec3::new(-1., 1., 0.),
+            half_extents,
+        );
+        assert_eq!(left, Vec3::new(1., 1., 0.));
+
+        let top =
+            reflect_at_screen_edges(Vec3::new(0., 51., 0.), Vec3::new(1., 1., 0.), half_extents);
+        assert_eq!(top, Vec3::new(1., -1., 0.));
+
+        let bottom = reflect_at_screen_edges(
+            Vec3::new(0., -51., 0.),
+            Vec3::new(-1., -1., 0.),
+            half_extents,
+        );
+        assert_eq!(bottom, Vec3::new(-1., 1., 0.));
+    }
+
+    #[test]
+    fn does_not_reflect_when_already_moving_back_inside() {
+        let direction = reflect_at_screen_edges(
+            Vec3::new(101., -51., 0.),
+            Vec3::new(-1., 1., 0.),
+            Vec2::new(100., 50.),
+        );
+        assert_eq!(direction, Vec3::new(-1., 1., 0.));
+    }
+}
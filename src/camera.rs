@@ -0,0 +1,77 @@
+//! Pan and zoom state for inspecting material tests up close. Positions are in world space, where
+//! the origin is the center of the screen and y goes up.
+
+use void_public::Vec2;
+
+pub const MIN_CAMERA_ZOOM: f32 = 0.25;
+pub const MAX_CAMERA_ZOOM: f32 = 8.;
+/// How much a single scroll wheel step zooms in or out
+pub const CAMERA_ZOOM_STEP: f32 = 1.1;
+
+/// `offset` is the world position at the center of the screen, `zoom` above 1 magnifies
+#[derive(Clone, Copy, Debug, PartialEq, snapshot::Deserialize, snapshot::Serialize)]
+pub struct CameraView {
+    pub offset: Vec2,
+    pub zoom: f32,
+}
+
+impl Default for CameraView {
+    fn default() -> Self {
+        Self {
+            offset: Vec2::ZERO,
+            zoom: 1.,
+        }
+    }
+}
+
+impl CameraView {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Moves the view so the point under the cursor follows it, `cursor_delta` is the on screen
+    /// cursor movement in world units
+    pub fn pan(&mut self, cursor_delta: Vec2) {
+        self.offset -= cursor_delta / self.zoom;
+    }
+
+    /// Zooms by `wheel_steps` while keeping the point under `cursor` in place, `cursor` is the on
+    /// screen cursor position in world units
+    pub fn zoom_at(&mut self, cursor: Vec2, wheel_steps: f32) {
+        let cursor_in_view = self.offset + cursor / self.zoom;
+        self.zoom = (self.zoom * CAMERA_ZOOM_STEP.powf(wheel_steps))
+            .clamp(MIN_CAMERA_ZOOM, MAX_CAMERA_ZOOM);
+        self.offset = cursor_in_view - cursor / self.zoom;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use void_public::Vec2;
+
+    use crate::camera::{CameraView, MAX_CAMERA_ZOOM};
+
+    #[test]
+    fn zoom_keeps_the_point_under_the_cursor() {
+        let mut camera_view = CameraView::default();
+        let cursor = Vec2::new(100., 50.);
+        camera_view.zoom_at(cursor, 4.);
+        assert!(camera_view.zoom > 1.);
+        let cursor_in_view = camera_view.offset + cursor / camera_view.zoom;
+        assert!((cursor_in_view - cursor).length() < 0.001);
+
+        camera_view.zoom_at(cursor, 1000.);
+        assert_eq!(camera_view.zoom, MAX_CAMERA_ZOOM);
+    }
+
+    #[test]
+    fn pan_follows_the_cursor() {
+        let mut camera_view = CameraView {
+            offset: Vec2::ZERO,
+            zoom: 2.,
+        };
+        camera_view.pan(Vec2::new(10., -20.));
+        assert_eq!(camera_view.offset, Vec2::new(-5., 10.));
+        assert!(!camera_view.is_default());
+    }
+}
@@ -0,0 +1,91 @@
+//! Color vision deficiency simulation, a postprocess over everything on screen including the menus.
+//! F8 cycles it from any view through protanopia, deuteranopia and tritanopia, so shader examples
+//! and menu colors can be checked for staying distinguishable. The matrices are Machado et al.'s
+//! (2009) at full severity and are applied to the scene color as sampled.
+
+pub const COLOR_BLIND_MATERIAL_NAME: &str = "color_blind";
+pub const COLOR_BLIND_MATERIAL_PATH: &str = "toml_materials/post_processing/color_blind.toml";
+/// The material's uniforms holding the simulation matrix's rows
+pub const COLOR_BLIND_ROW_UNIFORMS: [&str; 3] = ["row_0", "row_1", "row_2"];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ColorBlindMode {
+    #[default]
+    Off,
+    /// No long wavelength cones, red looks dark and close to green
+    Protanopia,
+    /// No medium wavelength cones, red and green look alike
+    Deuteranopia,
+    /// No short wavelength cones, blue looks like green and yellow like pink
+    Tritanopia,
+}
+
+impl ColorBlindMode {
+    pub fn next(self) -> Self {
+        match self {
+            ColorBlindMode::Off => ColorBlindMode::Protanopia,
+            ColorBlindMode::Protanopia => ColorBlindMode::Deuteranopia,
+            ColorBlindMode::Deuteranopia => ColorBlindMode::Tritanopia,
+            ColorBlindMode::Tritanopia => ColorBlindMode::Off,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ColorBlindMode::Off => "off",
+            ColorBlindMode::Protanopia => "protanopia",
+            ColorBlindMode::Deuteranopia => "deuteranopia",
+            ColorBlindMode::Tritanopia => "tritanopia",
+        }
+    }
+
+    /// The rows of the matrix turning an RGB color into how it's seen, none when off
+    pub fn matrix(self) -> Option<[[f32; 3]; 3]> {
+        match self {
+            ColorBlindMode::Off => None,
+            ColorBlindMode::Protanopia => Some([
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ]),
+            ColorBlindMode::Deuteranopia => Some([
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ]),
+            ColorBlindMode::Tritanopia => Some([
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::color_blind::ColorBlindMode;
+
+    #[test]
+    fn modes_cycle_and_keep_grays() {
+        let mut color_blind_mode = ColorBlindMode::Off;
+        let mut names = vec![];
+        for _ in 0..4 {
+            color_blind_mode = color_blind_mode.next();
+            names.push(color_blind_mode.name());
+        }
+        assert_eq!(names, ["protanopia", "deuteranopia", "tritanopia", "off"]);
+        assert_eq!(ColorBlindMode::Off.matrix(), None);
+
+        // Every row sums to one, so white and grays are seen unchanged
+        for color_blind_mode in [
+            ColorBlindMode::Protanopia,
+            ColorBlindMode::Deuteranopia,
+            ColorBlindMode::Tritanopia,
+        ] {
+            for row in color_blind_mode.matrix().unwrap() {
+                assert!((row.iter().sum::<f32>() - 1.).abs() < 1e-3);
+            }
+        }
+    }
+}
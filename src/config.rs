@@ -0,0 +1,170 @@
+//! Reads startup options from `shader_test_module.toml` in the working directory, so demo
+//! machines can be set up without a command line, e.g.
+//!
+//! ```toml
+//! start_test = "warp"
+//! seed = 42
+//! perf_overlay = false
+//! vsync = true
+//! playlist = ["warp:10", "starfield:15"]
+//! ```
+//!
+//! The options are turned into command line args and merged with the real ones, which take
+//! precedence.
+
+use std::{fs, path::Path};
+
+use crate::{
+    flat_toml::{FlatTomlEntry, flat_toml_entries, parse_bool, parse_string, parse_string_list},
+    perf_overlay::PERF_OVERLAY_ARG,
+    playlist::parse_playlist_arg,
+    rng::SEED_ARG,
+};
+
+pub const CONFIG_PATH: &str = "shader_test_module.toml";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    pub start_test: Option<String>,
+    pub seed: Option<u64>,
+    pub perf_overlay: Option<bool>,
+    /// Only a hint, the engine picks the present mode
+    pub vsync: Option<bool>,
+    /// `<test name>:<seconds>` entries, like the playlist args
+    pub playlist: Vec<String>,
+}
+
+impl Config {
+    /// Returns the config along with a warning for every line that couldn't be used, which is
+    /// skipped
+    pub fn from_toml_string(toml_string: &str) -> (Self, Vec<String>) {
+        let mut config = Self::default();
+        let mut warnings = Vec::new();
+        for entry in flat_toml_entries(toml_string) {
+            let FlatTomlEntry {
+                key: name, value, ..
+            } = match entry {
+                Ok(entry) => entry,
+                Err(line) => {
+                    warnings.push(format!("Malformed option: {line}"));
+                    continue;
+                }
+            };
+            let parsed = match name {
+                "start_test" => parse_string(value).map(|start_test| {
+                    config.start_test = Some(start_test);
+                }),
+                "seed" => value.parse().ok().map(|seed| {
+                    config.seed = Some(seed);
+                }),
+                "perf_overlay" => parse_bool(value).map(|perf_overlay| {
+                    config.perf_overlay = Some(perf_overlay);
+                }),
+                "vsync" => parse_bool(value).map(|vsync| {
+                    config.vsync = Some(vsync);
+                }),
+                "playlist" => parse_string_list(value).map(|playlist| {
+                    let (valid, invalid): (Vec<_>, Vec<_>) = playlist
+                        .into_iter()
+                        .partition(|entry| parse_playlist_arg(entry).is_some());
+                    warnings.extend(invalid.into_iter().map(|entry| {
+                        format!("Playlist entry {entry} must be <test name>:<seconds>")
+                    }));
+                    config.playlist = valid;
+                }),
+                _ => {
+                    warnings.push(format!("Unknown option {name}"));
+                    continue;
+                }
+            };
+            if parsed.is_none() {
+                warnings.push(format!("Invalid value for {name}: {value}"));
+            }
+        }
+        (config, warnings)
+    }
+
+    /// `None` when there is no config file, which is the usual case
+    pub fn read<P: AsRef<Path>>(path: P) -> Option<(Self, Vec<String>)> {
+        Some(Self::from_toml_string(&fs::read_to_string(path).ok()?))
+    }
+
+    /// The options as the command line args they stand for. The vsync hint has no arg.
+    pub fn to_args(&self) -> Vec<String> {
+        self.start_test
+            .iter()
+            .cloned()
+            .chain(self.playlist.iter().cloned())
+            .chain(self.seed.map(|seed| format!("{SEED_ARG}{seed}")))
+            .chain(self.perf_overlay.map(|perf_overlay| {
+                format!(
+                    "{PERF_OVERLAY_ARG}{}",
+                    if perf_overlay { "on" } else { "off" }
+                )
+            }))
+            .collect()
+    }
+}
+
+/// Appends the config's args to `args`, the command line including the executable. Args are
+/// looked up first match first, so the command line's options win. The config's start test and
+/// playlist are left out when the command line names tests itself.
+pub fn merged_args(mut args: Vec<String>, config: &Config) -> Vec<String> {
+    let names_tests = args.iter().skip(1).any(|arg| !arg.starts_with("--"));
+    args.extend(
+        config
+            .to_args()
+            .into_iter()
+            .filter(|arg| arg.starts_with("--") || !names_tests),
+    );
+    args
+}
+
+#[cfg(test)]
+mod test {
+    use crate::config::{Config, merged_args};
+
+    #[test]
+    fn config_options_and_warnings() {
+        let (config, warnings) = Config::from_toml_string(
+            r#"
+# demo machine
+start_test = "warp"
+seed = 42
+perf_overlay = false
+vsync = yes
+playlist = ["warp:10", "starfield", "fire:5"]
+fullscreen = true
+"#,
+        );
+        assert_eq!(
+            config,
+            Config {
+                start_test: Some("warp".to_string()),
+                seed: Some(42),
+                perf_overlay: Some(false),
+                vsync: None,
+                playlist: vec!["warp:10".to_string(), "fire:5".to_string()],
+            }
+        );
+        assert_eq!(warnings.len(), 3);
+    }
+
+    #[test]
+    fn command_line_args_take_precedence() {
+        let config = Config {
+            start_test: Some("warp".to_string()),
+            seed: Some(42),
+            ..Default::default()
+        };
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            merged_args(args(&["shader_test", "--seed=7"]), &config),
+            args(&["shader_test", "--seed=7", "warp", "--seed=42"])
+        );
+        assert_eq!(
+            merged_args(args(&["shader_test", "fire"]), &config),
+            args(&["shader_test", "fire", "--seed=42"])
+        );
+    }
+}
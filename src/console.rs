@@ -0,0 +1,132 @@
+//! Captures warnings and errors logged through `log` so they can be shown on screen, errors like
+//! "Could not find material test" are otherwise only visible with a terminal attached.
+//! [`init`] installs a logger that echoes every record to stderr and keeps the most recent
+//! warnings and errors in a ring buffer.
+
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, MutexGuard},
+};
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// How many warnings and errors are kept
+pub const CONSOLE_CAPACITY: usize = 64;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsoleLine {
+    pub level: Level,
+    pub message: String,
+}
+
+impl ConsoleLine {
+    pub fn to_overlay_string(&self) -> String {
+        format!("[{}] {}", self.level, self.message)
+    }
+}
+
+/// A ring buffer of the most recent [`ConsoleLine`]s. `generation` goes up with every push, so the
+/// overlay only has to rebuild its text when something new was logged.
+#[derive(Debug)]
+pub struct ConsoleBuffer {
+    lines: VecDeque<ConsoleLine>,
+    capacity: usize,
+    generation: u64,
+}
+
+impl ConsoleBuffer {
+    pub const fn new(capacity: usize) -> Self {
+        Self {
+            lines: VecDeque::new(),
+            capacity,
+            generation: 0,
+        }
+    }
+
+    pub fn push(&mut self, console_line: ConsoleLine) {
+        if self.lines.len() == self.capacity {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(console_line);
+        self.generation += 1;
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// The newest `count` lines, from oldest to newest
+    pub fn last(&self, count: usize) -> impl Iterator<Item = &ConsoleLine> {
+        self.lines
+            .iter()
+            .skip(self.lines.len().saturating_sub(count))
+    }
+}
+
+static CONSOLE_BUFFER: Mutex<ConsoleBuffer> = Mutex::new(ConsoleBuffer::new(CONSOLE_CAPACITY));
+static CONSOLE_LOGGER: ConsoleLogger = ConsoleLogger;
+
+struct ConsoleLogger;
+
+impl Log for ConsoleLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Info
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+        if record.level() <= Level::Warn {
+            console_buffer().push(ConsoleLine {
+                level: record.level(),
+                message: record.args().to_string(),
+            });
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Only one logger can be installed, so if the host already installed one nothing is captured
+pub fn init() {
+    match log::set_logger(&CONSOLE_LOGGER) {
+        Ok(()) => log::set_max_level(LevelFilter::Info),
+        Err(_) => eprintln!("A logger is already installed, the console overlay will stay empty"),
+    }
+}
+
+/// A panic while holding the lock can only have interrupted a push, so the buffer is still usable
+pub fn console_buffer() -> MutexGuard<'static, ConsoleBuffer> {
+    CONSOLE_BUFFER
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(test)]
+mod test {
+    use log::Level;
+
+    use crate::console::{ConsoleBuffer, ConsoleLine};
+
+    #[test]
+    fn console_buffer_keeps_the_newest_lines() {
+        let mut console_buffer = ConsoleBuffer::new(3);
+        for index in 0..5 {
+            console_buffer.push(ConsoleLine {
+                level: Level::Warn,
+                message: format!("warning {index}"),
+            });
+        }
+        assert_eq!(console_buffer.generation(), 5);
+        assert_eq!(
+            console_buffer
+                .last(2)
+                .map(ConsoleLine::to_overlay_string)
+                .collect::<Vec<_>>(),
+            vec!["[WARN] warning 3", "[WARN] warning 4"]
+        );
+        assert_eq!(console_buffer.last(10).count(), 3);
+    }
+}
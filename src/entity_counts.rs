@@ -0,0 +1,87 @@
+//! An overlay counting live entities by component and the assets loaded at startup, to catch
+//! entities a view change leaves behind. F9 toggles it in every view.
+
+/// The overlay's text lines
+pub const ENTITY_COUNTS_LINES: usize = 6;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EntityCounts {
+    pub material_test_objects: usize,
+    pub text_renders: usize,
+    pub texture_renders: usize,
+    pub material_parameters: usize,
+}
+
+/// The assets registered while loading, the asset managers can't be asked how many they hold
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LoadedAssetCounts {
+    pub textures: usize,
+    pub texts: usize,
+    pub pipelines: usize,
+}
+
+/// The overlay text. `flag_leftovers` marks [`EntityCounts::material_test_objects`] as leaked when
+/// there are any, it should only be set once a view without a test has settled.
+pub fn entity_counts_lines(
+    entity_counts: &EntityCounts,
+    loaded_asset_counts: Option<&LoadedAssetCounts>,
+    flag_leftovers: bool,
+) -> Vec<String> {
+    let leftover_flag = if flag_leftovers && entity_counts.material_test_objects > 0 {
+        "  <- not despawned"
+    } else {
+        ""
+    };
+    vec![
+        "Entities:".to_string(),
+        format!(
+            "  material test objects {}{leftover_flag}",
+            entity_counts.material_test_objects
+        ),
+        format!("  text renders {}", entity_counts.text_renders),
+        format!("  texture renders {}", entity_counts.texture_renders),
+        format!(
+            "  material parameters {}",
+            entity_counts.material_parameters
+        ),
+        match loaded_asset_counts {
+            Some(loaded_asset_counts) => format!(
+                "Loaded: {} textures  {} texts  {} pipelines",
+                loaded_asset_counts.textures,
+                loaded_asset_counts.texts,
+                loaded_asset_counts.pipelines
+            ),
+            None => "Loaded: still loading".to_string(),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use crate::entity_counts::{
+        ENTITY_COUNTS_LINES, EntityCounts, LoadedAssetCounts, entity_counts_lines,
+    };
+
+    #[test]
+    fn leftover_test_objects_are_flagged() {
+        let entity_counts = EntityCounts {
+            material_test_objects: 3,
+            text_renders: 40,
+            texture_renders: 2,
+            material_parameters: 2,
+        };
+        let loaded_asset_counts = LoadedAssetCounts {
+            textures: 20,
+            texts: 31,
+            pipelines: 25,
+        };
+        let lines = entity_counts_lines(&entity_counts, Some(&loaded_asset_counts), true);
+        assert_eq!(lines.len(), ENTITY_COUNTS_LINES);
+        assert_eq!(lines[1], "  material test objects 3  <- not despawned");
+        assert_eq!(lines[5], "Loaded: 20 textures  31 texts  25 pipelines");
+
+        let lines = entity_counts_lines(&entity_counts, None, false);
+        assert_eq!(lines[1], "  material test objects 3");
+        assert_eq!(lines[5], "Loaded: still loading");
+    }
+}
@@ -0,0 +1,155 @@
+//! Reads the subset of TOML the module's own files are written in: `[table]` headers and single
+//! line `key = value` pairs, with blank lines and `#` comments skipped. Presets, uniform ranges,
+//! locales, key bindings and the startup config are all read with it, so they agree on what a
+//! line means.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlatTomlEntry<'a> {
+    /// Empty for the keys above the first table header
+    pub table: &'a str,
+    pub key: &'a str,
+    /// Trimmed, but otherwise unparsed
+    pub value: &'a str,
+}
+
+/// The `key = value` pairs of `toml_string` in order. Lines that are neither a pair nor a table
+/// header are returned as `Err` with the line, so each file decides whether to skip them or
+/// warn about them.
+pub fn flat_toml_entries(
+    toml_string: &str,
+) -> impl Iterator<Item = Result<FlatTomlEntry<'_>, &str>> {
+    let mut table = "";
+    toml_string
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(move |line| {
+            if let Some(table_name) = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+            {
+                table = table_name.trim();
+                return None;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Some(Err(line));
+            };
+            let key = key.trim();
+            if key.is_empty() {
+                return Some(Err(line));
+            }
+            Some(Ok(FlatTomlEntry {
+                table,
+                key,
+                value: value.trim(),
+            }))
+        })
+}
+
+/// A double quoted string, with `\n`, `\t` and `\<character>` escapes resolved
+pub fn parse_string(value: &str) -> Option<String> {
+    value.strip_prefix('"')?.strip_suffix('"').map(unescape)
+}
+
+pub fn parse_bool(value: &str) -> Option<bool> {
+    match value {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// The trimmed items of a `[a, b, c]` list, left unparsed. A trailing comma is allowed.
+pub fn parse_list(value: &str) -> Option<Vec<&str>> {
+    Some(
+        value
+            .strip_prefix('[')?
+            .strip_suffix(']')?
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .collect(),
+    )
+}
+
+pub fn parse_f32_list(value: &str) -> Option<Vec<f32>> {
+    parse_list(value)?
+        .into_iter()
+        .map(|item| item.parse().ok())
+        .collect()
+}
+
+pub fn parse_string_list(value: &str) -> Option<Vec<String>> {
+    parse_list(value)?.into_iter().map(parse_string).collect()
+}
+
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut characters = value.chars();
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            unescaped.push(character);
+            continue;
+        }
+        match characters.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some(escaped) => unescaped.push(escaped),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod test {
+    use crate::flat_toml::{
+        FlatTomlEntry, flat_toml_entries, parse_f32_list, parse_string, parse_string_list,
+    };
+
+    #[test]
+    fn flat_toml_entries_track_tables() {
+        let toml_string = r#"
+# comment
+seed = 42
+= 1
+[menu]
+title = "Shader tests"
+broken line
+"#;
+        assert_eq!(
+            flat_toml_entries(toml_string).collect::<Vec<_>>(),
+            vec![
+                Ok(FlatTomlEntry {
+                    table: "",
+                    key: "seed",
+                    value: "42",
+                }),
+                Err("= 1"),
+                Ok(FlatTomlEntry {
+                    table: "menu",
+                    key: "title",
+                    value: "\"Shader tests\"",
+                }),
+                Err("broken line"),
+            ]
+        );
+    }
+
+    #[test]
+    fn flat_toml_values() {
+        assert_eq!(
+            parse_string(r#""line\nbreak \"quoted\"""#),
+            Some("line\nbreak \"quoted\"".to_string())
+        );
+        assert_eq!(parse_string("unquoted"), None);
+        assert_eq!(parse_f32_list("[0, 1.5, -2,]"), Some(vec![0., 1.5, -2.]));
+        assert_eq!(parse_f32_list("[0, fast]"), None);
+        assert_eq!(parse_f32_list("0.5"), None);
+        assert_eq!(
+            parse_string_list(r#"["warp:10", "starfield:15"]"#),
+            Some(vec!["warp:10".to_string(), "starfield:15".to_string()])
+        );
+        assert_eq!(parse_string_list("[warp]"), None);
+    }
+}
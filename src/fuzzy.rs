@@ -0,0 +1,70 @@
+//! Fuzzy string matching, used to suggest a registered test name when a command line argument
+//! doesn't match any test exactly.
+
+/// The Levenshtein distance between `left` and `right`, counted in chars
+pub fn edit_distance(left: &str, right: &str) -> usize {
+    let right_chars = right.chars().collect::<Vec<_>>();
+    let mut previous_row = (0..=right_chars.len()).collect::<Vec<_>>();
+    let mut current_row = vec![0; right_chars.len() + 1];
+
+    for (left_index, left_char) in left.chars().enumerate() {
+        current_row[0] = left_index + 1;
+        for (right_index, right_char) in right_chars.iter().enumerate() {
+            let substitution_cost = usize::from(left_char != *right_char);
+            current_row[right_index + 1] = (previous_row[right_index] + substitution_cost)
+                .min(previous_row[right_index + 1] + 1)
+                .min(current_row[right_index] + 1);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    previous_row[right_chars.len()]
+}
+
+/// Returns the candidate closest to `target`, as long as it is close enough to plausibly be a
+/// typo. Candidates that start with `target` are preferred so abbreviations are suggested too.
+pub fn closest_match<'a>(
+    target: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max_distance = (target.chars().count() / 3).max(2);
+    candidates
+        .into_iter()
+        .map(|candidate| {
+            let distance = if candidate.starts_with(target) {
+                0
+            } else {
+                edit_distance(target, candidate)
+            };
+            (distance, candidate)
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::fuzzy::{closest_match, edit_distance};
+
+    #[test]
+    fn edit_distance_counts_single_char_edits() {
+        assert_eq!(edit_distance("", "warp"), 4);
+        assert_eq!(edit_distance("warp", "warp"), 0);
+        assert_eq!(edit_distance("wrap", "warp"), 2);
+        assert_eq!(edit_distance("starfeld", "starfield"), 1);
+        assert_eq!(edit_distance("fire", "file"), 1);
+    }
+
+    #[test]
+    fn closest_match_suggests_typos_and_prefixes() {
+        let candidates = ["starfield", "stress_test", "immediate_mode_test", "warp"];
+        assert_eq!(closest_match("starfeld", candidates), Some("starfield"));
+        assert_eq!(
+            closest_match("immediate", candidates),
+            Some("immediate_mode_test")
+        );
+        assert_eq!(closest_match("wrp", candidates), Some("warp"));
+        assert_eq!(closest_match("color_replacement", candidates), None);
+    }
+}
@@ -0,0 +1,466 @@
+//! Maps the module's logical [`InputAction`]s to the keys that trigger them. Bindings are read
+//! from [`KEYBINDINGS_PATH`], a TOML file of `action = ["Key", ...]` lines such as
+//! `navigate_left = ["ArrowLeft", "KeyA"]`, with key names matching [`KeyCode`]'s variants. The
+//! shipped file is compiled in as well, so actions missing from the file on disk keep their
+//! default keys. The help overlay lists the keys from the same bindings.
+
+use std::{fs, path::Path, sync::OnceLock};
+
+use void_public::event::input::KeyCode;
+
+use crate::flat_toml::{FlatTomlEntry, flat_toml_entries, parse_list};
+
+pub const KEYBINDINGS_PATH: &str = "assets/keybindings.toml";
+/// The default bindings, used for every action the loaded file doesn't bind
+const DEFAULT_KEYBINDINGS_TOML: &str = include_str!("../assets/keybindings.toml");
+
+static INPUT_BINDINGS: OnceLock<InputBindings> = OnceLock::new();
+static DEFAULT_INPUT_BINDINGS: OnceLock<InputBindings> = OnceLock::new();
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InputAction {
+    NavigateLeft,
+    NavigateRight,
+    NavigateUp,
+    NavigateDown,
+    Select,
+    Back,
+    Quit,
+    PageUp,
+    PageDown,
+    FilterToggle,
+    FavoriteToggle,
+    TagNext,
+    MaterialToggle,
+    ConsoleToggle,
+    HelpToggle,
+    TogglePerf,
+    BurstToggle,
+    PauseToggle,
+    SingleStep,
+    TimeScaleDown,
+    TimeScaleUp,
+    SlowMotionToggle,
+    PostStackReorder,
+    PostprocessInspectorToggle,
+    MipLevelToggle,
+    RandomizeUniforms,
+    UniformEntryNext,
+    CameraReset,
+    ShaderSourceToggle,
+    UniformDiffToggle,
+    UniformReset,
+    EntityCountsToggle,
+    SplitViewToggle,
+    BackgroundCycle,
+    ColorBlindCycle,
+    DayNightPauseToggle,
+}
+
+const INPUT_ACTION_COUNT: usize = 36;
+
+impl InputAction {
+    pub const ALL: [InputAction; INPUT_ACTION_COUNT] = [
+        InputAction::NavigateLeft,
+        InputAction::NavigateRight,
+        InputAction::NavigateUp,
+        InputAction::NavigateDown,
+        InputAction::Select,
+        InputAction::Back,
+        InputAction::Quit,
+        InputAction::PageUp,
+        InputAction::PageDown,
+        InputAction::FilterToggle,
+        InputAction::FavoriteToggle,
+        InputAction::TagNext,
+        InputAction::MaterialToggle,
+        InputAction::ConsoleToggle,
+        InputAction::HelpToggle,
+        InputAction::TogglePerf,
+        InputAction::BurstToggle,
+        InputAction::PauseToggle,
+        InputAction::SingleStep,
+        InputAction::TimeScaleDown,
+        InputAction::TimeScaleUp,
+        InputAction::SlowMotionToggle,
+        InputAction::PostStackReorder,
+        InputAction::PostprocessInspectorToggle,
+        InputAction::MipLevelToggle,
+        InputAction::RandomizeUniforms,
+        InputAction::UniformEntryNext,
+        InputAction::CameraReset,
+        InputAction::ShaderSourceToggle,
+        InputAction::UniformDiffToggle,
+        InputAction::UniformReset,
+        InputAction::EntityCountsToggle,
+        InputAction::SplitViewToggle,
+        InputAction::BackgroundCycle,
+        InputAction::ColorBlindCycle,
+        InputAction::DayNightPauseToggle,
+    ];
+
+    /// The actions available in every test, listed below the test's own controls in the help
+    /// overlay
+    pub const HELP_ACTIONS: [InputAction; 14] = [
+        InputAction::PauseToggle,
+        InputAction::SingleStep,
+        InputAction::TimeScaleDown,
+        InputAction::TimeScaleUp,
+        InputAction::SlowMotionToggle,
+        InputAction::RandomizeUniforms,
+        InputAction::TogglePerf,
+        InputAction::ShaderSourceToggle,
+        InputAction::UniformDiffToggle,
+        InputAction::SplitViewToggle,
+        InputAction::BackgroundCycle,
+        InputAction::ColorBlindCycle,
+        InputAction::ConsoleToggle,
+        InputAction::HelpToggle,
+    ];
+
+    /// The action's key in the bindings file
+    pub fn name(self) -> &'static str {
+        match self {
+            InputAction::NavigateLeft => "navigate_left",
+            InputAction::NavigateRight => "navigate_right",
+            InputAction::NavigateUp => "navigate_up",
+            InputAction::NavigateDown => "navigate_down",
+            InputAction::Select => "select",
+            InputAction::Back => "back",
+            InputAction::Quit => "quit",
+            InputAction::PageUp => "page_up",
+            InputAction::PageDown => "page_down",
+            InputAction::FilterToggle => "filter_toggle",
+            InputAction::FavoriteToggle => "favorite_toggle",
+            InputAction::TagNext => "tag_next",
+            InputAction::MaterialToggle => "material_toggle",
+            InputAction::ConsoleToggle => "console_toggle",
+            InputAction::HelpToggle => "help_toggle",
+            InputAction::TogglePerf => "toggle_perf",
+            InputAction::BurstToggle => "burst_toggle",
+            InputAction::PauseToggle => "pause_toggle",
+            InputAction::SingleStep => "single_step",
+            InputAction::TimeScaleDown => "time_scale_down",
+            InputAction::TimeScaleUp => "time_scale_up",
+            InputAction::SlowMotionToggle => "slow_motion_toggle",
+            InputAction::PostStackReorder => "post_stack_reorder",
+            InputAction::PostprocessInspectorToggle => "postprocess_inspector_toggle",
+            InputAction::MipLevelToggle => "mip_level_toggle",
+            InputAction::RandomizeUniforms => "randomize_uniforms",
+            InputAction::UniformEntryNext => "uniform_entry_next",
+            InputAction::CameraReset => "camera_reset",
+            InputAction::ShaderSourceToggle => "shader_source_toggle",
+            InputAction::UniformDiffToggle => "uniform_diff_toggle",
+            InputAction::UniformReset => "uniform_reset",
+            InputAction::EntityCountsToggle => "entity_counts_toggle",
+            InputAction::SplitViewToggle => "split_view_toggle",
+            InputAction::BackgroundCycle => "background_cycle",
+            InputAction::ColorBlindCycle => "color_blind_cycle",
+            InputAction::DayNightPauseToggle => "day_night_pause_toggle",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        InputAction::ALL
+            .into_iter()
+            .find(|input_action| input_action.name() == name)
+    }
+
+    /// What the action does, as shown in the help overlay
+    pub fn description(self) -> &'static str {
+        match self {
+            InputAction::NavigateLeft => "move left",
+            InputAction::NavigateRight => "move right",
+            InputAction::NavigateUp => "move up",
+            InputAction::NavigateDown => "move down",
+            InputAction::Select => "select",
+            InputAction::Back => "go back",
+            InputAction::Quit => "quit",
+            InputAction::PageUp => "previous page",
+            InputAction::PageDown => "next page",
+            InputAction::FilterToggle => "filter tests",
+            InputAction::FavoriteToggle => "toggle favorite",
+            InputAction::TagNext => "next tag filter",
+            InputAction::MaterialToggle => "toggle material",
+            InputAction::ConsoleToggle => "toggle console",
+            InputAction::HelpToggle => "toggle this help",
+            InputAction::TogglePerf => "toggle perf overlay",
+            InputAction::BurstToggle => "toggle burst mode",
+            InputAction::PauseToggle => "pause",
+            InputAction::SingleStep => "step a frame while paused",
+            InputAction::TimeScaleDown => "halve time scale",
+            InputAction::TimeScaleUp => "double time scale",
+            InputAction::SlowMotionToggle => "toggle slow motion",
+            InputAction::PostStackReorder => "reorder post stack",
+            InputAction::PostprocessInspectorToggle => "toggle postprocess inspector",
+            InputAction::MipLevelToggle => "toggle mip levels",
+            InputAction::RandomizeUniforms => "randomize uniforms, hold to reset",
+            InputAction::UniformEntryNext => "next uniform field",
+            InputAction::CameraReset => "reset camera",
+            InputAction::ShaderSourceToggle => "toggle shader source",
+            InputAction::UniformDiffToggle => "toggle uniform diff",
+            InputAction::UniformReset => "reset uniform, Shift for all",
+            InputAction::EntityCountsToggle => "toggle entity counts",
+            InputAction::SplitViewToggle => "toggle split view, 1-4 step variants",
+            InputAction::BackgroundCycle => "cycle background",
+            InputAction::ColorBlindCycle => "cycle color blindness simulation",
+            InputAction::DayNightPauseToggle => "pause/resume day night cycle",
+        }
+    }
+}
+
+/// The name of each bindable key in the bindings file, and how the help overlay shows it
+const KEY_NAMES: &[(KeyCode, &str, &str)] = &[
+    (KeyCode::ArrowLeft, "ArrowLeft", "Left"),
+    (KeyCode::ArrowRight, "ArrowRight", "Right"),
+    (KeyCode::ArrowUp, "ArrowUp", "Up"),
+    (KeyCode::ArrowDown, "ArrowDown", "Down"),
+    (KeyCode::KeyA, "KeyA", "A"),
+    (KeyCode::KeyB, "KeyB", "B"),
+    (KeyCode::KeyC, "KeyC", "C"),
+    (KeyCode::KeyD, "KeyD", "D"),
+    (KeyCode::KeyE, "KeyE", "E"),
+    (KeyCode::KeyF, "KeyF", "F"),
+    (KeyCode::KeyG, "KeyG", "G"),
+    (KeyCode::KeyH, "KeyH", "H"),
+    (KeyCode::KeyI, "KeyI", "I"),
+    (KeyCode::KeyJ, "KeyJ", "J"),
+    (KeyCode::KeyK, "KeyK", "K"),
+    (KeyCode::KeyL, "KeyL", "L"),
+    (KeyCode::KeyM, "KeyM", "M"),
+    (KeyCode::KeyN, "KeyN", "N"),
+    (KeyCode::KeyO, "KeyO", "O"),
+    (KeyCode::KeyP, "KeyP", "P"),
+    (KeyCode::KeyQ, "KeyQ", "Q"),
+    (KeyCode::KeyR, "KeyR", "R"),
+    (KeyCode::KeyS, "KeyS", "S"),
+    (KeyCode::KeyT, "KeyT", "T"),
+    (KeyCode::KeyU, "KeyU", "U"),
+    (KeyCode::KeyV, "KeyV", "V"),
+    (KeyCode::KeyW, "KeyW", "W"),
+    (KeyCode::KeyX, "KeyX", "X"),
+    (KeyCode::KeyY, "KeyY", "Y"),
+    (KeyCode::KeyZ, "KeyZ", "Z"),
+    (KeyCode::Digit0, "Digit0", "0"),
+    (KeyCode::Digit1, "Digit1", "1"),
+    (KeyCode::Digit2, "Digit2", "2"),
+    (KeyCode::Digit3, "Digit3", "3"),
+    (KeyCode::Digit4, "Digit4", "4"),
+    (KeyCode::Digit5, "Digit5", "5"),
+    (KeyCode::Digit6, "Digit6", "6"),
+    (KeyCode::Digit7, "Digit7", "7"),
+    (KeyCode::Digit8, "Digit8", "8"),
+    (KeyCode::Digit9, "Digit9", "9"),
+    (KeyCode::F1, "F1", "F1"),
+    (KeyCode::F2, "F2", "F2"),
+    (KeyCode::F3, "F3", "F3"),
+    (KeyCode::F4, "F4", "F4"),
+    (KeyCode::F5, "F5", "F5"),
+    (KeyCode::F6, "F6", "F6"),
+    (KeyCode::F7, "F7", "F7"),
+    (KeyCode::F8, "F8", "F8"),
+    (KeyCode::F9, "F9", "F9"),
+    (KeyCode::F10, "F10", "F10"),
+    (KeyCode::F11, "F11", "F11"),
+    (KeyCode::F12, "F12", "F12"),
+    (KeyCode::Enter, "Enter", "Enter"),
+    (KeyCode::Space, "Space", "Space"),
+    (KeyCode::Escape, "Escape", "Escape"),
+    (KeyCode::Backspace, "Backspace", "Backspace"),
+    (KeyCode::Delete, "Delete", "Delete"),
+    (KeyCode::Tab, "Tab", "Tab"),
+    (KeyCode::Home, "Home", "Home"),
+    (KeyCode::PageUp, "PageUp", "PageUp"),
+    (KeyCode::PageDown, "PageDown", "PageDown"),
+    (KeyCode::Slash, "Slash", "/"),
+    (KeyCode::Backslash, "Backslash", "\\"),
+    (KeyCode::Backquote, "Backquote", "`"),
+    (KeyCode::BracketLeft, "BracketLeft", "["),
+    (KeyCode::BracketRight, "BracketRight", "]"),
+    (KeyCode::Period, "Period", "."),
+    (KeyCode::Minus, "Minus", "-"),
+    (KeyCode::ControlLeft, "ControlLeft", "Ctrl"),
+    (KeyCode::ControlRight, "ControlRight", "Right Ctrl"),
+    (KeyCode::ShiftLeft, "ShiftLeft", "Shift"),
+    (KeyCode::ShiftRight, "ShiftRight", "Right Shift"),
+    (KeyCode::AltLeft, "AltLeft", "Alt"),
+    (KeyCode::AltRight, "AltRight", "Right Alt"),
+];
+
+pub fn key_from_name(name: &str) -> Option<KeyCode> {
+    KEY_NAMES
+        .iter()
+        .find(|(_, key_name, _)| *key_name == name)
+        .map(|(key_code, _, _)| *key_code)
+}
+
+/// The key's name in the key bindings file, `None` for keys that can't be bound
+pub fn key_name(key_code: KeyCode) -> Option<&'static str> {
+    KEY_NAMES
+        .iter()
+        .find(|(named_key_code, _, _)| *named_key_code == key_code)
+        .map(|(_, key_name, _)| *key_name)
+}
+
+/// Every key that has a [`key_name`]
+pub fn named_keys() -> impl Iterator<Item = KeyCode> {
+    KEY_NAMES.iter().map(|(key_code, _, _)| *key_code)
+}
+
+pub fn key_display_name(key_code: KeyCode) -> &'static str {
+    KEY_NAMES
+        .iter()
+        .find(|(bound_key_code, _, _)| *bound_key_code == key_code)
+        .map_or("?", |(_, _, display_name)| display_name)
+}
+
+/// The keys bound to each [`InputAction`], indexed in [`InputAction::ALL`] order
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputBindings {
+    keys: Vec<Option<Vec<KeyCode>>>,
+}
+
+impl InputBindings {
+    /// Returns the bindings along with a warning for every line that couldn't be used. Lines
+    /// that aren't a table header or an `action = [...]` binding are skipped, and an action
+    /// with an unknown key keeps its default binding.
+    pub fn from_toml_string(toml_string: &str) -> (Self, Vec<String>) {
+        let mut keys = vec![None; INPUT_ACTION_COUNT];
+        let mut warnings = Vec::new();
+        for entry in flat_toml_entries(toml_string) {
+            let FlatTomlEntry {
+                key: name,
+                value: key_list,
+                ..
+            } = match entry {
+                Ok(entry) => entry,
+                Err(line) => {
+                    warnings.push(format!("Malformed key binding: {line}"));
+                    continue;
+                }
+            };
+            let Some(input_action) = InputAction::from_name(name) else {
+                warnings.push(format!("Unknown input action {name}"));
+                continue;
+            };
+            let Some(key_list) = parse_list(key_list) else {
+                warnings.push(format!(
+                    "The keys of {} must be a list",
+                    input_action.name()
+                ));
+                continue;
+            };
+            let action_keys = key_list
+                .into_iter()
+                .map(|key_name| {
+                    let key_name = key_name.trim_matches('"');
+                    key_from_name(key_name).ok_or_else(|| {
+                        format!("Unknown key {key_name} bound to {}", input_action.name())
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>();
+            match action_keys {
+                Ok(action_keys) => keys[input_action as usize] = Some(action_keys),
+                Err(warning) => warnings.push(warning),
+            }
+        }
+        (Self { keys }, warnings)
+    }
+
+    /// A missing file is logged by the caller and the default bindings are used instead
+    pub fn read<P: AsRef<Path>>(path: P) -> Option<(Self, Vec<String>)> {
+        Some(Self::from_toml_string(&fs::read_to_string(path).ok()?))
+    }
+
+    /// `None` when the action isn't bound in these bindings
+    pub fn get(&self, input_action: InputAction) -> Option<&[KeyCode]> {
+        self.keys
+            .get(input_action as usize)
+            .and_then(|keys| keys.as_deref())
+    }
+}
+
+/// Only the first bindings set are used, returns false if some were already set
+pub fn set_input_bindings(input_bindings: InputBindings) -> bool {
+    INPUT_BINDINGS.set(input_bindings).is_ok()
+}
+
+/// The keys bound to `input_action`, from the loaded bindings or else the defaults
+pub fn bound_keys(input_action: InputAction) -> &'static [KeyCode] {
+    INPUT_BINDINGS
+        .get()
+        .and_then(|input_bindings| input_bindings.get(input_action))
+        .or_else(|| {
+            DEFAULT_INPUT_BINDINGS
+                .get_or_init(|| InputBindings::from_toml_string(DEFAULT_KEYBINDINGS_TOML).0)
+                .get(input_action)
+        })
+        .unwrap_or_default()
+}
+
+/// The keys bound to `input_action` as the help overlay shows them, e.g. `Left/A`
+pub fn bound_keys_text(input_action: InputAction) -> String {
+    bound_keys(input_action)
+        .iter()
+        .map(|key_code| key_display_name(*key_code))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// A help overlay line, e.g. `P: pause`
+pub fn help_line(input_action: InputAction) -> String {
+    format!(
+        "{}: {}",
+        bound_keys_text(input_action),
+        input_action.description()
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use void_public::event::input::KeyCode;
+
+    use crate::input_bindings::{
+        DEFAULT_KEYBINDINGS_TOML, InputAction, InputBindings, bound_keys, help_line,
+    };
+
+    #[test]
+    fn every_action_has_a_default_binding() {
+        let (input_bindings, warnings) = InputBindings::from_toml_string(DEFAULT_KEYBINDINGS_TOML);
+        assert!(warnings.is_empty(), "{warnings:?}");
+        for input_action in InputAction::ALL {
+            assert!(
+                input_bindings.get(input_action).is_some(),
+                "{}",
+                input_action.name()
+            );
+        }
+        assert_eq!(
+            bound_keys(InputAction::NavigateLeft),
+            &[KeyCode::ArrowLeft, KeyCode::KeyA]
+        );
+        assert_eq!(help_line(InputAction::HelpToggle), "H: toggle this help");
+    }
+
+    #[test]
+    fn unknown_keys_and_actions_are_reported() {
+        let (input_bindings, warnings) = InputBindings::from_toml_string(
+            r#"
+[keybindings]
+toggle_perf = ["F9", "KeyK"]
+quit = ["Hyper"]
+teleport = ["KeyT"]
+pause_toggle = []
+back = "Escape"
+"#,
+        );
+        assert_eq!(
+            input_bindings.get(InputAction::TogglePerf),
+            Some(&[KeyCode::F9, KeyCode::KeyK][..])
+        );
+        assert_eq!(input_bindings.get(InputAction::Quit), None);
+        assert_eq!(input_bindings.get(InputAction::PauseToggle), Some(&[][..]));
+        assert_eq!(input_bindings.get(InputAction::Back), None);
+        assert_eq!(warnings.len(), 3);
+    }
+}
@@ -1,40 +1,832 @@
-//! Utility functions related to handling keyboard and mouse inputs.
+//! Utility functions related to handling keyboard and mouse inputs. Action keys are looked up in
+//! [`input_bindings`](crate::input_bindings), text entry keys, modifiers and chords are fixed.
+//! Ctrl and Alt are kept for chords like Ctrl+R, so actions don't trigger while either is held.
 
 use void_public::{
+    Aspect, Vec2,
     event::input::{KeyCode, MouseButton},
     input::InputState,
 };
 
+use crate::{
+    input_bindings::{InputAction, bound_keys, named_keys},
+    math::screen_to_world,
+    post_stack::POST_STACK_LAYER_COUNT,
+    split_view::SPLIT_VIEW_QUADRANTS,
+};
+
 pub fn any_keys_just_pressed(input_state: &InputState, keys: &[KeyCode]) -> bool {
     keys.iter()
         .any(|key_code| input_state.keys[*key_code].just_pressed())
 }
 
+pub fn any_keys_pressed(input_state: &InputState, keys: &[KeyCode]) -> bool {
+    keys.iter()
+        .any(|key_code| input_state.keys[*key_code].pressed())
+}
+
+/// Whether every key of `keys` is held, in any order
+pub fn is_chord_pressed(input_state: &InputState, keys: &[KeyCode]) -> bool {
+    !keys.is_empty()
+        && keys
+            .iter()
+            .all(|key_code| input_state.keys[*key_code].pressed())
+}
+
+/// Whether the last key of a chord went down this frame, so a held chord triggers once
+pub fn is_chord_just_pressed(input_state: &InputState, keys: &[KeyCode]) -> bool {
+    is_chord_pressed(input_state, keys) && any_keys_just_pressed(input_state, keys)
+}
+
+/// A modifier key, either its left or its right key
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Modifier {
+    Ctrl,
+    Shift,
+    Alt,
+}
+
+impl Modifier {
+    pub fn keys(self) -> &'static [KeyCode] {
+        match self {
+            Modifier::Ctrl => &[KeyCode::ControlLeft, KeyCode::ControlRight],
+            Modifier::Shift => &[KeyCode::ShiftLeft, KeyCode::ShiftRight],
+            Modifier::Alt => &[KeyCode::AltLeft, KeyCode::AltRight],
+        }
+    }
+}
+
+pub fn is_modifier_pressed(input_state: &InputState, modifier: Modifier) -> bool {
+    any_keys_pressed(input_state, modifier.keys())
+}
+
+/// Whether `key_code` was pressed this frame while `modifier` is held, e.g. Ctrl+S
+pub fn is_modified_key_just_pressed(
+    input_state: &InputState,
+    modifier: Modifier,
+    key_code: KeyCode,
+) -> bool {
+    is_modifier_pressed(input_state, modifier) && input_state.keys[key_code].just_pressed()
+}
+
+/// Whether any key bound to `input_action` in [`input_bindings`](crate::input_bindings) was
+/// pressed this frame without Ctrl or Alt held. Shift is allowed, it changes what some actions do.
+pub fn is_action_just_pressed(input_state: &InputState, input_action: InputAction) -> bool {
+    any_keys_just_pressed(input_state, bound_keys(input_action))
+        && !is_modifier_pressed(input_state, Modifier::Ctrl)
+        && !is_modifier_pressed(input_state, Modifier::Alt)
+}
+
 pub fn is_left_just_pressed(input_state: &InputState) -> bool {
-    any_keys_just_pressed(input_state, &[KeyCode::ArrowLeft, KeyCode::KeyA])
+    is_action_just_pressed(input_state, InputAction::NavigateLeft)
 }
 
 pub fn is_right_just_pressed(input_state: &InputState) -> bool {
-    any_keys_just_pressed(input_state, &[KeyCode::ArrowRight, KeyCode::KeyD])
+    is_action_just_pressed(input_state, InputAction::NavigateRight)
 }
 
 pub fn is_up_just_pressed(input_state: &InputState) -> bool {
-    any_keys_just_pressed(input_state, &[KeyCode::ArrowUp, KeyCode::KeyW])
+    is_action_just_pressed(input_state, InputAction::NavigateUp)
 }
 
 pub fn is_down_just_pressed(input_state: &InputState) -> bool {
-    any_keys_just_pressed(input_state, &[KeyCode::ArrowDown, KeyCode::KeyS])
+    is_action_just_pressed(input_state, InputAction::NavigateDown)
+}
+
+/// Seconds a direction is held before it starts repeating
+pub const DEFAULT_KEY_REPEAT_DELAY_SECONDS: f32 = 0.4;
+/// Repeats per second once a held direction repeats
+pub const DEFAULT_KEY_REPEAT_RATE: f32 = 12.;
+pub const KEY_REPEAT_ARG: &str = "--key-repeat=";
+
+/// The directions that repeat while held, each covering the keys bound to its navigate action
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RepeatKey {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl RepeatKey {
+    const ALL: [RepeatKey; 4] = [
+        RepeatKey::Left,
+        RepeatKey::Right,
+        RepeatKey::Up,
+        RepeatKey::Down,
+    ];
+
+    fn input_action(self) -> InputAction {
+        match self {
+            RepeatKey::Left => InputAction::NavigateLeft,
+            RepeatKey::Right => InputAction::NavigateRight,
+            RepeatKey::Up => InputAction::NavigateUp,
+            RepeatKey::Down => InputAction::NavigateDown,
+        }
+    }
+}
+
+/// Repeats held directions, after `delay_seconds` a held direction repeats `rate` times a second.
+/// Updated once a frame by `key_repeat_system`, the first press of a key isn't a repeat, so the
+/// `is_*_repeated` functions check for it separately.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KeyRepeat {
+    pub delay_seconds: f32,
+    pub rate: f32,
+    /// How long each [`RepeatKey`] has been held, `None` while it's released
+    held_seconds: [Option<f32>; 4],
+    repeated: [bool; 4],
+}
+
+impl Default for KeyRepeat {
+    fn default() -> Self {
+        Self::new(DEFAULT_KEY_REPEAT_DELAY_SECONDS, DEFAULT_KEY_REPEAT_RATE)
+    }
+}
+
+impl KeyRepeat {
+    pub fn new(delay_seconds: f32, rate: f32) -> Self {
+        Self {
+            delay_seconds,
+            rate,
+            held_seconds: [None; 4],
+            repeated: [false; 4],
+        }
+    }
+
+    pub fn update(&mut self, input_state: &InputState, delta_time: f32) {
+        let held = RepeatKey::ALL
+            .map(|repeat_key| any_keys_pressed(input_state, bound_keys(repeat_key.input_action())));
+        self.update_held(held, delta_time);
+    }
+
+    /// `held` is whether each of [`RepeatKey::ALL`] is held this frame
+    pub fn update_held(&mut self, held: [bool; 4], delta_time: f32) {
+        for (index, held) in held.into_iter().enumerate() {
+            let previous_seconds = self.held_seconds[index];
+            let held_seconds =
+                held.then(|| previous_seconds.map_or(0., |seconds| seconds + delta_time));
+            self.repeated[index] = match (previous_seconds, held_seconds) {
+                (Some(previous_seconds), Some(held_seconds)) => {
+                    self.repeat_count(held_seconds) > self.repeat_count(previous_seconds)
+                }
+                _ => false,
+            };
+            self.held_seconds[index] = held_seconds;
+        }
+    }
+
+    /// How many times a key held this long has repeated
+    fn repeat_count(&self, held_seconds: f32) -> u32 {
+        if held_seconds < self.delay_seconds {
+            0
+        } else {
+            ((held_seconds - self.delay_seconds) * self.rate) as u32 + 1
+        }
+    }
+
+    pub fn is_repeated(&self, repeat_key: RepeatKey) -> bool {
+        self.repeated[repeat_key as usize]
+    }
+}
+
+/// Parses `--key-repeat=<delay seconds>,<repeats per second>`, the rate must be positive
+pub fn parse_key_repeat_arg(arg: &str) -> Option<KeyRepeat> {
+    let (delay_seconds, rate) = arg.strip_prefix(KEY_REPEAT_ARG)?.split_once(',')?;
+    let delay_seconds = delay_seconds
+        .trim()
+        .parse::<f32>()
+        .ok()
+        .filter(|delay_seconds| *delay_seconds >= 0.)?;
+    let rate = rate.trim().parse::<f32>().ok().filter(|rate| *rate > 0.)?;
+    Some(KeyRepeat::new(delay_seconds, rate))
+}
+
+/// Left was pressed this frame, or has been held long enough to repeat
+pub fn is_left_repeated(input_state: &InputState, key_repeat: &KeyRepeat) -> bool {
+    is_left_just_pressed(input_state) || key_repeat.is_repeated(RepeatKey::Left)
+}
+
+pub fn is_right_repeated(input_state: &InputState, key_repeat: &KeyRepeat) -> bool {
+    is_right_just_pressed(input_state) || key_repeat.is_repeated(RepeatKey::Right)
+}
+
+pub fn is_up_repeated(input_state: &InputState, key_repeat: &KeyRepeat) -> bool {
+    is_up_just_pressed(input_state) || key_repeat.is_repeated(RepeatKey::Up)
+}
+
+pub fn is_down_repeated(input_state: &InputState, key_repeat: &KeyRepeat) -> bool {
+    is_down_just_pressed(input_state) || key_repeat.is_repeated(RepeatKey::Down)
 }
 
 pub fn is_back_just_pressed(input_state: &InputState) -> bool {
-    any_keys_just_pressed(
-        input_state,
-        &[KeyCode::Escape, KeyCode::Backspace, KeyCode::Delete],
-    )
+    is_action_just_pressed(input_state, InputAction::Back)
 }
 
+/// Clicks and taps select through [`TouchTracker`] instead, on release
 pub fn is_select_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::Select)
+}
+
+pub fn is_material_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::MaterialToggle)
+}
+
+pub fn is_console_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::ConsoleToggle)
+}
+
+pub fn is_help_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::HelpToggle)
+}
+
+pub fn is_perf_overlay_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::TogglePerf)
+}
+
+pub fn is_shader_source_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::ShaderSourceToggle)
+}
+
+pub fn is_entity_counts_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::EntityCountsToggle)
+}
+
+pub fn is_uniform_diff_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::UniformDiffToggle)
+}
+
+pub fn is_uniform_reset_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::UniformReset)
+}
+
+pub fn is_burst_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::BurstToggle)
+}
+
+pub fn is_page_up_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::PageUp)
+}
+
+pub fn is_page_down_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::PageDown)
+}
+
+pub fn is_filter_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::FilterToggle)
+}
+
+pub fn is_favorite_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::FavoriteToggle)
+}
+
+pub fn is_tag_next_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::TagNext)
+}
+
+pub fn is_filter_delete_just_pressed(input_state: &InputState) -> bool {
+    input_state.keys[KeyCode::Backspace].just_pressed()
+}
+
+pub fn is_filter_cancel_just_pressed(input_state: &InputState) -> bool {
+    input_state.keys[KeyCode::Escape].just_pressed()
+}
+
+/// Keys that can be typed into the material selection filter, test names are lowercase
+/// snake_case so shift is ignored and minus types an underscore
+const FILTER_CHARACTER_KEYS: [(KeyCode, char); 37] = [
+    (KeyCode::KeyA, 'a'),
+    (KeyCode::KeyB, 'b'),
+    (KeyCode::KeyC, 'c'),
+    (KeyCode::KeyD, 'd'),
+    (KeyCode::KeyE, 'e'),
+    (KeyCode::KeyF, 'f'),
+    (KeyCode::KeyG, 'g'),
+    (KeyCode::KeyH, 'h'),
+    (KeyCode::KeyI, 'i'),
+    (KeyCode::KeyJ, 'j'),
+    (KeyCode::KeyK, 'k'),
+    (KeyCode::KeyL, 'l'),
+    (KeyCode::KeyM, 'm'),
+    (KeyCode::KeyN, 'n'),
+    (KeyCode::KeyO, 'o'),
+    (KeyCode::KeyP, 'p'),
+    (KeyCode::KeyQ, 'q'),
+    (KeyCode::KeyR, 'r'),
+    (KeyCode::KeyS, 's'),
+    (KeyCode::KeyT, 't'),
+    (KeyCode::KeyU, 'u'),
+    (KeyCode::KeyV, 'v'),
+    (KeyCode::KeyW, 'w'),
+    (KeyCode::KeyX, 'x'),
+    (KeyCode::KeyY, 'y'),
+    (KeyCode::KeyZ, 'z'),
+    (KeyCode::Digit0, '0'),
+    (KeyCode::Digit1, '1'),
+    (KeyCode::Digit2, '2'),
+    (KeyCode::Digit3, '3'),
+    (KeyCode::Digit4, '4'),
+    (KeyCode::Digit5, '5'),
+    (KeyCode::Digit6, '6'),
+    (KeyCode::Digit7, '7'),
+    (KeyCode::Digit8, '8'),
+    (KeyCode::Digit9, '9'),
+    (KeyCode::Minus, '_'),
+];
+
+pub fn filter_characters_just_pressed(input_state: &InputState) -> impl Iterator<Item = char> {
+    FILTER_CHARACTER_KEYS
+        .iter()
+        .filter(|(key_code, _)| input_state.keys[*key_code].just_pressed())
+        .map(|(_, character)| *character)
+}
+
+pub fn is_quit_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::Quit)
+}
+
+pub fn is_pause_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::PauseToggle)
+}
+
+pub fn is_single_step_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::SingleStep)
+}
+
+pub fn is_time_scale_down_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::TimeScaleDown)
+}
+
+pub fn is_time_scale_up_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::TimeScaleUp)
+}
+
+pub fn is_slow_motion_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::SlowMotionToggle)
+}
+
+const PRESET_SLOT_KEYS: [KeyCode; 9] = [
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+];
+
+/// Returns the uniform preset slot, 1 through 9, of a digit key pressed this frame
+pub fn preset_slot_just_pressed(input_state: &InputState) -> Option<usize> {
+    PRESET_SLOT_KEYS
+        .iter()
+        .position(|key_code| input_state.keys[*key_code].just_pressed())
+        .map(|index| index + 1)
+}
+
+/// Holding Ctrl while pressing a preset slot saves to it instead of restoring it
+pub fn is_preset_save_modifier_pressed(input_state: &InputState) -> bool {
+    is_modifier_pressed(input_state, Modifier::Ctrl)
+}
+
+/// Returns the `post_stack` layer, counting from 0, of a digit key pressed this frame
+pub fn post_stack_layer_just_pressed(input_state: &InputState) -> Option<usize> {
+    PRESET_SLOT_KEYS[..POST_STACK_LAYER_COUNT]
+        .iter()
+        .position(|key_code| input_state.keys[*key_code].just_pressed())
+}
+
+/// Returns the split view quadrant, counting from 0, of a digit key pressed this frame
+pub fn split_view_quadrant_just_pressed(input_state: &InputState) -> Option<usize> {
+    PRESET_SLOT_KEYS[..SPLIT_VIEW_QUADRANTS]
+        .iter()
+        .position(|key_code| input_state.keys[*key_code].just_pressed())
+}
+
+pub fn is_split_view_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::SplitViewToggle)
+}
+
+pub fn is_background_cycle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::BackgroundCycle)
+}
+
+pub fn is_color_blind_cycle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::ColorBlindCycle)
+}
+
+pub fn is_post_stack_reorder_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::PostStackReorder)
+}
+
+pub fn is_postprocess_inspector_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::PostprocessInspectorToggle)
+}
+
+/// Holding Shift while pressing the uniform reset key resets every uniform instead of the selected
+/// one
+pub fn is_uniform_reset_all_modifier_pressed(input_state: &InputState) -> bool {
+    is_modifier_pressed(input_state, Modifier::Shift)
+}
+
+/// Holding Shift while pressing Up/Down moves the selected postprocess instead of selecting
+pub fn is_postprocess_move_modifier_pressed(input_state: &InputState) -> bool {
+    is_modifier_pressed(input_state, Modifier::Shift)
+}
+
+pub fn is_mip_level_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::MipLevelToggle)
+}
+
+pub fn is_day_night_pause_toggle_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::DayNightPauseToggle)
+}
+
+pub fn is_particle_spawn_just_pressed(input_state: &InputState) -> bool {
+    input_state.mouse.buttons[MouseButton::Left].just_pressed()
+}
+
+pub fn is_randomize_uniforms_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::RandomizeUniforms)
+}
+
+/// Ctrl+R restarts the running test, R alone randomizes its uniforms
+pub fn is_force_reload_just_pressed(input_state: &InputState) -> bool {
+    is_modified_key_just_pressed(input_state, Modifier::Ctrl, KeyCode::KeyR)
+}
+
+pub fn is_uniform_entry_next_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::UniformEntryNext)
+}
+
+pub fn is_text_input_confirm_just_pressed(input_state: &InputState) -> bool {
     input_state.keys[KeyCode::Enter].just_pressed()
-        || input_state.keys[KeyCode::Space].just_pressed()
-        || input_state.mouse.buttons[MouseButton::Left].just_pressed()
+}
+
+pub fn is_text_input_cancel_just_pressed(input_state: &InputState) -> bool {
+    input_state.keys[KeyCode::Escape].just_pressed()
+}
+
+pub fn is_text_input_backspace_just_pressed(input_state: &InputState) -> bool {
+    input_state.keys[KeyCode::Backspace].just_pressed()
+}
+
+pub fn is_text_input_delete_just_pressed(input_state: &InputState) -> bool {
+    input_state.keys[KeyCode::Delete].just_pressed()
+}
+
+pub fn is_text_input_cursor_left_just_pressed(input_state: &InputState) -> bool {
+    input_state.keys[KeyCode::ArrowLeft].just_pressed()
+}
+
+pub fn is_text_input_cursor_right_just_pressed(input_state: &InputState) -> bool {
+    input_state.keys[KeyCode::ArrowRight].just_pressed()
+}
+
+/// Keys that can be typed into numeric text inputs
+const NUMERIC_CHARACTER_KEYS: [(KeyCode, char); 12] = [
+    (KeyCode::Digit0, '0'),
+    (KeyCode::Digit1, '1'),
+    (KeyCode::Digit2, '2'),
+    (KeyCode::Digit3, '3'),
+    (KeyCode::Digit4, '4'),
+    (KeyCode::Digit5, '5'),
+    (KeyCode::Digit6, '6'),
+    (KeyCode::Digit7, '7'),
+    (KeyCode::Digit8, '8'),
+    (KeyCode::Digit9, '9'),
+    (KeyCode::Period, '.'),
+    (KeyCode::Minus, '-'),
+];
+
+pub fn numeric_characters_just_pressed(input_state: &InputState) -> impl Iterator<Item = char> {
+    NUMERIC_CHARACTER_KEYS
+        .iter()
+        .filter(|(key_code, _)| input_state.keys[*key_code].just_pressed())
+        .map(|(_, character)| *character)
+}
+
+pub fn is_camera_drag_pressed(input_state: &InputState) -> bool {
+    input_state.mouse.buttons[MouseButton::Middle].pressed()
+}
+
+/// Scroll wheel steps this frame, positive when scrolling up
+pub fn camera_zoom_wheel_steps(input_state: &InputState) -> f32 {
+    input_state.mouse.scroll_delta.y
+}
+
+pub fn is_scroll_up(input_state: &InputState) -> bool {
+    input_state.mouse.scroll_delta.y > 0.
+}
+
+pub fn is_scroll_down(input_state: &InputState) -> bool {
+    input_state.mouse.scroll_delta.y < 0.
+}
+
+pub fn is_camera_reset_just_pressed(input_state: &InputState) -> bool {
+    is_action_just_pressed(input_state, InputAction::CameraReset)
+}
+
+/// A tap moves less than this fraction of the screen width between press and release
+pub const TAP_MAX_DISTANCE_PERCENT: f32 = 0.03;
+/// A swipe covers at least this fraction of the screen width
+pub const SWIPE_MIN_DISTANCE_PERCENT: f32 = 0.12;
+/// Slower drags are ignored, so dragging the cursor around in a test isn't read as a swipe
+pub const SWIPE_MAX_SECONDS: f32 = 0.5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SwipeDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TouchGesture {
+    /// Where the pointer was released, in world coordinates
+    Tap(Vec2),
+    Swipe(SwipeDirection),
+}
+
+/// Turns presses of the primary pointer into taps and swipes. Touch targets report their primary
+/// touch as the cursor and left mouse button, so a mouse click is a tap as well. Updated once a
+/// frame by `touch_system`, a gesture is reported on the frame the pointer is released.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TouchTracker {
+    /// Where the current press started, in world coordinates, and how long it has lasted
+    press: Option<(Vec2, f32)>,
+    gesture: Option<TouchGesture>,
+}
+
+impl TouchTracker {
+    pub fn update(&mut self, input_state: &InputState, aspect: &Aspect, delta_time: f32) {
+        let cursor_position = input_state.mouse.cursor_position;
+        self.update_pointer(
+            input_state.mouse.buttons[MouseButton::Left].pressed(),
+            screen_to_world(aspect, Vec2::new(cursor_position.x, cursor_position.y)),
+            aspect.width,
+            delta_time,
+        );
+    }
+
+    /// `position` is in world coordinates, with y pointing up
+    pub fn update_pointer(
+        &mut self,
+        pressed: bool,
+        position: Vec2,
+        screen_width: f32,
+        delta_time: f32,
+    ) {
+        self.gesture = None;
+        match (self.press, pressed) {
+            (None, true) => self.press = Some((position, 0.)),
+            (Some((start, seconds)), true) => self.press = Some((start, seconds + delta_time)),
+            (Some((start, seconds)), false) => {
+                self.press = None;
+                let offset = position - start;
+                let distance = offset.length();
+                self.gesture = if distance < TAP_MAX_DISTANCE_PERCENT * screen_width {
+                    Some(TouchGesture::Tap(position))
+                } else if distance >= SWIPE_MIN_DISTANCE_PERCENT * screen_width
+                    && seconds <= SWIPE_MAX_SECONDS
+                {
+                    Some(TouchGesture::Swipe(if offset.x.abs() >= offset.y.abs() {
+                        if offset.x < 0. {
+                            SwipeDirection::Left
+                        } else {
+                            SwipeDirection::Right
+                        }
+                    } else if offset.y < 0. {
+                        SwipeDirection::Down
+                    } else {
+                        SwipeDirection::Up
+                    }))
+                } else {
+                    None
+                };
+            }
+            (None, false) => {}
+        }
+    }
+
+    pub fn gesture(&self) -> Option<TouchGesture> {
+        self.gesture
+    }
+
+    pub fn tap_position(&self) -> Option<Vec2> {
+        match self.gesture {
+            Some(TouchGesture::Tap(position)) => Some(position),
+            _ => None,
+        }
+    }
+
+    pub fn swipe(&self) -> Option<SwipeDirection> {
+        match self.gesture {
+            Some(TouchGesture::Swipe(swipe_direction)) => Some(swipe_direction),
+            _ => None,
+        }
+    }
+}
+
+/// Whether `position` is on text centered at `text_position`, padded by half the font size so
+/// entries are easy to hit with a finger
+pub fn is_on_text(position: Vec2, text_position: Vec2, text_width: f32, font_size: f32) -> bool {
+    let offset = (position - text_position).abs();
+    offset.x <= (text_width + font_size) / 2. && offset.y <= font_size
+}
+
+/// How soon a second click has to follow the first to count as a double-click
+pub const DOUBLE_CLICK_SECONDS: f32 = 0.4;
+
+/// The mouse buttons [`PressTiming`] tracks clicks of
+const TIMED_MOUSE_BUTTONS: [MouseButton; 3] =
+    [MouseButton::Left, MouseButton::Right, MouseButton::Middle];
+
+/// Times clicks and held keys for double-clicks and long presses. Updated once a frame by
+/// `press_timing_system`, only keys with a [`key_name`](crate::input_bindings::key_name) are
+/// timed.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PressTiming {
+    elapsed: f32,
+    /// When each of the [`TIMED_MOUSE_BUTTONS`] was last clicked, cleared by a double-click so
+    /// a third click doesn't count as another one
+    last_clicks: [Option<f32>; 3],
+    double_clicked: [bool; 3],
+    /// Each held key with how long it has been held this frame and the frame before
+    held_keys: Vec<(KeyCode, f32, f32)>,
+}
+
+impl PressTiming {
+    pub fn update(&mut self, input_state: &InputState, delta_time: f32) {
+        let held_keys = named_keys()
+            .filter(|key_code| input_state.keys[*key_code].pressed())
+            .collect::<Vec<_>>();
+        self.update_pressed(
+            TIMED_MOUSE_BUTTONS
+                .map(|mouse_button| input_state.mouse.buttons[mouse_button].just_pressed()),
+            &held_keys,
+            delta_time,
+        );
+    }
+
+    /// `clicks` are indexed in [`TIMED_MOUSE_BUTTONS`] order
+    pub fn update_pressed(&mut self, clicks: [bool; 3], held_keys: &[KeyCode], delta_time: f32) {
+        self.elapsed += delta_time;
+        for (index, clicked) in clicks.into_iter().enumerate() {
+            self.double_clicked[index] = clicked
+                && self.last_clicks[index]
+                    .is_some_and(|last_click| self.elapsed - last_click <= DOUBLE_CLICK_SECONDS);
+            self.last_clicks[index] = match (clicked, self.double_clicked[index]) {
+                (true, false) => Some(self.elapsed),
+                (true, true) => None,
+                (false, _) => self.last_clicks[index],
+            };
+        }
+        self.held_keys = held_keys
+            .iter()
+            .map(|key_code| {
+                let previous_seconds = self
+                    .held_keys
+                    .iter()
+                    .find(|(held_key, _, _)| held_key == key_code)
+                    .map(|(_, held_seconds, _)| *held_seconds);
+                match previous_seconds {
+                    Some(previous_seconds) => {
+                        (*key_code, previous_seconds + delta_time, previous_seconds)
+                    }
+                    None => (*key_code, 0., 0.),
+                }
+            })
+            .collect();
+    }
+
+    /// Whether `mouse_button` was clicked this frame shortly after a previous click
+    pub fn is_double_clicked(&self, mouse_button: MouseButton) -> bool {
+        TIMED_MOUSE_BUTTONS
+            .iter()
+            .position(|timed_mouse_button| *timed_mouse_button == mouse_button)
+            .is_some_and(|index| self.double_clicked[index])
+    }
+
+    /// Whether `key_code` has been held for `seconds` as of this frame, true once per press
+    pub fn is_long_pressed(&self, key_code: KeyCode, seconds: f32) -> bool {
+        self.held_keys
+            .iter()
+            .find(|(held_key, _, _)| *held_key == key_code)
+            .is_some_and(|(_, held_seconds, previous_seconds)| {
+                *previous_seconds < seconds && *held_seconds >= seconds
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use void_public::{
+        Vec2,
+        event::input::{KeyCode, MouseButton},
+    };
+
+    use crate::input_handlers::{
+        DEFAULT_KEY_REPEAT_RATE, KeyRepeat, PressTiming, RepeatKey, SwipeDirection, TouchGesture,
+        TouchTracker, is_on_text, parse_key_repeat_arg,
+    };
+
+    #[test]
+    fn held_keys_repeat_after_the_delay() {
+        let mut key_repeat = KeyRepeat::new(0.5, 4.);
+        let mut repeats = 0;
+        // Held for a second at 8 frames a second, after the first press frame
+        for _ in 0..=8 {
+            key_repeat.update_held([false, false, true, false], 0.125);
+            if key_repeat.is_repeated(RepeatKey::Up) {
+                repeats += 1;
+            }
+            assert!(!key_repeat.is_repeated(RepeatKey::Down));
+        }
+        // Repeats at 0.5, 0.75 and 1 seconds
+        assert_eq!(repeats, 3);
+
+        key_repeat.update_held([false; 4], 0.125);
+        key_repeat.update_held([false, false, true, false], 0.125);
+        assert!(!key_repeat.is_repeated(RepeatKey::Up));
+    }
+
+    #[test]
+    fn key_repeat_arg_needs_a_positive_rate() {
+        assert_eq!(
+            parse_key_repeat_arg("--key-repeat=0.25,20"),
+            Some(KeyRepeat::new(0.25, 20.))
+        );
+        assert_eq!(parse_key_repeat_arg("--key-repeat=0.25,0"), None);
+        assert_eq!(parse_key_repeat_arg("--key-repeat=-1,20"), None);
+        assert_eq!(parse_key_repeat_arg("--key-repeat=0.25"), None);
+        assert_eq!(KeyRepeat::default().rate, DEFAULT_KEY_REPEAT_RATE);
+    }
+
+    #[test]
+    fn releases_are_read_as_taps_or_swipes() {
+        let mut touch_tracker = TouchTracker::default();
+        touch_tracker.update_pointer(true, Vec2::new(10., 10.), 1000., 0.1);
+        assert_eq!(touch_tracker.gesture(), None);
+        touch_tracker.update_pointer(false, Vec2::new(15., 12.), 1000., 0.1);
+        assert_eq!(
+            touch_tracker.gesture(),
+            Some(TouchGesture::Tap(Vec2::new(15., 12.)))
+        );
+        touch_tracker.update_pointer(false, Vec2::new(15., 12.), 1000., 0.1);
+        assert_eq!(touch_tracker.gesture(), None);
+
+        touch_tracker.update_pointer(true, Vec2::new(0., 0.), 1000., 0.1);
+        touch_tracker.update_pointer(false, Vec2::new(-200., 30.), 1000., 0.1);
+        assert_eq!(touch_tracker.swipe(), Some(SwipeDirection::Left));
+        touch_tracker.update_pointer(true, Vec2::new(0., 0.), 1000., 0.1);
+        touch_tracker.update_pointer(false, Vec2::new(30., -200.), 1000., 0.1);
+        assert_eq!(touch_tracker.swipe(), Some(SwipeDirection::Down));
+
+        // Too slow for a swipe, and too far for a tap
+        touch_tracker.update_pointer(true, Vec2::new(0., 0.), 1000., 0.1);
+        touch_tracker.update_pointer(true, Vec2::new(100., 0.), 1000., 1.);
+        touch_tracker.update_pointer(false, Vec2::new(200., 0.), 1000., 0.1);
+        assert_eq!(touch_tracker.gesture(), None);
+    }
+
+    #[test]
+    fn text_hit_area_is_padded() {
+        let text_position = Vec2::new(100., 50.);
+        assert!(is_on_text(Vec2::new(145., 60.), text_position, 80., 20.));
+        assert!(!is_on_text(Vec2::new(155., 60.), text_position, 80., 20.));
+        assert!(!is_on_text(Vec2::new(100., 75.), text_position, 80., 20.));
+    }
+
+    #[test]
+    fn double_clicks_and_long_presses_are_timed() {
+        let mut press_timing = PressTiming::default();
+        press_timing.update_pressed([true, false, false], &[], 0.125);
+        assert!(!press_timing.is_double_clicked(MouseButton::Left));
+        press_timing.update_pressed([false; 3], &[], 0.125);
+        press_timing.update_pressed([true, false, false], &[], 0.125);
+        assert!(press_timing.is_double_clicked(MouseButton::Left));
+        assert!(!press_timing.is_double_clicked(MouseButton::Right));
+        // A third click starts a new double-click instead of completing another one
+        press_timing.update_pressed([true, false, false], &[], 0.125);
+        assert!(!press_timing.is_double_clicked(MouseButton::Left));
+        // Too slow for a double-click
+        press_timing.update_pressed([false; 3], &[], 0.5);
+        press_timing.update_pressed([true, false, false], &[], 0.125);
+        assert!(!press_timing.is_double_clicked(MouseButton::Left));
+
+        let mut long_presses = 0;
+        for _ in 0..16 {
+            press_timing.update_pressed([false; 3], &[KeyCode::KeyR], 0.125);
+            if press_timing.is_long_pressed(KeyCode::KeyR, 1.) {
+                long_presses += 1;
+            }
+        }
+        assert_eq!(long_presses, 1);
+        press_timing.update_pressed([false; 3], &[], 0.125);
+        press_timing.update_pressed([false; 3], &[KeyCode::KeyR], 0.125);
+        assert!(!press_timing.is_long_pressed(KeyCode::KeyR, 1.));
+    }
 }
@@ -0,0 +1,389 @@
+//! Records the input of a run to a file with `--record=<path>` and plays it back with
+//! `--replay=<path>`, so menu flows and shader interactions can be reproduced exactly, e.g. to
+//! compare screenshots against golden images. Only changes are stored, one tab separated line
+//! per change, starting with the frame it happened on:
+//!
+//! ```text
+//! 12	key	ArrowRight	1
+//! 15	key	ArrowRight	0
+//! 40	cursor	-120.5	64
+//! 41	button	left	1
+//! 52	scroll	-1
+//! ```
+//!
+//! The replayed input replaces the real keyboard and mouse input for the whole run. A replay
+//! only matches its recording when run with the same `--seed`, window size and frame rate.
+
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use void_public::{
+    Vec2,
+    event::input::{KeyCode, MouseButton},
+    input::InputState,
+};
+
+use crate::input_bindings::{key_from_name, key_name, named_keys};
+
+pub const RECORD_INPUT_ARG: &str = "--record=";
+pub const REPLAY_INPUT_ARG: &str = "--replay=";
+
+/// Parses `--record=<path>`
+pub fn parse_record_input_arg(arg: &str) -> Option<PathBuf> {
+    arg.strip_prefix(RECORD_INPUT_ARG)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Parses `--replay=<path>`
+pub fn parse_replay_input_arg(arg: &str) -> Option<PathBuf> {
+    arg.strip_prefix(REPLAY_INPUT_ARG)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReplayMouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+impl ReplayMouseButton {
+    pub const ALL: [ReplayMouseButton; 3] = [
+        ReplayMouseButton::Left,
+        ReplayMouseButton::Right,
+        ReplayMouseButton::Middle,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ReplayMouseButton::Left => "left",
+            ReplayMouseButton::Right => "right",
+            ReplayMouseButton::Middle => "middle",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|replay_mouse_button| replay_mouse_button.name() == name)
+    }
+
+    pub fn mouse_button(self) -> MouseButton {
+        match self {
+            ReplayMouseButton::Left => MouseButton::Left,
+            ReplayMouseButton::Right => MouseButton::Right,
+            ReplayMouseButton::Middle => MouseButton::Middle,
+        }
+    }
+}
+
+/// The input of a single frame
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputFrame {
+    /// Only keys with a [`key_name`] are recorded
+    pub held_keys: Vec<KeyCode>,
+    /// Indexed in [`ReplayMouseButton::ALL`] order
+    pub held_buttons: [bool; 3],
+    /// In screen coordinates, as the engine reports it
+    pub cursor_position: Vec2,
+    /// Scroll wheel steps this frame, positive when scrolling up
+    pub scroll: f32,
+}
+
+impl InputFrame {
+    pub fn capture(input_state: &InputState) -> Self {
+        let cursor_position = input_state.mouse.cursor_position;
+        Self {
+            held_keys: named_keys()
+                .filter(|key_code| input_state.keys[*key_code].pressed())
+                .collect(),
+            held_buttons: ReplayMouseButton::ALL.map(|replay_mouse_button| {
+                input_state.mouse.buttons[replay_mouse_button.mouse_button()].pressed()
+            }),
+            cursor_position: Vec2::new(cursor_position.x, cursor_position.y),
+            scroll: input_state.mouse.scroll_delta.y,
+        }
+    }
+
+    /// Overwrites the engine's input with this frame's, the engine derives which keys were just
+    /// pressed or released from the previous frame
+    pub fn inject(&self, input_state: &mut InputState) {
+        for key_code in named_keys() {
+            input_state.keys[key_code].set_pressed(self.held_keys.contains(&key_code));
+        }
+        for (replay_mouse_button, held) in ReplayMouseButton::ALL.into_iter().zip(self.held_buttons)
+        {
+            input_state.mouse.buttons[replay_mouse_button.mouse_button()].set_pressed(held);
+        }
+        input_state.mouse.cursor_position.x = self.cursor_position.x;
+        input_state.mouse.cursor_position.y = self.cursor_position.y;
+        input_state.mouse.scroll_delta.x = 0.;
+        input_state.mouse.scroll_delta.y = self.scroll;
+    }
+
+    pub fn apply(&mut self, input_change: &InputChange) {
+        match input_change {
+            InputChange::Key(key_code, true) => {
+                if !self.held_keys.contains(key_code) {
+                    self.held_keys.push(*key_code);
+                }
+            }
+            InputChange::Key(key_code, false) => {
+                self.held_keys.retain(|held_key| held_key != key_code);
+            }
+            InputChange::Button(replay_mouse_button, held) => {
+                self.held_buttons[*replay_mouse_button as usize] = *held;
+            }
+            InputChange::Cursor(cursor_position) => self.cursor_position = *cursor_position,
+            InputChange::Scroll(scroll) => self.scroll = *scroll,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InputChange {
+    /// A key pressed, `true`, or released, `false`
+    Key(KeyCode, bool),
+    Button(ReplayMouseButton, bool),
+    Cursor(Vec2),
+    Scroll(f32),
+}
+
+impl InputChange {
+    /// The changes that turn `previous` into `current`
+    pub fn between(previous: &InputFrame, current: &InputFrame) -> Vec<Self> {
+        let released_keys = previous
+            .held_keys
+            .iter()
+            .filter(|key_code| !current.held_keys.contains(key_code))
+            .map(|key_code| InputChange::Key(*key_code, false));
+        let pressed_keys = current
+            .held_keys
+            .iter()
+            .filter(|key_code| !previous.held_keys.contains(key_code))
+            .map(|key_code| InputChange::Key(*key_code, true));
+        let buttons = ReplayMouseButton::ALL
+            .into_iter()
+            .filter(|replay_mouse_button| {
+                previous.held_buttons[*replay_mouse_button as usize]
+                    != current.held_buttons[*replay_mouse_button as usize]
+            })
+            .map(|replay_mouse_button| {
+                InputChange::Button(
+                    replay_mouse_button,
+                    current.held_buttons[replay_mouse_button as usize],
+                )
+            });
+        released_keys
+            .chain(pressed_keys)
+            .chain(buttons)
+            .chain(
+                (previous.cursor_position != current.cursor_position)
+                    .then_some(InputChange::Cursor(current.cursor_position)),
+            )
+            .chain(
+                (previous.scroll != current.scroll).then_some(InputChange::Scroll(current.scroll)),
+            )
+            .collect()
+    }
+
+    pub fn to_line(self, frame: u64) -> String {
+        match self {
+            InputChange::Key(key_code, pressed) => {
+                format!(
+                    "{frame}\tkey\t{}\t{}",
+                    key_name(key_code).unwrap_or_default(),
+                    u8::from(pressed)
+                )
+            }
+            InputChange::Button(replay_mouse_button, held) => {
+                format!(
+                    "{frame}\tbutton\t{}\t{}",
+                    replay_mouse_button.name(),
+                    u8::from(held)
+                )
+            }
+            InputChange::Cursor(cursor_position) => {
+                format!(
+                    "{frame}\tcursor\t{}\t{}",
+                    cursor_position.x, cursor_position.y
+                )
+            }
+            InputChange::Scroll(scroll) => format!("{frame}\tscroll\t{scroll}"),
+        }
+    }
+
+    /// Returns the frame of the change along with it
+    pub fn from_line(line: &str) -> Option<(u64, Self)> {
+        let mut fields = line.trim().split('\t');
+        let frame = fields.next()?.parse().ok()?;
+        let parse_held = |field: Option<&str>| match field? {
+            "1" => Some(true),
+            "0" => Some(false),
+            _ => None,
+        };
+        let input_change = match fields.next()? {
+            "key" => InputChange::Key(key_from_name(fields.next()?)?, parse_held(fields.next())?),
+            "button" => InputChange::Button(
+                ReplayMouseButton::from_name(fields.next()?)?,
+                parse_held(fields.next())?,
+            ),
+            "cursor" => InputChange::Cursor(Vec2::new(
+                fields.next()?.parse().ok()?,
+                fields.next()?.parse().ok()?,
+            )),
+            "scroll" => InputChange::Scroll(fields.next()?.parse().ok()?),
+            _ => return None,
+        };
+        Some((frame, input_change))
+    }
+}
+
+/// Writes the changes of every frame as they happen, so a recording survives the app quitting
+#[derive(Debug)]
+pub struct InputRecorder {
+    writer: BufWriter<File>,
+    frame: u64,
+    previous: InputFrame,
+}
+
+impl InputRecorder {
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+            frame: 0,
+            previous: InputFrame::default(),
+        })
+    }
+
+    pub fn record(&mut self, input_frame: InputFrame) -> io::Result<()> {
+        let input_changes = InputChange::between(&self.previous, &input_frame);
+        if !input_changes.is_empty() {
+            for input_change in input_changes {
+                writeln!(self.writer, "{}", input_change.to_line(self.frame))?;
+            }
+            self.writer.flush()?;
+        }
+        self.previous = input_frame;
+        self.frame += 1;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputReplay {
+    /// Sorted by frame
+    input_changes: Vec<(u64, InputChange)>,
+    next_change: usize,
+    frame: u64,
+    current: InputFrame,
+}
+
+impl InputReplay {
+    /// Malformed lines are skipped
+    pub fn from_file_string(file_string: &str) -> Self {
+        let mut input_changes = file_string
+            .lines()
+            .filter_map(InputChange::from_line)
+            .collect::<Vec<_>>();
+        input_changes.sort_by_key(|(frame, _)| *frame);
+        Self {
+            input_changes,
+            ..Default::default()
+        }
+    }
+
+    pub fn read<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self::from_file_string(&fs::read_to_string(path)?))
+    }
+
+    /// Advances to the next frame and returns its input
+    pub fn next_frame(&mut self) -> &InputFrame {
+        while let Some((_, input_change)) = self
+            .input_changes
+            .get(self.next_change)
+            .filter(|(frame, _)| *frame == self.frame)
+        {
+            self.current.apply(input_change);
+            self.next_change += 1;
+        }
+        self.frame += 1;
+        &self.current
+    }
+
+    /// Whether every recorded change has been played
+    pub fn is_finished(&self) -> bool {
+        self.next_change >= self.input_changes.len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use void_public::{Vec2, event::input::KeyCode};
+
+    use crate::input_replay::{
+        InputChange, InputFrame, InputReplay, ReplayMouseButton, parse_replay_input_arg,
+    };
+
+    #[test]
+    fn recorded_changes_replay_the_same_frames() {
+        let frames = [
+            InputFrame::default(),
+            InputFrame {
+                held_keys: vec![KeyCode::ArrowRight],
+                ..Default::default()
+            },
+            InputFrame {
+                held_keys: vec![KeyCode::ArrowRight, KeyCode::ControlLeft],
+                held_buttons: [true, false, false],
+                cursor_position: Vec2::new(-120.5, 64.),
+                scroll: -1.,
+            },
+            InputFrame {
+                held_keys: vec![KeyCode::ControlLeft],
+                cursor_position: Vec2::new(-120.5, 64.),
+                ..Default::default()
+            },
+        ];
+        let file_string = frames
+            .windows(2)
+            .enumerate()
+            .flat_map(|(index, frame_pair)| {
+                InputChange::between(&frame_pair[0], &frame_pair[1])
+                    .into_iter()
+                    .map(move |input_change| {
+                        format!("{}\n", input_change.to_line(index as u64 + 1))
+                    })
+            })
+            .collect::<String>();
+        assert!(file_string.contains("2\tbutton\tleft\t1\n"));
+
+        let mut input_replay = InputReplay::from_file_string(&file_string);
+        for frame in &frames {
+            assert!(!input_replay.is_finished());
+            assert_eq!(input_replay.next_frame(), frame);
+        }
+        assert!(input_replay.is_finished());
+    }
+
+    #[test]
+    fn malformed_lines_are_skipped() {
+        assert_eq!(
+            InputChange::from_line("3\tbutton\tmiddle\t0"),
+            Some((3, InputChange::Button(ReplayMouseButton::Middle, false)))
+        );
+        assert_eq!(InputChange::from_line("3\tkey\tHyper\t1"), None);
+        assert_eq!(InputChange::from_line("x\tscroll\t1"), None);
+        assert_eq!(InputChange::from_line("3\tcursor\t1"), None);
+        assert_eq!(
+            parse_replay_input_arg("--replay=demo.replay"),
+            Some("demo.replay".into())
+        );
+        assert_eq!(parse_replay_input_arg("--replay="), None);
+    }
+}
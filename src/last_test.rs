@@ -0,0 +1,82 @@
+//! Persists the most recently opened test so the main view can offer to resume it on the next
+//! run.
+
+use std::{fs, io, path::Path};
+
+use game_asset::resource_managers::material_manager::materials::MaterialType;
+
+pub const LAST_TEST_PATH: &str = "last_test.txt";
+
+/// How a [`MaterialType`] is written in the files this module and
+/// [`test_usage`](crate::test_usage) persist
+pub fn material_type_to_file_string(material_type: MaterialType) -> &'static str {
+    match material_type {
+        MaterialType::Sprite => "sprite",
+        MaterialType::PostProcessing => "post_processing",
+    }
+}
+
+pub fn material_type_from_file_string(file_string: &str) -> Option<MaterialType> {
+    match file_string {
+        "sprite" => Some(MaterialType::Sprite),
+        "post_processing" => Some(MaterialType::PostProcessing),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LastTest {
+    pub material_type: MaterialType,
+    pub name: String,
+}
+
+impl LastTest {
+    /// The first line is the material type and the second line is the test name
+    pub fn to_file_string(&self) -> String {
+        format!(
+            "{}\n{}\n",
+            material_type_to_file_string(self.material_type),
+            self.name
+        )
+    }
+
+    pub fn from_file_string(file_string: &str) -> Option<Self> {
+        let mut lines = file_string.lines().map(str::trim);
+        let material_type = material_type_from_file_string(lines.next()?)?;
+        let name = lines.next().filter(|name| !name.is_empty())?;
+        Some(Self {
+            material_type,
+            name: name.to_string(),
+        })
+    }
+
+    /// A missing or malformed file just means there is nothing to resume
+    pub fn read<P: AsRef<Path>>(path: P) -> Option<Self> {
+        Self::from_file_string(&fs::read_to_string(path).ok()?)
+    }
+
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_file_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use game_asset::resource_managers::material_manager::materials::MaterialType;
+
+    use crate::last_test::LastTest;
+
+    #[test]
+    fn last_test_file_string_round_trip() {
+        let last_test = LastTest {
+            material_type: MaterialType::PostProcessing,
+            name: "warp".to_string(),
+        };
+        assert_eq!(
+            LastTest::from_file_string(&last_test.to_file_string()),
+            Some(last_test)
+        );
+        assert_eq!(LastTest::from_file_string("sprite\n"), None);
+        assert_eq!(LastTest::from_file_string("mesh\nwarp\n"), None);
+    }
+}
@@ -2,19 +2,39 @@
 //! followed by a menu or input system for interactively selecting between the examples.
 
 use std::{
+    collections::HashMap,
     env::args,
     error::Error,
+    f32::consts::PI,
     ffi::CStr,
     fmt::{Debug, Display},
+    fs,
     num::NonZero,
     ops::{Add, AddAssign, ControlFlow, Deref},
     path::PathBuf,
+    process::exit,
 };
 
 use array::array_from_iterator;
-use asset_registering::register_material;
+use asset_registering::{
+    asset_file_path, load_material, load_optional_texture, register_material, register_texture,
+    require_material, require_texture,
+};
+use background::{
+    BACKGROUND_CHECKER_SIZE, BACKGROUND_CHECKER_TEXTURE_PATH, BACKGROUND_COLORS,
+    BACKGROUND_PHOTO_TEXTURE_PATH, BACKGROUND_SOLID_TEXTURE_PATH, Background, TestBackgrounds,
+    checker_pixels,
+};
+use bounce::reflect_at_screen_edges;
+use camera::CameraView;
+use color_blind::{
+    COLOR_BLIND_MATERIAL_NAME, COLOR_BLIND_MATERIAL_PATH, COLOR_BLIND_ROW_UNIFORMS, ColorBlindMode,
+};
+use config::{CONFIG_PATH, Config, merged_args};
+use console::{ConsoleLine, console_buffer};
+use entity_counts::{ENTITY_COUNTS_LINES, EntityCounts, LoadedAssetCounts, entity_counts_lines};
 use game_asset::{
-    ecs_module::{GpuInterface, TextAssetManager},
+    ecs_module::{GpuInterface, MaterialManager, TextAssetManager},
     resource_managers::{
         material_manager::{
             material_parameters_extension::MaterialParametersExt,
@@ -26,23 +46,112 @@ use game_asset::{
     world_render_manager::WorldRenderManager,
 };
 use game_module_macro::{Component, Resource, set_system_enabled, system, system_once};
+use input_bindings::{
+    InputAction, InputBindings, KEYBINDINGS_PATH, bound_keys, bound_keys_text, help_line,
+    set_input_bindings,
+};
 use input_handlers::{
-    is_back_just_pressed, is_down_just_pressed, is_left_just_pressed, is_right_just_pressed,
-    is_select_just_pressed, is_up_just_pressed,
+    KeyRepeat, PressTiming, SwipeDirection, TouchTracker, camera_zoom_wheel_steps,
+    filter_characters_just_pressed, is_back_just_pressed, is_background_cycle_just_pressed,
+    is_burst_toggle_just_pressed, is_camera_drag_pressed, is_camera_reset_just_pressed,
+    is_color_blind_cycle_just_pressed, is_console_toggle_just_pressed,
+    is_day_night_pause_toggle_just_pressed, is_down_just_pressed, is_down_repeated,
+    is_entity_counts_toggle_just_pressed, is_favorite_toggle_just_pressed,
+    is_filter_cancel_just_pressed, is_filter_delete_just_pressed, is_filter_toggle_just_pressed,
+    is_force_reload_just_pressed, is_help_toggle_just_pressed, is_left_just_pressed,
+    is_left_repeated, is_material_toggle_just_pressed, is_mip_level_toggle_just_pressed,
+    is_on_text, is_page_down_just_pressed, is_page_up_just_pressed, is_particle_spawn_just_pressed,
+    is_pause_toggle_just_pressed, is_perf_overlay_toggle_just_pressed,
+    is_post_stack_reorder_just_pressed, is_postprocess_inspector_toggle_just_pressed,
+    is_postprocess_move_modifier_pressed, is_preset_save_modifier_pressed, is_quit_just_pressed,
+    is_randomize_uniforms_just_pressed, is_right_just_pressed, is_right_repeated, is_scroll_down,
+    is_scroll_up, is_select_just_pressed, is_shader_source_toggle_just_pressed,
+    is_single_step_just_pressed, is_slow_motion_toggle_just_pressed,
+    is_split_view_toggle_just_pressed, is_tag_next_just_pressed,
+    is_text_input_backspace_just_pressed, is_text_input_cancel_just_pressed,
+    is_text_input_confirm_just_pressed, is_text_input_cursor_left_just_pressed,
+    is_text_input_cursor_right_just_pressed, is_text_input_delete_just_pressed,
+    is_time_scale_down_just_pressed, is_time_scale_up_just_pressed,
+    is_uniform_diff_toggle_just_pressed, is_uniform_entry_next_just_pressed,
+    is_uniform_reset_all_modifier_pressed, is_uniform_reset_just_pressed, is_up_just_pressed,
+    is_up_repeated, numeric_characters_just_pressed, post_stack_layer_just_pressed,
+    preset_slot_just_pressed, split_view_quadrant_just_pressed,
 };
-use log::{error, warn};
+use input_replay::{InputFrame, InputRecorder, InputReplay};
+use last_test::{LAST_TEST_PATH, LastTest};
+use local_error::TestModuleError;
+use localization::{Locale, locale_path, set_locale, test_display_name, tr};
+use log::{error, info, warn};
+use material_bench::{MATERIAL_BENCH_REPORT_PATH, MATERIAL_BENCH_SPRITE_COUNT, MaterialBench};
 use math::{
-    division_result, generate_equal_parts_rotation_matrix, screen_space_coordinate_by_percent,
+    ZeroToHundredPercent, arc_points, generate_equal_parts_rotation_matrix,
+    layout::{
+        Anchor, GridLayout, RelativeScale, anchored_position, percent_to_position,
+        position_to_percent, row_positions,
+    },
+    screen_space_coordinate_by_percent, screen_to_uv, screen_to_world, spiral_points,
+    world_to_sprite_uv,
+};
+use particles::{
+    PARTICLE_BURST_SLOTS, PARTICLE_BURST_UNIFORM_NAMES, PARTICLES_PER_BURST, ParticleBurst,
+    ParticleBursts,
+};
+use perf_overlay::{FrameTimeHistory, sparkline_points};
+use playlist::PlaylistEntry;
+use post_stack::{POST_STACK_GRAIN_LAYER, POST_STACK_LAYER_COUNT, PostStackLayers};
+use postprocess_inspector::{POSTPROCESS_INSPECTOR_LINES, inspector_lines, moved_index};
+use presets::{PresetValue, UniformPreset, preset_path};
+use procedural_texture::{
+    PROCEDURAL_TEXTURE_SIZE, PROCEDURAL_TEXTURE_UPLOAD_SECONDS, ProceduralPattern, generate_pixels,
+};
+use rand::{
+    Rng, SeedableRng,
+    distributions::uniform::{SampleRange, SampleUniform},
+    rngs::StdRng,
 };
-use rand::{Rng, thread_rng};
+use rng::DEFAULT_TEST_RNG_SEED;
 use serde_big_array::BigArray;
+use shader_bench::{bench_all_shaders, bench_table};
+use shader_dump::{DumpedTest, dump_all_shaders};
+use shader_error::{ShaderError, check_wgsl};
+use shader_source_viewer::{
+    SHADER_SOURCE_PAGE_LINES, SHADER_SOURCE_VIEWER_LINES, scrolled_first_line, viewer_lines,
+};
 use snapshot::{Deserialize, Serialize};
+use soak::{SOAK_REPORT_PATH, SOAK_TRANSITION_ONE_IN, Soak, soak_actions};
+use split_view::{
+    SPLIT_VIEW_QUADRANTS, SPLIT_VIEW_SPRITE_LIMIT, SplitViewVariants, quadrant_label_position,
+    quadrant_transform,
+};
+use startup_args::{StartupArgs, unknown_test_message};
+use stress_ramp::{STRESS_RAMP_REPORT_PATH, StressRamp};
+use teardown_check::{TeardownCheck, TeardownLeaks};
+use test_definition::{
+    MaterialTestSystems, material_test_systems, register_test_systems, registered_test_systems,
+};
+use test_details::{
+    AUTHOR_CAPACITY, DESCRIPTION_CAPACITY, TAGS_CAPACITY, available_tags, next_tag, tag_row_text,
+    test_details,
+};
+use test_usage::{SelectionGroup, TEST_USAGE_PATH, TestUsage, unix_seconds_now};
 use text::{
-    CreateTextInput, TextTypes, create_new_text, cstr_to_u8_array, str_to_u8_array,
+    CreateTextInput, HEADER_FONT_HEIGHT_PERCENT, REGULAR_FONT_HEIGHT_PERCENT, TextTypes,
+    create_new_text, cstr_to_u8_array, measure_text, str_to_u8_array, text_to_u8_array,
     title_from_material_type, u8_array_to_cstr, u8_array_to_str,
 };
-use texture::create_new_texture;
-use underline::{UNDERLINE_OFFSET_Y_PERCENT, create_underline};
+use text_input::TextInput;
+use texture::{
+    AtlasCellUniforms, atlas_cell_uniforms, create_new_relative_texture, create_new_texture,
+};
+use toast::{
+    DEFAULT_TOAST_SECONDS, MAX_VISIBLE_TOASTS, TOAST_FONT_SIZE, ToastSize, toast_alpha,
+    toast_stack_offsets,
+};
+use underline::{UNDERLINE_OFFSET_Y_PERCENT, create_underline, underline_scale};
+use uniform_diff::{UNIFORM_DIFF_LINES, diff_lines, reset_preset, uniform_diff};
+use uniform_ranges::{UniformRange, parse_uniform_ranges, scrolled_uniform_value};
+use uniform_timeline::{Easing, UniformKeyframe, evaluate_uniform, timeline_duration};
+use view_transitions::{TransitionStyle, ViewFade, iris_radius};
 use void_public::{
     Aspect, Component, ComponentId, EcsType, Engine, EntityId, EventReader, EventWriter,
     FrameConstants, Mat2, Query, Resource, Transform, Vec2, Vec3, Vec4, bundle, bundle_for_builder,
@@ -54,9 +163,9 @@ use void_public::{
             DrawRectangleBuilder, DrawText, DrawTextBuilder, MaterialIdFromTextId, NewText,
             NewTexture, TextAlignment,
         },
-        input::KeyCode,
+        input::{KeyCode, MouseButton},
     },
-    graphics::{TextRender, TextureId, TextureRender},
+    graphics::{TextRender, TextureId, TextureRender, camera::Camera},
     input::InputState,
     material::{DefaultMaterials, MaterialId, MaterialParameters},
     text::TextId,
@@ -64,1502 +173,8524 @@ use void_public::{
 
 pub mod array;
 pub mod asset_registering;
+pub mod background;
+pub mod bounce;
+pub mod camera;
+pub mod color_blind;
+pub mod config;
+pub mod console;
+pub mod entity_counts;
+pub mod flat_toml;
+pub mod fuzzy;
+pub mod input_bindings;
 pub mod input_handlers;
+pub mod input_replay;
+pub mod last_test;
 pub mod local_error;
+pub mod localization;
+pub mod material_bench;
 pub mod math;
+pub mod particles;
+pub mod perf_overlay;
+pub mod playlist;
+pub mod post_stack;
+pub mod postprocess_inspector;
+pub mod presets;
+pub mod procedural_texture;
+pub mod rng;
+pub mod shader_bench;
+pub mod shader_dump;
+pub mod shader_error;
+pub mod shader_source_viewer;
+pub mod soak;
+pub mod split_view;
+pub mod startup_args;
+pub mod stress_ramp;
+pub mod teardown_check;
+pub mod test_definition;
+pub mod test_details;
+pub mod test_usage;
 #[cfg(test)]
 pub(crate) mod test_validation;
 pub mod text;
+pub mod text_input;
 pub mod texture;
+pub mod toast;
 pub mod underline;
+pub mod uniform_diff;
+pub mod uniform_ranges;
+pub mod uniform_timeline;
+pub mod view_transitions;
 
 #[system_once]
 fn turn_off_systems() {
-    set_system_enabled!(false, handle_assets_loaded);
-}
-
-#[system_once]
-// We probably need some helper code to have systems start off if desired
-fn turn_off_material_test_systems() {
     set_system_enabled!(
         false,
-        invert_y_startup_system,
-        invert_y_system,
-        test_post_startup_system,
-        test_post_system,
-        warp_startup_system,
-        warp_system,
-        channel_inspector_startup_system,
-        color_replacement_startup_system,
-        color_replacement_system,
-        desat_sprite_startup_system,
-        pan_sprite_startup_system,
-        scrolling_color_startup_system,
-        scrolling_color_system,
-        starfield_startup_system,
-        starfield_system,
-        immediate_mode_test,
-        stress_test_startup_system,
-        stress_test_system,
+        handle_assets_loaded,
+        startup_selection_system,
+        stress_test_teardown_system
     );
 }
 
 #[system_once]
-/// This system sets up all material tests. [`MaterialTest`]'s should all be created in this system,
-/// along with any supporting [`Material`]'s and textures that the [`MaterialTest`] may need.
-///
-/// Please note, this system currently accesses [`GpuResource`] and [`PipelineManager`] from `gpu_web`, which is not the proper
-/// way that a module should access the engine. `gpu_web` is a platform implementation for [`GpuResource`]. In the future,
-/// [`PipelineManager`] will be moved to `void_public` and [`AssetManager`] will be expanded to properly load textures.
-fn materials_setup(
-    gpu_interface: &mut GpuInterface,
-    material_test_id_holder: &mut MaterialTestIdHolder,
-    text_asset_manager: &mut TextAssetManager,
-    new_texture_event_writer: EventWriter<NewTexture>,
-    new_text_event_writer: EventWriter<NewText<'_>>,
-    view: &mut View,
-) {
-    let pending_texture = gpu_interface
-        .texture_asset_manager
-        .load_texture(
-            &PathBuf::from("textures/arrow_up.png").into(),
-            true,
-            &new_texture_event_writer,
-        )
-        .unwrap();
-    Engine::spawn(bundle!(&MaterialTextureAsset::new(pending_texture.id())));
-
-    let pending_texture = gpu_interface
-        .texture_asset_manager
-        .load_texture(
-            &PathBuf::from("textures/random.png").into(),
-            false,
-            &new_texture_event_writer,
-        )
-        .unwrap();
-    Engine::spawn(bundle!(&MaterialTextureAsset::new(pending_texture.id())));
-
-    let pending_texture = gpu_interface
-        .texture_asset_manager
-        .load_texture(
-            &PathBuf::from("textures/scared.png").into(),
-            true,
-            &new_texture_event_writer,
-        )
-        .unwrap();
-    Engine::spawn(bundle!(&MaterialTextureAsset::new(pending_texture.id())));
-
-    let pending_texture = gpu_interface
-        .texture_asset_manager
-        .load_texture(
-            &PathBuf::from("textures/star_map_with_mask.png").into(),
-            false,
-            &new_texture_event_writer,
-        )
-        .unwrap();
-    Engine::spawn(bundle!(&MaterialTextureAsset::new(pending_texture.id())));
+/// Turns off the systems of every registered test, see [`register_test_systems`]
+fn turn_off_material_test_systems() {
+    for system in registered_test_systems() {
+        Engine::set_system_enabled(system, false, module_name);
+    }
+}
 
-    let (_, invert_y_y_test_id) = register_material(
-        "invert_y",
-        MaterialType::PostProcessing,
-        &"toml_materials/post_processing/invert_y.toml".into(),
-        c"invert_y_startup_system",
-        gpu_interface,
-        material_test_id_holder,
-        &new_text_event_writer,
-        text_asset_manager,
-    );
-    let (_, test_post_test_id) = register_material(
-        "test_post",
-        MaterialType::PostProcessing,
-        &"toml_materials/post_processing/test_post.toml".into(),
-        c"test_post_startup_system",
-        gpu_interface,
-        material_test_id_holder,
-        &new_text_event_writer,
-        text_asset_manager,
-    );
-    let (_, warp_test_id) = register_material(
-        "warp",
-        MaterialType::PostProcessing,
-        &"toml_materials/post_processing/warp.toml".into(),
-        c"warp_startup_system",
-        gpu_interface,
-        material_test_id_holder,
-        &new_text_event_writer,
-        text_asset_manager,
-    );
+/// A [`Resource`] holding the `--check-teardown` debug mode, see [`teardown_check`]
+#[derive(Debug, Default, Resource)]
+pub struct TeardownCheckMode {
+    pub teardown_check: Option<TeardownCheck>,
+}
 
-    let (_, channel_inspector_test_id) = register_material(
-        "channel_inspector",
-        MaterialType::Sprite,
-        &"toml_materials/sprite/channel_inspector.toml".into(),
-        c"channel_inspector_startup_system",
-        gpu_interface,
-        material_test_id_holder,
-        &new_text_event_writer,
-        text_asset_manager,
-    );
-    let (_, color_replacement_test_id) = register_material(
-        "color_replacement",
-        MaterialType::Sprite,
-        &"toml_materials/sprite/color_replacement.toml".into(),
-        c"color_replacement_startup_system",
-        gpu_interface,
-        material_test_id_holder,
-        &new_text_event_writer,
-        text_asset_manager,
-    );
-    let (desat_sprite_text_id, desat_sprite_test_id) = register_material(
-        "desat_sprite",
-        MaterialType::Sprite,
-        &"toml_materials/sprite/desat_sprite.toml".into(),
-        c"desat_sprite_startup_system",
-        gpu_interface,
-        material_test_id_holder,
-        &new_text_event_writer,
-        text_asset_manager,
-    );
-    let (pan_sprite_text_id, pan_sprite_test_id) = register_material(
-        "pan_sprite",
-        MaterialType::Sprite,
-        &"toml_materials/sprite/pan_sprite.toml".into(),
-        c"pan_sprite_startup_system",
-        gpu_interface,
-        material_test_id_holder,
-        &new_text_event_writer,
-        text_asset_manager,
-    );
-    let (_, scrolling_color_test_id) = register_material(
-        "scrolling_color",
-        MaterialType::Sprite,
-        &"toml_materials/sprite/scrolling_color.toml".into(),
-        c"scrolling_color_startup_system",
-        gpu_interface,
-        material_test_id_holder,
-        &new_text_event_writer,
-        text_asset_manager,
-    );
-    let (_, starfield_test_id) = register_material(
-        "starfield",
-        MaterialType::Sprite,
-        &"toml_materials/sprite/starfield.toml".into(),
-        c"starfield_startup_system",
-        gpu_interface,
-        material_test_id_holder,
-        &new_text_event_writer,
-        text_asset_manager,
-    );
+/// Logs and toasts whatever a test left behind once the view has left it, only with
+/// `--check-teardown`
+#[system]
+fn teardown_check_system(
+    view: &View,
+    world_render_manager: &WorldRenderManager,
+    color_blind_simulation: &ColorBlindSimulation,
+    teardown_check_mode: &mut TeardownCheckMode,
+    material_test_query: Query<&MaterialTest>,
+    material_test_object_query: Query<&MaterialTestObject>,
+) {
+    let Some(teardown_check) = &mut teardown_check_mode.teardown_check else {
+        return;
+    };
+    let running_test = match view.view_state() {
+        ViewState::Material((_, name)) => Some(name.as_str()),
+        _ => None,
+    };
+    let Some(left_test) = teardown_check.update(running_test) else {
+        return;
+    };
 
-    let material_ids = &[
-        MaybeLoadedMaterial::new(MaterialType::Sprite, desat_sprite_text_id),
-        MaybeLoadedMaterial::new(MaterialType::Sprite, pan_sprite_text_id),
-        MaybeLoadedMaterial::new_material_loaded(
-            MaterialType::Sprite,
-            DefaultMaterials::Sprite.material_id(),
-        ),
-    ];
+    let registered_test_systems = registered_test_systems();
+    let teardown_leaks = TeardownLeaks {
+        test_objects: material_test_object_query.len(),
+        postprocesses: world_render_manager
+            .postprocesses()
+            .iter()
+            // The color blindness simulation stays on across views
+            .filter(|postprocess| {
+                color_blind_simulation.material_id != Some(*postprocess.material_id())
+            })
+            .map(|postprocess| {
+                let material_id = *postprocess.material_id();
+                material_test_query
+                    .iter()
+                    .find(|material_test| {
+                        material_test
+                            .material_id_iter()
+                            .any(|test_material_id| test_material_id == Some(material_id))
+                    })
+                    .map_or_else(
+                        || format!("{material_id:?}"),
+                        |material_test| material_test.name().to_string(),
+                    )
+            })
+            .collect(),
+        unregistered_tests: material_test_query
+            .iter()
+            .filter(|material_test| {
+                !registered_test_systems
+                    .iter()
+                    .any(|system| *system == material_test.startup_system_name())
+            })
+            .map(|material_test| material_test.name().to_string())
+            .collect(),
+    };
+    if teardown_leaks.is_empty() {
+        info!("Leaving {left_test} cleaned up after it");
+        return;
+    }
+    let message = teardown_leaks.report(&left_test);
+    error!("{message}");
+    toast::show(&message, DEFAULT_TOAST_SECONDS);
+}
 
-    let stress_test_material_test = &MaterialTest::new(
-        "stress_test",
-        c"stress_test_startup_system",
-        material_ids,
-        &MaterialType::Sprite,
-        material_test_id_holder,
-    );
-    Engine::spawn(bundle!(stress_test_material_test));
+/// A [`Resource`] recording the input to `--record=<path>` and replaying it from
+/// `--replay=<path>`, see [`input_replay`]
+#[derive(Debug, Default, Resource)]
+pub struct InputReplayState {
+    recorder: Option<InputRecorder>,
+    replay: Option<InputReplay>,
+}
 
-    let immediate_mode_test_material_test = &MaterialTest::new(
-        "immediate_mode_test",
-        c"immediate_mode_test",
-        material_ids,
-        &MaterialType::Sprite,
-        material_test_id_holder,
-    );
-    Engine::spawn(bundle!(immediate_mode_test_material_test));
-
-    let args = args().collect::<Vec<String>>();
-    if args.len() > 1 {
-        let test_name = &args[1];
-        let test_id = match test_name.to_lowercase().as_str() {
-            "invert_y" => Some((MaterialType::PostProcessing, invert_y_y_test_id)),
-            "test_post" => Some((MaterialType::PostProcessing, test_post_test_id)),
-            "warp" => Some((MaterialType::PostProcessing, warp_test_id)),
-            "channel_inspector" => Some((MaterialType::Sprite, channel_inspector_test_id)),
-            "color_replacement" => Some((MaterialType::Sprite, color_replacement_test_id)),
-            "desat_sprite" => Some((MaterialType::Sprite, desat_sprite_test_id)),
-            "pan_sprite" => Some((MaterialType::Sprite, pan_sprite_test_id)),
-            "scrolling_color" => Some((MaterialType::Sprite, scrolling_color_test_id)),
-            "starfield" => Some((MaterialType::Sprite, starfield_test_id)),
-            "immediate_mode_test" => {
-                Some((MaterialType::Sprite, immediate_mode_test_material_test.id()))
-            }
-            "stress_test" => Some((MaterialType::Sprite, stress_test_material_test.id())),
-            _ => None,
-        };
-        if let Some((material_type, test_id)) = test_id {
-            view.post_load_transition = Some(TransitionTo::Material((material_type, test_id)));
+/// The first system, so every other system sees the replayed input
+#[system]
+fn input_replay_system(input_state: &mut InputState, input_replay_state: &mut InputReplayState) {
+    if let Some(input_replay) = &mut input_replay_state.replay {
+        input_replay.next_frame().inject(input_state);
+        if input_replay.is_finished() {
+            info!("Input replay finished");
+            toast::show("Input replay finished", DEFAULT_TOAST_SECONDS);
+            input_replay_state.replay = None;
+        }
+    }
+    if let Some(recorder) = &mut input_replay_state.recorder {
+        if let Err(error) = recorder.record(InputFrame::capture(input_state)) {
+            let message = format!("Stopped recording input: {error}");
+            error!("{message}");
+            toast::show(&message, DEFAULT_TOAST_SECONDS);
+            input_replay_state.recorder = None;
         }
     }
+}
 
-    view.set_transition_to(TransitionTo::Loading);
-    set_system_enabled!(true, handle_assets_loaded);
+/// A [`Resource`] holding the `--soak` mode, see [`soak`]. It has its own random numbers, so the
+/// tests' layouts don't depend on what the soak did before.
+#[derive(Debug, Default, Resource)]
+pub struct SoakMode {
+    pub soak: Option<Soak>,
+    rng: TestRng,
+    /// The key pressed on the previous frame, released on this one
+    pressed_key: Option<KeyCode>,
 }
 
-#[system]
-fn handle_material_id_from_text_id_events(
-    mut material_test_assets: Query<&mut MaterialTest>,
-    material_id_from_text_id_events: EventReader<MaterialIdFromTextId>,
-) {
-    for material_id_from_text_id_event in &material_id_from_text_id_events {
-        material_test_assets.for_each(|material_test_asset| {
-            let text_id =
-                TextId(unsafe { NonZero::new_unchecked(material_id_from_text_id_event.text_id()) });
-            let material_id = MaterialId(material_id_from_text_id_event.material_id());
-            material_test_asset.update_maybe_loaded_materials(text_id, material_id);
-            Engine::spawn(bundle!(&MaterialAsset::new(material_id)));
-        });
+fn random_soak_transition(
+    rng: &mut TestRng,
+    material_test_query: &Query<&MaterialTest>,
+) -> TransitionTo {
+    let material_tests = material_test_query.iter().collect::<Vec<_>>();
+    match rng.gen_range(0..3) {
+        0 => TransitionTo::MainView,
+        1 => {
+            let material_type = if rng.gen_bool(0.5) {
+                MaterialType::Sprite
+            } else {
+                MaterialType::PostProcessing
+            };
+            TransitionTo::MaterialSelection(material_type, None)
+        }
+        _ if material_tests.is_empty() => TransitionTo::MainView,
+        _ => {
+            let material_test = material_tests[rng.gen_range(0..material_tests.len())];
+            TransitionTo::Material((*material_test.material_type(), material_test.id()))
+        }
     }
 }
 
+/// Presses a random key or changes the view while `--soak` runs, at most one action a frame. Runs
+/// after [`input_replay_system`], so every other system sees the presses.
 #[system]
-fn handle_assets_loaded(
-    gpu_interface: &GpuInterface,
-    text_asset_manager: &TextAssetManager,
-    mut material_assets: Query<(&EntityId, &MaterialAsset)>,
-    mut material_text_assets: Query<(&EntityId, &MaterialTextAsset)>,
-    mut material_texture_assets: Query<(&EntityId, &MaterialTextureAsset)>,
+fn soak_system(
+    frame_constants: &FrameConstants,
+    entity_counts_overlay: &EntityCountsOverlay,
     view: &mut View,
+    input_state: &mut InputState,
+    soak_mode: &mut SoakMode,
+    material_test_query: Query<&MaterialTest>,
 ) {
-    let texture_ids_iter = material_texture_assets.iter().map(|query_components_ref| {
-        let (_, material_texture_asset) = query_components_ref.unpack();
-        material_texture_asset.texture_id()
-    });
-    let text_ids_iter = material_text_assets.iter().map(|query_components_ref| {
-        let (_, material_text_asset) = query_components_ref.unpack();
-        material_text_asset.text_id()
-    });
-    let pipeline_ids =
-        material_assets
-            .iter()
-            .fold(vec![], |mut accumulator, query_components_ref| {
-                let (_, material_asset) = query_components_ref.unpack();
-                let material_id = material_asset.material_id();
-                if let Some(pipeline_id) = gpu_interface
-                    .pipeline_asset_manager
-                    .get_pipeline_id_from_material_id(*material_id)
-                {
-                    accumulator.push(pipeline_id);
-                }
-                accumulator
-            });
-    if gpu_interface
-        .texture_asset_manager
-        .are_all_ids_loaded(texture_ids_iter)
-        && text_asset_manager.are_all_ids_loaded(text_ids_iter)
-        && !pipeline_ids.is_empty()
-        && gpu_interface
-            .pipeline_asset_manager
-            .are_all_ids_loaded(pipeline_ids.iter())
-    {
-        view.set_transition_to(match view.post_load_transition {
-            Some(transition_to) => transition_to,
-            None => TransitionTo::MainView,
-        });
+    let SoakMode {
+        soak: Some(soak),
+        rng,
+        pressed_key,
+    } = soak_mode
+    else {
+        return;
+    };
+    if let Some(key_code) = pressed_key.take() {
+        input_state.keys[key_code].set_pressed(false);
+    }
 
-        view.post_load_transition = None;
+    let soak_tick = soak.advance(frame_constants.delta_time);
+    if soak_tick.actions > 0 {
+        if rng.gen_range(0..SOAK_TRANSITION_ONE_IN) == 0 {
+            view.set_transition_to(random_soak_transition(rng, &material_test_query));
+        } else {
+            // Back from the main view quits
+            let in_main_view = matches!(view.view_state(), ViewState::MainView(_));
+            let keys = soak_actions()
+                .filter(|input_action| !in_main_view || *input_action != InputAction::Back)
+                .flat_map(|input_action| bound_keys(input_action).iter().copied())
+                .collect::<Vec<_>>();
+            if !keys.is_empty() {
+                let key_code = keys[rng.gen_range(0..keys.len())];
+                input_state.keys[key_code].set_pressed(true);
+                *pressed_key = Some(key_code);
+            }
+        }
+    }
 
-        material_texture_assets.for_each(|(entity_id, _)| {
-            Engine::despawn(**entity_id);
-        });
+    if soak_tick.sample {
+        soak.record_sample(entity_counts_overlay.entity_counts);
+        // Written at every sample, so a soak that crashes still leaves its report
+        if let Err(error) = soak.write_report(SOAK_REPORT_PATH) {
+            error!("Could not write soak report to {SOAK_REPORT_PATH}: {error}");
+        }
+    }
+    if soak_tick.finished {
+        info!(
+            "Soak finished after {} samples, report written to {SOAK_REPORT_PATH}",
+            soak.samples().len()
+        );
+        view.set_transition_to(TransitionTo::Exit);
+    }
+}
 
-        material_text_assets.for_each(|(entity_id, _)| {
-            Engine::despawn(**entity_id);
-        });
+pub const TEST_CLOCK_MIN_TIME_SCALE: f32 = 1. / 16.;
+pub const TEST_CLOCK_MAX_TIME_SCALE: f32 = 16.;
+pub const TEST_CLOCK_SLOW_MOTION_TIME_SCALE: f32 = 0.1;
+
+/// A [`Resource`] scaling and pausing the time fed to the per-frame test systems, so animated
+/// shaders can be inspected. P toggles pause, period steps a single frame while paused, [ and ]
+/// halve and double the time scale, and backslash toggles slow motion.
+#[derive(Debug, Deserialize, Resource, Serialize)]
+pub struct TestClock {
+    pub paused: bool,
+    pub time_scale: f32,
+    delta_time: f32,
+}
 
-        material_assets.for_each(|(entity_id, _)| {
-            Engine::despawn(**entity_id);
-        });
+impl Default for TestClock {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            time_scale: 1.,
+            delta_time: 0.,
+        }
+    }
+}
 
-        set_system_enabled!(
-            false,
-            handle_assets_loaded,
-            handle_material_id_from_text_id_events
-        );
+impl TestClock {
+    /// The scaled time since the last frame, zero while paused
+    pub fn delta_time(&self) -> f32 {
+        self.delta_time
+    }
+
+    pub fn toggle_slow_motion(&mut self) {
+        self.time_scale = if self.time_scale == TEST_CLOCK_SLOW_MOTION_TIME_SCALE {
+            1.
+        } else {
+            TEST_CLOCK_SLOW_MOTION_TIME_SCALE
+        };
+    }
+
+    pub fn tick(&mut self, frame_delta_time: f32, single_step: bool) {
+        self.delta_time = if self.paused && !single_step {
+            0.
+        } else {
+            frame_delta_time * self.time_scale
+        };
+    }
+
+    /// The time scale itself is shown in the perf overlay
+    pub fn status_text(&self) -> Option<String> {
+        self.paused.then(|| {
+            format!(
+                "Paused  ({}: resume, {}: step)",
+                bound_keys_text(InputAction::PauseToggle),
+                bound_keys_text(InputAction::SingleStep)
+            )
+        })
     }
 }
 
-#[system_once]
-fn channel_inspector_startup_system(
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct TestClockText;
+
+#[system]
+fn test_clock_system(
     aspect: &Aspect,
-    material_test_query: Query<&MaterialTest>,
-    gpu_interface: &GpuInterface,
+    frame_constants: &FrameConstants,
+    input_state: &InputState,
+    uniform_entry: &UniformEntry,
+    view: &View,
+    test_clock: &mut TestClock,
+    mut test_clock_texts: Query<(&mut TextRender, &TestClockText)>,
 ) {
-    let Some(channel_inspector_material_test) = material_test_query
-        .iter()
-        .find(|material_test| material_test.name() == "channel_inspector")
-    else {
-        error!("Could not find channel_inspector material test");
-        return;
-    };
-    let Some(Some(material_id)) = channel_inspector_material_test.material_id_iter().next() else {
-        error!("Could not find material id on channel inspector");
-        return;
-    };
-
-    let star_map_texture_id = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/star_map_with_mask.png".into())
-        .unwrap()
-        .id();
-
-    let channel_images_scale = Vec2::splat(aspect.width * 0.1);
-
-    let mut base_material_params = MaterialParameters::new(material_id)
-        .update_texture(
-            &gpu_interface.material_manager,
-            &("map", &star_map_texture_id),
-        )
-        .unwrap()
-        .end_chain();
-
-    let channel_names = ["red", "green", "blue", "alpha"];
+    let in_material_test = matches!(view.view_state(), ViewState::Material((_, _)));
+    let mut single_step = false;
+    // Period is typed into the uniform entry field while it's open
+    if in_material_test && !uniform_entry.is_open() {
+        if is_pause_toggle_just_pressed(input_state) {
+            test_clock.paused = !test_clock.paused;
+        }
+        single_step = test_clock.paused && is_single_step_just_pressed(input_state);
+        if is_time_scale_down_just_pressed(input_state) {
+            test_clock.time_scale = (test_clock.time_scale * 0.5).max(TEST_CLOCK_MIN_TIME_SCALE);
+        } else if is_time_scale_up_just_pressed(input_state) {
+            test_clock.time_scale = (test_clock.time_scale * 2.).min(TEST_CLOCK_MAX_TIME_SCALE);
+        } else if is_slow_motion_toggle_just_pressed(input_state) {
+            test_clock.toggle_slow_motion();
+        }
+    }
+    test_clock.tick(frame_constants.delta_time, single_step);
 
-    for (index, channel_name) in channel_names.into_iter().enumerate() {
-        let channel_value = index as f32;
-        let channel_material_params = base_material_params
-            .update_uniform(
-                &gpu_interface.material_manager,
-                &("channel", &channel_value.into()),
-            )
-            .unwrap()
-            .end_chain();
+    if !in_material_test {
+        return;
+    }
 
-        let x_percent = 0.125 + 2. * 0.125 * channel_value;
-        let texture_position =
-            screen_space_coordinate_by_percent(aspect, x_percent.into(), 0.5.into()).extend(0.);
-        let mut texture_component_builder = create_new_texture(
-            texture_position.into(),
-            *palette::WHITE,
-            star_map_texture_id,
-            Some(channel_images_scale),
+    let status_text = test_clock.status_text();
+    if test_clock_texts.is_empty() {
+        let mut text_component_builder = create_new_text::<_, CustomText>(
+            CreateTextInput {
+                text: status_text.clone().unwrap_or_default(),
+                visible: status_text.is_some(),
+                position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.975.into())
+                    .extend(4000.),
+                text_type: TextTypes::Custom(24.),
+                ..Default::default()
+            },
+            aspect,
         );
-        texture_component_builder.add_components(bundle_for_builder!(
-            MaterialTestObject,
-            channel_material_params
-        ));
-        Engine::spawn(&texture_component_builder.build());
-
-        let mut text_component_builder = create_new_text::<_, RegularText>(CreateTextInput {
-            position: texture_position - Vec3::new(0., aspect.height * 0.2, 0.),
-            text: channel_name,
-            ..Default::default()
-        });
-        text_component_builder.add_component(MaterialTestObject);
+        text_component_builder
+            .add_components(bundle_for_builder!(MaterialTestObject, TestClockText));
         Engine::spawn(&text_component_builder.build());
+    } else {
+        test_clock_texts.for_each(|(text_render, _)| {
+            text_render.text = text_to_u8_array(status_text.as_deref().unwrap_or_default());
+            text_render.visible = status_text.is_some();
+        });
     }
 }
 
-#[system_once]
-fn color_replacement_startup_system(
-    aspect: &Aspect,
-    gpu_interface: &GpuInterface,
-    material_test_query: Query<&mut MaterialTest>,
-) {
-    let Some(channel_inspector_material_test) = material_test_query
-        .iter()
-        .find(|material_test| material_test.name() == "color_replacement")
-    else {
-        error!("Could not find color_replacement material test");
-        return;
-    };
-    let Some(Some(material_id)) = channel_inspector_material_test.material_id_iter().next() else {
-        error!("Could not find material id on color_replacement");
-        return;
-    };
-
-    let white_color_uniform: UniformValue = (*palette::WHITE).get().into();
-    let grey_color_uniform = (*palette::GRAY).get().into();
-    let scared_id = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/scared.png".into())
-        .unwrap()
-        .id();
+/// A [`Resource`] repeating held arrow keys in menus and test controls, see [`KeyRepeat`]. The
+/// delay and rate are set with `--key-repeat=<delay seconds>,<repeats per second>`.
+#[derive(Debug, Default, Resource)]
+pub struct KeyRepeatState {
+    key_repeat: KeyRepeat,
+}
 
-    let material_params = MaterialParameters::new(material_id)
-        .update_uniforms(
-            &gpu_interface.material_manager,
-            &[
-                ("color_to_replace", &white_color_uniform),
-                ("color_to_insert", &grey_color_uniform),
-            ],
-        )
-        .unwrap()
-        .update_texture(&gpu_interface.material_manager, &("color_tex", &scared_id))
-        .unwrap()
-        .end_chain();
+impl KeyRepeatState {
+    pub fn new(key_repeat: KeyRepeat) -> Self {
+        Self { key_repeat }
+    }
+}
 
-    let mut texture_component_builder = create_new_texture(
-        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
-            .extend(0.)
-            .into(),
-        *palette::WHITE,
-        scared_id,
-        Some(Vec2::splat(aspect.width * 0.25)),
-    );
-    texture_component_builder.add_components(bundle_for_builder!(
-        MaterialTestObject,
-        material_params,
-        TimePassedSinceCreation::default()
-    ));
-    Engine::spawn(&texture_component_builder.build());
+impl Deref for KeyRepeatState {
+    type Target = KeyRepeat;
 
-    let mut text_component_builder = create_new_text::<_, HeaderText>(CreateTextInput {
-        position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.75.into()).extend(0.),
-        text: "Test",
-        ..Default::default()
-    });
-    text_component_builder.add_component(MaterialTestObject);
-    Engine::spawn(&text_component_builder.build());
-    set_system_enabled!(true, color_replacement_system);
+    fn deref(&self) -> &Self::Target {
+        &self.key_repeat
+    }
 }
 
+/// Runs on the unscaled frame time, so keys keep repeating while the [`TestClock`] is paused
 #[system]
-fn color_replacement_system(
+fn key_repeat_system(
     frame_constants: &FrameConstants,
-    gpu_interface: &GpuInterface,
-    mut textures: Query<(
-        &TextureRender,
-        &mut TimePassedSinceCreation,
-        &mut MaterialParameters,
-    )>,
+    input_state: &InputState,
+    key_repeat_state: &mut KeyRepeatState,
 ) {
-    textures.for_each(|(_, time_passed_since_creation, material_params)| {
-        *time_passed_since_creation += frame_constants.delta_time;
+    key_repeat_state
+        .key_repeat
+        .update(input_state, frame_constants.delta_time);
+}
 
-        let new_target_color: UniformValue = Vec4::new(
-            f32::sin(***time_passed_since_creation * 0.1).abs(),
-            f32::cos(***time_passed_since_creation * 0.5).abs(),
-            f32::sin(***time_passed_since_creation * 0.3 + 0.5).abs(),
-            1.,
-        )
-        .into();
+/// A [`Resource`] timing clicks and held keys, see [`PressTiming`]
+#[derive(Debug, Default, Resource)]
+pub struct PressTimingState {
+    press_timing: PressTiming,
+}
 
-        material_params
-            .update_uniform(
-                &gpu_interface.material_manager,
-                &("color_to_insert", &new_target_color),
-            )
-            .unwrap();
-    });
+impl Deref for PressTimingState {
+    type Target = PressTiming;
+
+    fn deref(&self) -> &Self::Target {
+        &self.press_timing
+    }
 }
 
-#[system_once]
-fn pan_sprite_startup_system(
-    aspect: &Aspect,
-    gpu_interface: &GpuInterface,
-    material_test_query: Query<&MaterialTest>,
+#[system]
+fn press_timing_system(
+    frame_constants: &FrameConstants,
+    input_state: &InputState,
+    press_timing_state: &mut PressTimingState,
 ) {
-    let Some(pan_sprite_material_test) = material_test_query
-        .iter()
-        .find(|material_test| material_test.name() == "pan_sprite")
-    else {
-        error!("Could not find pan_sprite material test");
-        return;
-    };
-    let Some(Some(material_id)) = pan_sprite_material_test.material_id_iter().next() else {
-        error!("Could not find material id on pan_sprite");
-        return;
-    };
-
-    let arrow_up_id = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/arrow_up.png".into())
-        .unwrap()
-        .id();
+    press_timing_state
+        .press_timing
+        .update(input_state, frame_constants.delta_time);
+}
 
-    let material_params = MaterialParameters::new(material_id)
-        .update_texture(
-            &gpu_interface.material_manager,
-            &("color_tex", &arrow_up_id),
-        )
-        .unwrap()
-        .end_chain();
+/// A [`Resource`] reading taps and swipes from the primary pointer, see [`TouchTracker`]. Menus
+/// open tapped entries and go back on a downward swipe, so they work without a keyboard.
+#[derive(Debug, Default, Resource)]
+pub struct TouchState {
+    touch_tracker: TouchTracker,
+}
 
-    let mut texture_component_builder = create_new_texture(
-        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
-            .extend(0.)
-            .into(),
-        *palette::WHITE,
-        arrow_up_id,
-        Some(Vec2::splat(aspect.width * 0.15)),
-    );
-    texture_component_builder
-        .add_components(bundle_for_builder!(MaterialTestObject, material_params));
-    Engine::spawn(&texture_component_builder.build());
+impl Deref for TouchState {
+    type Target = TouchTracker;
 
-    let mut text_component_builder = create_new_text::<_, HeaderText>(CreateTextInput {
-        position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.75.into()).extend(0.),
-        text: "Test",
-        ..Default::default()
-    });
-    text_component_builder.add_component(MaterialTestObject);
-    Engine::spawn(&text_component_builder.build());
+    fn deref(&self) -> &Self::Target {
+        &self.touch_tracker
+    }
 }
 
-#[system_once]
-fn desat_sprite_startup_system(
+#[system]
+fn touch_system(
     aspect: &Aspect,
-    gpu_interface: &GpuInterface,
-    material_test_query: Query<&MaterialTest>,
+    frame_constants: &FrameConstants,
+    input_state: &InputState,
+    touch_state: &mut TouchState,
 ) {
-    let Some(desat_sprite_material_test) = material_test_query
-        .iter()
-        .find(|material_test| material_test.name() == "desat_sprite")
-    else {
-        error!("Could not find desat_sprite material test");
-        return;
-    };
-    let Some(Some(material_id)) = desat_sprite_material_test.material_id_iter().next() else {
-        error!("Could not find material id on desat_sprite");
-        return;
-    };
+    touch_state
+        .touch_tracker
+        .update(input_state, aspect, frame_constants.delta_time);
+}
 
-    let arrow_up_id = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/arrow_up.png".into())
-        .unwrap()
-        .id();
+/// A [`Component`] keeping an entity at the same screen percentage when the window is resized,
+/// see [`relayout_system`]. The text, texture and underline builders add it to everything they
+/// create. The percentage is captured from the [`Transform`] on the first resize, and captured
+/// again whenever something else has moved the entity since the last relayout. Entities sized
+/// relative to the screen are rescaled along with being moved.
+#[derive(
+    Debug, Default, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize,
+)]
+pub struct PercentPosition {
+    percent: Option<[f32; 2]>,
+    laid_out_position: [f32; 2],
+    relative_scale: Option<RelativeScale>,
+}
 
-    let material_params = MaterialParameters::new(material_id)
-        .update_texture(
-            &gpu_interface.material_manager,
-            &("color_tex", &arrow_up_id),
-        )
-        .unwrap()
-        .end_chain();
+impl PercentPosition {
+    pub fn with_relative_scale(relative_scale: RelativeScale) -> Self {
+        Self {
+            relative_scale: Some(relative_scale),
+            ..Default::default()
+        }
+    }
 
-    let mut texture_component_builder = create_new_texture(
-        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
-            .extend(0.)
-            .into(),
-        *palette::WHITE,
-        arrow_up_id,
-        Some(Vec2::splat(aspect.width * 0.15)),
-    );
+    /// Returns `scale`, sized for `previous_screen_size`, sized for `screen_size`. The scale is
+    /// kept when the entity isn't sized relative to the screen.
+    pub fn rescale(&self, scale: Vec2, previous_screen_size: Vec2, screen_size: Vec2) -> Vec2 {
+        match self.relative_scale {
+            Some(relative_scale) => {
+                relative_scale.rescale(scale, previous_screen_size, screen_size)
+            }
+            None => scale,
+        }
+    }
 
-    texture_component_builder
-        .add_components(bundle_for_builder!(MaterialTestObject, material_params));
-    Engine::spawn(&texture_component_builder.build());
+    /// Returns where `position`, laid out for `previous_screen_size`, belongs on `screen_size`
+    pub fn relayout(
+        &mut self,
+        position: Vec2,
+        previous_screen_size: Vec2,
+        screen_size: Vec2,
+    ) -> Vec2 {
+        let percent = match self.percent {
+            Some(percent) if Vec2::from(self.laid_out_position) == position => Vec2::from(percent),
+            _ => position_to_percent(position, previous_screen_size),
+        };
+        let laid_out_position = percent_to_position(percent, screen_size);
+        self.percent = Some(percent.to_array());
+        self.laid_out_position = laid_out_position.to_array();
+        laid_out_position
+    }
+}
 
-    let mut text_component_builder = create_new_text::<_, HeaderText>(CreateTextInput {
-        position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.75.into()).extend(0.),
-        text: "Test",
-        ..Default::default()
-    });
-    text_component_builder.add_component(MaterialTestObject);
-    Engine::spawn(&text_component_builder.build());
+/// A [`Component`] added to text created with a relative [`TextTypes`], [`relayout_system`]
+/// recalculates its font size from the screen height when the window is resized
+#[derive(
+    Debug, Default, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize,
+)]
+pub struct RelativeFontSize {
+    pub percent_of_height: f32,
 }
 
-const SCROLLING_COLOR_SCROLL_SPEED_CENTER_POINT: f32 = 1.;
+/// A [`Resource`] remembering the screen size [`relayout_system`] last laid entities out for
+#[derive(Debug, Default, Resource)]
+pub struct LayoutScreenSize {
+    screen_size: Option<Vec2>,
+}
 
-#[system_once]
-fn scrolling_color_startup_system(
+/// Positions and sizes are computed from the [`Aspect`] when entities are spawned, so this moves
+/// every entity with a [`PercentPosition`] back to its screen percentage, and resizes the ones
+/// sized relative to the screen, when the window is resized
+#[system]
+fn relayout_system(
     aspect: &Aspect,
-    gpu_interface: &GpuInterface,
-    material_test_query: Query<&MaterialTest>,
+    layout_screen_size: &mut LayoutScreenSize,
+    mut percent_position_query: Query<(&mut Transform, &mut PercentPosition)>,
+    mut relative_font_size_query: Query<(&mut TextRender, &RelativeFontSize)>,
 ) {
-    let Some(scrolling_color_material_test) = material_test_query
-        .iter()
-        .find(|material_test| material_test.name() == "scrolling_color")
-    else {
-        error!("Could not find scrolling_color material test");
+    let screen_size = Vec2::new(aspect.width, aspect.height);
+    let Some(previous_screen_size) = layout_screen_size.screen_size.replace(screen_size) else {
         return;
     };
-    let Some(Some(material_id)) = scrolling_color_material_test.material_id_iter().next() else {
-        error!("Could not find material id on scrolling_color");
+    if previous_screen_size == screen_size {
         return;
-    };
+    }
+
+    percent_position_query.for_each(|(transform, percent_position)| {
+        let position = transform.position.get();
+        let laid_out_position =
+            percent_position.relayout(position.truncate(), previous_screen_size, screen_size);
+        transform.position.set(laid_out_position.extend(position.z));
+        transform.scale.set(percent_position.rescale(
+            transform.scale.get(),
+            previous_screen_size,
+            screen_size,
+        ));
+    });
+    relative_font_size_query.for_each(|(text_render, relative_font_size)| {
+        text_render.font_size = relative_font_size.percent_of_height * screen_size.y;
+    });
+}
+
+/// A [`Resource`] for panning and zooming around material tests, the middle mouse button drags,
+/// the scroll wheel zooms around the cursor and Home resets the view. Outside of
+/// [`ViewState::Material`] the view is always reset.
+#[derive(Debug, Default, Deserialize, Resource, Serialize)]
+pub struct TestCamera {
+    pub view: CameraView,
+    drag_cursor: Option<Vec2>,
+    applied_view: Option<CameraView>,
+}
+
+#[system]
+fn camera_system(
+    aspect: &Aspect,
+    input_state: &InputState,
+    shader_source_viewer: &ShaderSourceViewer,
+    uniform_entry: &UniformEntry,
+    view: &View,
+    test_camera: &mut TestCamera,
+    mut camera_query: Query<(&mut Transform, &Camera)>,
+) {
+    if matches!(view.view_state(), ViewState::Material((_, _))) {
+        let cursor_position = input_state.mouse.cursor_position;
+        let cursor = screen_to_world(aspect, Vec2::new(cursor_position.x, cursor_position.y));
+
+        if is_camera_reset_just_pressed(input_state) {
+            test_camera.view = CameraView::default();
+        }
+        if is_camera_drag_pressed(input_state) {
+            if let Some(drag_cursor) = test_camera.drag_cursor {
+                test_camera.view.pan(cursor - drag_cursor);
+            }
+            test_camera.drag_cursor = Some(cursor);
+        } else {
+            test_camera.drag_cursor = None;
+        }
+        let wheel_steps = camera_zoom_wheel_steps(input_state);
+        // The wheel adjusts the uniform entry field's value or scrolls the shader source while
+        // they are open
+        if wheel_steps != 0. && !uniform_entry.is_open() && !shader_source_viewer.visible {
+            test_camera.view.zoom_at(cursor, wheel_steps);
+        }
+    } else {
+        test_camera.view = CameraView::default();
+        test_camera.drag_cursor = None;
+    }
+
+    if test_camera.applied_view == Some(test_camera.view) {
+        return;
+    }
+    let camera_view = test_camera.view;
+    camera_query.for_each(|(transform, _)| {
+        let camera_z = transform.position.get().z;
+        transform.position.set(camera_view.offset.extend(camera_z));
+        transform.scale = Vec2::splat(1. / camera_view.zoom).into();
+    });
+    test_camera.applied_view = Some(camera_view);
+}
+
+/// Loads the textures and material definitions the tests use and registers every [`MaterialTest`].
+/// A failure is logged and only skips the tests depending on what failed to load.
+fn register_material_tests(
+    gpu_interface: &mut GpuInterface,
+    material_test_id_holder: &mut MaterialTestIdHolder,
+    text_asset_manager: &mut TextAssetManager,
+    new_texture_event_writer: &EventWriter<NewTexture>,
+    new_text_event_writer: &EventWriter<NewText<'_>>,
+) {
+    log_registration_failure(register_texture(
+        "textures/arrow_up.png",
+        true,
+        gpu_interface,
+        new_texture_event_writer,
+    ));
+    log_registration_failure(register_texture(
+        "textures/checker.png",
+        false,
+        gpu_interface,
+        new_texture_event_writer,
+    ));
+    // The nine slice material samples the whole image
+    log_registration_failure(register_texture(
+        "textures/panel.png",
+        false,
+        gpu_interface,
+        new_texture_event_writer,
+    ));
+    log_registration_failure(register_texture(
+        "textures/msdf_glyphs.png",
+        false,
+        gpu_interface,
+        new_texture_event_writer,
+    ));
+    // Not packed into the engine's atlas, the atlas test's uvs select cells of the whole image
+    log_registration_failure(register_texture(
+        "textures/atlas.png",
+        true,
+        gpu_interface,
+        new_texture_event_writer,
+    ));
+    log_registration_failure(register_texture(
+        "textures/random.png",
+        false,
+        gpu_interface,
+        new_texture_event_writer,
+    ));
+    log_registration_failure(register_texture(
+        "textures/scared.png",
+        true,
+        gpu_interface,
+        new_texture_event_writer,
+    ));
+    log_registration_failure(register_texture(
+        "textures/bumps_color.png",
+        false,
+        gpu_interface,
+        new_texture_event_writer,
+    ));
+    log_registration_failure(register_texture(
+        "textures/bumps_normal.png",
+        false,
+        gpu_interface,
+        new_texture_event_writer,
+    ));
+    log_registration_failure(register_texture(
+        "textures/splat_mask.png",
+        false,
+        gpu_interface,
+        new_texture_event_writer,
+    ));
+    log_registration_failure(register_texture(
+        "textures/star_map_with_mask.png",
+        false,
+        gpu_interface,
+        new_texture_event_writer,
+    ));
+    log_registration_failure(register_texture(
+        "textures/pixel_heart.png",
+        true,
+        gpu_interface,
+        new_texture_event_writer,
+    ));
+    log_registration_failure(register_texture(
+        "textures/white.png",
+        true,
+        gpu_interface,
+        new_texture_event_writer,
+    ));
+
+    let invert_y_text_id = log_registration_failure(register_material::<InvertYTest>(
+        "invert_y",
+        MaterialType::PostProcessing,
+        "toml_materials/post_processing/invert_y.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ))
+    .map(|(text_id, _)| text_id);
+    log_registration_failure(register_material::<TestPostTest>(
+        "test_post",
+        MaterialType::PostProcessing,
+        "toml_materials/post_processing/test_post.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<WarpTest>(
+        "warp",
+        MaterialType::PostProcessing,
+        "toml_materials/post_processing/warp.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+
+    log_registration_failure(register_material::<HudExclusionTest>(
+        "hud_exclusion",
+        MaterialType::PostProcessing,
+        "toml_materials/post_processing/hud_exclusion.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<IrisWipeTest>(
+        "iris_wipe",
+        MaterialType::PostProcessing,
+        "toml_materials/post_processing/iris_wipe.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<MirrorFloorTest>(
+        "mirror_floor",
+        MaterialType::PostProcessing,
+        "toml_materials/post_processing/mirror_floor.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+
+    let channel_inspector_text_id =
+        log_registration_failure(register_material::<ChannelInspectorTest>(
+            "channel_inspector",
+            MaterialType::Sprite,
+            "toml_materials/sprite/channel_inspector.toml",
+            gpu_interface,
+            material_test_id_holder,
+            new_text_event_writer,
+            text_asset_manager,
+        ))
+        .map(|(text_id, _)| text_id);
+    log_registration_failure(register_material::<ColorReplacementTest>(
+        "color_replacement",
+        MaterialType::Sprite,
+        "toml_materials/sprite/color_replacement.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    let desat_sprite_text_id = log_registration_failure(register_material::<DesatSpriteTest>(
+        "desat_sprite",
+        MaterialType::Sprite,
+        "toml_materials/sprite/desat_sprite.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ))
+    .map(|(text_id, _)| text_id);
+    let pan_sprite_text_id = log_registration_failure(register_material::<PanSpriteTest>(
+        "pan_sprite",
+        MaterialType::Sprite,
+        "toml_materials/sprite/pan_sprite.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ))
+    .map(|(text_id, _)| text_id);
+    log_registration_failure(register_material::<ScrollingColorTest>(
+        "scrolling_color",
+        MaterialType::Sprite,
+        "toml_materials/sprite/scrolling_color.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<StarfieldTest>(
+        "starfield",
+        MaterialType::Sprite,
+        "toml_materials/sprite/starfield.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<FireTest>(
+        "fire",
+        MaterialType::Sprite,
+        "toml_materials/sprite/fire.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<LensFlareTest>(
+        "lens_flare",
+        MaterialType::Sprite,
+        "toml_materials/sprite/lens_flare.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<DayNightTest>(
+        "day_night",
+        MaterialType::Sprite,
+        "toml_materials/sprite/day_night.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<ParallaxTest>(
+        "parallax",
+        MaterialType::Sprite,
+        "toml_materials/sprite/parallax.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<PixelUpscaleTest>(
+        "pixel_upscale",
+        MaterialType::Sprite,
+        "toml_materials/sprite/pixel_upscale.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+
+    // Tests made of other tests' materials are skipped when one of those failed to register
+    let material_ids = match (desat_sprite_text_id, pan_sprite_text_id) {
+        (Some(desat_sprite_text_id), Some(pan_sprite_text_id)) => Some([
+            MaybeLoadedMaterial::new(MaterialType::Sprite, desat_sprite_text_id),
+            MaybeLoadedMaterial::new(MaterialType::Sprite, pan_sprite_text_id),
+            MaybeLoadedMaterial::new_material_loaded(
+                MaterialType::Sprite,
+                DefaultMaterials::Sprite.material_id(),
+            ),
+        ]),
+        _ => None,
+    };
+
+    register_test_systems::<StressTest>();
+    if let Some(material_ids) = &material_ids {
+        let stress_test_material_test = &MaterialTest::new(
+            "stress_test",
+            StressTest::STARTUP_SYSTEM,
+            material_ids,
+            &MaterialType::Sprite,
+            material_test_id_holder,
+        )
+        .with_teardown_system(c"stress_test_teardown_system");
+        Engine::spawn(bundle!(stress_test_material_test));
+    }
+
+    let vignette_text_id = log_registration_failure(load_material(
+        "vignette",
+        MaterialType::PostProcessing,
+        &"toml_materials/post_processing/vignette.toml".into(),
+        gpu_interface,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    let grain_text_id = log_registration_failure(load_material(
+        "grain",
+        MaterialType::PostProcessing,
+        &"toml_materials/post_processing/grain.toml".into(),
+        gpu_interface,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    register_test_systems::<PostStackTest>();
+    if let (Some(invert_y_text_id), Some(vignette_text_id), Some(grain_text_id)) =
+        (invert_y_text_id, vignette_text_id, grain_text_id)
+    {
+        // In the order of `POST_STACK_LAYER_NAMES`
+        let post_stack_material_test = &MaterialTest::new(
+            "post_stack",
+            PostStackTest::STARTUP_SYSTEM,
+            &[
+                MaybeLoadedMaterial::new(MaterialType::PostProcessing, invert_y_text_id),
+                MaybeLoadedMaterial::new(MaterialType::PostProcessing, vignette_text_id),
+                MaybeLoadedMaterial::new(MaterialType::PostProcessing, grain_text_id),
+            ],
+            &MaterialType::PostProcessing,
+            material_test_id_holder,
+        );
+        Engine::spawn(bundle!(post_stack_material_test));
+    }
+
+    log_registration_failure(register_material::<AtlasTest>(
+        "atlas",
+        MaterialType::Sprite,
+        "toml_materials/sprite/atlas.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    for (_, texture_path) in TEXTURE_FORMAT_SAMPLES {
+        // The texture formats test flags formats that fail, instead of them stopping every test
+        if let Err(error) =
+            load_optional_texture(texture_path, false, gpu_interface, new_texture_event_writer)
+        {
+            warn!("{error}");
+        }
+    }
+    register_test_systems::<TextureFormatsTest>();
+    if let Some(channel_inspector_text_id) = channel_inspector_text_id {
+        let texture_formats_material_test = &MaterialTest::new(
+            "texture_formats",
+            TextureFormatsTest::STARTUP_SYSTEM,
+            &[MaybeLoadedMaterial::new(
+                MaterialType::Sprite,
+                channel_inspector_text_id,
+            )],
+            &MaterialType::Sprite,
+            material_test_id_holder,
+        );
+        Engine::spawn(bundle!(texture_formats_material_test));
+    }
+
+    log_registration_failure(register_material::<NineSliceTest>(
+        "nine_slice",
+        MaterialType::Sprite,
+        "toml_materials/sprite/nine_slice.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<MsdfTextTest>(
+        "msdf_text",
+        MaterialType::Sprite,
+        "toml_materials/sprite/msdf_text.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<NormalLightTest>(
+        "normal_light",
+        MaterialType::Sprite,
+        "toml_materials/sprite/normal_light.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<SplatBlendTest>(
+        "splat_blend",
+        MaterialType::Sprite,
+        "toml_materials/sprite/splat_blend.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<ParticlesTest>(
+        "particles",
+        MaterialType::Sprite,
+        "toml_materials/sprite/particles.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+    log_registration_failure(register_material::<MipInspectorTest>(
+        "mip_inspector",
+        MaterialType::Sprite,
+        "toml_materials/sprite/mip_inspector.toml",
+        gpu_interface,
+        material_test_id_holder,
+        new_text_event_writer,
+        text_asset_manager,
+    ));
+
+    let procedural_texture_material_test = &MaterialTest::new(
+        "procedural_texture",
+        ProceduralTextureTest::STARTUP_SYSTEM,
+        &[MaybeLoadedMaterial::new_material_loaded(
+            MaterialType::Sprite,
+            DefaultMaterials::Sprite.material_id(),
+        )],
+        &MaterialType::Sprite,
+        material_test_id_holder,
+    );
+    Engine::spawn(bundle!(procedural_texture_material_test));
+    register_test_systems::<ProceduralTextureTest>();
+
+    register_test_systems::<ImmediateModeTest>();
+    if let Some(material_ids) = &material_ids {
+        let immediate_mode_test_material_test = &MaterialTest::new(
+            "immediate_mode_test",
+            ImmediateModeTest::STARTUP_SYSTEM,
+            material_ids,
+            &MaterialType::Sprite,
+            material_test_id_holder,
+        );
+        Engine::spawn(bundle!(immediate_mode_test_material_test));
+    }
+}
+
+/// Logs a texture or material test that couldn't be registered, so a broken asset only takes
+/// out the tests using it and [`register_material_tests`] carries on with the rest
+fn log_registration_failure<T>(result: local_error::Result<T>) -> Option<T> {
+    result
+        .inspect_err(|error| {
+            let message = format!("{error}, skipping the material tests that need it");
+            error!("{message}");
+            toast::show(&message, DEFAULT_TOAST_SECONDS);
+        })
+        .ok()
+}
+
+#[system_once]
+/// This system sets up all material tests. [`MaterialTest`]'s should all be created in this system,
+/// along with any supporting [`Material`]'s and textures that the [`MaterialTest`] may need.
+///
+/// Please note, this system currently accesses [`GpuResource`] and [`PipelineManager`] from `gpu_web`, which is not the proper
+/// way that a module should access the engine. `gpu_web` is a platform implementation for [`GpuResource`]. In the future,
+/// [`PipelineManager`] will be moved to `void_public` and [`AssetManager`] will be expanded to properly load textures.
+fn materials_setup(
+    gpu_interface: &mut GpuInterface,
+    material_test_id_holder: &mut MaterialTestIdHolder,
+    text_asset_manager: &mut TextAssetManager,
+    new_texture_event_writer: EventWriter<NewTexture>,
+    new_text_event_writer: EventWriter<NewText<'_>>,
+    color_blind_simulation: &mut ColorBlindSimulation,
+    startup_args_state: &mut StartupArgsState,
+    view: &mut View,
+) {
+    console::init();
+
+    register_material_tests(
+        gpu_interface,
+        material_test_id_holder,
+        text_asset_manager,
+        &new_texture_event_writer,
+        &new_text_event_writer,
+    );
+    match load_material(
+        COLOR_BLIND_MATERIAL_NAME,
+        MaterialType::PostProcessing,
+        &COLOR_BLIND_MATERIAL_PATH.into(),
+        gpu_interface,
+        &new_text_event_writer,
+        text_asset_manager,
+    ) {
+        Ok(text_id) => color_blind_simulation.text_id = Some(text_id),
+        Err(error) => {
+            let message = format!("Could not load the color blindness simulation: {error}");
+            error!("{message}");
+            toast::show(&message, DEFAULT_TOAST_SECONDS);
+        }
+    }
+    // The systems are only known once their tests are registered
+    turn_off_material_test_systems();
+
+    let args = match Config::read(CONFIG_PATH) {
+        Some((config, warnings)) => {
+            for warning in warnings {
+                let message = format!("{CONFIG_PATH}: {warning}");
+                warn!("{message}");
+                toast::show(&message, DEFAULT_TOAST_SECONDS);
+            }
+            if let Some(vsync) = config.vsync {
+                info!("{CONFIG_PATH} asks for vsync {vsync}, the engine's present mode decides");
+            }
+            merged_args(args().collect(), &config)
+        }
+        None => args().collect::<Vec<String>>(),
+    };
+    startup_args_state.args = StartupArgs::parse(&args);
+    if startup_args_state.args.list {
+        for material_type in [MaterialType::Sprite, MaterialType::PostProcessing] {
+            println!("{}:", title_from_material_type(&material_type));
+            material_test_id_holder
+                .registered_tests()
+                .iter()
+                .filter(|registered_test| registered_test.material_type == material_type)
+                .for_each(|registered_test| println!("    {}", registered_test.name));
+        }
+        exit(0);
+    }
+
+    view.set_transition_to(TransitionTo::Loading);
+    set_system_enabled!(true, handle_assets_loaded, startup_selection_system);
+}
+
+/// A [`Resource`] holding the command line parsed by [`materials_setup`], see [`startup_args`]
+#[derive(Debug, Default, Resource)]
+pub struct StartupArgsState {
+    args: StartupArgs,
+}
+
+#[system_once]
+/// Applies the locale and the input options. Runs after [`materials_setup`] has parsed the
+/// command line.
+fn input_setup(
+    startup_args_state: &StartupArgsState,
+    input_replay_state: &mut InputReplayState,
+    key_repeat_state: &mut KeyRepeatState,
+) {
+    let startup_args = &startup_args_state.args;
+    if let Some(locale) = &startup_args.locale {
+        match Locale::read(locale_path(locale)) {
+            Some(locale) => {
+                set_locale(locale);
+            }
+            None => {
+                let message = format!("Could not read locale {locale}, falling back to English");
+                warn!("{message}");
+                toast::show(&message, DEFAULT_TOAST_SECONDS);
+            }
+        }
+    }
+    match InputBindings::read(KEYBINDINGS_PATH) {
+        Some((input_bindings, warnings)) => {
+            for warning in warnings {
+                let message = format!("{KEYBINDINGS_PATH}: {warning}");
+                warn!("{message}");
+                toast::show(&message, DEFAULT_TOAST_SECONDS);
+            }
+            set_input_bindings(input_bindings);
+        }
+        None => warn!("Could not read {KEYBINDINGS_PATH}, using the default key bindings"),
+    }
+    if let Some(path) = &startup_args.replay_input {
+        match InputReplay::read(path) {
+            Ok(input_replay) => {
+                info!("Replaying input from {}", path.display());
+                input_replay_state.replay = Some(input_replay);
+            }
+            Err(error) => {
+                let message = format!("Could not read input replay {}: {error}", path.display());
+                error!("{message}");
+                toast::show(&message, DEFAULT_TOAST_SECONDS);
+            }
+        }
+    }
+    if let Some(path) = &startup_args.record_input {
+        match InputRecorder::create(path) {
+            Ok(recorder) => {
+                info!("Recording input to {}", path.display());
+                input_replay_state.recorder = Some(recorder);
+            }
+            Err(error) => {
+                let message = format!("Could not record input to {}: {error}", path.display());
+                error!("{message}");
+                toast::show(&message, DEFAULT_TOAST_SECONDS);
+            }
+        }
+    }
+    if let Some(key_repeat) = startup_args.key_repeat {
+        *key_repeat_state = KeyRepeatState::new(key_repeat);
+    }
+}
+
+#[system_once]
+/// Applies the view and randomness options. Runs after [`materials_setup`] has parsed the command
+/// line.
+fn view_setup(
+    startup_args_state: &StartupArgsState,
+    soak_mode: &mut SoakMode,
+    test_rng: &mut TestRng,
+    view: &mut View,
+) {
+    let startup_args = &startup_args_state.args;
+    if let Some(fade_seconds) = startup_args.fade_seconds {
+        view.fade = ViewFade::new(fade_seconds);
+    }
+    if let Some(transition_style) = startup_args.transition_style {
+        view.transition_style = transition_style;
+    }
+    if let Some(seed) = startup_args.seed {
+        *test_rng = TestRng::from_seed(seed);
+    }
+    if let Some(soak_config) = startup_args.soak {
+        soak_mode.soak = Some(Soak::new(soak_config));
+        soak_mode.rng = TestRng::from_seed(test_rng.seed());
+    }
+}
+
+#[system_once]
+/// Turns on the debug modes asked for on the command line. Runs after [`materials_setup`] has
+/// parsed it.
+fn debug_modes_setup(
+    startup_args_state: &StartupArgsState,
+    material_bench_mode: &mut MaterialBenchMode,
+    perf_overlay: &mut PerfOverlay,
+    shader_bench: &mut ShaderBench,
+    shader_dump: &mut ShaderDump,
+    stress_ramp_mode: &mut StressRampMode,
+    teardown_check_mode: &mut TeardownCheckMode,
+) {
+    let startup_args = &startup_args_state.args;
+    shader_bench.iterations = startup_args.bench_shaders;
+    shader_dump.directory = startup_args.dump_shaders.clone();
+    if let Some(visible) = startup_args.perf_overlay {
+        perf_overlay.visible = visible;
+    }
+    if let Some(step_seconds) = startup_args.stress_ramp_seconds {
+        stress_ramp_mode.stress_ramp = Some(StressRamp::new(step_seconds));
+    }
+    material_bench_mode.requested = startup_args.bench_materials;
+    if startup_args.check_teardown {
+        teardown_check_mode.teardown_check = Some(TeardownCheck::default());
+    }
+}
+
+/// Tests queued on the command line as `<test name>:<seconds>`, played in a loop until the user
+/// leaves the current test
+#[derive(Debug, Default, Resource)]
+pub struct TestPlaylist {
+    entries: Vec<PlaylistEntry>,
+    current_index: usize,
+    elapsed: f32,
+}
+
+impl TestPlaylist {
+    pub fn new(entries: Vec<PlaylistEntry>) -> Self {
+        Self {
+            entries,
+            current_index: 0,
+            elapsed: 0.,
+        }
+    }
+
+    pub fn current_entry(&self) -> Option<&PlaylistEntry> {
+        self.entries.get(self.current_index)
+    }
+
+    pub fn stop(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the next entry once the current one has played for its duration
+    pub fn advance(&mut self, delta_time: f32) -> Option<PlaylistEntry> {
+        let current_entry = self.current_entry()?;
+        self.elapsed += delta_time;
+        if self.elapsed < current_entry.duration_seconds {
+            return None;
+        }
+
+        self.elapsed = 0.;
+        self.current_index = (self.current_index + 1) % self.entries.len();
+        self.current_entry().copied()
+    }
+}
+
+#[system]
+fn test_playlist_system(
+    frame_constants: &FrameConstants,
+    test_playlist: &mut TestPlaylist,
+    view: &mut View,
+) {
+    let Some(current_entry) = test_playlist.current_entry() else {
+        return;
+    };
+    match view.view_state() {
+        ViewState::Material((material_test_id, _))
+            if *material_test_id == current_entry.material_test_id => {}
+        // Leaving the playlist's test, e.g. with Esc or when it fails to start, hands control back
+        // to the menus
+        ViewState::MainView(_) | ViewState::MaterialSelection(_) | ViewState::Error(_) => {
+            test_playlist.stop();
+            return;
+        }
+        _ => return,
+    }
+
+    if let Some(next_entry) = test_playlist.advance(frame_constants.delta_time) {
+        view.set_transition_to(TransitionTo::Material((
+            next_entry.material_type,
+            next_entry.material_test_id,
+        )));
+    }
+}
+
+#[system]
+fn handle_material_id_from_text_id_events(
+    gpu_interface: &GpuInterface,
+    material_test_registry: &mut MaterialTestRegistry,
+    mut material_test_assets: Query<&mut MaterialTest>,
+    material_id_from_text_id_events: EventReader<MaterialIdFromTextId>,
+    color_blind_simulation: &mut ColorBlindSimulation,
+    view: &mut View,
+) {
+    for material_id_from_text_id_event in &material_id_from_text_id_events {
+        let material_id = MaterialId(material_id_from_text_id_event.material_id());
+        // A material whose WGSL doesn't compile would leave its pipeline stuck loading
+        let shader_error = match gpu_interface
+            .material_manager
+            .generate_shader_text(material_id)
+        {
+            Ok(wgsl) => check_wgsl(&wgsl).err(),
+            Err(error) => Some(ShaderError {
+                summary: format!("Could not generate WGSL: {error:?}"),
+                location: None,
+                source_line: None,
+            }),
+        };
+        let text_id =
+            TextId(unsafe { NonZero::new_unchecked(material_id_from_text_id_event.text_id()) });
+        if color_blind_simulation.text_id == Some(text_id) {
+            match &shader_error {
+                Some(shader_error) => {
+                    error!("{}", shader_error.panel_text(COLOR_BLIND_MATERIAL_NAME));
+                    toast::show(
+                        &format!("{COLOR_BLIND_MATERIAL_NAME} failed to compile"),
+                        DEFAULT_TOAST_SECONDS,
+                    );
+                }
+                None => {
+                    color_blind_simulation.material_id = Some(material_id);
+                    Engine::spawn(bundle!(&MaterialAsset::new(material_id)));
+                }
+            }
+            continue;
+        }
+        material_test_assets.for_each(|material_test_asset| {
+            material_test_asset.update_maybe_loaded_materials(text_id, material_id);
+            material_test_registry.update(material_test_asset);
+            Engine::spawn(bundle!(&MaterialAsset::new(material_id)));
+
+            if !material_test_asset
+                .material_id_iter()
+                .flatten()
+                .any(|test_material_id| test_material_id == material_id)
+            {
+                return;
+            }
+            let panel_text = shader_error
+                .as_ref()
+                .map(|shader_error| shader_error.panel_text(material_test_asset.name()));
+            if let Some(panel_text) = &panel_text {
+                error!("{panel_text}");
+                toast::show(
+                    &format!("{} failed to compile", material_test_asset.name()),
+                    DEFAULT_TOAST_SECONDS,
+                );
+            }
+            view.set_shader_error(material_test_asset.id(), material_id, panel_text);
+        });
+    }
+}
+
+/// A [`Resource`] holding the directory given with `--dump-shaders=<directory>`. Once every
+/// material has loaded, their WGSL is written there and the module exits, see [`shader_dump`].
+#[derive(Debug, Default, Resource)]
+pub struct ShaderDump {
+    directory: Option<PathBuf>,
+}
+
+/// A [`Resource`] holding the iteration count given with `--bench-shaders[=<iterations>]`. Once
+/// every material has loaded, their shaders are timed, the table is printed and the module exits,
+/// see [`shader_bench`].
+#[derive(Debug, Default, Resource)]
+pub struct ShaderBench {
+    iterations: Option<u32>,
+}
+
+#[system]
+/// Resolves the tests named on the command line into the first test or the playlist, once
+/// `handle_material_id_from_text_id_events` has filled in the [`MaterialTestRegistry`]. Runs
+/// before [`handle_assets_loaded`] opens the test, then turns itself off.
+fn startup_selection_system(
+    material_test_registry: &MaterialTestRegistry,
+    startup_args_state: &StartupArgsState,
+    test_playlist: &mut TestPlaylist,
+    view: &mut View,
+) {
+    if material_test_registry.is_empty() {
+        return;
+    }
+    set_system_enabled!(false, startup_selection_system);
+
+    let startup_args = &startup_args_state.args;
+    let playlist_entries = startup_args
+        .playlist
+        .iter()
+        .filter_map(|(test_name, duration_seconds)| {
+            let Some(entry) = material_test_registry.get_by_name(test_name) else {
+                let message = format!("Unknown test {test_name} in playlist, skipping it");
+                warn!("{message}");
+                toast::show(&message, DEFAULT_TOAST_SECONDS);
+                return None;
+            };
+            Some(PlaylistEntry {
+                material_type: entry.material_type,
+                material_test_id: entry.id,
+                duration_seconds: *duration_seconds,
+            })
+        })
+        .collect::<Vec<_>>();
+    if startup_args.plays_playlist() {
+        if let Some(first_entry) = playlist_entries.first() {
+            view.post_load_transition = Some(TransitionTo::Material((
+                first_entry.material_type,
+                first_entry.material_test_id,
+            )));
+            *test_playlist = TestPlaylist::new(playlist_entries);
+            return;
+        }
+    }
+
+    let Some(test_name) = startup_args.requested_test_name() else {
+        return;
+    };
+    match material_test_registry.get_by_name(test_name) {
+        Some(entry) => {
+            view.post_load_transition =
+                Some(TransitionTo::Material((entry.material_type, entry.id)));
+        }
+        None => {
+            let message = unknown_test_message(test_name, material_test_registry.names());
+            warn!("{message}");
+            toast::show(&message, DEFAULT_TOAST_SECONDS);
+        }
+    }
+}
+
+#[system]
+#[allow(clippy::too_many_arguments)]
+fn handle_assets_loaded(
+    gpu_interface: &GpuInterface,
+    text_asset_manager: &TextAssetManager,
+    mut material_assets: Query<(&EntityId, &MaterialAsset)>,
+    mut material_text_assets: Query<(&EntityId, &MaterialTextAsset)>,
+    mut material_texture_assets: Query<(&EntityId, &MaterialTextureAsset)>,
+    material_test_query: Query<&MaterialTest>,
+    shader_bench: &ShaderBench,
+    shader_dump: &ShaderDump,
+    entity_counts_overlay: &mut EntityCountsOverlay,
+    view: &mut View,
+) {
+    let texture_ids_iter = material_texture_assets.iter().map(|query_components_ref| {
+        let (_, material_texture_asset) = query_components_ref.unpack();
+        material_texture_asset.texture_id()
+    });
+    let text_ids_iter = material_text_assets.iter().map(|query_components_ref| {
+        let (_, material_text_asset) = query_components_ref.unpack();
+        material_text_asset.text_id()
+    });
+    let pipeline_ids =
+        material_assets
+            .iter()
+            .fold(vec![], |mut accumulator, query_components_ref| {
+                let (_, material_asset) = query_components_ref.unpack();
+                let material_id = material_asset.material_id();
+                // Broken materials never finish loading, their tests show the error instead
+                if view.has_shader_error(*material_id) {
+                    return accumulator;
+                }
+                if let Some(pipeline_id) = gpu_interface
+                    .pipeline_asset_manager
+                    .get_pipeline_id_from_material_id(*material_id)
+                {
+                    accumulator.push(pipeline_id);
+                }
+                accumulator
+            });
+    if gpu_interface
+        .texture_asset_manager
+        .are_all_ids_loaded(texture_ids_iter)
+        && text_asset_manager.are_all_ids_loaded(text_ids_iter)
+        && !pipeline_ids.is_empty()
+        && gpu_interface
+            .pipeline_asset_manager
+            .are_all_ids_loaded(pipeline_ids.iter())
+    {
+        entity_counts_overlay.loaded_asset_counts = Some(LoadedAssetCounts {
+            textures: material_texture_assets.len(),
+            texts: material_text_assets.len(),
+            pipelines: pipeline_ids.len(),
+        });
+
+        let tests = material_test_query
+            .iter()
+            .map(|material_test| DumpedTest {
+                material_type: *material_test.material_type(),
+                name: material_test.name(),
+                material_ids: material_test.material_id_iter().flatten().collect(),
+            })
+            .collect::<Vec<_>>();
+        if let Some(iterations) = shader_bench.iterations {
+            match bench_all_shaders(&gpu_interface.material_manager, &tests, iterations) {
+                Ok(rows) => {
+                    println!("Mean of {iterations} iterations per material");
+                    print!("{}", bench_table(&rows));
+                    exit(0);
+                }
+                Err(error) => {
+                    eprintln!("Could not bench shaders: {error}");
+                    exit(1);
+                }
+            }
+        }
+        if let Some(directory) = &shader_dump.directory {
+            match dump_all_shaders(&gpu_interface.material_manager, &tests, directory) {
+                Ok(written_count) => {
+                    println!("Wrote {written_count} shaders to {}", directory.display());
+                    exit(0);
+                }
+                Err(error) => {
+                    eprintln!("Could not dump shaders to {}: {error}", directory.display());
+                    exit(1);
+                }
+            }
+        }
+
+        view.set_transition_to(match view.post_load_transition {
+            Some(transition_to) => transition_to,
+            None => TransitionTo::MainView,
+        });
+
+        view.post_load_transition = None;
+
+        material_texture_assets.for_each(|(entity_id, _)| {
+            Engine::despawn(**entity_id);
+        });
+
+        material_text_assets.for_each(|(entity_id, _)| {
+            Engine::despawn(**entity_id);
+        });
+
+        material_assets.for_each(|(entity_id, _)| {
+            Engine::despawn(**entity_id);
+        });
+
+        set_system_enabled!(
+            false,
+            handle_assets_loaded,
+            handle_material_id_from_text_id_events
+        );
+    }
+}
+
+pub struct ChannelInspectorTest;
+
+material_test_systems!(ChannelInspectorTest, channel_inspector_startup_system, []);
+
+#[system_once]
+fn channel_inspector_startup_system(
+    aspect: &Aspect,
+    material_test_query: Query<&MaterialTest>,
+    gpu_interface: &GpuInterface,
+    view: &mut View,
+) {
+    if let Err(error) = channel_inspector_startup(aspect, &material_test_query, gpu_interface) {
+        show_error_view(view, &error);
+    }
+}
+
+fn channel_inspector_startup(
+    aspect: &Aspect,
+    material_test_query: &Query<&MaterialTest>,
+    gpu_interface: &GpuInterface,
+) -> local_error::Result<()> {
+    let channel_inspector_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "channel_inspector")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "channel_inspector".to_string(),
+        })?;
+    let (material_id, _) = require_material(gpu_interface, &channel_inspector_material_test)?;
+
+    let star_map_texture_id = require_texture(gpu_interface, "textures/star_map_with_mask.png")?;
+
+    let channel_images_scale = Vec2::splat(aspect.width * 0.1);
+
+    let mut base_material_params = MaterialParameters::new(material_id)
+        .update_texture(
+            &gpu_interface.material_manager,
+            &("map", &star_map_texture_id),
+        )
+        .unwrap()
+        .end_chain();
+
+    let channel_names = ["red", "green", "blue", "alpha"];
+    let channel_positions = row_positions(
+        aspect,
+        Vec2::new(0.5, 0.5),
+        1. / channel_names.len() as f32,
+        channel_names.len(),
+    );
+
+    for (index, (channel_name, channel_position)) in
+        channel_names.into_iter().zip(channel_positions).enumerate()
+    {
+        let channel_value = index as f32;
+        let channel_material_params = base_material_params
+            .update_uniform(
+                &gpu_interface.material_manager,
+                &("channel", &channel_value.into()),
+            )
+            .unwrap()
+            .end_chain();
+
+        let texture_position = channel_position.extend(0.);
+        let mut texture_component_builder = create_new_relative_texture(
+            texture_position.into(),
+            *palette::WHITE,
+            star_map_texture_id,
+            channel_images_scale,
+            RelativeScale::OfWidth,
+        );
+        texture_component_builder.add_components(bundle_for_builder!(
+            MaterialTestObject,
+            channel_material_params
+        ));
+        Engine::spawn(&texture_component_builder.build());
+
+        let mut text_component_builder = create_new_text::<_, RegularText>(
+            CreateTextInput {
+                position: texture_position - Vec3::new(0., aspect.height * 0.2, 0.),
+                text: channel_name,
+                ..Default::default()
+            },
+            aspect,
+        );
+        text_component_builder.add_component(MaterialTestObject);
+        Engine::spawn(&text_component_builder.build());
+    }
+
+    Ok(())
+}
+
+/// The textures shown by the texture formats test, as `(label, path)`. Each is the same image
+/// saved with a different PNG encoding, which the platform turns into a different texture format.
+const TEXTURE_FORMAT_SAMPLES: [(&str, &str); 6] = [
+    ("RGBA8", "textures/formats/rgba8.png"),
+    ("RGB8", "textures/formats/rgb8.png"),
+    ("Gray8", "textures/formats/gray8.png"),
+    ("Gray+alpha8", "textures/formats/gray_alpha8.png"),
+    ("RGBA16", "textures/formats/rgba16.png"),
+    ("Indexed", "textures/formats/indexed.png"),
+];
+/// A texture still not loaded this long after the test started is flagged as failed
+const TEXTURE_FORMAT_LOAD_TIMEOUT_SECONDS: f32 = 3.;
+
+/// A [`Component`] for the status line under a column of the texture formats test, `.0` indexes
+/// [`TEXTURE_FORMAT_SAMPLES`]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct TextureFormatStatusText(usize);
+
+pub struct TextureFormatsTest;
+
+material_test_systems!(
+    TextureFormatsTest,
+    texture_formats_startup_system,
+    [texture_formats_system]
+);
+
+#[system_once]
+fn texture_formats_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = texture_formats_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+/// Shows each of [`TEXTURE_FORMAT_SAMPLES`] as a column, with one row per channel through the
+/// channel inspector material
+fn texture_formats_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let texture_formats_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "texture_formats")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "texture_formats".to_string(),
+        })?;
+    let (material_id, _) = require_material(gpu_interface, &texture_formats_material_test)?;
+
+    let channel_names = ["red", "green", "blue", "alpha"];
+    let grid_layout = GridLayout {
+        first_cell_percent: Vec2::new(0.175, 0.7),
+        columns: TEXTURE_FORMAT_SAMPLES.len(),
+        cell_spacing_percent: Vec2::new(0.13, 0.16),
+    };
+    let texture_scale = Vec2::splat(aspect.height * 0.13);
+
+    for (channel_index, channel_name) in channel_names.into_iter().enumerate() {
+        let row_percent = grid_layout.cell_percent(channel_index * TEXTURE_FORMAT_SAMPLES.len());
+        let mut text_component_builder = create_new_text::<_, RegularText>(
+            CreateTextInput {
+                position: screen_space_coordinate_by_percent(
+                    aspect,
+                    0.06.into(),
+                    row_percent.y.into(),
+                )
+                .extend(0.),
+                text: channel_name,
+                ..Default::default()
+            },
+            aspect,
+        );
+        text_component_builder.add_component(MaterialTestObject);
+        Engine::spawn(&text_component_builder.build());
+    }
+
+    for (format_index, (format_label, texture_path)) in TEXTURE_FORMAT_SAMPLES.iter().enumerate() {
+        let column_percent = grid_layout.cell_percent(format_index);
+        let mut text_component_builder = create_new_text::<_, RegularText>(
+            CreateTextInput {
+                position: screen_space_coordinate_by_percent(
+                    aspect,
+                    column_percent.x.into(),
+                    0.85.into(),
+                )
+                .extend(0.),
+                text: *format_label,
+                ..Default::default()
+            },
+            aspect,
+        );
+        text_component_builder.add_component(MaterialTestObject);
+        Engine::spawn(&text_component_builder.build());
+
+        let mut text_component_builder = create_new_text::<_, CustomText>(
+            CreateTextInput {
+                position: screen_space_coordinate_by_percent(
+                    aspect,
+                    column_percent.x.into(),
+                    0.1.into(),
+                )
+                .extend(0.),
+                text: "loading",
+                text_type: TextTypes::Custom(22.),
+                ..Default::default()
+            },
+            aspect,
+        );
+        text_component_builder.add_components(bundle_for_builder!(
+            MaterialTestObject,
+            TextureFormatStatusText(format_index)
+        ));
+        Engine::spawn(&text_component_builder.build());
+
+        // Formats that failed to load are left empty, their status line says so
+        let Ok(texture_id) = require_texture(gpu_interface, texture_path) else {
+            continue;
+        };
+        let base_material_params = MaterialParameters::new(material_id)
+            .update_texture(&gpu_interface.material_manager, &("map", &texture_id))
+            .unwrap()
+            .end_chain();
+        for channel_index in 0..channel_names.len() {
+            let channel_value = channel_index as f32;
+            let channel_material_params = base_material_params
+                .update_uniform(
+                    &gpu_interface.material_manager,
+                    &("channel", &channel_value.into()),
+                )
+                .unwrap()
+                .end_chain();
+            let mut texture_component_builder = create_new_relative_texture(
+                grid_layout
+                    .position(
+                        aspect,
+                        channel_index * TEXTURE_FORMAT_SAMPLES.len() + format_index,
+                    )
+                    .extend(0.)
+                    .into(),
+                *palette::WHITE,
+                texture_id,
+                texture_scale,
+                RelativeScale::OfHeight,
+            );
+            texture_component_builder.add_components(bundle_for_builder!(
+                MaterialTestObject,
+                channel_material_params
+            ));
+            Engine::spawn(&texture_component_builder.build());
+        }
+    }
+
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TimePassedSinceCreation::default()
+    ));
+    set_system_enabled!(true, texture_formats_system);
+
+    Ok(())
+}
+
+/// Marks each format ok once its texture is loaded, and failed when it was never created or
+/// hasn't loaded in [`TEXTURE_FORMAT_LOAD_TIMEOUT_SECONDS`]
+#[system]
+fn texture_formats_system(
+    gpu_interface: &GpuInterface,
+    test_clock: &TestClock,
+    mut time_passed_query: Query<(&mut TimePassedSinceCreation, &MaterialTestObject)>,
+    mut status_text_query: Query<(&mut TextRender, &mut Color, &TextureFormatStatusText)>,
+) {
+    let mut timed_out = false;
+    time_passed_query.for_each(|(time_passed_since_creation, _)| {
+        *time_passed_since_creation += test_clock.delta_time();
+        timed_out = ***time_passed_since_creation > TEXTURE_FORMAT_LOAD_TIMEOUT_SECONDS;
+    });
+
+    status_text_query.for_each(|(text_render, color, status_text)| {
+        let (_, texture_path) = TEXTURE_FORMAT_SAMPLES[status_text.0];
+        let (status, status_color) = match gpu_interface
+            .texture_asset_manager
+            .get_texture_by_path(&texture_path.into())
+        {
+            None => ("failed: not created", Vec4::new(1., 0.3, 0.3, 1.)),
+            Some(texture)
+                if gpu_interface
+                    .texture_asset_manager
+                    .are_all_ids_loaded(std::iter::once(&texture.id())) =>
+            {
+                ("ok", Vec4::new(0.4, 1., 0.4, 1.))
+            }
+            Some(_) if timed_out => ("failed: not loaded", Vec4::new(1., 0.3, 0.3, 1.)),
+            Some(_) => ("loading", *palette::WHITE),
+        };
+        text_render.text = text_to_u8_array(status);
+        *color = Color::from(status_color);
+    });
+}
+
+pub struct ColorReplacementTest;
+
+material_test_systems!(
+    ColorReplacementTest,
+    color_replacement_startup_system,
+    [color_replacement_system]
+);
+
+#[system_once]
+fn color_replacement_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = color_replacement_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn color_replacement_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let channel_inspector_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "color_replacement")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "color_replacement".to_string(),
+        })?;
+    let (material_id, _) = require_material(gpu_interface, &channel_inspector_material_test)?;
+
+    let white_color_uniform: UniformValue = (*palette::WHITE).get().into();
+    let grey_color_uniform = (*palette::GRAY).get().into();
+    let scared_id = require_texture(gpu_interface, "textures/scared.png")?;
+
+    let material_params = MaterialParameters::new(material_id)
+        .update_uniforms(
+            &gpu_interface.material_manager,
+            &[
+                ("color_to_replace", &white_color_uniform),
+                ("color_to_insert", &grey_color_uniform),
+            ],
+        )
+        .unwrap()
+        .update_texture(&gpu_interface.material_manager, &("color_tex", &scared_id))
+        .unwrap()
+        .end_chain();
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        scared_id,
+        Vec2::splat(aspect.width * 0.25),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        material_params,
+        TimePassedSinceCreation::default(),
+        MaterialAbToggle::for_default_sprite(&gpu_interface.material_manager, scared_id)
+    ));
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, HeaderText>(
+        CreateTextInput {
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.75.into())
+                .extend(0.),
+            text: "Test",
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&text_component_builder.build());
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("M", "toggle default sprite material")])
+    ));
+    set_system_enabled!(true, color_replacement_system);
+
+    Ok(())
+}
+
+#[system]
+fn color_replacement_system(
+    gpu_interface: &GpuInterface,
+    test_clock: &TestClock,
+    mut textures: Query<(
+        &mut MaterialAbToggle,
+        &mut TimePassedSinceCreation,
+        &mut MaterialParameters,
+    )>,
+) {
+    textures.for_each(|(ab_toggle, time_passed_since_creation, material_params)| {
+        *time_passed_since_creation += test_clock.delta_time();
+        let material_params = ab_toggle.test_material_parameters_mut(material_params);
+
+        let new_target_color: UniformValue = Vec4::new(
+            f32::sin(***time_passed_since_creation * 0.1).abs(),
+            f32::cos(***time_passed_since_creation * 0.5).abs(),
+            f32::sin(***time_passed_since_creation * 0.3 + 0.5).abs(),
+            1.,
+        )
+        .into();
+
+        material_params
+            .update_uniform(
+                &gpu_interface.material_manager,
+                &("color_to_insert", &new_target_color),
+            )
+            .unwrap();
+    });
+}
+
+pub struct PanSpriteTest;
+
+material_test_systems!(PanSpriteTest, pan_sprite_startup_system, []);
+
+#[system_once]
+fn pan_sprite_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = pan_sprite_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn pan_sprite_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let pan_sprite_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "pan_sprite")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "pan_sprite".to_string(),
+        })?;
+    let (material_id, _) = require_material(gpu_interface, &pan_sprite_material_test)?;
+
+    let arrow_up_id = require_texture(gpu_interface, "textures/arrow_up.png")?;
+
+    let material_params = MaterialParameters::new(material_id)
+        .update_texture(
+            &gpu_interface.material_manager,
+            &("color_tex", &arrow_up_id),
+        )
+        .unwrap()
+        .end_chain();
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        arrow_up_id,
+        Vec2::splat(aspect.width * 0.15),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        material_params,
+        MaterialAbToggle::for_default_sprite(&gpu_interface.material_manager, arrow_up_id)
+    ));
+    Engine::spawn(&texture_component_builder.build());
+
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("M", "toggle default sprite material")])
+    ));
+
+    let mut text_component_builder = create_new_text::<_, HeaderText>(
+        CreateTextInput {
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.75.into())
+                .extend(0.),
+            text: "Test",
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&text_component_builder.build());
+
+    Ok(())
+}
+
+pub struct DesatSpriteTest;
+
+material_test_systems!(DesatSpriteTest, desat_sprite_startup_system, []);
+
+#[system_once]
+fn desat_sprite_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = desat_sprite_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn desat_sprite_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let desat_sprite_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "desat_sprite")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "desat_sprite".to_string(),
+        })?;
+    let (material_id, _) = require_material(gpu_interface, &desat_sprite_material_test)?;
+
+    let arrow_up_id = require_texture(gpu_interface, "textures/arrow_up.png")?;
+
+    let material_params = MaterialParameters::new(material_id)
+        .update_texture(
+            &gpu_interface.material_manager,
+            &("color_tex", &arrow_up_id),
+        )
+        .unwrap()
+        .end_chain();
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        arrow_up_id,
+        Vec2::splat(aspect.width * 0.15),
+        RelativeScale::OfWidth,
+    );
+
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        material_params,
+        MaterialAbToggle::for_default_sprite(&gpu_interface.material_manager, arrow_up_id)
+    ));
+    Engine::spawn(&texture_component_builder.build());
+
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("M", "toggle default sprite material")])
+    ));
+
+    let mut text_component_builder = create_new_text::<_, HeaderText>(
+        CreateTextInput {
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.75.into())
+                .extend(0.),
+            text: "Test",
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&text_component_builder.build());
+
+    Ok(())
+}
+
+const SCROLLING_COLOR_SCROLL_SPEED_CENTER_POINT: f32 = 1.;
+
+pub struct ScrollingColorTest;
+
+material_test_systems!(
+    ScrollingColorTest,
+    scrolling_color_startup_system,
+    [scrolling_color_system]
+);
+
+#[system_once]
+fn scrolling_color_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = scrolling_color_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn scrolling_color_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let scrolling_color_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "scrolling_color")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "scrolling_color".to_string(),
+        })?;
+    let (material_id, _) = require_material(gpu_interface, &scrolling_color_material_test)?;
 
     let material_params = MaterialParameters::new(material_id)
         .update_uniforms(
             &gpu_interface.material_manager,
             &[
-                ("time", &0.0.into()),
-                (
-                    "scroll_speed",
-                    &SCROLLING_COLOR_SCROLL_SPEED_CENTER_POINT.into(),
-                ),
+                ("time", &0.0.into()),
+                (
+                    "scroll_speed",
+                    &SCROLLING_COLOR_SCROLL_SPEED_CENTER_POINT.into(),
+                ),
+            ],
+        )
+        .unwrap()
+        .end_chain();
+
+    let scared_id = require_texture(gpu_interface, "textures/scared.png")?;
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        scared_id,
+        Vec2::splat(aspect.width * 0.15),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        material_params,
+        TimePassedSinceCreation::default()
+    ));
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, HeaderText>(
+        CreateTextInput {
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.75.into())
+                .extend(0.),
+            text: "Test",
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&text_component_builder.build());
+    set_system_enabled!(true, scrolling_color_system);
+
+    Ok(())
+}
+
+#[system]
+fn scrolling_color_system(
+    gpu_interface: &GpuInterface,
+    test_clock: &TestClock,
+    mut textures: Query<(
+        &TextureRender,
+        &mut TimePassedSinceCreation,
+        &mut MaterialParameters,
+    )>,
+) {
+    textures.for_each(|(_, time_passed_since_creation, material_params)| {
+        *time_passed_since_creation += test_clock.delta_time();
+
+        let current_speed = SCROLLING_COLOR_SCROLL_SPEED_CENTER_POINT
+            + 0.75 * f32::sin(***time_passed_since_creation * 0.001);
+
+        material_params
+            .update_uniforms(
+                &gpu_interface.material_manager,
+                &[
+                    ("time", &(***time_passed_since_creation).into()),
+                    ("scroll_speed", &current_speed.into()),
+                ],
+            )
+            .unwrap();
+    });
+}
+
+pub struct StarfieldTest;
+
+material_test_systems!(StarfieldTest, starfield_startup_system, [starfield_system]);
+
+#[system_once]
+fn starfield_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = starfield_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn starfield_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let starfield_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "starfield")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "starfield".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &starfield_material_test)?;
+
+    let random_texture = gpu_interface
+        .texture_asset_manager
+        .get_texture_by_path(&"textures/random.png".into())
+        .and_then(|random_texture| random_texture.as_loaded_texture())
+        .ok_or_else(|| TestModuleError::AssetNotLoaded {
+            asset_path: "textures/random.png".to_string(),
+            reason: "the texture has not finished loading".to_string(),
+        })?;
+    let star_map_id = require_texture(gpu_interface, "textures/star_map_with_mask.png")?;
+
+    let material_params = material
+        .generate_default_material_parameters()
+        .update_uniform(
+            &gpu_interface.material_manager,
+            &(
+                "texture_height",
+                &(random_texture.height() as f32 * aspect.height * 0.1).into(),
+            ),
+        )
+        .unwrap()
+        .update_textures(
+            &gpu_interface.material_manager,
+            &[("star_map", &star_map_id), ("random", &random_texture.id())],
+        )
+        .unwrap()
+        .end_chain();
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        star_map_id,
+        Vec2::splat(aspect.width * 0.325),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        material_params,
+        TimePassedSinceCreation::default()
+    ));
+    Engine::spawn(&texture_component_builder.build());
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[
+            ("Left/Right", "change speed"),
+            ("Up/Down", "change star count"),
+            ("Space (hold)", "speed burst"),
+        ])
+    ));
+    set_system_enabled!(true, starfield_system);
+
+    Ok(())
+}
+
+#[system]
+fn starfield_system(
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    key_repeat_state: &KeyRepeatState,
+    material_test_registry: &MaterialTestRegistry,
+    test_clock: &TestClock,
+    mut textures: Query<(
+        &TextureRender,
+        &mut TimePassedSinceCreation,
+        &mut MaterialParameters,
+    )>,
+) {
+    let Some(starfield_material_test) = material_test_registry.get_by_name("starfield") else {
+        error!("Could not find starfield material test");
+        return;
+    };
+    let Some(material_id) = starfield_material_test.first_material_id() else {
+        error!("Could not find material id on starfield");
+        return;
+    };
+    let material = gpu_interface
+        .material_manager
+        .get_material(material_id)
+        .unwrap();
+    textures.for_each(|(_, time_passed_since_creation, material_params)| {
+        *time_passed_since_creation += test_clock.delta_time();
+        let current_uniforms = material
+            .get_current_uniforms(&material_params.data)
+            .unwrap();
+        let current_speed = current_uniforms.get("speed").unwrap();
+        let current_speed = match current_speed {
+            UniformValue::F32(value) => value.current_value(),
+            _ => unreachable!(),
+        };
+
+        let current_stars = current_uniforms.get("star_number").unwrap();
+        let current_stars = match current_stars {
+            UniformValue::F32(value) => value.current_value(),
+            _ => unreachable!(),
+        };
+
+        const SPEED_INCREMENT: f32 = 0.1;
+
+        let new_speed = if is_left_repeated(input_state, key_repeat_state) {
+            Some(current_speed - SPEED_INCREMENT)
+        } else if is_right_repeated(input_state, key_repeat_state) {
+            Some(current_speed + SPEED_INCREMENT)
+        } else {
+            None
+        };
+
+        let speed_burst_value = if input_state.keys[KeyCode::Space].just_pressed() {
+            Some(80.0.into())
+        } else if input_state.keys[KeyCode::Space].just_released() {
+            let default_uniforms = material.generate_default_material_uniforms().unwrap();
+            Some(default_uniforms.get("speed").unwrap().clone())
+        } else {
+            None
+        };
+
+        const STARS_INCREMENT: f32 = 5.;
+        let new_stars = if is_up_repeated(input_state, key_repeat_state) {
+            Some(current_stars + STARS_INCREMENT)
+        } else if is_down_repeated(input_state, key_repeat_state) {
+            Some(current_stars - STARS_INCREMENT)
+        } else {
+            None
+        };
+
+        let mut material_uniforms = material_params
+            .as_material_uniforms(&gpu_interface.material_manager)
+            .unwrap();
+
+        if let Some(new_stars) = new_stars {
+            material_uniforms
+                .update("star_number", new_stars.into())
+                .unwrap();
+        }
+
+        if let Some(speed_burst_value) = speed_burst_value {
+            material_uniforms
+                .update("speed", speed_burst_value)
+                .unwrap();
+        } else if let Some(new_speed) = new_speed {
+            material_uniforms.update("speed", new_speed.into()).unwrap();
+        }
+
+        material_uniforms
+            .update("time_elapsed", (***time_passed_since_creation).into())
+            .unwrap();
+        material_params
+            .update_from_material_uniforms(&material_uniforms)
+            .unwrap();
+    });
+}
+
+pub struct FireTest;
+
+material_test_systems!(FireTest, fire_startup_system, [fire_system]);
+
+#[system_once]
+fn fire_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = fire_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn fire_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let fire_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "fire")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "fire".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &fire_material_test)?;
+
+    let random_id = require_texture(gpu_interface, "textures/random.png")?;
+
+    let material_params = material
+        .generate_default_material_parameters()
+        .update_texture(&gpu_interface.material_manager, &("noise", &random_id))
+        .unwrap()
+        .end_chain();
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        random_id,
+        Vec2::new(aspect.width * 0.2, aspect.width * 0.3),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        material_params,
+        TimePassedSinceCreation::default()
+    ));
+    Engine::spawn(&texture_component_builder.build());
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[
+            ("Up/Down", "change intensity"),
+            ("Left/Right", "change speed"),
+        ])
+    ));
+    set_system_enabled!(true, fire_system);
+
+    Ok(())
+}
+
+#[system]
+fn fire_system(
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    key_repeat_state: &KeyRepeatState,
+    test_clock: &TestClock,
+    mut textures: Query<(
+        &TextureRender,
+        &mut TimePassedSinceCreation,
+        &mut MaterialParameters,
+    )>,
+) {
+    const INTENSITY_INCREMENT: f32 = 0.1;
+    const SPEED_INCREMENT: f32 = 0.1;
+
+    let intensity_shift = if is_up_repeated(input_state, key_repeat_state) {
+        INTENSITY_INCREMENT
+    } else if is_down_repeated(input_state, key_repeat_state) {
+        -INTENSITY_INCREMENT
+    } else {
+        0.
+    };
+    let speed_shift = if is_right_repeated(input_state, key_repeat_state) {
+        SPEED_INCREMENT
+    } else if is_left_repeated(input_state, key_repeat_state) {
+        -SPEED_INCREMENT
+    } else {
+        0.
+    };
+
+    textures.for_each(|(_, time_passed_since_creation, material_params)| {
+        *time_passed_since_creation += test_clock.delta_time();
+
+        let mut material_uniforms = material_params
+            .as_material_uniforms(&gpu_interface.material_manager)
+            .unwrap();
+
+        let current_intensity = match material_uniforms.get("intensity").unwrap() {
+            UniformValue::F32(value) => value.current_value(),
+            _ => unreachable!(),
+        };
+        let current_speed = match material_uniforms.get("speed").unwrap() {
+            UniformValue::F32(value) => value.current_value(),
+            _ => unreachable!(),
+        };
+
+        material_uniforms
+            .update(
+                "intensity",
+                (current_intensity + intensity_shift).max(0.).into(),
+            )
+            .unwrap();
+        material_uniforms
+            .update("speed", (current_speed + speed_shift).into())
+            .unwrap();
+        material_uniforms
+            .update("time", (***time_passed_since_creation).into())
+            .unwrap();
+        material_params
+            .update_from_material_uniforms(&material_uniforms)
+            .unwrap();
+    });
+}
+
+pub struct LensFlareTest;
+
+material_test_systems!(
+    LensFlareTest,
+    lens_flare_startup_system,
+    [lens_flare_system]
+);
+
+#[system_once]
+fn lens_flare_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = lens_flare_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn lens_flare_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let lens_flare_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "lens_flare")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "lens_flare".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &lens_flare_material_test)?;
+
+    let scared_id = require_texture(gpu_interface, "textures/scared.png")?;
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        scared_id,
+        Vec2::splat(aspect.width * 0.25),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&texture_component_builder.build());
+
+    let material_params = material
+        .generate_default_material_parameters()
+        .update_uniform(
+            &gpu_interface.material_manager,
+            &("aspect_ratio", &(aspect.width / aspect.height).into()),
+        )
+        .unwrap()
+        .end_chain();
+
+    // The flare covers the whole screen so its uvs line up with the cursor position
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(1.)
+            .into(),
+        *palette::WHITE,
+        scared_id,
+        Vec2::new(aspect.width, aspect.height),
+        RelativeScale::OfScreen,
+    );
+    texture_component_builder
+        .add_components(bundle_for_builder!(MaterialTestObject, material_params));
+    Engine::spawn(&texture_component_builder.build());
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("Mouse", "move flare")])
+    ));
+    set_system_enabled!(true, lens_flare_system);
+
+    Ok(())
+}
+
+#[system]
+fn lens_flare_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    material_test_registry: &MaterialTestRegistry,
+    mut textures: Query<(&mut MaterialParameters, &MaterialTestObject)>,
+) {
+    let Some(material_id) = material_test_registry
+        .get_by_name("lens_flare")
+        .and_then(|material_test_entry| material_test_entry.first_material_id())
+    else {
+        error!("Could not find material id on lens_flare");
+        return;
+    };
+    let cursor_position = input_state.mouse.cursor_position;
+    let cursor_uv = screen_to_uv(aspect, Vec2::new(cursor_position.x, cursor_position.y));
+    let flare_center: UniformValue = Vec4::new(cursor_uv.x, cursor_uv.y, 0., 0.).into();
+    let aspect_ratio: UniformValue = (aspect.width / aspect.height).into();
+
+    // Only the flare has these uniforms, the sprite under it keeps the default material
+    textures.for_each(|(material_params, _)| {
+        if material_params.material_id != material_id {
+            return;
+        }
+        material_params
+            .update_uniforms(
+                &gpu_interface.material_manager,
+                &[
+                    ("flare_center", &flare_center),
+                    ("aspect_ratio", &aspect_ratio),
+                ],
+            )
+            .unwrap();
+    });
+}
+
+/// The length in seconds of a full day -> night -> day cycle
+const DAY_NIGHT_CYCLE_SECONDS: f32 = 30.;
+
+/// Test local [`Component`] tracking whether the day/night cycle is currently advancing
+#[derive(
+    Debug, Default, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize,
+)]
+pub struct DayNightPlayback {
+    pub paused: bool,
+}
+
+pub struct DayNightTest;
+
+material_test_systems!(DayNightTest, day_night_startup_system, [day_night_system]);
+
+#[system_once]
+fn day_night_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = day_night_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn day_night_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let day_night_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "day_night")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "day_night".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &day_night_material_test)?;
+
+    let scared_id = require_texture(gpu_interface, "textures/scared.png")?;
+
+    let material_params = material
+        .generate_default_material_parameters()
+        .update_texture(&gpu_interface.material_manager, &("color_tex", &scared_id))
+        .unwrap()
+        .end_chain();
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        scared_id,
+        Vec2::splat(aspect.width * 0.25),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        material_params,
+        TimePassedSinceCreation::default(),
+        DayNightPlayback::default()
+    ));
+    Engine::spawn(&texture_component_builder.build());
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("Space", "pause/resume cycle")])
+    ));
+    set_system_enabled!(true, day_night_system);
+
+    Ok(())
+}
+
+#[system]
+fn day_night_system(
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    test_clock: &TestClock,
+    mut textures: Query<(
+        &mut DayNightPlayback,
+        &mut TimePassedSinceCreation,
+        &mut MaterialParameters,
+    )>,
+) {
+    let toggle_pause = is_day_night_pause_toggle_just_pressed(input_state);
+
+    textures.for_each(|(playback, time_passed_since_creation, material_params)| {
+        if toggle_pause {
+            playback.paused = !playback.paused;
+        }
+        if playback.paused {
+            return;
+        }
+
+        *time_passed_since_creation += test_clock.delta_time();
+
+        let cycle_angle = 2. * PI * ***time_passed_since_creation / DAY_NIGHT_CYCLE_SECONDS;
+        let cycle = 0.5 - 0.5 * cycle_angle.cos();
+
+        material_params
+            .update_uniform(&gpu_interface.material_manager, &("cycle", &cycle.into()))
+            .unwrap();
+    });
+}
+
+/// How far, in uv units per second, the nearest parallax layer scrolls
+const PARALLAX_SCROLL_SPEED: f32 = 0.05;
+
+/// Test local [`Component`] holding the current scroll direction and the accumulated uv offset
+/// for the parallax test
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct ParallaxScroll {
+    pub direction: Vec2,
+    pub offset: Vec2,
+}
+
+impl Default for ParallaxScroll {
+    fn default() -> Self {
+        Self {
+            direction: Vec2::new(1., 0.),
+            offset: Vec2::new(0., 0.),
+        }
+    }
+}
+
+pub struct ParallaxTest;
+
+material_test_systems!(ParallaxTest, parallax_startup_system, [parallax_system]);
+
+#[system_once]
+fn parallax_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = parallax_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn parallax_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let parallax_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "parallax")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "parallax".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &parallax_material_test)?;
+
+    let scared_id = require_texture(gpu_interface, "textures/scared.png")?;
+
+    let material_params = material
+        .generate_default_material_parameters()
+        .update_texture(&gpu_interface.material_manager, &("layer_tex", &scared_id))
+        .unwrap()
+        .end_chain();
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        scared_id,
+        Vec2::new(aspect.width * 0.6, aspect.height * 0.6),
+        RelativeScale::OfScreen,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        material_params,
+        ParallaxScroll::default()
+    ));
+    Engine::spawn(&texture_component_builder.build());
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("Arrows", "change scroll direction")])
+    ));
+    set_system_enabled!(true, parallax_system);
+
+    Ok(())
+}
+
+#[system]
+fn parallax_system(
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    test_clock: &TestClock,
+    mut textures: Query<(&mut ParallaxScroll, &mut MaterialParameters)>,
+) {
+    // Directions are in uv space, so "up" is negative y
+    let new_direction = if is_left_just_pressed(input_state) {
+        Some(Vec2::new(-1., 0.))
+    } else if is_right_just_pressed(input_state) {
+        Some(Vec2::new(1., 0.))
+    } else if is_up_just_pressed(input_state) {
+        Some(Vec2::new(0., -1.))
+    } else if is_down_just_pressed(input_state) {
+        Some(Vec2::new(0., 1.))
+    } else {
+        None
+    };
+
+    textures.for_each(|(parallax_scroll, material_params)| {
+        if let Some(new_direction) = new_direction {
+            parallax_scroll.direction = new_direction;
+        }
+
+        let offset = parallax_scroll.offset
+            + parallax_scroll.direction * PARALLAX_SCROLL_SPEED * test_clock.delta_time();
+        // The slowest layer scrolls at a quarter speed, so wrapping at 4 keeps every layer seamless
+        // while avoiding precision loss over time
+        parallax_scroll.offset = Vec2::new(offset.x.rem_euclid(4.), offset.y.rem_euclid(4.));
+
+        material_params
+            .update_uniform(
+                &gpu_interface.material_manager,
+                &(
+                    "scroll_offset",
+                    &Vec4::new(parallax_scroll.offset.x, parallax_scroll.offset.y, 0., 0.).into(),
+                ),
+            )
+            .unwrap();
+    });
+}
+
+/// textures/atlas.png is a grid of this many rows and columns
+const ATLAS_ROWS: usize = 4;
+const ATLAS_COLS: usize = 4;
+
+/// Test local [`Component`] holding the atlas cell the atlas test's sprite shows
+#[derive(
+    Debug, Default, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize,
+)]
+pub struct AtlasCell {
+    pub index: usize,
+}
+
+/// Marker [`Component`] for the text naming the selected atlas cell
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct AtlasCellText;
+
+fn atlas_cell_text(index: usize) -> String {
+    format!("Cell {} / {}", index + 1, ATLAS_ROWS * ATLAS_COLS)
+}
+
+fn update_atlas_cell_uniforms(
+    material_params: &mut MaterialParameters,
+    material_manager: &MaterialManager,
+    atlas_cell_uniforms: AtlasCellUniforms,
+) {
+    let uv_offset: UniformValue = atlas_cell_uniforms.uv_offset.extend(0.).extend(0.).into();
+    let uv_scale: UniformValue = atlas_cell_uniforms.uv_scale.extend(0.).extend(0.).into();
+    material_params
+        .update_uniforms(
+            material_manager,
+            &[("uv_offset", &uv_offset), ("uv_scale", &uv_scale)],
+        )
+        .unwrap();
+}
+
+pub struct AtlasTest;
+
+material_test_systems!(AtlasTest, atlas_startup_system, [atlas_system]);
+
+#[system_once]
+fn atlas_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = atlas_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn atlas_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let atlas_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "atlas")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "atlas".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &atlas_material_test)?;
+
+    let atlas_id = require_texture(gpu_interface, "textures/atlas.png")?;
+
+    let mut material_params = material
+        .generate_default_material_parameters()
+        .update_texture(&gpu_interface.material_manager, &("color_tex", &atlas_id))
+        .unwrap()
+        .end_chain();
+    let atlas_cell = AtlasCell::default();
+    update_atlas_cell_uniforms(
+        &mut material_params,
+        &gpu_interface.material_manager,
+        atlas_cell_uniforms(ATLAS_ROWS, ATLAS_COLS, atlas_cell.index),
+    );
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        atlas_id,
+        Vec2::splat(aspect.height * 0.5),
+        RelativeScale::OfHeight,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        material_params,
+        atlas_cell
+    ));
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, CustomText>(
+        CreateTextInput {
+            text: atlas_cell_text(0),
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.9.into())
+                .extend(4000.),
+            text_type: TextTypes::Custom(24.),
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_components(bundle_for_builder!(MaterialTestObject, AtlasCellText));
+    Engine::spawn(&text_component_builder.build());
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("Arrows", "select atlas cell")])
+    ));
+    set_system_enabled!(true, atlas_system);
+
+    Ok(())
+}
+
+#[system]
+fn atlas_system(
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    mut atlas_cell_query: Query<(&mut AtlasCell, &mut MaterialParameters)>,
+    mut atlas_cell_text_query: Query<(&mut TextRender, &AtlasCellText)>,
+) {
+    let mut cell_step = 0;
+    if is_left_just_pressed(input_state) {
+        cell_step -= 1;
+    }
+    if is_right_just_pressed(input_state) {
+        cell_step += 1;
+    }
+    if is_up_just_pressed(input_state) {
+        cell_step -= ATLAS_COLS as isize;
+    }
+    if is_down_just_pressed(input_state) {
+        cell_step += ATLAS_COLS as isize;
+    }
+    if cell_step == 0 {
+        return;
+    }
+
+    let cell_count = (ATLAS_ROWS * ATLAS_COLS) as isize;
+    atlas_cell_query.for_each(|(atlas_cell, material_params)| {
+        atlas_cell.index = (atlas_cell.index as isize + cell_step).rem_euclid(cell_count) as usize;
+        update_atlas_cell_uniforms(
+            material_params,
+            &gpu_interface.material_manager,
+            atlas_cell_uniforms(ATLAS_ROWS, ATLAS_COLS, atlas_cell.index),
+        );
+
+        let cell_text = atlas_cell_text(atlas_cell.index);
+        atlas_cell_text_query.for_each(|(text_render, _)| {
+            text_render.text = text_to_u8_array(&cell_text);
+        });
+    });
+}
+
+/// The nine slice panel's sizes, as fractions of the screen height, cycled with Left/Right. The
+/// last is smaller than both borders together.
+const NINE_SLICE_SIZES: [(f32, f32); 5] =
+    [(0.2, 0.2), (0.6, 0.2), (0.2, 0.6), (0.9, 0.5), (0.06, 0.06)];
+/// The panel's border width on screen, as a fraction of the screen height
+const NINE_SLICE_BORDER_SIZE: f32 = 0.04;
+/// textures/panel.png's border width in texels
+const PANEL_SLICE_BORDER: f32 = 12.;
+
+/// Test local [`Component`] holding the nine slice panel's size and whether it's sliced
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct NineSlicePanel {
+    pub size_index: usize,
+    pub sliced: bool,
+}
+
+impl Default for NineSlicePanel {
+    fn default() -> Self {
+        Self {
+            size_index: 0,
+            sliced: true,
+        }
+    }
+}
+
+impl NineSlicePanel {
+    fn quad_size(&self, aspect: &Aspect) -> Vec2 {
+        let (width, height) = NINE_SLICE_SIZES[self.size_index];
+        Vec2::new(width, height) * aspect.height
+    }
+
+    fn status_text(&self) -> String {
+        let (width, height) = NINE_SLICE_SIZES[self.size_index];
+        let mode = if self.sliced {
+            "nine slice"
+        } else {
+            "stretched"
+        };
+        format!(
+            "Size {} / {}: {width:.2} x {height:.2} of screen height, {mode}",
+            self.size_index + 1,
+            NINE_SLICE_SIZES.len()
+        )
+    }
+
+    fn update_uniforms(
+        &self,
+        material_params: &mut MaterialParameters,
+        material_manager: &MaterialManager,
+        aspect: &Aspect,
+    ) {
+        let quad_size: UniformValue = self.quad_size(aspect).extend(0.).extend(0.).into();
+        let border_size: UniformValue = (aspect.height * NINE_SLICE_BORDER_SIZE).into();
+        let slice_border: UniformValue = PANEL_SLICE_BORDER.into();
+        let sliced: UniformValue = if self.sliced { 1. } else { 0. }.into();
+        material_params
+            .update_uniforms(
+                material_manager,
+                &[
+                    ("quad_size", &quad_size),
+                    ("border_size", &border_size),
+                    ("slice_border", &slice_border),
+                    ("sliced", &sliced),
+                ],
+            )
+            .unwrap();
+    }
+}
+
+/// Marker [`Component`] for the text describing the nine slice panel
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct NineSliceText;
+
+pub struct NineSliceTest;
+
+material_test_systems!(
+    NineSliceTest,
+    nine_slice_startup_system,
+    [nine_slice_system]
+);
+
+#[system_once]
+fn nine_slice_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = nine_slice_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn nine_slice_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let nine_slice_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "nine_slice")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "nine_slice".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &nine_slice_material_test)?;
+
+    let panel_id = require_texture(gpu_interface, "textures/panel.png")?;
+
+    let mut material_params = material
+        .generate_default_material_parameters()
+        .update_texture(&gpu_interface.material_manager, &("color_tex", &panel_id))
+        .unwrap()
+        .end_chain();
+    let nine_slice_panel = NineSlicePanel::default();
+    nine_slice_panel.update_uniforms(
+        &mut material_params,
+        &gpu_interface.material_manager,
+        aspect,
+    );
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.45.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        panel_id,
+        nine_slice_panel.quad_size(aspect),
+        RelativeScale::OfHeight,
+    );
+    let status_text = nine_slice_panel.status_text();
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        material_params,
+        nine_slice_panel
+    ));
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, CustomText>(
+        CreateTextInput {
+            text: status_text,
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.9.into())
+                .extend(4000.),
+            text_type: TextTypes::Custom(24.),
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_components(bundle_for_builder!(MaterialTestObject, NineSliceText));
+    Engine::spawn(&text_component_builder.build());
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[
+            ("Left/Right", "cycle panel size"),
+            ("Space", "toggle nine slice/stretched"),
+        ])
+    ));
+    set_system_enabled!(true, nine_slice_system);
+
+    Ok(())
+}
+
+#[system]
+fn nine_slice_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    mut panel_query: Query<(&mut NineSlicePanel, &mut Transform, &mut MaterialParameters)>,
+    mut nine_slice_text_query: Query<(&mut TextRender, &NineSliceText)>,
+) {
+    let size_step = match (
+        is_left_just_pressed(input_state),
+        is_right_just_pressed(input_state),
+    ) {
+        (true, false) => NINE_SLICE_SIZES.len() - 1,
+        (false, true) => 1,
+        _ => 0,
+    };
+    let toggle_sliced = input_state.keys[KeyCode::Space].just_pressed();
+    if size_step == 0 && !toggle_sliced {
+        return;
+    }
+
+    panel_query.for_each(|(nine_slice_panel, transform, material_params)| {
+        nine_slice_panel.size_index =
+            (nine_slice_panel.size_index + size_step) % NINE_SLICE_SIZES.len();
+        if toggle_sliced {
+            nine_slice_panel.sliced = !nine_slice_panel.sliced;
+        }
+        transform.scale = nine_slice_panel.quad_size(aspect).into();
+        nine_slice_panel.update_uniforms(material_params, &gpu_interface.material_manager, aspect);
+
+        let status_text = nine_slice_panel.status_text();
+        nine_slice_text_query.for_each(|(text_render, _)| {
+            text_render.text = text_to_u8_array(&status_text);
+        });
+    });
+}
+
+/// textures/msdf_glyphs.png holds these glyphs in a 2 by 2 grid as a multi-channel distance
+/// field. Each channel follows a different set of edges, so the median keeps the corners sharp.
+const MSDF_GLYPHS: [char; 4] = ['V', 'O', 'I', 'D'];
+/// The glyph cell sizes, as a fraction of the screen height, and the row each is drawn at
+const MSDF_TEXT_ROWS: [(f32, f32); 4] = [(0.04, 0.85), (0.08, 0.75), (0.16, 0.58), (0.32, 0.3)];
+/// The distance between glyphs as a fraction of the glyph cell size
+const MSDF_GLYPH_ADVANCE: f32 = 0.75;
+
+pub struct MsdfTextTest;
+
+material_test_systems!(MsdfTextTest, msdf_text_startup_system, []);
+
+#[system_once]
+fn msdf_text_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = msdf_text_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+/// Draws the same word at several sizes with the MSDF material on the left and the built in
+/// [`TextRender`] on the right
+fn msdf_text_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let msdf_text_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "msdf_text")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "msdf_text".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &msdf_text_material_test)?;
+
+    let msdf_glyphs_id = require_texture(gpu_interface, "textures/msdf_glyphs.png")?;
+
+    let word = MSDF_GLYPHS.iter().collect::<String>();
+    for (title, x_percent) in [("MSDF material", 0.28), ("TextRender", 0.75)] {
+        let mut text_component_builder = create_new_text::<_, RegularText>(
+            CreateTextInput {
+                position: screen_space_coordinate_by_percent(aspect, x_percent.into(), 0.95.into())
+                    .extend(0.),
+                text: title,
+                ..Default::default()
+            },
+            aspect,
+        );
+        text_component_builder.add_component(MaterialTestObject);
+        Engine::spawn(&text_component_builder.build());
+    }
+
+    for (size_percent, y_percent) in MSDF_TEXT_ROWS {
+        let glyph_size = aspect.height * size_percent;
+        let advance = glyph_size * MSDF_GLYPH_ADVANCE;
+        let word_center = screen_space_coordinate_by_percent(aspect, 0.28.into(), y_percent.into());
+        let first_glyph_x = word_center.x - advance * (MSDF_GLYPHS.len() - 1) as f32 * 0.5;
+
+        for glyph_index in 0..MSDF_GLYPHS.len() {
+            let mut material_params = material
+                .generate_default_material_parameters()
+                .update_texture(
+                    &gpu_interface.material_manager,
+                    &("msdf_tex", &msdf_glyphs_id),
+                )
+                .unwrap()
+                .end_chain();
+            update_atlas_cell_uniforms(
+                &mut material_params,
+                &gpu_interface.material_manager,
+                atlas_cell_uniforms(2, 2, glyph_index),
+            );
+
+            let mut texture_component_builder = create_new_relative_texture(
+                Vec3::new(
+                    first_glyph_x + advance * glyph_index as f32,
+                    word_center.y,
+                    0.,
+                )
+                .into(),
+                *palette::WHITE,
+                msdf_glyphs_id,
+                Vec2::splat(glyph_size),
+                RelativeScale::OfHeight,
+            );
+            texture_component_builder
+                .add_components(bundle_for_builder!(MaterialTestObject, material_params));
+            Engine::spawn(&texture_component_builder.build());
+        }
+
+        // The glyphs are drawn 40 texels tall in 64 texel cells, and a capital is about 0.7 of
+        // the font size
+        let mut text_component_builder = create_new_text::<_, CustomText>(
+            CreateTextInput {
+                text: word.as_str(),
+                position: screen_space_coordinate_by_percent(aspect, 0.75.into(), y_percent.into())
+                    .extend(0.),
+                text_type: TextTypes::Custom(glyph_size * 40. / 64. / 0.7),
+                ..Default::default()
+            },
+            aspect,
+        );
+        text_component_builder.add_component(MaterialTestObject);
+        Engine::spawn(&text_component_builder.build());
+    }
+
+    Ok(())
+}
+
+/// How much Up/Down raise or lower the light, in sprite widths
+const NORMAL_LIGHT_HEIGHT_STEP: f32 = 0.05;
+const NORMAL_LIGHT_MIN_HEIGHT: f32 = 0.05;
+const NORMAL_LIGHT_MAX_HEIGHT: f32 = 1.;
+
+/// Test local [`Component`] holding the height of the normal light test's light
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct NormalLight {
+    pub light_height: f32,
+}
+
+impl Default for NormalLight {
+    fn default() -> Self {
+        Self { light_height: 0.25 }
+    }
+}
+
+/// Marker [`Component`] for the text showing the normal light test's light height
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct NormalLightText;
+
+fn normal_light_text(light_height: f32) -> String {
+    format!("Light height {light_height:.2} sprite widths")
+}
+
+pub struct NormalLightTest;
+
+material_test_systems!(
+    NormalLightTest,
+    normal_light_startup_system,
+    [normal_light_system]
+);
+
+#[system_once]
+fn normal_light_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = normal_light_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn normal_light_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let normal_light_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "normal_light")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "normal_light".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &normal_light_material_test)?;
+
+    let bumps_color_id = require_texture(gpu_interface, "textures/bumps_color.png")?;
+    let bumps_normal_id = require_texture(gpu_interface, "textures/bumps_normal.png")?;
+
+    let material_params = material
+        .generate_default_material_parameters()
+        .update_textures(
+            &gpu_interface.material_manager,
+            &[
+                ("color_tex", &bumps_color_id),
+                ("normal_map", &bumps_normal_id),
+            ],
+        )
+        .unwrap()
+        .end_chain();
+    let normal_light = NormalLight::default();
+    let light_text = normal_light_text(normal_light.light_height);
+
+    // Square, so the light's distance is measured the same way across and down the sprite
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        bumps_color_id,
+        Vec2::splat(aspect.height * 0.6),
+        RelativeScale::OfHeight,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        material_params,
+        normal_light
+    ));
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, CustomText>(
+        CreateTextInput {
+            text: light_text,
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.9.into())
+                .extend(4000.),
+            text_type: TextTypes::Custom(24.),
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_components(bundle_for_builder!(MaterialTestObject, NormalLightText));
+    Engine::spawn(&text_component_builder.build());
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("Mouse", "move light"), ("Up/Down", "raise/lower light"),])
+    ));
+    set_system_enabled!(true, normal_light_system);
+
+    Ok(())
+}
+
+/// Moves the light to the cursor, in the uvs of each lit sprite
+#[system]
+fn normal_light_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    key_repeat_state: &KeyRepeatState,
+    mut normal_light_query: Query<(&mut NormalLight, &Transform, &mut MaterialParameters)>,
+    mut normal_light_text_query: Query<(&mut TextRender, &NormalLightText)>,
+) {
+    let height_step = match (
+        is_up_repeated(input_state, key_repeat_state),
+        is_down_repeated(input_state, key_repeat_state),
+    ) {
+        (true, false) => NORMAL_LIGHT_HEIGHT_STEP,
+        (false, true) => -NORMAL_LIGHT_HEIGHT_STEP,
+        _ => 0.,
+    };
+    let cursor_position = input_state.mouse.cursor_position;
+    let cursor = screen_to_world(aspect, Vec2::new(cursor_position.x, cursor_position.y));
+
+    normal_light_query.for_each(|(normal_light, transform, material_params)| {
+        if height_step != 0. {
+            normal_light.light_height = (normal_light.light_height + height_step)
+                .clamp(NORMAL_LIGHT_MIN_HEIGHT, NORMAL_LIGHT_MAX_HEIGHT);
+            let light_text = normal_light_text(normal_light.light_height);
+            normal_light_text_query.for_each(|(text_render, _)| {
+                text_render.text = text_to_u8_array(&light_text);
+            });
+        }
+
+        let light_uv = world_to_sprite_uv(
+            cursor,
+            transform.position.get().truncate(),
+            transform.scale.get(),
+        );
+        let light_position: UniformValue =
+            Vec4::new(light_uv.x, light_uv.y, normal_light.light_height, 0.).into();
+        material_params
+            .update_uniform(
+                &gpu_interface.material_manager,
+                &("light_position", &light_position),
+            )
+            .unwrap();
+    });
+}
+
+/// The splat blend test's layer textures, blended by the red, green and blue channels of
+/// textures/splat_mask.png
+const SPLAT_BLEND_LAYERS: [(&str, &str); 3] = [
+    ("layer_a", "textures/checker.png"),
+    ("layer_b", "textures/random.png"),
+    ("layer_c", "textures/star_map_with_mask.png"),
+];
+/// How much Up/Down changes the selected layer's weight
+const SPLAT_WEIGHT_STEP: f32 = 0.1;
+const SPLAT_WEIGHT_LIMIT: f32 = 2.;
+
+/// Test local [`Component`] holding the splat blend test's layer weights
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct SplatBlend {
+    pub weights: [f32; 3],
+    pub selected_layer: usize,
+    pub use_mask: bool,
+}
+
+impl Default for SplatBlend {
+    fn default() -> Self {
+        Self {
+            weights: [1.; 3],
+            selected_layer: 0,
+            use_mask: true,
+        }
+    }
+}
+
+impl SplatBlend {
+    fn status_text(&self) -> String {
+        let weights = SPLAT_BLEND_LAYERS
+            .iter()
+            .zip(self.weights)
+            .enumerate()
+            .map(|(layer, ((uniform_name, _), weight))| {
+                if layer == self.selected_layer {
+                    format!("[{uniform_name} {weight:.1}]")
+                } else {
+                    format!("{uniform_name} {weight:.1}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mask = if self.use_mask { "on" } else { "off" };
+        format!("{weights}, mask {mask}")
+    }
+
+    fn update_uniforms(
+        &self,
+        material_params: &mut MaterialParameters,
+        material_manager: &MaterialManager,
+    ) {
+        let [weight_a, weight_b, weight_c] = self.weights;
+        let layer_weights: UniformValue = Vec4::new(weight_a, weight_b, weight_c, 0.).into();
+        let use_mask: UniformValue = if self.use_mask { 1. } else { 0. }.into();
+        material_params
+            .update_uniforms(
+                material_manager,
+                &[("layer_weights", &layer_weights), ("use_mask", &use_mask)],
+            )
+            .unwrap();
+    }
+}
+
+/// Marker [`Component`] for the text listing the splat blend test's weights
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct SplatBlendText;
+
+pub struct SplatBlendTest;
+
+material_test_systems!(
+    SplatBlendTest,
+    splat_blend_startup_system,
+    [splat_blend_system]
+);
+
+#[system_once]
+fn splat_blend_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = splat_blend_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+/// Binds all four of the material's textures, the most of any test material, and draws each
+/// texture unblended along the bottom for reference
+fn splat_blend_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let splat_blend_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "splat_blend")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "splat_blend".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &splat_blend_material_test)?;
+
+    let mut textures = Vec::with_capacity(SPLAT_BLEND_LAYERS.len() + 1);
+    for (texture_name, texture_path) in SPLAT_BLEND_LAYERS
+        .into_iter()
+        .chain([("blend_mask", "textures/splat_mask.png")])
+    {
+        textures.push((
+            texture_name,
+            texture_path,
+            require_texture(gpu_interface, texture_path)?,
+        ));
+    }
+    let texture_bindings = textures
+        .iter()
+        .map(|(texture_name, _, texture_id)| (*texture_name, texture_id))
+        .collect::<Vec<_>>();
+    let mut material_params = material
+        .generate_default_material_parameters()
+        .update_textures(&gpu_interface.material_manager, &texture_bindings)
+        .unwrap()
+        .end_chain();
+    let splat_blend = SplatBlend::default();
+    splat_blend.update_uniforms(&mut material_params, &gpu_interface.material_manager);
+    let status_text = splat_blend.status_text();
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.55.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        textures[0].2,
+        Vec2::splat(aspect.height * 0.55),
+        RelativeScale::OfHeight,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        material_params,
+        splat_blend
+    ));
+    Engine::spawn(&texture_component_builder.build());
+
+    for (index, (texture_name, _, texture_id)) in textures.iter().enumerate() {
+        let x_percent = 0.2 + 0.2 * index as f32;
+        let mut texture_component_builder = create_new_relative_texture(
+            screen_space_coordinate_by_percent(aspect, x_percent.into(), 0.15.into())
+                .extend(0.)
+                .into(),
+            *palette::WHITE,
+            *texture_id,
+            Vec2::splat(aspect.height * 0.12),
+            RelativeScale::OfHeight,
+        );
+        texture_component_builder.add_component(MaterialTestObject);
+        Engine::spawn(&texture_component_builder.build());
+
+        let mut text_component_builder = create_new_text::<_, CustomText>(
+            CreateTextInput {
+                text: *texture_name,
+                position: screen_space_coordinate_by_percent(aspect, x_percent.into(), 0.05.into())
+                    .extend(4000.),
+                text_type: TextTypes::Custom(20.),
+                ..Default::default()
+            },
+            aspect,
+        );
+        text_component_builder.add_component(MaterialTestObject);
+        Engine::spawn(&text_component_builder.build());
+    }
+
+    let mut text_component_builder = create_new_text::<_, CustomText>(
+        CreateTextInput {
+            text: status_text,
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.9.into())
+                .extend(4000.),
+            text_type: TextTypes::Custom(24.),
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_components(bundle_for_builder!(MaterialTestObject, SplatBlendText));
+    Engine::spawn(&text_component_builder.build());
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[
+            ("Left/Right", "select layer"),
+            ("Up/Down", "raise/lower layer weight"),
+            ("Space", "toggle blend mask"),
+        ])
+    ));
+    set_system_enabled!(true, splat_blend_system);
+
+    Ok(())
+}
+
+#[system]
+fn splat_blend_system(
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    key_repeat_state: &KeyRepeatState,
+    mut splat_blend_query: Query<(&mut SplatBlend, &mut MaterialParameters)>,
+    mut splat_blend_text_query: Query<(&mut TextRender, &SplatBlendText)>,
+) {
+    let layer_step = match (
+        is_left_just_pressed(input_state),
+        is_right_just_pressed(input_state),
+    ) {
+        (true, false) => SPLAT_BLEND_LAYERS.len() - 1,
+        (false, true) => 1,
+        _ => 0,
+    };
+    let weight_step = match (
+        is_up_repeated(input_state, key_repeat_state),
+        is_down_repeated(input_state, key_repeat_state),
+    ) {
+        (true, false) => SPLAT_WEIGHT_STEP,
+        (false, true) => -SPLAT_WEIGHT_STEP,
+        _ => 0.,
+    };
+    let toggle_mask = input_state.keys[KeyCode::Space].just_pressed();
+    if layer_step == 0 && weight_step == 0. && !toggle_mask {
+        return;
+    }
+
+    splat_blend_query.for_each(|(splat_blend, material_params)| {
+        splat_blend.selected_layer =
+            (splat_blend.selected_layer + layer_step) % SPLAT_BLEND_LAYERS.len();
+        let weight = &mut splat_blend.weights[splat_blend.selected_layer];
+        *weight = (*weight + weight_step).clamp(0., SPLAT_WEIGHT_LIMIT);
+        if toggle_mask {
+            splat_blend.use_mask = !splat_blend.use_mask;
+        }
+        splat_blend.update_uniforms(material_params, &gpu_interface.material_manager);
+
+        let status_text = splat_blend.status_text();
+        splat_blend_text_query.for_each(|(text_render, _)| {
+            text_render.text = text_to_u8_array(&status_text);
+        });
+    });
+}
+
+/// Seconds between the bursts spawned at random positions while auto spawning
+const PARTICLE_AUTO_SPAWN_SECONDS: f32 = 0.25;
+
+/// Test local [`Component`] holding the particles test's bursts and clock
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct ParticleEmitter {
+    pub particle_bursts: ParticleBursts,
+    pub time: f32,
+    pub auto_spawn: bool,
+    pub since_auto_spawn: f32,
+    /// Live bursts overwritten because every uniform slot was in use
+    pub replaced_bursts: usize,
+}
+
+impl Default for ParticleEmitter {
+    fn default() -> Self {
+        Self {
+            particle_bursts: ParticleBursts::default(),
+            time: 0.,
+            auto_spawn: true,
+            since_auto_spawn: 0.,
+            replaced_bursts: 0,
+        }
+    }
+}
+
+impl ParticleEmitter {
+    fn spawn(&mut self, uv: Vec2, test_rng: &mut TestRng) {
+        let replaced = self.particle_bursts.spawn(ParticleBurst {
+            uv: [uv.x, uv.y],
+            spawn_time: self.time,
+            seed: test_rng.gen_range(0. ..1000.),
+        });
+        if replaced {
+            self.replaced_bursts += 1;
+        }
+    }
+
+    fn status_text(&self) -> String {
+        let live_bursts = self.particle_bursts.live_burst_count(self.time);
+        format!(
+            "Bursts {live_bursts} / {PARTICLE_BURST_SLOTS}, particles {} / {}, replaced {}{}",
+            live_bursts * PARTICLES_PER_BURST,
+            PARTICLE_BURST_SLOTS * PARTICLES_PER_BURST,
+            self.replaced_bursts,
+            if self.auto_spawn {
+                ", auto spawning"
+            } else {
+                ""
+            }
+        )
+    }
+}
+
+/// Marker [`Component`] for the text counting the particles test's bursts
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct ParticlesText;
+
+pub struct ParticlesTest;
+
+material_test_systems!(ParticlesTest, particles_startup_system, [particles_system]);
+
+#[system_once]
+fn particles_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = particles_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+/// A single screen sized quad draws every particle, its uvs line up with the cursor position
+fn particles_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let particles_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "particles")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "particles".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &particles_material_test)?;
+
+    let white_id = require_texture(gpu_interface, "textures/white.png")?;
+
+    let material_params = material
+        .generate_default_material_parameters()
+        .update_uniform(
+            &gpu_interface.material_manager,
+            &("aspect_ratio", &(aspect.width / aspect.height).into()),
+        )
+        .unwrap()
+        .end_chain();
+    let particle_emitter = ParticleEmitter::default();
+    let status_text = particle_emitter.status_text();
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        white_id,
+        Vec2::new(aspect.width, aspect.height),
+        RelativeScale::OfScreen,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        material_params,
+        particle_emitter
+    ));
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, CustomText>(
+        CreateTextInput {
+            text: status_text,
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.9.into())
+                .extend(4000.),
+            text_type: TextTypes::Custom(24.),
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_components(bundle_for_builder!(MaterialTestObject, ParticlesText));
+    Engine::spawn(&text_component_builder.build());
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("Click", "spawn burst"), ("Space", "toggle auto spawn"),])
+    ));
+    set_system_enabled!(true, particles_system);
+
+    Ok(())
+}
+
+/// Every frame the bursts are written to the material's uniforms, ages and all
+#[system]
+fn particles_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    test_clock: &TestClock,
+    test_rng: &mut TestRng,
+    mut emitter_query: Query<(&mut ParticleEmitter, &mut MaterialParameters)>,
+    mut particles_text_query: Query<(&mut TextRender, &ParticlesText)>,
+) {
+    let spawn_at_cursor = is_particle_spawn_just_pressed(input_state);
+    let toggle_auto_spawn = input_state.keys[KeyCode::Space].just_pressed();
+    let cursor_position = input_state.mouse.cursor_position;
+    let cursor_uv = screen_to_uv(aspect, Vec2::new(cursor_position.x, cursor_position.y));
+
+    emitter_query.for_each(|(particle_emitter, material_params)| {
+        particle_emitter.time += test_clock.delta_time();
+        if toggle_auto_spawn {
+            particle_emitter.auto_spawn = !particle_emitter.auto_spawn;
+        }
+        if spawn_at_cursor {
+            particle_emitter.spawn(cursor_uv, test_rng);
+        }
+        if particle_emitter.auto_spawn {
+            particle_emitter.since_auto_spawn += test_clock.delta_time();
+            if particle_emitter.since_auto_spawn >= PARTICLE_AUTO_SPAWN_SECONDS {
+                particle_emitter.since_auto_spawn = 0.;
+                let random_uv =
+                    Vec2::new(test_rng.gen_range(0.1..0.9), test_rng.gen_range(0.1..0.7));
+                particle_emitter.spawn(random_uv, test_rng);
+            }
+        }
+
+        let burst_values = particle_emitter
+            .particle_bursts
+            .uniform_values(particle_emitter.time)
+            .map(|[x, y, age, seed]| UniformValue::from(Vec4::new(x, y, age, seed)));
+        let burst_uniforms = PARTICLE_BURST_UNIFORM_NAMES
+            .iter()
+            .zip(&burst_values)
+            .map(|(uniform_name, uniform_value)| (*uniform_name, uniform_value))
+            .collect::<Vec<_>>();
+        material_params
+            .update_uniforms(&gpu_interface.material_manager, &burst_uniforms)
+            .unwrap();
+
+        let status_text = particle_emitter.status_text();
+        particles_text_query.for_each(|(text_render, _)| {
+            text_render.text = text_to_u8_array(&status_text);
+        });
+    });
+}
+
+/// The sizes, as a fraction of the screen height, the mip inspector draws its texture at
+const MIP_INSPECTOR_SCALES: [f32; 6] = [0.4, 0.2, 0.1, 0.05, 0.025, 0.0125];
+/// How much Up/Down changes the mip bias
+const MIP_BIAS_STEP: f32 = 0.5;
+const MIP_BIAS_LIMIT: f32 = 4.;
+
+/// Test local [`Component`] holding the sampling settings the mip inspector applies to all of its
+/// sprites
+#[derive(
+    Debug, Default, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize,
+)]
+pub struct MipInspectorSettings {
+    pub nearest_filtering: bool,
+    pub mip_bias: f32,
+    pub show_mip_level: bool,
+}
+
+impl MipInspectorSettings {
+    fn status_text(&self) -> String {
+        format!(
+            "Filtering: {}  Mip bias: {:+.1}  Mip level tint: {}",
+            if self.nearest_filtering {
+                "nearest"
+            } else {
+                "linear"
+            },
+            self.mip_bias,
+            if self.show_mip_level { "on" } else { "off" }
+        )
+    }
+
+    fn update_uniforms(
+        &self,
+        material_params: &mut MaterialParameters,
+        material_manager: &MaterialManager,
+    ) {
+        let filter_mode: UniformValue = if self.nearest_filtering { 1. } else { 0. }.into();
+        let mip_bias: UniformValue = self.mip_bias.into();
+        let show_mip_level: UniformValue = if self.show_mip_level { 1. } else { 0. }.into();
+        material_params
+            .update_uniforms(
+                material_manager,
+                &[
+                    ("filter_mode", &filter_mode),
+                    ("mip_bias", &mip_bias),
+                    ("show_mip_level", &show_mip_level),
+                ],
+            )
+            .unwrap();
+    }
+}
+
+/// Marker [`Component`] for the sprites drawn by the mip inspector
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct MipInspectorSprite;
+
+/// Marker [`Component`] for the text describing the mip inspector's settings
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct MipInspectorText;
+
+pub struct MipInspectorTest;
+
+material_test_systems!(
+    MipInspectorTest,
+    mip_inspector_startup_system,
+    [mip_inspector_system]
+);
+
+#[system_once]
+fn mip_inspector_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = mip_inspector_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn mip_inspector_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let mip_inspector_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "mip_inspector")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "mip_inspector".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &mip_inspector_material_test)?;
+
+    let checker_id = require_texture(gpu_interface, "textures/checker.png")?;
+
+    let mip_inspector_settings = MipInspectorSettings::default();
+
+    // Laid out left to right, largest first
+    let gap = aspect.width * 0.02;
+    let total_width = MIP_INSPECTOR_SCALES
+        .iter()
+        .map(|scale| scale * aspect.height)
+        .sum::<f32>()
+        + gap * (MIP_INSPECTOR_SCALES.len() - 1) as f32;
+    let mut left = -total_width * 0.5;
+    for scale in MIP_INSPECTOR_SCALES {
+        let mut material_params = material
+            .generate_default_material_parameters()
+            .update_texture(&gpu_interface.material_manager, &("color_tex", &checker_id))
+            .unwrap()
+            .end_chain();
+        mip_inspector_settings
+            .update_uniforms(&mut material_params, &gpu_interface.material_manager);
+
+        let size = scale * aspect.height;
+        let mut texture_component_builder = create_new_relative_texture(
+            Vec3::new(left + size * 0.5, 0., 0.).into(),
+            *palette::WHITE,
+            checker_id,
+            Vec2::splat(size),
+            RelativeScale::OfHeight,
+        );
+        texture_component_builder.add_components(bundle_for_builder!(
+            MaterialTestObject,
+            MipInspectorSprite,
+            material_params
+        ));
+        Engine::spawn(&texture_component_builder.build());
+        left += size + gap;
+    }
+
+    let mut text_component_builder = create_new_text::<_, CustomText>(
+        CreateTextInput {
+            text: mip_inspector_settings.status_text(),
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.9.into())
+                .extend(4000.),
+            text_type: TextTypes::Custom(24.),
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder
+        .add_components(bundle_for_builder!(MaterialTestObject, MipInspectorText));
+    Engine::spawn(&text_component_builder.build());
+    Engine::spawn(bundle!(&MaterialTestObject, &mip_inspector_settings));
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[
+            ("Space", "toggle nearest filtering"),
+            ("Up/Down", "raise/lower mip bias"),
+            ("V", "toggle mip level tint"),
+        ])
+    ));
+    set_system_enabled!(true, mip_inspector_system);
+
+    Ok(())
+}
+
+#[system]
+fn mip_inspector_system(
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    mut settings_query: Query<&mut MipInspectorSettings>,
+    mut sprite_query: Query<(&mut MaterialParameters, &MipInspectorSprite)>,
+    mut settings_text_query: Query<(&mut TextRender, &MipInspectorText)>,
+) {
+    let toggle_filtering = input_state.keys[KeyCode::Space].just_pressed();
+    let toggle_mip_level = is_mip_level_toggle_just_pressed(input_state);
+    let mip_bias_step = match (
+        is_up_just_pressed(input_state),
+        is_down_just_pressed(input_state),
+    ) {
+        (true, false) => MIP_BIAS_STEP,
+        (false, true) => -MIP_BIAS_STEP,
+        _ => 0.,
+    };
+    if !toggle_filtering && !toggle_mip_level && mip_bias_step == 0. {
+        return;
+    }
+
+    settings_query.for_each(|mip_inspector_settings| {
+        if toggle_filtering {
+            mip_inspector_settings.nearest_filtering = !mip_inspector_settings.nearest_filtering;
+        }
+        if toggle_mip_level {
+            mip_inspector_settings.show_mip_level = !mip_inspector_settings.show_mip_level;
+        }
+        mip_inspector_settings.mip_bias = (mip_inspector_settings.mip_bias + mip_bias_step)
+            .clamp(-MIP_BIAS_LIMIT, MIP_BIAS_LIMIT);
+
+        sprite_query.for_each(|(material_params, _)| {
+            mip_inspector_settings
+                .update_uniforms(material_params, &gpu_interface.material_manager);
+        });
+        let status_text = mip_inspector_settings.status_text();
+        settings_text_query.for_each(|(text_render, _)| {
+            text_render.text = text_to_u8_array(&status_text);
+        });
+    });
+}
+
+pub struct PixelUpscaleTest;
+
+material_test_systems!(PixelUpscaleTest, pixel_upscale_startup_system, []);
+
+#[system_once]
+fn pixel_upscale_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = pixel_upscale_startup(aspect, gpu_interface, &material_test_query) {
+        show_error_view(view, &error);
+    }
+}
+
+fn pixel_upscale_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let pixel_upscale_material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "pixel_upscale")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "pixel_upscale".to_string(),
+        })?;
+    let (material_id, _) = require_material(gpu_interface, &pixel_upscale_material_test)?;
+
+    let pixel_heart_id = require_texture(gpu_interface, "textures/pixel_heart.png")?;
+
+    let base_material_params = MaterialParameters::new(material_id)
+        .update_texture(
+            &gpu_interface.material_manager,
+            &("color_tex", &pixel_heart_id),
+        )
+        .unwrap()
+        .end_chain();
+
+    let sampling_mode_names = ["nearest", "bilinear", "sharp bilinear"];
+
+    for (index, sampling_mode_name) in sampling_mode_names.into_iter().enumerate() {
+        let mode_value = index as f32;
+        let mode_material_params = base_material_params
+            .update_uniform(
+                &gpu_interface.material_manager,
+                &("mode", &mode_value.into()),
+            )
+            .unwrap()
+            .end_chain();
+
+        let x_percent = 0.2 + 0.3 * mode_value;
+        let texture_position =
+            screen_space_coordinate_by_percent(aspect, x_percent.into(), 0.5.into()).extend(0.);
+        // A non integer scale, so the differences between the modes at texel edges are visible
+        let mut texture_component_builder = create_new_relative_texture(
+            texture_position.into(),
+            *palette::WHITE,
+            pixel_heart_id,
+            Vec2::splat(aspect.width * 0.213),
+            RelativeScale::OfWidth,
+        );
+        texture_component_builder.add_components(bundle_for_builder!(
+            MaterialTestObject,
+            mode_material_params
+        ));
+        Engine::spawn(&texture_component_builder.build());
+
+        let mut text_component_builder = create_new_text::<_, RegularText>(
+            CreateTextInput {
+                position: texture_position - Vec3::new(0., aspect.height * 0.25, 0.),
+                text: sampling_mode_name,
+                ..Default::default()
+            },
+            aspect,
+        );
+        text_component_builder.add_component(MaterialTestObject);
+        Engine::spawn(&text_component_builder.build());
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct Velocity {
+    pub direction: Vec3,
+    pub rotation: f32,
+}
+
+pub struct ImmediateModeTest;
+
+// The per frame system is also the startup system, enabled when the test is chosen
+material_test_systems!(ImmediateModeTest, immediate_mode_test, []);
+
+#[system]
+#[allow(clippy::too_many_arguments)]
+fn immediate_mode_test(
+    draw_circle_writer: EventWriter<DrawCircle>,
+    draw_line_writer: EventWriter<DrawLine>,
+    draw_text_writer: EventWriter<DrawText>,
+    draw_rectangle_writer: EventWriter<DrawRectangle>,
+    aspect: &Aspect,
+    frame_constants: &FrameConstants,
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    key_repeat_state: &KeyRepeatState,
+    material_test_registry: &MaterialTestRegistry,
+    test_clock: &TestClock,
+    mut immediate_mode_state_query: Query<(&mut TimePassedSinceCreation, &mut ImmediateModeBurst)>,
+) {
+    let scared_id = match gpu_interface
+        .texture_asset_manager
+        .get_texture_by_path(&"textures/scared.png".into())
+    {
+        Some(texture) => texture.id(),
+        None => {
+            warn!(
+                "Could not find texture scared.png, if this occurs at the beginning of the first frame it is normal (for now), otherwise this is an error"
+            );
+            return;
+        }
+    };
+
+    let scared_distance = Vec2::new(aspect.width * 0.15, 0.);
+    let circle_distance = Vec2::new(aspect.width * 0.275, 0.);
+    let line_distance = Vec2::new(aspect.width * 0.375, 0.);
+    let center_point_vec2 = screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into());
+    let center_point_vec3 = center_point_vec2.extend(1.);
+    let center_point_vec3t = Vec3T {
+        x: center_point_vec3.x,
+        y: center_point_vec3.y,
+        z: center_point_vec3.z,
+    };
+
+    let (time_passed, burst_primitive_count) = if immediate_mode_state_query.is_empty() {
+        Engine::spawn(bundle!(
+            &MaterialTestObject,
+            &TimePassedSinceCreation::default(),
+            &ImmediateModeBurst::default(),
+            &TestControls::new(&[
+                ("B", "toggle burst mode"),
+                ("Up/Down", "change burst primitive count"),
+            ])
+        ));
+        (0., None)
+    } else {
+        let mut time_passed = 0.;
+        let mut burst_primitive_count = None;
+        immediate_mode_state_query.for_each(|(time_passed_since_creation, burst)| {
+            *time_passed_since_creation += test_clock.delta_time();
+            time_passed = ***time_passed_since_creation;
+
+            if is_burst_toggle_just_pressed(input_state) {
+                burst.enabled = !burst.enabled;
+            }
+            if burst.enabled {
+                if is_up_repeated(input_state, key_repeat_state) {
+                    burst.primitive_count = (burst.primitive_count + IMMEDIATE_MODE_BURST_STEP)
+                        .min(IMMEDIATE_MODE_BURST_MAX);
+                } else if is_down_repeated(input_state, key_repeat_state) {
+                    burst.primitive_count = burst
+                        .primitive_count
+                        .saturating_sub(IMMEDIATE_MODE_BURST_STEP)
+                        .max(IMMEDIATE_MODE_BURST_STEP);
+                }
+                burst_primitive_count = Some(burst.primitive_count);
+            }
+        });
+        (time_passed, burst_primitive_count)
+    };
+
+    if let Some(primitive_count) = burst_primitive_count {
+        write_immediate_mode_burst(
+            &draw_circle_writer,
+            &draw_line_writer,
+            &draw_text_writer,
+            aspect,
+            frame_constants,
+            time_passed,
+            primitive_count,
+        );
+        return;
+    }
+
+    draw_text_writer.write_builder(|builder| {
+        let flatbuffer_test_string = builder.create_string("This is a test");
+        let mut draw_text_builder = DrawTextBuilder::new(builder);
+        draw_text_builder.add_font_size(48.);
+        draw_text_builder.add_text(flatbuffer_test_string);
+        let red = 0.25 * time_passed.sin() + 0.75;
+        let green = 0.25 * time_passed.cos() + 0.75;
+        draw_text_builder.add_color(&void_public::event::graphics::Color::new(
+            red, green, 1., 1.,
+        ));
+        draw_text_builder.add_bounds(&Vec2T { x: 500., y: 500. }.pack());
+        draw_text_builder.add_text_alignment(TextAlignment::Center);
+        let transform = TransformT {
+            position: center_point_vec3t,
+            scale: Vec2T { x: 1., y: 1. },
+            ..Default::default()
+        };
+        draw_text_builder.add_transform(&transform.pack());
+        draw_text_builder.add_z(1.);
+        draw_text_builder.finish()
+    });
+
+    let starting_rotation_matrix = Mat2::from_angle(time_passed);
+    let mut rotation_matrix = starting_rotation_matrix;
+    let num_of_images = 5;
+    let image_shift_rotation_matrix = generate_equal_parts_rotation_matrix(num_of_images as f32);
+    for index in 0..num_of_images {
+        draw_rectangle_writer.write_builder(|builder| {
+            let mut draw_rectangle_builder = DrawRectangleBuilder::new(builder);
+            draw_rectangle_builder.add_asset_id(*scared_id);
+            let red = 0.25 * (index as f32).cos() + 0.75;
+            let green = 0.25 * (index as f32).sin() + 0.75;
+            draw_rectangle_builder.add_color(&void_public::event::graphics::Color::new(
+                red, green, 1., 1.,
+            ));
+            let position = center_point_vec3 + (rotation_matrix * scared_distance).extend(0.);
+            rotation_matrix *= image_shift_rotation_matrix;
+            let transform = TransformT {
+                position: Vec3T {
+                    x: position.x,
+                    y: position.y,
+                    z: position.z,
+                },
+                scale: Vec2T { x: 125., y: 125. },
+                rotation: (index as f32 + time_passed).sin(),
+                ..Default::default()
+            };
+            draw_rectangle_builder.add_transform(&transform.pack());
+            draw_rectangle_builder.finish()
+        });
+    }
+
+    rotation_matrix = starting_rotation_matrix;
+    let num_of_circles = 6;
+    let circle_shift_rotation_matrix = generate_equal_parts_rotation_matrix(num_of_circles as f32);
+    for index in 0..num_of_circles {
+        let position = center_point_vec2 + (rotation_matrix * circle_distance);
+        rotation_matrix *= circle_shift_rotation_matrix;
+        let r = 0.25 * (index as f32).sin() + 0.75;
+        let g = 0.25 * (index as f32).cos() + 0.75;
+        draw_circle_writer.write(
+            DrawCircleT {
+                position: Vec2T {
+                    x: position.x,
+                    y: position.y,
+                },
+                z: 0.,
+                radius: 100.,
+                subdivisions: 32,
+                rotation: 0.,
+                color: ColorT { r, g, b: 1., a: 1. },
+            }
+            .pack(),
+        );
+    }
+
+    rotation_matrix = starting_rotation_matrix;
+    let num_of_lines = 4;
+    let half_line_length = 35.;
+    let thickness = 20.;
+    let line_shift_rotation_matrix = generate_equal_parts_rotation_matrix(num_of_lines as f32);
+    for index in 0..num_of_lines {
+        let center_position = center_point_vec2 + (rotation_matrix * line_distance);
+        rotation_matrix *= line_shift_rotation_matrix;
+        let from_position = center_position - Vec2::new(half_line_length, 0.);
+        let to_position = center_position + Vec2::new(half_line_length, 0.);
+        let r = 0.25 * (index as f32).cos() + 0.75;
+        let g = 0.25 * (index as f32).sin() + 0.75;
+        draw_line_writer.write(
+            DrawLineT {
+                from: Vec2T {
+                    x: from_position.x,
+                    y: from_position.y,
+                },
+                to: Vec2T {
+                    x: to_position.x,
+                    y: to_position.y,
+                },
+                z: 0.,
+                thickness,
+                color: ColorT { r, g, b: 1., a: 1. },
+            }
+            .pack(),
+        );
+    }
+
+    // Polyline spiral, drawn as connected line segments
+
+    let spiral_center = screen_space_coordinate_by_percent(aspect, 0.1.into(), 0.5.into());
+    let spiral_points = spiral_points(spiral_center, aspect.width * 0.07, 3., 96, -time_passed);
+    write_polyline(
+        &draw_line_writer,
+        &spiral_points,
+        4.,
+        ColorT {
+            r: 1.,
+            g: 0.8,
+            b: 0.3,
+            a: 1.,
+        },
+    );
+
+    // Arcs with increasing subdivisions, so the tessellation is visible
+
+    let arc_center = screen_space_coordinate_by_percent(aspect, 0.9.into(), 0.5.into());
+    for (index, subdivisions) in [2, 4, 8, 32].into_iter().enumerate() {
+        let arc_points = arc_points(
+            arc_center,
+            aspect.width * (0.025 + 0.015 * index as f32),
+            time_passed + index as f32,
+            1.5 * PI,
+            subdivisions,
+        );
+        let g = 0.25 * (index as f32).cos() + 0.75;
+        write_polyline(
+            &draw_line_writer,
+            &arc_points,
+            3.,
+            ColorT {
+                r: 0.4,
+                g,
+                b: 1.,
+                a: 1.,
+            },
+        );
+    }
+
+    // Circles with increasing subdivisions
+
+    let circle_subdivisions = [3, 4, 5, 6, 8, 12, 32];
+    for (index, subdivisions) in circle_subdivisions.into_iter().enumerate() {
+        let x_percent = ZeroToHundredPercent::new(0.2)
+            + ZeroToHundredPercent::from_fraction(
+                index as f32,
+                (circle_subdivisions.len() - 1) as f32,
+            ) * 0.6;
+        let position = screen_space_coordinate_by_percent(aspect, x_percent, 0.08.into());
+        draw_circle_writer.write(
+            DrawCircleT {
+                position: Vec2T {
+                    x: position.x,
+                    y: position.y,
+                },
+                z: 0.,
+                radius: aspect.height * 0.04,
+                subdivisions,
+                rotation: time_passed,
+                color: ColorT {
+                    r: 1.,
+                    g: 0.5,
+                    b: 0.5,
+                    a: 1.,
+                },
+            }
+            .pack(),
+        );
+    }
+
+    // Rectangles drawn with each of the test's materials
+
+    let Some(immediate_mode_material_test) =
+        material_test_registry.get_by_name("immediate_mode_test")
+    else {
+        error!("Could not find immediate_mode_test material test");
+        return;
+    };
+    let material_ids = immediate_mode_material_test
+        .material_id_iter()
+        .map_while(|material_id| material_id)
+        .collect::<Vec<_>>();
+    let positions = row_positions(aspect, Vec2::new(0.5, 0.9), 0.15, material_ids.len());
+    for (material_id, position) in material_ids.iter().zip(positions) {
+        draw_rectangle_writer.write_builder(|builder| {
+            let mut draw_rectangle_builder = DrawRectangleBuilder::new(builder);
+            draw_rectangle_builder.add_asset_id(*scared_id);
+            draw_rectangle_builder.add_material_id(**material_id);
+            draw_rectangle_builder
+                .add_color(&void_public::event::graphics::Color::new(1., 1., 1., 1.));
+            let transform = TransformT {
+                position: Vec3T {
+                    x: position.x,
+                    y: position.y,
+                    z: 1.,
+                },
+                scale: Vec2T {
+                    x: aspect.height * 0.12,
+                    y: aspect.height * 0.12,
+                },
+                ..Default::default()
+            };
+            draw_rectangle_builder.add_transform(&transform.pack());
+            draw_rectangle_builder.finish()
+        });
+    }
+}
+
+/// Half of the burst primitives are circles and half are lines, laid out on a sunflower spiral so
+/// they cover the screen evenly
+fn write_immediate_mode_burst(
+    draw_circle_writer: &EventWriter<DrawCircle>,
+    draw_line_writer: &EventWriter<DrawLine>,
+    draw_text_writer: &EventWriter<DrawText>,
+    aspect: &Aspect,
+    frame_constants: &FrameConstants,
+    time_passed: f32,
+    primitive_count: usize,
+) {
+    const GOLDEN_ANGLE: f32 = 2.399_963;
+
+    let center = screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into());
+    let max_radius = aspect.width.max(aspect.height) * 0.55;
+    let circle_count = primitive_count.div_ceil(2);
+    let line_count = primitive_count - circle_count;
+
+    for index in 0..primitive_count {
+        let normalized_index = index as f32 / primitive_count as f32;
+        let angle = index as f32 * GOLDEN_ANGLE + time_passed * 0.2;
+        let direction = Vec2::from_angle(angle);
+        let position = center + direction * max_radius * normalized_index.sqrt();
+        let color = ColorT {
+            r: 0.5 * (angle + time_passed).sin() + 0.5,
+            g: 0.5 * normalized_index + 0.5,
+            b: 1. - 0.5 * normalized_index,
+            a: 1.,
+        };
+
+        if index % 2 == 0 {
+            draw_circle_writer.write(
+                DrawCircleT {
+                    position: Vec2T {
+                        x: position.x,
+                        y: position.y,
+                    },
+                    z: 0.,
+                    radius: 3.,
+                    subdivisions: 8,
+                    rotation: 0.,
+                    color,
+                }
+                .pack(),
+            );
+        } else {
+            let half_line = direction.perp() * 4.;
+            draw_line_writer.write(
+                DrawLineT {
+                    from: Vec2T {
+                        x: position.x - half_line.x,
+                        y: position.y - half_line.y,
+                    },
+                    to: Vec2T {
+                        x: position.x + half_line.x,
+                        y: position.y + half_line.y,
+                    },
+                    z: 0.,
+                    thickness: 2.,
+                    color,
+                }
+                .pack(),
+            );
+        }
+    }
+
+    let counts_text = format!(
+        "Burst: {circle_count} circles  {line_count} lines  FPS: {:.0}",
+        frame_constants.frame_rate
+    );
+    let counts_position = screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.9.into());
+    draw_text_writer.write_builder(|builder| {
+        let flatbuffer_counts_string = builder.create_string(&counts_text);
+        let mut draw_text_builder = DrawTextBuilder::new(builder);
+        draw_text_builder.add_font_size(36.);
+        draw_text_builder.add_text(flatbuffer_counts_string);
+        draw_text_builder.add_color(&void_public::event::graphics::Color::new(1., 1., 1., 1.));
+        draw_text_builder.add_bounds(&Vec2T { x: 1000., y: 100. }.pack());
+        draw_text_builder.add_text_alignment(TextAlignment::Center);
+        let transform = TransformT {
+            position: Vec3T {
+                x: counts_position.x,
+                y: counts_position.y,
+                z: 1.,
+            },
+            scale: Vec2T { x: 1., y: 1. },
+            ..Default::default()
+        };
+        draw_text_builder.add_transform(&transform.pack());
+        draw_text_builder.add_z(1.);
+        draw_text_builder.finish()
+    });
+}
+
+/// Writes a [`DrawLine`] event for every segment between consecutive `points`
+fn write_polyline(
+    draw_line_writer: &EventWriter<DrawLine>,
+    points: &[Vec2],
+    thickness: f32,
+    color: ColorT,
+) {
+    for segment in points.windows(2) {
+        draw_line_writer.write(
+            DrawLineT {
+                from: Vec2T {
+                    x: segment[0].x,
+                    y: segment[0].y,
+                },
+                to: Vec2T {
+                    x: segment[1].x,
+                    y: segment[1].y,
+                },
+                z: 0.,
+                thickness,
+                color: color.clone(),
+            }
+            .pack(),
+        );
+    }
+}
+
+/// The asset path the procedural texture is registered under, no file exists at this path
+const PROCEDURAL_TEXTURE_PATH: &str = "procedural/procedural_texture";
+
+/// Test local [`Component`] holding the procedural texture and the pattern currently uploaded to
+/// it, see [`procedural_texture`]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct ProceduralTexture {
+    pub texture_id: TextureId,
+    pub pattern: ProceduralPattern,
+    pub step: u32,
+    pub since_upload: f32,
+}
+
+/// Marker [`Component`] for the text naming the procedural texture's pattern
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct ProceduralTextureText;
+
+pub struct ProceduralTextureTest;
+
+material_test_systems!(
+    ProceduralTextureTest,
+    procedural_texture_startup_system,
+    [procedural_texture_system]
+);
+
+#[system_once]
+fn procedural_texture_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &mut GpuInterface,
+    new_texture_event_writer: EventWriter<NewTexture>,
+    view: &mut View,
+) {
+    if let Err(error) = procedural_texture_startup(aspect, gpu_interface, &new_texture_event_writer)
+    {
+        show_error_view(view, &error);
+    }
+}
+
+fn procedural_texture_startup(
+    aspect: &Aspect,
+    gpu_interface: &mut GpuInterface,
+    new_texture_event_writer: &EventWriter<NewTexture>,
+) -> local_error::Result<()> {
+    let pattern = ProceduralPattern::default();
+    let pixels = generate_pixels(pattern, PROCEDURAL_TEXTURE_SIZE, 0);
+    let texture_asset_manager = &mut gpu_interface.texture_asset_manager;
+    // The texture outlives the test, so coming back to it uploads to the existing one
+    let texture_id =
+        match texture_asset_manager.get_texture_by_path(&PROCEDURAL_TEXTURE_PATH.into()) {
+            Some(texture) => texture.id(),
+            None => texture_asset_manager
+                .load_texture_by_pixels(
+                    &PROCEDURAL_TEXTURE_PATH.into(),
+                    PROCEDURAL_TEXTURE_SIZE,
+                    PROCEDURAL_TEXTURE_SIZE,
+                    &pixels,
+                    false,
+                    new_texture_event_writer,
+                )
+                .map_err(|error| TestModuleError::AssetNotLoaded {
+                    asset_path: PROCEDURAL_TEXTURE_PATH.to_string(),
+                    reason: format!("{error:?}"),
+                })?
+                .id(),
+        };
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        texture_id,
+        Vec2::splat(aspect.height * 0.6),
+        RelativeScale::OfHeight,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        ProceduralTexture {
+            texture_id,
+            pattern,
+            step: 0,
+            since_upload: 0.,
+        }
+    ));
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, CustomText>(
+        CreateTextInput {
+            text: pattern.name(),
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.9.into())
+                .extend(4000.),
+            text_type: TextTypes::Custom(24.),
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        ProceduralTextureText
+    ));
+    Engine::spawn(&text_component_builder.build());
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("Space", "next pattern")])
+    ));
+    set_system_enabled!(true, procedural_texture_system);
+
+    Ok(())
+}
+
+/// Regenerates the pixels every [`PROCEDURAL_TEXTURE_UPLOAD_SECONDS`] and uploads them to the
+/// texture, Space switches to the next pattern
+#[system]
+fn procedural_texture_system(
+    gpu_interface: &mut GpuInterface,
+    input_state: &InputState,
+    test_clock: &TestClock,
+    new_texture_event_writer: EventWriter<NewTexture>,
+    mut procedural_texture_query: Query<&mut ProceduralTexture>,
+    mut pattern_text_query: Query<(&mut TextRender, &ProceduralTextureText)>,
+) {
+    let next_pattern = input_state.keys[KeyCode::Space].just_pressed();
+
+    procedural_texture_query.for_each(|procedural_texture| {
+        procedural_texture.since_upload += test_clock.delta_time();
+        if next_pattern {
+            procedural_texture.pattern = procedural_texture.pattern.next();
+            procedural_texture.step = 0;
+            pattern_text_query.for_each(|(text_render, _)| {
+                text_render.text = text_to_u8_array(procedural_texture.pattern.name());
+            });
+        } else if procedural_texture.since_upload < PROCEDURAL_TEXTURE_UPLOAD_SECONDS {
+            return;
+        } else {
+            procedural_texture.step = procedural_texture.step.wrapping_add(1);
+        }
+        procedural_texture.since_upload = 0.;
+
+        let pixels = generate_pixels(
+            procedural_texture.pattern,
+            PROCEDURAL_TEXTURE_SIZE,
+            procedural_texture.step,
+        );
+        if let Err(error) = gpu_interface
+            .texture_asset_manager
+            .update_texture_by_pixels(
+                procedural_texture.texture_id,
+                PROCEDURAL_TEXTURE_SIZE,
+                PROCEDURAL_TEXTURE_SIZE,
+                &pixels,
+                &new_texture_event_writer,
+            )
+        {
+            error!("Could not upload the procedural texture: {error:?}");
+        }
+    });
+}
+
+/// Sprites are added to and removed from the stress test in batches of this size
+const STRESS_TEST_BATCH_SIZE: usize = 100;
+
+/// A [`Resource`] providing deterministic random numbers for material tests, so layouts are
+/// reproducible for golden image comparison. The seed can be set with `--seed=<u64>`.
+#[derive(Debug, Resource)]
+pub struct TestRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl Default for TestRng {
+    fn default() -> Self {
+        Self::from_seed(DEFAULT_TEST_RNG_SEED)
+    }
+}
+
+impl TestRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Restarts the random sequence from the seed
+    pub fn reset(&mut self) {
+        self.rng = StdRng::seed_from_u64(self.seed);
+    }
+
+    pub fn gen_range<T: SampleUniform, R: SampleRange<T>>(&mut self, range: R) -> T {
+        self.rng.gen_range(range)
+    }
+
+    pub fn gen_bool(&mut self, probability: f64) -> bool {
+        self.rng.gen_bool(probability)
+    }
+}
+
+/// A [`Resource`] holding the stress test's scripted ramp, which is only set when the module is
+/// started with `--stress-ramp` or `--stress-ramp=<seconds per step>`
+#[derive(Debug, Default, Resource)]
+pub struct StressRampMode {
+    pub stress_ramp: Option<StressRamp>,
+}
+
+/// A [`Resource`] for `--bench-materials`, which runs the stress test with one sprite material at
+/// a time, see [`material_bench`]. The bench starts when the stress test does.
+#[derive(Debug, Default, Resource)]
+pub struct MaterialBenchMode {
+    pub requested: bool,
+    pub material_bench: Option<MaterialBench<MaterialId>>,
+}
+
+/// Every material of the sprite tests, named after its test, in the order the bench runs them
+fn material_bench_materials(
+    material_test_query: &Query<&MaterialTest>,
+) -> Vec<(String, MaterialId)> {
+    let mut materials = Vec::<(String, MaterialId)>::new();
+    for material_test in material_test_query.iter() {
+        if *material_test.material_type() != MaterialType::Sprite {
+            continue;
+        }
+        let material_ids = material_test
+            .material_id_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        for (index, material_id) in material_ids.iter().enumerate() {
+            if materials
+                .iter()
+                .any(|(_, bench_material_id)| bench_material_id == material_id)
+            {
+                continue;
+            }
+            let name = if material_ids.len() > 1 {
+                format!("{}_{index}", material_test.name())
+            } else {
+                material_test.name().to_string()
+            };
+            materials.push((name, *material_id));
+        }
+    }
+    materials
+}
+
+/// Marker [`Component`] for the stress test's sprite count text
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct StressTestCountText;
+
+/// Finds the stress test's materials, in the order sprites rotate through them
+fn stress_test_material_ids(
+    material_ids: impl IntoIterator<Item = Option<MaterialId>>,
+) -> Option<[MaterialId; 3]> {
+    let mut materials_id_iter = material_ids.into_iter();
+    let Some(Some(desat_material_id)) = materials_id_iter.next() else {
+        error!("Could not find desat_material_id on stress_test");
+        return None;
+    };
+    let Some(Some(pan_material_id)) = materials_id_iter.next() else {
+        error!("Could not find pan_material_id on stress_test");
+        return None;
+    };
+    let Some(Some(default_sprite_material_id)) = materials_id_iter.next() else {
+        error!("Could not find default_sprite_material_id on stress_test");
+        return None;
+    };
+    Some([
+        default_sprite_material_id,
+        pan_material_id,
+        desat_material_id,
+    ])
+}
+
+/// Spawns `count` stress test sprites. `first_index` continues the material rotation from
+/// previously spawned sprites.
+fn spawn_stress_test_sprites(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    rng: &mut TestRng,
+    sprite_material_ids: &[MaterialId],
+    first_index: usize,
+    count: usize,
+) {
+    let scared_id = gpu_interface
+        .texture_asset_manager
+        .get_texture_by_path(&"textures/scared.png".into())
+        .unwrap()
+        .id();
+
+    for i in first_index..first_index + count {
+        let material_id = sprite_material_ids[i % sprite_material_ids.len()];
+
+        // Benchmarked materials without a color_tex are drawn with their texture unset
+        let material_params = MaterialParameters::new(material_id)
+            .update_texture(&gpu_interface.material_manager, &("color_tex", &scared_id))
+            .map(|material_params| material_params.end_chain())
+            .unwrap_or_else(|_| MaterialParameters::new(material_id));
+
+        // This scales the velocity with the size of the window, using the
+        // width as a shorthand for that
+        let velocity_scalar = aspect.width * 0.15;
+        let velocity = Velocity {
+            direction: Vec3::new(
+                rng.gen_range(-velocity_scalar..velocity_scalar),
+                rng.gen_range(-velocity_scalar..velocity_scalar),
+                0.,
+            ),
+            rotation: rng.gen_range(-6.0..6.),
+        };
+
+        let mut texture_component_builder = create_new_relative_texture(
+            Vec3::new(
+                rng.gen_range(-1.0..1.) * aspect.width * 0.5,
+                rng.gen_range(-1.0..1.) * aspect.height * 0.5,
+                1.,
+            )
+            .into(),
+            Vec4::new(
+                rng.gen_range(0.5..3.0),
+                rng.gen_range(0.5..3.0),
+                rng.gen_range(0.5..3.0),
+                1.,
+            )
+            .into(),
+            scared_id,
+            Vec2::new(
+                rng.gen_range(0.25..1.0) * aspect.width * 0.125,
+                rng.gen_range(0.25..1.0) * aspect.width * 0.125,
+            ),
+            RelativeScale::OfWidth,
+        );
+        texture_component_builder.add_components(bundle_for_builder!(
+            MaterialTestObject,
+            material_params,
+            velocity,
+            ScreenBounce
+        ));
+        Engine::spawn(&texture_component_builder.build());
+    }
+}
+
+/// Stops a running stress ramp or material bench when leaving the test, so coming back starts a
+/// fresh test
+#[system_once]
+fn stress_test_teardown_system(
+    material_bench_mode: &mut MaterialBenchMode,
+    stress_ramp_mode: &mut StressRampMode,
+) {
+    material_bench_mode.requested = false;
+    if let Some(material_bench) = material_bench_mode.material_bench.take() {
+        if !material_bench.is_finished() && !material_bench.results().is_empty() {
+            match material_bench.write_report(MATERIAL_BENCH_REPORT_PATH) {
+                Ok(()) => info!(
+                    "Material bench stopped after {} materials, report written to \
+                     {MATERIAL_BENCH_REPORT_PATH}",
+                    material_bench.results().len()
+                ),
+                Err(error) => error!(
+                    "Could not write material bench report to {MATERIAL_BENCH_REPORT_PATH}: \
+                     {error}"
+                ),
+            }
+        }
+    }
+
+    let Some(stress_ramp) = stress_ramp_mode.stress_ramp.take() else {
+        return;
+    };
+    if stress_ramp.steps().is_empty() {
+        return;
+    }
+    match stress_ramp.write_report(STRESS_RAMP_REPORT_PATH) {
+        Ok(()) => info!(
+            "Stress ramp stopped after {} steps, report written to {STRESS_RAMP_REPORT_PATH}",
+            stress_ramp.steps().len()
+        ),
+        Err(error) => {
+            error!("Could not write stress ramp report to {STRESS_RAMP_REPORT_PATH}: {error}")
+        }
+    }
+}
+
+pub struct StressTest;
+
+material_test_systems!(
+    StressTest,
+    stress_test_startup_system,
+    [stress_test_system],
+    stress_test_teardown_system
+);
+
+#[system_once]
+fn stress_test_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    material_bench_mode: &mut MaterialBenchMode,
+    test_rng: &mut TestRng,
+    material_test_query: Query<&MaterialTest>,
+) {
+    let Some(stress_test_material_test) = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "stress_test")
+    else {
+        error!("Could not find stress_test material test");
+        return;
+    };
+    let Some(sprite_material_ids) =
+        stress_test_material_ids(stress_test_material_test.material_id_iter())
+    else {
+        return;
+    };
+
+    // Reseeding makes every run of the stress test start from the same layout
+    test_rng.reset();
+    if material_bench_mode.requested {
+        let material_bench = MaterialBench::new(material_bench_materials(&material_test_query));
+        if let Some((name, material_id)) = material_bench.current_material() {
+            info!("Benchmarking {name}");
+            spawn_stress_test_sprites(
+                aspect,
+                gpu_interface,
+                test_rng,
+                &[material_id],
+                0,
+                MATERIAL_BENCH_SPRITE_COUNT,
+            );
+        }
+        material_bench_mode.material_bench = Some(material_bench);
+    } else {
+        spawn_stress_test_sprites(
+            aspect,
+            gpu_interface,
+            test_rng,
+            &sprite_material_ids,
+            0,
+            STRESS_TEST_BATCH_SIZE,
+        );
+    }
+
+    let mut text_component_builder = create_new_text::<_, CustomText>(
+        CreateTextInput {
+            text: format!("Sprites: {STRESS_TEST_BATCH_SIZE}"),
+            position: screen_space_coordinate_by_percent(aspect, 0.85.into(), 0.975.into())
+                .extend(4000.),
+            text_type: TextTypes::Custom(24.),
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder
+        .add_components(bundle_for_builder!(MaterialTestObject, StressTestCountText));
+    Engine::spawn(&text_component_builder.build());
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("Up/Down", "add/remove 100 sprites")])
+    ));
+    set_system_enabled!(true, stress_test_system);
+}
+
+#[system]
+#[allow(clippy::too_many_arguments)]
+fn stress_test_system(
+    aspect: &Aspect,
+    frame_constants: &FrameConstants,
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    material_test_registry: &MaterialTestRegistry,
+    material_bench_mode: &mut MaterialBenchMode,
+    stress_ramp_mode: &mut StressRampMode,
+    test_rng: &mut TestRng,
+    mut count_text_query: Query<(&mut TextRender, &StressTestCountText)>,
+    sprites_query: Query<(&EntityId, &MaterialTestObject, &ScreenBounce)>,
+) {
+    let mut sprite_count = sprites_query.len();
+
+    if let Some(material_bench) = &mut material_bench_mode.material_bench {
+        if material_bench.record_frame(frame_constants.delta_time) {
+            sprites_query.iter().for_each(|query_components_ref| {
+                let (entity_id, _, _) = query_components_ref.unpack();
+                Engine::despawn(**entity_id);
+            });
+            match material_bench.current_material() {
+                Some((name, material_id)) => {
+                    info!("Benchmarking {name}");
+                    // Every material starts from the same layout
+                    test_rng.reset();
+                    spawn_stress_test_sprites(
+                        aspect,
+                        gpu_interface,
+                        test_rng,
+                        &[material_id],
+                        0,
+                        MATERIAL_BENCH_SPRITE_COUNT,
+                    );
+                }
+                None => match material_bench.write_report(MATERIAL_BENCH_REPORT_PATH) {
+                    Ok(()) => {
+                        info!(
+                            "Material bench finished, report written to {MATERIAL_BENCH_REPORT_PATH}"
+                        )
+                    }
+                    Err(error) => error!(
+                        "Could not write material bench report to {MATERIAL_BENCH_REPORT_PATH}: \
+                         {error}"
+                    ),
+                },
+            }
+        }
+        let count_text = match material_bench.current_material() {
+            Some((name, _)) => format!(
+                "Benchmarking {name} ({}/{})  FPS: {:.0}",
+                material_bench.results().len() + 1,
+                material_bench.material_count(),
+                frame_constants.frame_rate
+            ),
+            None => format!("Material bench written to {MATERIAL_BENCH_REPORT_PATH}"),
+        };
+        count_text_query.for_each(|(text_render, _)| {
+            text_render.text = text_to_u8_array(&count_text);
+        });
+        return;
+    }
+
+    let mut spawn_count = 0;
+    if let Some(stress_ramp) = &mut stress_ramp_mode.stress_ramp {
+        if let Some(step) = stress_ramp.record_frame(frame_constants.delta_time, sprite_count) {
+            if let Err(error) = stress_ramp.write_report(STRESS_RAMP_REPORT_PATH) {
+                error!("Could not write stress ramp report to {STRESS_RAMP_REPORT_PATH}: {error}");
+            }
+            if stress_ramp.is_finished() {
+                info!("Stress ramp finished, report written to {STRESS_RAMP_REPORT_PATH}");
+            } else {
+                spawn_count = step.sprite_count;
+            }
+        }
+    } else if is_up_just_pressed(input_state) {
+        spawn_count = STRESS_TEST_BATCH_SIZE;
+    }
+
+    if spawn_count > 0 {
+        let Some(sprite_material_ids) =
+            material_test_registry
+                .get_by_name("stress_test")
+                .and_then(|material_test_entry| {
+                    stress_test_material_ids(material_test_entry.material_id_iter())
+                })
+        else {
+            error!("Could not find stress_test materials to add sprites with");
+            return;
+        };
+        spawn_stress_test_sprites(
+            aspect,
+            gpu_interface,
+            test_rng,
+            &sprite_material_ids,
+            sprite_count,
+            spawn_count,
+        );
+        sprite_count += spawn_count;
+    } else if stress_ramp_mode.stress_ramp.is_none() && is_down_just_pressed(input_state) {
+        let despawn_count = STRESS_TEST_BATCH_SIZE.min(sprite_count);
+        sprites_query
+            .iter()
+            .take(despawn_count)
+            .for_each(|query_components_ref| {
+                let (entity_id, _, _) = query_components_ref.unpack();
+                Engine::despawn(**entity_id);
+            });
+        sprite_count -= despawn_count;
+    }
+
+    let count_text = format!(
+        "Sprites: {sprite_count}  FPS: {:.0}",
+        frame_constants.frame_rate
+    );
+    count_text_query.for_each(|(text_render, _)| {
+        text_render.text = text_to_u8_array(&count_text);
+    });
+}
+
+/// Marker [`Component`] for entities that [`screen_bounce_system`] moves by their [`Velocity`]
+/// and bounces off the edges of the screen
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct ScreenBounce;
+
+#[system]
+fn screen_bounce_system(
+    aspect: &Aspect,
+    test_clock: &TestClock,
+    mut bouncing_query: Query<(&mut Transform, &mut Velocity, &ScreenBounce)>,
+) {
+    let half_extents = Vec2::new(aspect.width * 0.5, aspect.height * 0.5);
+    bouncing_query.for_each(|(transform, velocity, _)| {
+        transform
+            .position
+            .set(transform.position.get() + velocity.direction * test_clock.delta_time());
+        velocity.direction =
+            reflect_at_screen_edges(transform.position.get(), velocity.direction, half_extents);
+        transform.rotation += velocity.rotation * test_clock.delta_time();
+    });
+}
+
+fn invert_y_scared_distance(aspect: &Aspect) -> Vec2 {
+    Vec2::new(aspect.width * 0.3, 0.)
+}
+
+pub struct InvertYTest;
+
+material_test_systems!(InvertYTest, invert_y_startup_system, [invert_y_system]);
+
+#[system_once]
+fn invert_y_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = invert_y_startup(
+        aspect,
+        gpu_interface,
+        world_render_manager,
+        &material_test_query,
+    ) {
+        show_error_view(view, &error);
+    }
+}
+
+fn invert_y_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let scared_distance = invert_y_scared_distance(aspect);
+    let material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "invert_y")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "invert_y".to_string(),
+        })?;
+    let (material_id, material) = require_material(gpu_interface, &material_test)?;
+    let material_uniforms = MaterialUniforms::empty(material_id);
+
+    world_render_manager.add_or_update_postprocess(material, &material_uniforms);
+
+    let arrow_up_id = require_texture(gpu_interface, "textures/arrow_up.png")?;
+    let scared_id = require_texture(gpu_interface, "textures/scared.png")?;
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        arrow_up_id,
+        Vec2::splat(aspect.width * 0.08),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut texture_component_builder = create_new_relative_texture(
+        scared_distance.extend(0.).into(),
+        *palette::WHITE,
+        scared_id,
+        Vec2::splat(aspect.width * 0.11),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        TimePassedSinceCreation::default()
+    ));
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, HeaderText>(
+        CreateTextInput {
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.7.into()).extend(0.),
+            text: "This is up",
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&text_component_builder.build());
+    set_system_enabled!(true, invert_y_system);
+
+    Ok(())
+}
+
+#[system]
+fn invert_y_system(
+    aspect: &Aspect,
+    test_clock: &TestClock,
+    mut texture_query: Query<(&mut Transform, &TextureRender, &mut TimePassedSinceCreation)>,
+) {
+    let scared_distance = invert_y_scared_distance(aspect);
+    texture_query.for_each(|(transform, _, time_passed_since_creation)| {
+        *time_passed_since_creation += test_clock.delta_time();
+        let rotation_matrix = Mat2::from_angle(***time_passed_since_creation);
+        transform.position = (rotation_matrix * scared_distance).extend(0.).into();
+        transform.rotation += (***time_passed_since_creation).cos() / 8.;
+    });
+}
+
+fn test_post_scared_distance(aspect: &Aspect) -> Vec2 {
+    Vec2::new(aspect.width * 0.3, 0.)
+}
+
+pub struct TestPostTest;
+
+material_test_systems!(TestPostTest, test_post_startup_system, [test_post_system]);
+
+#[system_once]
+fn test_post_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = test_post_startup(
+        aspect,
+        gpu_interface,
+        world_render_manager,
+        &material_test_query,
+    ) {
+        show_error_view(view, &error);
+    }
+}
+
+fn test_post_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let scared_distance = test_post_scared_distance(aspect);
+    let material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "test_post")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "test_post".to_string(),
+        })?;
+    let (material_id, material) = require_material(gpu_interface, &material_test)?;
+
+    let material_uniforms = MaterialUniforms::empty(material_id);
+
+    world_render_manager.add_or_update_postprocess(material, &material_uniforms);
+
+    let arrow_up_id = require_texture(gpu_interface, "textures/arrow_up.png")?;
+    let scared_id = require_texture(gpu_interface, "textures/scared.png")?;
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        arrow_up_id,
+        Vec2::splat(aspect.width * 0.08),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut texture_component_builder = create_new_relative_texture(
+        scared_distance.extend(0.).into(),
+        *palette::WHITE,
+        scared_id,
+        Vec2::splat(aspect.width * 0.11),
+        RelativeScale::OfWidth,
+    );
+
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        TimePassedSinceCreation::default()
+    ));
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, HeaderText>(
+        CreateTextInput {
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.7.into()).extend(0.),
+            text: "This is up",
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&text_component_builder.build());
+    set_system_enabled!(true, test_post_system);
+
+    Ok(())
+}
+
+#[system]
+fn test_post_system(
+    aspect: &Aspect,
+    test_clock: &TestClock,
+    mut texture_query: Query<(&mut Transform, &TextureRender, &mut TimePassedSinceCreation)>,
+) {
+    let scared_distance = test_post_scared_distance(aspect);
+    texture_query.for_each(|(transform, _, time_passed_since_creation)| {
+        *time_passed_since_creation += test_clock.delta_time();
+        let rotation_matrix = Mat2::from_angle(***time_passed_since_creation);
+        transform.position = (rotation_matrix * scared_distance).extend(0.).into();
+        transform.rotation += (***time_passed_since_creation).cos() / 8.;
+    });
+}
+
+fn warp_scared_distance(aspect: &Aspect) -> Vec2 {
+    Vec2::new(aspect.width * 0.3, 0.)
+}
+
+pub struct WarpTest;
+
+material_test_systems!(WarpTest, warp_startup_system, [warp_system]);
+
+#[system_once]
+fn warp_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = warp_startup(
+        aspect,
+        gpu_interface,
+        world_render_manager,
+        &material_test_query,
+    ) {
+        show_error_view(view, &error);
+    }
+}
+
+fn warp_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let scared_distance = warp_scared_distance(aspect);
+    let material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "warp")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "warp".to_string(),
+        })?;
+    let (material_id, material) = require_material(gpu_interface, &material_test)?;
+    let material_uniforms = material.generate_default_material_uniforms().unwrap();
+
+    world_render_manager.add_or_update_postprocess(material, material_uniforms);
+
+    let arrow_up_id = require_texture(gpu_interface, "textures/arrow_up.png")?;
+    let scared_id = require_texture(gpu_interface, "textures/scared.png")?;
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        arrow_up_id,
+        Vec2::splat(aspect.width * 0.08),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut texture_component_builder = create_new_relative_texture(
+        scared_distance.extend(0.).into(),
+        *palette::WHITE,
+        scared_id,
+        Vec2::splat(aspect.width * 0.11),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_components(bundle_for_builder!(
+        MaterialTestObject,
+        TimePassedSinceCreation::default()
+    ));
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, HeaderText>(
+        CreateTextInput {
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.7.into()).extend(0.),
+            text: "This is up",
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&text_component_builder.build());
+
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &UniformTimeline::new(
+            &[
+                UniformKeyframe::new(0., "param_0", 0.5, Easing::Linear),
+                UniformKeyframe::new(8., "param_0", 0.75, Easing::EaseInOut),
+                UniformKeyframe::new(16., "param_0", 0.5, Easing::EaseInOut),
             ],
+            UniformTimelineTarget::Postprocess(material_id),
+        )
+        .looping()
+    ));
+    set_system_enabled!(true, warp_system);
+
+    Ok(())
+}
+
+#[system]
+fn warp_system(
+    aspect: &Aspect,
+    test_clock: &TestClock,
+    mut texture_query: Query<(&mut Transform, &TextureRender, &mut TimePassedSinceCreation)>,
+) {
+    let scared_distance = warp_scared_distance(aspect);
+
+    texture_query.for_each(|(transform, _, time_passed_since_creation)| {
+        *time_passed_since_creation += test_clock.delta_time();
+        let rotation_matrix = Mat2::from_angle(***time_passed_since_creation);
+        transform.position = (rotation_matrix * scared_distance).extend(0.).into();
+        transform.rotation += (***time_passed_since_creation).cos() / 8.;
+    });
+}
+
+/// Test local [`Component`] holding the layer order and toggles of the post stack test, see
+/// [`post_stack`]
+#[derive(
+    Debug, Default, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize,
+)]
+pub struct PostStack {
+    pub layers: PostStackLayers,
+}
+
+/// Marker [`Component`] for the text listing the post stack test's layers
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct PostStackText;
+
+/// Finds the post stack test's materials, in the order of `POST_STACK_LAYER_NAMES`
+fn post_stack_material_ids(
+    material_ids: impl IntoIterator<Item = Option<MaterialId>>,
+) -> local_error::Result<[MaterialId; POST_STACK_LAYER_COUNT]> {
+    let material_ids = material_ids.into_iter().collect::<Option<Vec<_>>>();
+    material_ids
+        .and_then(|material_ids| material_ids.try_into().ok())
+        .ok_or_else(|| TestModuleError::MaterialMissing {
+            test_name: "post_stack".to_string(),
+        })
+}
+
+/// Removes the post stack's postprocesses and adds the enabled layers back in order, as
+/// `WorldRenderManager` keeps postprocesses in the order they were added
+fn apply_post_stack(
+    post_stack_layers: &PostStackLayers,
+    material_ids: &[MaterialId; POST_STACK_LAYER_COUNT],
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+) -> local_error::Result<()> {
+    world_render_manager.remove_postprocesses(material_ids);
+    for layer in post_stack_layers.active_layers() {
+        let material_id = material_ids[layer];
+        let material = gpu_interface
+            .material_manager
+            .get_material(material_id)
+            .ok_or_else(|| TestModuleError::AssetNotLoaded {
+                asset_path: format!("material {material_id:?} of post_stack"),
+                reason: "the material was not found in the material manager".to_string(),
+            })?;
+        // invert_y has no uniforms to generate defaults from
+        match material.generate_default_material_uniforms() {
+            Some(material_uniforms) => {
+                world_render_manager.add_or_update_postprocess(material, material_uniforms);
+            }
+            None => world_render_manager
+                .add_or_update_postprocess(material, &MaterialUniforms::empty(material_id)),
+        }
+    }
+
+    Ok(())
+}
+
+pub struct PostStackTest;
+
+material_test_systems!(
+    PostStackTest,
+    post_stack_startup_system,
+    [post_stack_system]
+);
+
+#[system_once]
+fn post_stack_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = post_stack_startup(
+        aspect,
+        gpu_interface,
+        world_render_manager,
+        &material_test_query,
+    ) {
+        show_error_view(view, &error);
+    }
+}
+
+fn post_stack_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "post_stack")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "post_stack".to_string(),
+        })?;
+    let material_ids = post_stack_material_ids(material_test.material_id_iter())?;
+    let post_stack = PostStack::default();
+    apply_post_stack(
+        &post_stack.layers,
+        &material_ids,
+        gpu_interface,
+        world_render_manager,
+    )?;
+
+    let arrow_up_id = require_texture(gpu_interface, "textures/arrow_up.png")?;
+    let scared_id = require_texture(gpu_interface, "textures/scared.png")?;
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        arrow_up_id,
+        Vec2::splat(aspect.width * 0.08),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.2.into(), 0.5.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        scared_id,
+        Vec2::splat(aspect.width * 0.11),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, HeaderText>(
+        CreateTextInput {
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.7.into()).extend(0.),
+            text: "This is up",
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&text_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, CustomText>(
+        CreateTextInput {
+            text: post_stack.layers.status_text(),
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.9.into())
+                .extend(4000.),
+            text_type: TextTypes::Custom(24.),
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_components(bundle_for_builder!(MaterialTestObject, PostStackText));
+    Engine::spawn(&text_component_builder.build());
+
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &post_stack,
+        &TimePassedSinceCreation::default()
+    ));
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("1-3", "toggle layer"), ("O", "move first layer last")])
+    ));
+    set_system_enabled!(true, post_stack_system);
+
+    Ok(())
+}
+
+#[system]
+fn post_stack_system(
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    material_test_registry: &MaterialTestRegistry,
+    test_clock: &TestClock,
+    world_render_manager: &mut WorldRenderManager,
+    mut post_stack_query: Query<(&mut PostStack, &mut TimePassedSinceCreation)>,
+    mut post_stack_text_query: Query<(&mut TextRender, &PostStackText)>,
+) {
+    let material_ids = match material_test_registry
+        .get_by_name("post_stack")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "post_stack".to_string(),
+        })
+        .and_then(|material_test_entry| {
+            post_stack_material_ids(material_test_entry.material_id_iter())
+        }) {
+        Ok(material_ids) => material_ids,
+        Err(error) => {
+            error!("{error}");
+            return;
+        }
+    };
+    let toggled_layer = post_stack_layer_just_pressed(input_state);
+    let reorder = is_post_stack_reorder_just_pressed(input_state);
+
+    post_stack_query.for_each(|(post_stack, time_passed_since_creation)| {
+        *time_passed_since_creation += test_clock.delta_time();
+
+        if let Some(layer) = toggled_layer {
+            post_stack.layers.toggle(layer);
+        }
+        if reorder {
+            post_stack.layers.rotate();
+        }
+        if toggled_layer.is_some() || reorder {
+            if let Err(error) = apply_post_stack(
+                &post_stack.layers,
+                &material_ids,
+                gpu_interface,
+                world_render_manager,
+            ) {
+                error!("Could not reorder the post stack: {error}");
+            }
+            let status_text = post_stack.layers.status_text();
+            post_stack_text_query.for_each(|(text_render, _)| {
+                text_render.text = text_to_u8_array(&status_text);
+            });
+        }
+
+        if let Some(postprocess) = world_render_manager
+            .get_postprocess_by_material_id_mut(material_ids[POST_STACK_GRAIN_LAYER])
+        {
+            postprocess
+                .material_uniforms
+                .update("time", (***time_passed_since_creation).into())
+                .unwrap();
+        }
+    });
+}
+
+/// The HUD bar of the hud exclusion test covers the screen above this height
+const HUD_EXCLUSION_BAR_BOTTOM_PERCENT: f32 = 0.85;
+
+/// The HUD bar as the `hud_rect` uniform of the hud exclusion material, `(min x, min y, max x,
+/// max y)` in screen uvs, which start at the top left
+fn hud_exclusion_rect(excluded: bool) -> Vec4 {
+    if excluded {
+        Vec4::new(0., 0., 1., 1. - HUD_EXCLUSION_BAR_BOTTOM_PERCENT)
+    } else {
+        Vec4::ZERO
+    }
+}
+
+/// Test local [`Component`] tracking whether the hud exclusion test currently keeps its HUD out
+/// of the postprocess
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct HudExclusion {
+    pub excluded: bool,
+}
+
+/// Marker [`Component`] for the hud exclusion test's HUD text
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct HudExclusionText;
+
+/// A reference for keeping a HUD unaffected by a fullscreen postprocess. Every postprocess
+/// samples the whole scene color texture and the engine has no per entity or per layer way to
+/// skip one, so the postprocess leaves the HUD's screen rectangle alone instead, passed in as
+/// the `hud_rect` uniform.
+pub struct HudExclusionTest;
+
+material_test_systems!(
+    HudExclusionTest,
+    hud_exclusion_startup_system,
+    [hud_exclusion_system]
+);
+
+#[system_once]
+fn hud_exclusion_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = hud_exclusion_startup(
+        aspect,
+        gpu_interface,
+        world_render_manager,
+        &material_test_query,
+    ) {
+        show_error_view(view, &error);
+    }
+}
+
+fn hud_exclusion_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "hud_exclusion")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "hud_exclusion".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &material_test)?;
+    let material_uniforms = material.generate_default_material_uniforms().unwrap();
+
+    world_render_manager.add_or_update_postprocess(material, material_uniforms);
+
+    let scared_id = require_texture(gpu_interface, "textures/scared.png")?;
+
+    let mut texture_component_builder = create_new_relative_texture(
+        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.45.into())
+            .extend(0.)
+            .into(),
+        *palette::WHITE,
+        scared_id,
+        Vec2::splat(aspect.width * 0.2),
+        RelativeScale::OfWidth,
+    );
+    texture_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&texture_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, HeaderText>(
+        CreateTextInput {
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.2.into()).extend(0.),
+            text: "The scene is warped",
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&text_component_builder.build());
+
+    let mut text_component_builder = create_new_text::<_, HeaderText>(
+        CreateTextInput {
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.925.into())
+                .extend(4000.),
+            text: "HUD: 0.0 s",
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder
+        .add_components(bundle_for_builder!(MaterialTestObject, HudExclusionText));
+    Engine::spawn(&text_component_builder.build());
+
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &HudExclusion { excluded: true },
+        &TimePassedSinceCreation::default()
+    ));
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("Space", "toggle HUD exclusion")])
+    ));
+    set_system_enabled!(true, hud_exclusion_system);
+
+    Ok(())
+}
+
+#[system]
+fn hud_exclusion_system(
+    input_state: &InputState,
+    material_test_registry: &MaterialTestRegistry,
+    test_clock: &TestClock,
+    world_render_manager: &mut WorldRenderManager,
+    mut hud_exclusion_query: Query<(&mut HudExclusion, &mut TimePassedSinceCreation)>,
+    mut hud_text_query: Query<(&mut TextRender, &HudExclusionText)>,
+) {
+    let Some(material_id) = material_test_registry
+        .get_by_name("hud_exclusion")
+        .and_then(|material_test_entry| material_test_entry.first_material_id())
+    else {
+        error!("Could not find material id on hud_exclusion");
+        return;
+    };
+    let toggle_exclusion = input_state.keys[KeyCode::Space].just_pressed();
+
+    hud_exclusion_query.for_each(|(hud_exclusion, time_passed_since_creation)| {
+        *time_passed_since_creation += test_clock.delta_time();
+        if toggle_exclusion {
+            hud_exclusion.excluded = !hud_exclusion.excluded;
+        }
+
+        let Some(postprocess) =
+            world_render_manager.get_postprocess_by_material_id_mut(material_id)
+        else {
+            return;
+        };
+        postprocess
+            .material_uniforms
+            .update("time", (***time_passed_since_creation).into())
+            .unwrap();
+        postprocess
+            .material_uniforms
+            .update(
+                "hud_rect",
+                hud_exclusion_rect(hud_exclusion.excluded).into(),
+            )
+            .unwrap();
+
+        let hud_text = format!(
+            "HUD: {:.1} s{}",
+            ***time_passed_since_creation,
+            if hud_exclusion.excluded {
+                ""
+            } else {
+                " (warped)"
+            }
+        );
+        hud_text_query.for_each(|(text_render, _)| {
+            text_render.text = text_to_u8_array(&hud_text);
+        });
+    });
+}
+
+/// How much Up/Down move the mirror floor's line, in screen uvs
+const MIRROR_FLOOR_STEP: f32 = 0.05;
+const MIRROR_FLOOR_MIN_Y: f32 = 0.2;
+const MIRROR_FLOOR_MAX_Y: f32 = 0.8;
+/// The `perturbation` uniform while ripples are on, its default in mirror_floor.toml
+const MIRROR_FLOOR_PERTURBATION: f32 = 0.02;
+/// How far, in world units, the sprites above the floor bob up and down
+const MIRROR_FLOOR_BOB_HEIGHT: f32 = 20.;
+
+/// Test local [`Component`] holding the mirror floor's line, in screen uvs starting at the top,
+/// and whether its reflection ripples
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct MirrorFloor {
+    pub floor_y: f32,
+    pub ripples: bool,
+}
+
+impl Default for MirrorFloor {
+    fn default() -> Self {
+        Self {
+            floor_y: 0.5,
+            ripples: true,
+        }
+    }
+}
+
+impl MirrorFloor {
+    fn status_text(&self) -> String {
+        format!(
+            "Floor at {:.2}, ripples {}",
+            self.floor_y,
+            if self.ripples { "on" } else { "off" }
         )
-        .unwrap()
-        .end_chain();
+    }
+}
 
-    let scared_id = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/scared.png".into())
-        .unwrap()
-        .id();
+/// Test local [`Component`] for the sprites bobbing above the mirror floor
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct MirrorFloorSprite {
+    pub base_y: f32,
+    pub phase: f32,
+}
+
+/// Marker [`Component`] for the text describing the mirror floor
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct MirrorFloorText;
+
+pub struct MirrorFloorTest;
+
+material_test_systems!(
+    MirrorFloorTest,
+    mirror_floor_startup_system,
+    [mirror_floor_system]
+);
+
+#[system_once]
+fn mirror_floor_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = mirror_floor_startup(
+        aspect,
+        gpu_interface,
+        world_render_manager,
+        &material_test_query,
+    ) {
+        show_error_view(view, &error);
+    }
+}
+
+fn mirror_floor_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "mirror_floor")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "mirror_floor".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &material_test)?;
+    let material_uniforms = material.generate_default_material_uniforms().unwrap();
+
+    world_render_manager.add_or_update_postprocess(material, material_uniforms);
+
+    let sprite_size = aspect.height * 0.15;
+    for (index, texture_path) in [
+        "textures/scared.png",
+        "textures/pixel_heart.png",
+        "textures/arrow_up.png",
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let texture_id = require_texture(gpu_interface, texture_path)?;
+        // Resting just above the floor line, at the middle of the screen
+        let position = screen_space_coordinate_by_percent(
+            aspect,
+            (0.25 + 0.25 * index as f32).into(),
+            0.5.into(),
+        ) + Vec2::new(0., sprite_size * 0.5 + MIRROR_FLOOR_BOB_HEIGHT * 2.);
+        let mut texture_component_builder = create_new_relative_texture(
+            position.extend(0.).into(),
+            *palette::WHITE,
+            texture_id,
+            Vec2::splat(sprite_size),
+            RelativeScale::OfHeight,
+        );
+        texture_component_builder.add_components(bundle_for_builder!(
+            MaterialTestObject,
+            MirrorFloorSprite {
+                base_y: position.y,
+                phase: index as f32 * 2.,
+            }
+        ));
+        Engine::spawn(&texture_component_builder.build());
+    }
+
+    let mirror_floor = MirrorFloor::default();
+    let mut text_component_builder = create_new_text::<_, CustomText>(
+        CreateTextInput {
+            text: mirror_floor.status_text(),
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.9.into())
+                .extend(4000.),
+            text_type: TextTypes::Custom(24.),
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_components(bundle_for_builder!(MaterialTestObject, MirrorFloorText));
+    Engine::spawn(&text_component_builder.build());
+
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &mirror_floor,
+        &TimePassedSinceCreation::default()
+    ));
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[("Up/Down", "move floor line"), ("Space", "toggle ripples"),])
+    ));
+    set_system_enabled!(true, mirror_floor_system);
+
+    Ok(())
+}
+
+/// The floor line and ripples are only written when changed, so randomized or preset uniforms
+/// stay in place until then
+#[system]
+fn mirror_floor_system(
+    input_state: &InputState,
+    material_test_registry: &MaterialTestRegistry,
+    test_clock: &TestClock,
+    world_render_manager: &mut WorldRenderManager,
+    mut mirror_floor_query: Query<(&mut MirrorFloor, &mut TimePassedSinceCreation)>,
+    mut mirror_floor_sprite_query: Query<(&mut Transform, &MirrorFloorSprite)>,
+    mut mirror_floor_text_query: Query<(&mut TextRender, &MirrorFloorText)>,
+) {
+    let Some(material_id) = material_test_registry
+        .get_by_name("mirror_floor")
+        .and_then(|material_test_entry| material_test_entry.first_material_id())
+    else {
+        error!("Could not find material id on mirror_floor");
+        return;
+    };
+    let floor_step = match (
+        is_up_just_pressed(input_state),
+        is_down_just_pressed(input_state),
+    ) {
+        (true, false) => -MIRROR_FLOOR_STEP,
+        (false, true) => MIRROR_FLOOR_STEP,
+        _ => 0.,
+    };
+    let toggle_ripples = input_state.keys[KeyCode::Space].just_pressed();
+
+    mirror_floor_query.for_each(|(mirror_floor, time_passed_since_creation)| {
+        *time_passed_since_creation += test_clock.delta_time();
+        let time = ***time_passed_since_creation;
+        mirror_floor_sprite_query.for_each(|(transform, mirror_floor_sprite)| {
+            let position = transform.position.get();
+            let bob = (time * 2. + mirror_floor_sprite.phase).sin() * MIRROR_FLOOR_BOB_HEIGHT;
+            transform.position.set(Vec3::new(
+                position.x,
+                mirror_floor_sprite.base_y + bob,
+                position.z,
+            ));
+        });
+
+        let Some(postprocess) =
+            world_render_manager.get_postprocess_by_material_id_mut(material_id)
+        else {
+            return;
+        };
+        postprocess
+            .material_uniforms
+            .update("time", time.into())
+            .unwrap();
+        if floor_step == 0. && !toggle_ripples {
+            return;
+        }
+
+        mirror_floor.floor_y =
+            (mirror_floor.floor_y + floor_step).clamp(MIRROR_FLOOR_MIN_Y, MIRROR_FLOOR_MAX_Y);
+        if toggle_ripples {
+            mirror_floor.ripples = !mirror_floor.ripples;
+        }
+        let perturbation = if mirror_floor.ripples {
+            MIRROR_FLOOR_PERTURBATION
+        } else {
+            0.
+        };
+        postprocess
+            .material_uniforms
+            .update("floor_y", mirror_floor.floor_y.into())
+            .unwrap();
+        postprocess
+            .material_uniforms
+            .update("perturbation", perturbation.into())
+            .unwrap();
+
+        let status_text = mirror_floor.status_text();
+        mirror_floor_text_query.for_each(|(text_render, _)| {
+            text_render.text = text_to_u8_array(&status_text);
+        });
+    });
+}
+
+/// The length in seconds of the iris wipe test's close and reopen
+const IRIS_WIPE_CYCLE_SECONDS: f32 = 4.;
+/// How far Left/Right scrub the iris wipe while it's paused, as a fraction of the cycle
+const IRIS_WIPE_SCRUB_STEP: f32 = 0.025;
+
+/// Test local [`Component`] driving the iris wipe test's radius
+#[derive(
+    Debug, Default, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize,
+)]
+pub struct IrisWipe {
+    /// How far through the close and reopen the iris is, from 0 to 1
+    pub phase: f32,
+    pub paused: bool,
+}
+
+impl IrisWipe {
+    /// Open at the start and end of the cycle, closed halfway through
+    fn radius(&self) -> f32 {
+        (2. * self.phase - 1.).abs()
+    }
+
+    fn status_text(&self) -> String {
+        format!(
+            "Radius {:.2}{}",
+            self.radius(),
+            if self.paused { " (paused)" } else { "" }
+        )
+    }
+}
+
+/// Marker [`Component`] for the text showing the iris wipe's radius
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct IrisWipeText;
+
+/// The shader wipe behind `--transition=iris`, see [`TransitionStyle::Iris`]
+pub struct IrisWipeTest;
+
+material_test_systems!(IrisWipeTest, iris_wipe_startup_system, [iris_wipe_system]);
+
+#[system_once]
+fn iris_wipe_startup_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: Query<&MaterialTest>,
+    view: &mut View,
+) {
+    if let Err(error) = iris_wipe_startup(
+        aspect,
+        gpu_interface,
+        world_render_manager,
+        &material_test_query,
+    ) {
+        show_error_view(view, &error);
+    }
+}
+
+fn iris_wipe_startup(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: &Query<&MaterialTest>,
+) -> local_error::Result<()> {
+    let material_test = material_test_query
+        .iter()
+        .find(|material_test| material_test.name() == "iris_wipe")
+        .ok_or_else(|| TestModuleError::MaterialTestMissing {
+            test_name: "iris_wipe".to_string(),
+        })?;
+    let (_, material) = require_material(gpu_interface, &material_test)?;
+    let material_uniforms = material.generate_default_material_uniforms().unwrap();
 
-    let mut texture_component_builder = create_new_texture(
+    world_render_manager.add_or_update_postprocess(material, material_uniforms);
+
+    let scared_id = require_texture(gpu_interface, "textures/scared.png")?;
+
+    let mut texture_component_builder = create_new_relative_texture(
         screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
             .extend(0.)
             .into(),
         *palette::WHITE,
         scared_id,
-        Some(Vec2::splat(aspect.width * 0.15)),
+        Vec2::splat(aspect.height * 0.4),
+        RelativeScale::OfHeight,
     );
-    texture_component_builder.add_components(bundle_for_builder!(
-        MaterialTestObject,
-        material_params,
-        TimePassedSinceCreation::default()
-    ));
+    texture_component_builder.add_component(MaterialTestObject);
     Engine::spawn(&texture_component_builder.build());
 
-    let mut text_component_builder = create_new_text::<_, HeaderText>(CreateTextInput {
-        position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.75.into()).extend(0.),
-        text: "Test",
-        ..Default::default()
-    });
-    text_component_builder.add_component(MaterialTestObject);
-    Engine::spawn(&text_component_builder.build());
-    set_system_enabled!(true, scrolling_color_system);
+    let mut text_component_builder = create_new_text::<_, HeaderText>(
+        CreateTextInput {
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.2.into()).extend(0.),
+            text: "Revealed by the iris",
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_component(MaterialTestObject);
+    Engine::spawn(&text_component_builder.build());
+
+    let iris_wipe = IrisWipe::default();
+    let mut text_component_builder = create_new_text::<_, CustomText>(
+        CreateTextInput {
+            text: iris_wipe.status_text(),
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.9.into())
+                .extend(4000.),
+            text_type: TextTypes::Custom(24.),
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_components(bundle_for_builder!(MaterialTestObject, IrisWipeText));
+    Engine::spawn(&text_component_builder.build());
+
+    Engine::spawn(bundle!(&MaterialTestObject, &iris_wipe));
+    Engine::spawn(bundle!(
+        &MaterialTestObject,
+        &TestControls::new(&[
+            ("Space", "pause iris"),
+            ("Left/Right", "scrub while paused"),
+        ])
+    ));
+    set_system_enabled!(true, iris_wipe_system);
+
+    Ok(())
+}
+
+/// Leaves the radius to `view_transition_system` while an iris transition out of the test runs
+#[allow(clippy::too_many_arguments)]
+#[system]
+fn iris_wipe_system(
+    aspect: &Aspect,
+    input_state: &InputState,
+    material_test_registry: &MaterialTestRegistry,
+    test_clock: &TestClock,
+    view: &View,
+    world_render_manager: &mut WorldRenderManager,
+    mut iris_wipe_query: Query<&mut IrisWipe>,
+    mut iris_wipe_text_query: Query<(&mut TextRender, &IrisWipeText)>,
+) {
+    let Some(material_id) = material_test_registry
+        .get_by_name("iris_wipe")
+        .and_then(|material_test_entry| material_test_entry.first_material_id())
+    else {
+        error!("Could not find material id on iris_wipe");
+        return;
+    };
+    if view.transition_style == TransitionStyle::Iris && view.fade.alpha() > 0. {
+        return;
+    }
+    let toggle_pause = input_state.keys[KeyCode::Space].just_pressed();
+    let scrub_step = match (
+        is_left_just_pressed(input_state),
+        is_right_just_pressed(input_state),
+    ) {
+        (true, false) => -IRIS_WIPE_SCRUB_STEP,
+        (false, true) => IRIS_WIPE_SCRUB_STEP,
+        _ => 0.,
+    };
+
+    iris_wipe_query.for_each(|iris_wipe| {
+        if toggle_pause {
+            iris_wipe.paused = !iris_wipe.paused;
+        }
+        let phase_step = if iris_wipe.paused {
+            scrub_step
+        } else {
+            test_clock.delta_time() / IRIS_WIPE_CYCLE_SECONDS
+        };
+        iris_wipe.phase = (iris_wipe.phase + phase_step).rem_euclid(1.);
+
+        let Some(postprocess) =
+            world_render_manager.get_postprocess_by_material_id_mut(material_id)
+        else {
+            return;
+        };
+        postprocess
+            .material_uniforms
+            .update("radius", iris_wipe.radius().into())
+            .unwrap();
+        postprocess
+            .material_uniforms
+            .update("aspect_ratio", (aspect.width / aspect.height).into())
+            .unwrap();
+
+        let status_text = iris_wipe.status_text();
+        iris_wipe_text_query.for_each(|(text_render, _)| {
+            text_render.text = text_to_u8_array(&status_text);
+        });
+    });
+}
+
+/// A message spawned by [`toast_system`], stacked in the bottom right corner and despawned once
+/// `remaining_seconds` runs out, see [`toast`]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct Toast {
+    remaining_seconds: f32,
+    /// Newer toasts have higher sequence numbers
+    sequence: u64,
+}
+
+/// A [`Resource`] numbering [`Toast`]s, so the stack stays in the order they were shown
+#[derive(Debug, Default, Resource)]
+pub struct Toasts {
+    next_sequence: u64,
+}
+
+#[system]
+fn toast_system(
+    aspect: &Aspect,
+    frame_constants: &FrameConstants,
+    toasts: &mut Toasts,
+    mut toast_query: Query<(
+        &EntityId,
+        &mut Toast,
+        &mut Transform,
+        &mut Color,
+        &TextRender,
+    )>,
+) {
+    for pending_toast in toast::take_pending() {
+        let mut text_component_builder = create_new_text::<_, CustomText>(
+            CreateTextInput {
+                text: &pending_toast.message,
+                position: anchored_position(aspect, Anchor::BottomRight, Vec2::ZERO, Vec2::ZERO)
+                    .extend(4000.),
+                text_type: TextTypes::Custom(TOAST_FONT_SIZE),
+                max_width: Some(aspect.width * 0.45),
+                ..Default::default()
+            },
+            aspect,
+        );
+        text_component_builder.add_component(Toast {
+            remaining_seconds: pending_toast.duration_seconds,
+            sequence: toasts.next_sequence,
+        });
+        toasts.next_sequence += 1;
+        Engine::spawn(&text_component_builder.build());
+    }
+
+    let mut visible_toasts = vec![];
+    toast_query.for_each(|(entity_id, toast, _, _, text_render)| {
+        toast.remaining_seconds -= frame_constants.delta_time;
+        if toast.remaining_seconds <= 0. {
+            Engine::despawn(**entity_id);
+            return;
+        }
+        let text = u8_array_to_str(&text_render.text).unwrap();
+        let toast_size = ToastSize {
+            width: text
+                .lines()
+                .map(|line| measure_text(line, TOAST_FONT_SIZE))
+                .fold(0., f32::max),
+            lines: text.lines().count(),
+        };
+        visible_toasts.push((toast.sequence, **entity_id, toast_size));
+    });
+    visible_toasts.sort_by(|(sequence, _, _), (other_sequence, _, _)| other_sequence.cmp(sequence));
+    for (_, entity_id, _) in visible_toasts.drain(MAX_VISIBLE_TOASTS.min(visible_toasts.len())..) {
+        Engine::despawn(entity_id);
+    }
+    let toast_sizes = visible_toasts
+        .iter()
+        .map(|(_, _, toast_size)| *toast_size)
+        .collect::<Vec<_>>();
+    let stack_offsets = toast_stack_offsets(&toast_sizes);
+
+    toast_query.for_each(|(entity_id, toast, transform, color, _)| {
+        let Some(slot) = visible_toasts
+            .iter()
+            .position(|(_, visible_entity_id, _)| *visible_entity_id == **entity_id)
+        else {
+            return;
+        };
+        let position =
+            anchored_position(aspect, Anchor::BottomRight, Vec2::ZERO, stack_offsets[slot]);
+        transform
+            .position
+            .set(position.extend(transform.position.get().z));
+        *color = Color::from(Vec4::new(1., 1., 1., toast_alpha(toast.remaining_seconds)));
+    });
+}
+
+fn uniform_preset_from_material_uniforms(material_uniforms: &MaterialUniforms) -> UniformPreset {
+    UniformPreset {
+        values: material_uniforms
+            .iter()
+            .filter_map(|(name, uniform_value)| {
+                let value = match uniform_value {
+                    UniformValue::F32(value) => PresetValue::F32(value.current_value()),
+                    UniformValue::Vec4(value) => {
+                        PresetValue::Vec4(Vec4::from(value.current_value()).to_array())
+                    }
+                    // Array uniforms are filled in by the test systems, not tweaked by hand
+                    UniformValue::Array(_) => return None,
+                };
+                Some((name.to_string(), value))
+            })
+            .collect(),
+    }
+}
+
+fn apply_uniform_preset(uniform_preset: &UniformPreset, material_uniforms: &mut MaterialUniforms) {
+    for (name, value) in &uniform_preset.values {
+        let uniform_value: UniformValue = match value {
+            PresetValue::F32(value) => (*value).into(),
+            PresetValue::Vec4(value) => Vec4::from_array(*value).into(),
+        };
+        if material_uniforms.update(name, uniform_value).is_err() {
+            warn!("Skipping preset uniform {name}, the material no longer has it");
+        }
+    }
+}
+
+/// Ctrl+1..9 saves the uniforms of the running material test to a preset slot and 1..9 restores
+/// them, see [`presets`]
+#[system]
+#[allow(clippy::too_many_arguments)]
+fn uniform_preset_system(
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    split_view: &SplitView,
+    uniform_entry: &UniformEntry,
+    view: &View,
+    world_render_manager: &mut WorldRenderManager,
+    material_test_query: Query<&MaterialTest>,
+    mut material_params_query: Query<(&mut MaterialParameters, &MaterialTestObject)>,
+) {
+    let ViewState::Material((material_test_id, _)) = view.view_state() else {
+        return;
+    };
+    // Digits are typed into the uniform entry field while it's open, and step the split view's
+    // quadrants while it's on
+    if uniform_entry.is_open() || split_view.active {
+        return;
+    }
+    let Some(slot) = preset_slot_just_pressed(input_state) else {
+        return;
+    };
+    let Some(material_test) = material_test_query
+        .iter()
+        .find(|material_test| material_test.id() == *material_test_id)
+    else {
+        error!("Could not find the running material test to apply preset {slot}");
+        return;
+    };
+    // The post stack test toggles its layers with the digits
+    if material_test.name() == "post_stack" {
+        return;
+    }
+    let Some(Some(material_id)) = material_test.material_id_iter().next() else {
+        error!("{} is missing expected material_id", material_test.name());
+        return;
+    };
+    let path = preset_path(material_test.name(), slot);
+
+    if is_preset_save_modifier_pressed(input_state) {
+        let Some(uniform_preset) = current_uniform_preset(
+            material_test,
+            material_id,
+            gpu_interface,
+            world_render_manager,
+            &mut material_params_query,
+        ) else {
+            warn!("{} has no uniforms to save", material_test.name());
+            toast::show(
+                "Nothing to save, the test material is not on screen",
+                DEFAULT_TOAST_SECONDS,
+            );
+            return;
+        };
+        match uniform_preset.write(&path) {
+            Ok(()) => {
+                info!("Saved preset {slot} to {}", path.display());
+                toast::show(&format!("Saved preset {slot}"), DEFAULT_TOAST_SECONDS);
+            }
+            Err(error) => {
+                error!("Could not save preset to {}: {error}", path.display());
+                toast::show(
+                    &format!("Saving preset {slot} failed: {error}"),
+                    DEFAULT_TOAST_SECONDS,
+                );
+            }
+        }
+        return;
+    }
+
+    let Some(uniform_preset) = UniformPreset::read(&path) else {
+        toast::show(
+            &format!("Preset {slot} is empty, Ctrl+{slot} saves it"),
+            DEFAULT_TOAST_SECONDS,
+        );
+        return;
+    };
+    apply_uniform_preset_to_material_test(
+        &uniform_preset,
+        material_test,
+        material_id,
+        gpu_interface,
+        world_render_manager,
+        &mut material_params_query,
+    );
+    info!("Loaded preset {slot} from {}", path.display());
+    toast::show(&format!("Loaded preset {slot}"), DEFAULT_TOAST_SECONDS);
 }
 
-#[system]
-fn scrolling_color_system(
-    frame_constants: &FrameConstants,
-    gpu_interface: &GpuInterface,
-    mut textures: Query<(
-        &TextureRender,
-        &mut TimePassedSinceCreation,
-        &mut MaterialParameters,
-    )>,
-) {
-    textures.for_each(|(_, time_passed_since_creation, material_params)| {
-        *time_passed_since_creation += frame_constants.delta_time;
-
-        let current_speed = SCROLLING_COLOR_SCROLL_SPEED_CENTER_POINT
-            + 0.75 * f32::sin(***time_passed_since_creation * 0.001);
-
-        material_params
-            .update_uniforms(
-                &gpu_interface.material_manager,
-                &[
-                    ("time", &(***time_passed_since_creation).into()),
-                    ("scroll_speed", &current_speed.into()),
-                ],
-            )
-            .unwrap();
-    });
-}
+/// How long R is held to reset the uniforms to their defaults
+const UNIFORM_RESET_HOLD_SECONDS: f32 = 1.;
 
-#[system_once]
-fn starfield_startup_system(
-    aspect: &Aspect,
+/// R assigns every f32/Vec4 uniform with a range in the `[uniform_ranges]` table of the running
+/// test's material TOML a random value from that range, see [`uniform_ranges`]. Holding R resets
+/// every uniform to its default.
+#[system]
+#[allow(clippy::too_many_arguments)]
+fn randomize_uniforms_system(
     gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    press_timing_state: &PressTimingState,
+    view: &View,
+    test_rng: &mut TestRng,
+    world_render_manager: &mut WorldRenderManager,
     material_test_query: Query<&MaterialTest>,
+    mut material_params_query: Query<(&mut MaterialParameters, &MaterialTestObject)>,
 ) {
-    let Some(starfield_material_test) = material_test_query
+    let ViewState::Material((material_test_id, _)) = view.view_state() else {
+        return;
+    };
+    // The press randomizes the uniforms and holding on resets them
+    let reset_pressed = bound_keys(InputAction::RandomizeUniforms)
         .iter()
-        .find(|material_test| material_test.name() == "starfield")
+        .any(|key_code| press_timing_state.is_long_pressed(*key_code, UNIFORM_RESET_HOLD_SECONDS));
+    if !reset_pressed && !is_randomize_uniforms_just_pressed(input_state) {
+        return;
+    }
+    let Some(material_test) = material_test_query
+        .iter()
+        .find(|material_test| material_test.id() == *material_test_id)
     else {
-        error!("Could not find starfield material test");
+        error!("Could not find the running material test to randomize");
         return;
     };
-    let Some(Some(material_id)) = starfield_material_test.material_id_iter().next() else {
-        error!("Could not find material id on starfield");
+    let Some(Some(material_id)) = material_test.material_id_iter().next() else {
+        error!("{} is missing expected material_id", material_test.name());
         return;
     };
-    let material = gpu_interface
-        .material_manager
-        .get_material(material_id)
-        .unwrap();
 
-    let random_texture = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/random.png".into())
-        .unwrap();
-    let random_texture = random_texture.as_loaded_texture().unwrap();
-    let star_map_id = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/star_map_with_mask.png".into())
-        .unwrap()
-        .id();
+    if reset_pressed {
+        let Some(default_uniforms) = gpu_interface
+            .material_manager
+            .get_material(material_id)
+            .and_then(|material| material.generate_default_material_uniforms())
+        else {
+            error!(
+                "Could not generate the default uniforms of {}",
+                material_test.name()
+            );
+            return;
+        };
+        apply_uniform_preset_to_material_test(
+            &uniform_preset_from_material_uniforms(&default_uniforms),
+            material_test,
+            material_id,
+            gpu_interface,
+            world_render_manager,
+            &mut material_params_query,
+        );
+        info!("Reset the uniforms of {}", material_test.name());
+        toast::show("Reset uniforms to their defaults", DEFAULT_TOAST_SECONDS);
+        return;
+    }
 
-    let material_params = material
-        .generate_default_material_parameters()
-        .update_uniform(
-            &gpu_interface.material_manager,
-            &(
-                "texture_height",
-                &(random_texture.height() as f32 * aspect.height * 0.1).into(),
-            ),
-        )
-        .unwrap()
-        .update_textures(
-            &gpu_interface.material_manager,
-            &[("star_map", &star_map_id), ("random", &random_texture.id())],
-        )
-        .unwrap()
-        .end_chain();
+    let uniform_ranges = material_test
+        .material_definition_path()
+        .and_then(|path| fs::read_to_string(asset_file_path(path)).ok())
+        .map(|toml_string| parse_uniform_ranges(&toml_string))
+        .unwrap_or_default();
+    if uniform_ranges.is_empty() {
+        toast::show(
+            &format!("{} declares no [uniform_ranges]", material_test.name()),
+            DEFAULT_TOAST_SECONDS,
+        );
+        return;
+    }
+    let Some(current_uniform_preset) = current_uniform_preset(
+        material_test,
+        material_id,
+        gpu_interface,
+        world_render_manager,
+        &mut material_params_query,
+    ) else {
+        toast::show(
+            "Nothing to randomize, the test material is not on screen",
+            DEFAULT_TOAST_SECONDS,
+        );
+        return;
+    };
 
-    let mut texture_component_builder = create_new_texture(
-        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
-            .extend(0.)
-            .into(),
-        *palette::WHITE,
-        star_map_id,
-        Some(Vec2::splat(aspect.width * 0.325)),
+    let mut sample =
+        |uniform_range: &UniformRange| test_rng.gen_range(uniform_range.min..=uniform_range.max);
+    let randomized_uniform_preset = UniformPreset {
+        values: current_uniform_preset
+            .values
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let (_, uniform_range) = uniform_ranges
+                    .iter()
+                    .find(|(range_name, _)| *range_name == name)?;
+                let value = match value {
+                    PresetValue::F32(_) => PresetValue::F32(sample(uniform_range)),
+                    PresetValue::Vec4(_) => {
+                        PresetValue::Vec4(std::array::from_fn(|_| sample(uniform_range)))
+                    }
+                };
+                Some((name, value))
+            })
+            .collect(),
+    };
+    apply_uniform_preset_to_material_test(
+        &randomized_uniform_preset,
+        material_test,
+        material_id,
+        gpu_interface,
+        world_render_manager,
+        &mut material_params_query,
+    );
+    info!(
+        "Randomized {} uniforms of {}",
+        randomized_uniform_preset.values.len(),
+        material_test.name()
+    );
+    toast::show(
+        &format!(
+            "Randomized {} uniforms, Ctrl+1..9 saves them",
+            randomized_uniform_preset.values.len()
+        ),
+        DEFAULT_TOAST_SECONDS,
+    );
+}
+
+/// A [`Resource`] for typing exact uniform values into the running material test. Tab opens a
+/// [`TextInput`] on the first f32 uniform and moves on to the next one, Enter applies the typed
+/// value and Escape closes the field. The mouse wheel steps the value through its
+/// `[uniform_ranges]` range and applies it right away. While the field is open, the keys it uses
+/// don't trigger their usual hotkeys and the wheel doesn't zoom the camera.
+#[derive(Debug, Default, Resource)]
+pub struct UniformEntry {
+    field: Option<UniformEntryField>,
+    /// The field closes a frame late, so systems running after [`uniform_entry_system`] don't
+    /// also act on the Enter or Escape that closed it
+    close_requested: bool,
+}
+
+#[derive(Debug)]
+struct UniformEntryField {
+    uniform_name: String,
+    text_input: TextInput,
+}
+
+impl UniformEntry {
+    pub fn is_open(&self) -> bool {
+        self.field.is_some()
+    }
+}
+
+/// Sets one f32 uniform of `material_test`'s first material
+fn set_uniform_of_material_test(
+    uniform_name: &str,
+    value: f32,
+    material_test: &MaterialTest,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_params_query: &mut Query<(&mut MaterialParameters, &MaterialTestObject)>,
+) {
+    let Some(Some(material_id)) = material_test.material_id_iter().next() else {
+        error!("{} is missing expected material_id", material_test.name());
+        return;
+    };
+    apply_uniform_preset_to_material_test(
+        &UniformPreset {
+            values: vec![(uniform_name.to_string(), PresetValue::F32(value))],
+        },
+        material_test,
+        material_id,
+        gpu_interface,
+        world_render_manager,
+        material_params_query,
     );
-    texture_component_builder.add_components(bundle_for_builder!(
-        MaterialTestObject,
-        material_params,
-        TimePassedSinceCreation::default()
-    ));
-    Engine::spawn(&texture_component_builder.build());
-    set_system_enabled!(true, starfield_system);
 }
 
+/// Marker [`Component`] for the text of the [`UniformEntry`] field
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct UniformEntryText;
+
+/// Marker [`Component`] for the blinking underline under the [`UniformEntry`] cursor
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct UniformEntryCursor;
+
+const UNIFORM_ENTRY_FONT_SIZE: f32 = 32.;
+
 #[system]
-fn starfield_system(
+#[allow(clippy::too_many_arguments)]
+fn uniform_entry_system(
+    aspect: &Aspect,
     frame_constants: &FrameConstants,
     gpu_interface: &GpuInterface,
     input_state: &InputState,
+    view: &View,
+    uniform_entry: &mut UniformEntry,
+    world_render_manager: &mut WorldRenderManager,
     material_test_query: Query<&MaterialTest>,
-    mut textures: Query<(
-        &TextureRender,
-        &mut TimePassedSinceCreation,
-        &mut MaterialParameters,
+    mut material_params_query: Query<(&mut MaterialParameters, &MaterialTestObject)>,
+    mut uniform_entry_text_query: Query<(&EntityId, &mut TextRender, &UniformEntryText)>,
+    mut uniform_entry_cursor_query: Query<(
+        &EntityId,
+        &mut Transform,
+        &mut TextureRender,
+        &UniformEntryCursor,
     )>,
 ) {
-    let Some(starfield_material_test) = material_test_query
-        .iter()
-        .find(|material_test| material_test.name() == "starfield")
-    else {
-        error!("Could not find starfield material test");
+    if uniform_entry.close_requested {
+        uniform_entry.field = None;
+        uniform_entry.close_requested = false;
+    }
+    let ViewState::Material((material_test_id, _)) = view.view_state() else {
+        // The field's entities are despawned with the rest of the test
+        uniform_entry.field = None;
         return;
     };
-    let Some(Some(material_id)) = starfield_material_test.material_id_iter().next() else {
-        error!("Could not find material id on starfield");
+
+    if is_uniform_entry_next_just_pressed(input_state) {
+        let Some(material_test) = material_test_query
+            .iter()
+            .find(|material_test| material_test.id() == *material_test_id)
+        else {
+            error!("Could not find the running material test to edit its uniforms");
+            return;
+        };
+        let Some(Some(material_id)) = material_test.material_id_iter().next() else {
+            error!("{} is missing expected material_id", material_test.name());
+            return;
+        };
+        let f32_uniforms = current_uniform_preset(
+            material_test,
+            material_id,
+            gpu_interface,
+            world_render_manager,
+            &mut material_params_query,
+        )
+        .map(|uniform_preset| {
+            uniform_preset
+                .values
+                .into_iter()
+                .filter_map(|(name, value)| match value {
+                    PresetValue::F32(value) => Some((name, value)),
+                    PresetValue::Vec4(_) => None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+        if f32_uniforms.is_empty() {
+            toast::show(
+                &format!("{} has no f32 uniforms to edit", material_test.name()),
+                DEFAULT_TOAST_SECONDS,
+            );
+            return;
+        }
+        let next_index = uniform_entry
+            .field
+            .as_ref()
+            .and_then(|field| {
+                f32_uniforms
+                    .iter()
+                    .position(|(name, _)| *name == field.uniform_name)
+            })
+            .map_or(0, |index| (index + 1) % f32_uniforms.len());
+        let (uniform_name, value) = &f32_uniforms[next_index];
+        uniform_entry.field = Some(UniformEntryField {
+            uniform_name: uniform_name.clone(),
+            text_input: TextInput::new(&value.to_string()),
+        });
+        uniform_entry.close_requested = false;
+    } else if let Some(field) = uniform_entry
+        .field
+        .as_mut()
+        .filter(|_| !uniform_entry.close_requested)
+    {
+        if is_text_input_cancel_just_pressed(input_state) {
+            uniform_entry.close_requested = true;
+        } else if is_text_input_confirm_just_pressed(input_state) {
+            match field.text_input.parse_f32() {
+                Some(value) => {
+                    let Some(material_test) = material_test_query
+                        .iter()
+                        .find(|material_test| material_test.id() == *material_test_id)
+                    else {
+                        error!("Could not find the running material test to edit its uniforms");
+                        return;
+                    };
+                    set_uniform_of_material_test(
+                        &field.uniform_name,
+                        value,
+                        material_test,
+                        gpu_interface,
+                        world_render_manager,
+                        &mut material_params_query,
+                    );
+                    toast::show(
+                        &format!("Set {} to {value}", field.uniform_name),
+                        DEFAULT_TOAST_SECONDS,
+                    );
+                    uniform_entry.close_requested = true;
+                }
+                None => toast::show(
+                    &format!("{} is not a number", field.text_input.text()),
+                    DEFAULT_TOAST_SECONDS,
+                ),
+            }
+        } else if is_scroll_up(input_state) || is_scroll_down(input_state) {
+            // The wheel fine-adjusts the uniform, applying every step right away
+            let Some(material_test) = material_test_query
+                .iter()
+                .find(|material_test| material_test.id() == *material_test_id)
+            else {
+                error!("Could not find the running material test to edit its uniforms");
+                return;
+            };
+            match field.text_input.parse_f32() {
+                Some(value) => {
+                    let uniform_range = material_test
+                        .material_definition_path()
+                        .and_then(|path| fs::read_to_string(asset_file_path(path)).ok())
+                        .and_then(|toml_string| {
+                            parse_uniform_ranges(&toml_string)
+                                .into_iter()
+                                .find(|(name, _)| *name == field.uniform_name)
+                        })
+                        .map(|(_, uniform_range)| uniform_range);
+                    let steps = if is_scroll_up(input_state) { 1. } else { -1. };
+                    let value = scrolled_uniform_value(value, steps, uniform_range);
+                    set_uniform_of_material_test(
+                        &field.uniform_name,
+                        value,
+                        material_test,
+                        gpu_interface,
+                        world_render_manager,
+                        &mut material_params_query,
+                    );
+                    field.text_input = TextInput::new(&value.to_string());
+                }
+                None => toast::show(
+                    &format!("{} is not a number", field.text_input.text()),
+                    DEFAULT_TOAST_SECONDS,
+                ),
+            }
+        } else {
+            numeric_characters_just_pressed(input_state)
+                .for_each(|character| field.text_input.insert(character));
+            if is_text_input_backspace_just_pressed(input_state) {
+                field.text_input.backspace();
+            }
+            if is_text_input_delete_just_pressed(input_state) {
+                field.text_input.delete();
+            }
+            if is_text_input_cursor_left_just_pressed(input_state) {
+                field.text_input.move_cursor_left();
+            } else if is_text_input_cursor_right_just_pressed(input_state) {
+                field.text_input.move_cursor_right();
+            }
+            field.text_input.advance_blink(frame_constants.delta_time);
+        }
+    }
+
+    let Some(field) = &uniform_entry.field else {
+        uniform_entry_text_query.for_each(|(entity_id, _, _)| Engine::despawn(**entity_id));
+        uniform_entry_cursor_query.for_each(|(entity_id, _, _, _)| Engine::despawn(**entity_id));
         return;
     };
-    let material = gpu_interface
-        .material_manager
-        .get_material(material_id)
-        .unwrap();
-    textures.for_each(|(_, time_passed_since_creation, material_params)| {
-        *time_passed_since_creation += frame_constants.delta_time;
-        let current_uniforms = material
-            .get_current_uniforms(&material_params.data)
-            .unwrap();
-        let current_speed = current_uniforms.get("speed").unwrap();
-        let current_speed = match current_speed {
-            UniformValue::F32(value) => value.current_value(),
-            _ => unreachable!(),
-        };
 
-        let current_stars = current_uniforms.get("star_number").unwrap();
-        let current_stars = match current_stars {
-            UniformValue::F32(value) => value.current_value(),
-            _ => unreachable!(),
-        };
+    let label = format!("{} = ", field.uniform_name);
+    let text = format!("{label}{}", field.text_input.text());
+    let text_position =
+        anchored_position(aspect, Anchor::TopCenter, Vec2::new(0., -0.1), Vec2::ZERO);
+    let cursor_width = measure_text("0", UNIFORM_ENTRY_FONT_SIZE);
+    // The text is centered, so the cursor is offset from where the text starts
+    let cursor_position = text_position
+        + Vec2::new(
+            measure_text(
+                &format!("{label}{}", field.text_input.text_before_cursor()),
+                UNIFORM_ENTRY_FONT_SIZE,
+            ) - measure_text(&text, UNIFORM_ENTRY_FONT_SIZE) * 0.5
+                + cursor_width * 0.5,
+            -UNIFORM_ENTRY_FONT_SIZE * 0.6,
+        );
+
+    if uniform_entry_text_query.is_empty() {
+        let mut text_component_builder = create_new_text::<_, CustomText>(
+            CreateTextInput {
+                text: text.as_str(),
+                position: text_position.extend(4000.),
+                text_type: TextTypes::Custom(UNIFORM_ENTRY_FONT_SIZE),
+                ..Default::default()
+            },
+            aspect,
+        );
+        text_component_builder
+            .add_components(bundle_for_builder!(MaterialTestObject, UniformEntryText));
+        Engine::spawn(&text_component_builder.build());
+
+        let mut underline_component_builder =
+            create_underline(cursor_position.extend(4000.), Some(cursor_width), aspect);
+        underline_component_builder
+            .add_components(bundle_for_builder!(MaterialTestObject, UniformEntryCursor));
+        Engine::spawn(&underline_component_builder.build());
+        return;
+    }
+
+    uniform_entry_text_query.for_each(|(_, text_render, _)| {
+        text_render.text = text_to_u8_array(&text);
+    });
+    uniform_entry_cursor_query.for_each(|(_, transform, texture_render, _)| {
+        transform.position.set(cursor_position.extend(4000.));
+        texture_render.visible = field.text_input.is_cursor_visible();
+    });
+}
+
+/// Reads the uniforms of the running test's first material, from the postprocess for
+/// post-processing tests and from the first sprite using it for sprite tests
+fn current_uniform_preset(
+    material_test: &MaterialTest,
+    material_id: MaterialId,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_params_query: &mut Query<(&mut MaterialParameters, &MaterialTestObject)>,
+) -> Option<UniformPreset> {
+    match material_test.material_type() {
+        MaterialType::PostProcessing => world_render_manager
+            .get_postprocess_by_material_id_mut(material_id)
+            .map(|postprocess| {
+                uniform_preset_from_material_uniforms(&postprocess.material_uniforms)
+            }),
+        MaterialType::Sprite => {
+            let material = gpu_interface
+                .material_manager
+                .get_material(material_id)
+                .unwrap();
+            let mut uniform_preset = None;
+            material_params_query.for_each(|(material_params, _)| {
+                if uniform_preset.is_none() && material_params.material_id == material_id {
+                    uniform_preset = material
+                        .get_current_uniforms(&material_params.data)
+                        .ok()
+                        .map(|material_uniforms| {
+                            uniform_preset_from_material_uniforms(&material_uniforms)
+                        });
+                }
+            });
+            uniform_preset
+        }
+    }
+}
+
+/// Writes `uniform_preset` to the postprocess for post-processing tests and to every sprite
+/// using the material for sprite tests
+fn apply_uniform_preset_to_material_test(
+    uniform_preset: &UniformPreset,
+    material_test: &MaterialTest,
+    material_id: MaterialId,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+    material_params_query: &mut Query<(&mut MaterialParameters, &MaterialTestObject)>,
+) {
+    match material_test.material_type() {
+        MaterialType::PostProcessing => {
+            if let Some(postprocess) =
+                world_render_manager.get_postprocess_by_material_id_mut(material_id)
+            {
+                apply_uniform_preset(uniform_preset, &mut postprocess.material_uniforms);
+            }
+        }
+        MaterialType::Sprite => {
+            material_params_query.for_each(|(material_params, _)| {
+                if material_params.material_id != material_id {
+                    return;
+                }
+                let mut material_uniforms = material_params
+                    .as_material_uniforms(&gpu_interface.material_manager)
+                    .unwrap();
+                apply_uniform_preset(uniform_preset, &mut material_uniforms);
+                material_params
+                    .update_from_material_uniforms(&material_uniforms)
+                    .unwrap();
+            });
+        }
+    }
+}
+
+/// The maximum number of keyframes a single [`UniformTimeline`] can hold
+pub const UNIFORM_TIMELINE_KEYFRAME_LIMIT: usize = 16;
+
+/// Where a [`UniformTimeline`] writes its values
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub enum UniformTimelineTarget {
+    /// The [`MaterialParameters`] of the entity carrying the timeline
+    MaterialParameters,
+    /// The uniforms of the postprocess using this material
+    Postprocess(MaterialId),
+}
+
+/// A [`Component`] animating f32 uniforms from [`UniformKeyframe`]s, evaluated every frame by
+/// [`uniform_timeline_system`] and [`postprocess_uniform_timeline_system`] on the [`TestClock`].
+/// Startup systems spawn it with [`MaterialTestObject`] so it goes away with the test.
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct UniformTimeline {
+    keyframes: [UniformKeyframe; UNIFORM_TIMELINE_KEYFRAME_LIMIT],
+    target: UniformTimelineTarget,
+    looping: bool,
+    elapsed: f32,
+}
 
-        const SPEED_INCREMENT: f32 = 0.1;
+impl UniformTimeline {
+    /// `keyframes` must be in time order
+    pub fn new(keyframes: &[UniformKeyframe], target: UniformTimelineTarget) -> Self {
+        Self {
+            keyframes: array_from_iterator(keyframes.iter().copied()),
+            target,
+            looping: false,
+            elapsed: 0.,
+        }
+    }
 
-        let new_speed = if is_left_just_pressed(input_state) {
-            Some(current_speed - SPEED_INCREMENT)
-        } else if is_right_just_pressed(input_state) {
-            Some(current_speed + SPEED_INCREMENT)
-        } else {
-            None
-        };
+    /// Restarts from the first keyframe after the last one instead of holding its values
+    pub fn looping(mut self) -> Self {
+        self.looping = true;
+        self
+    }
 
-        let speed_burst_value = if input_state.keys[KeyCode::Space].just_pressed() {
-            Some(80.0.into())
-        } else if input_state.keys[KeyCode::Space].just_released() {
-            let default_uniforms = material.generate_default_material_uniforms().unwrap();
-            Some(default_uniforms.get("speed").unwrap().clone())
-        } else {
-            None
-        };
+    pub fn keyframes(&self) -> impl Iterator<Item = &UniformKeyframe> {
+        self.keyframes
+            .iter()
+            .take_while(|keyframe| !keyframe.uniform_name().is_empty())
+    }
 
-        const STARS_INCREMENT: f32 = 5.;
-        let new_stars = if is_up_just_pressed(input_state) {
-            Some(current_stars + STARS_INCREMENT)
-        } else if is_down_just_pressed(input_state) {
-            Some(current_stars - STARS_INCREMENT)
-        } else {
-            None
-        };
+    pub fn advance(&mut self, delta_time: f32) {
+        self.elapsed += delta_time;
+        let duration = timeline_duration(self.keyframes());
+        if self.looping && duration > 0. {
+            self.elapsed %= duration;
+        }
+    }
 
-        let mut material_uniforms = material_params
-            .as_material_uniforms(&gpu_interface.material_manager)
-            .unwrap();
+    /// Each animated uniform with its value at the current time
+    pub fn current_values(&self) -> Vec<(&str, f32)> {
+        let mut uniform_names = self
+            .keyframes()
+            .map(|keyframe| keyframe.uniform_name())
+            .collect::<Vec<_>>();
+        uniform_names.sort_unstable();
+        uniform_names.dedup();
+        uniform_names
+            .into_iter()
+            .filter_map(|uniform_name| {
+                evaluate_uniform(self.keyframes(), uniform_name, self.elapsed)
+                    .map(|value| (uniform_name, value))
+            })
+            .collect()
+    }
+}
 
-        if let Some(new_stars) = new_stars {
-            material_uniforms
-                .update("star_number", new_stars.into())
+#[system]
+fn uniform_timeline_system(
+    gpu_interface: &GpuInterface,
+    test_clock: &TestClock,
+    mut uniform_timeline_query: Query<(&mut UniformTimeline, &mut MaterialParameters)>,
+) {
+    uniform_timeline_query.for_each(|(uniform_timeline, material_params)| {
+        if !matches!(
+            uniform_timeline.target,
+            UniformTimelineTarget::MaterialParameters
+        ) {
+            return;
+        }
+        uniform_timeline.advance(test_clock.delta_time());
+        for (uniform_name, value) in uniform_timeline.current_values() {
+            material_params
+                .update_uniform(
+                    &gpu_interface.material_manager,
+                    &(uniform_name, &value.into()),
+                )
                 .unwrap();
         }
+    });
+}
 
-        if let Some(speed_burst_value) = speed_burst_value {
-            material_uniforms
-                .update("speed", speed_burst_value)
+#[system]
+fn postprocess_uniform_timeline_system(
+    test_clock: &TestClock,
+    world_render_manager: &mut WorldRenderManager,
+    mut uniform_timeline_query: Query<&mut UniformTimeline>,
+) {
+    uniform_timeline_query.for_each(|uniform_timeline| {
+        let UniformTimelineTarget::Postprocess(material_id) = uniform_timeline.target else {
+            return;
+        };
+        uniform_timeline.advance(test_clock.delta_time());
+        let Some(postprocess) =
+            world_render_manager.get_postprocess_by_material_id_mut(material_id)
+        else {
+            return;
+        };
+        for (uniform_name, value) in uniform_timeline.current_values() {
+            postprocess
+                .material_uniforms
+                .update(uniform_name, value.into())
                 .unwrap();
-        } else if let Some(new_speed) = new_speed {
-            material_uniforms.update("speed", new_speed.into()).unwrap();
         }
-
-        material_uniforms
-            .update("time_elapsed", (***time_passed_since_creation).into())
-            .unwrap();
-        material_params
-            .update_from_material_uniforms(&material_uniforms)
-            .unwrap();
     });
 }
 
-#[derive(Debug, Component, serde::Deserialize, serde::Serialize)]
-pub struct Velocity {
-    pub direction: Vec3,
-    pub rotation: f32,
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct PerfOverlayText;
+
+/// A [`Resource`] holding recent frame times for the performance overlay, and whether it is
+/// currently shown. F3 toggles the overlay.
+#[derive(Debug, Resource)]
+pub struct PerfOverlay {
+    pub visible: bool,
+    pub frame_time_history: FrameTimeHistory,
+}
+
+impl Default for PerfOverlay {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            frame_time_history: FrameTimeHistory::default(),
+        }
+    }
+}
+
+/// How many of the newest warnings and errors the console overlay shows
+const CONSOLE_OVERLAY_LINES: usize = 10;
+
+/// A [`Resource`] for the on screen log console, backtick toggles it. It shows the newest
+/// warnings and errors captured by [`console`] in every view.
+#[derive(Debug, Default, Resource)]
+pub struct ConsoleOverlay {
+    pub visible: bool,
+    shown_generation: Option<u64>,
 }
 
+/// Marker [`Component`] for a line of the [`ConsoleOverlay`], `.0` is the line index from the top
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct ConsoleText(usize);
+
 #[system]
-#[allow(clippy::too_many_arguments)]
-fn immediate_mode_test(
-    draw_circle_writer: EventWriter<DrawCircle>,
-    draw_line_writer: EventWriter<DrawLine>,
-    draw_text_writer: EventWriter<DrawText>,
-    draw_rectangle_writer: EventWriter<DrawRectangle>,
+fn console_overlay_system(
     aspect: &Aspect,
-    frame_constants: &FrameConstants,
-    gpu_interface: &GpuInterface,
-    mut time_passed_since_creation: Query<&mut TimePassedSinceCreation>,
+    input_state: &InputState,
+    console_overlay: &mut ConsoleOverlay,
+    mut console_text_query: Query<(&mut TextRender, &ConsoleText)>,
 ) {
-    let scared_id = match gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/scared.png".into())
-    {
-        Some(texture) => texture.id(),
-        None => {
-            warn!(
-                "Could not find texture scared.png, if this occurs at the beginning of the first frame it is normal (for now), otherwise this is an error"
+    if is_console_toggle_just_pressed(input_state) {
+        console_overlay.visible = !console_overlay.visible;
+    }
+
+    if console_text_query.is_empty() {
+        for index in 0..CONSOLE_OVERLAY_LINES {
+            let y_percent =
+                ZeroToHundredPercent::new(0.9) - ZeroToHundredPercent::new(0.035) * index as f32;
+            let mut text_component_builder = create_new_text::<_, CustomText>(
+                CreateTextInput {
+                    text: "",
+                    visible: false,
+                    position: screen_space_coordinate_by_percent(aspect, 0.5.into(), y_percent)
+                        .extend(4500.),
+                    text_type: TextTypes::Custom(22.),
+                    ..Default::default()
+                },
+                aspect,
             );
-            return;
+            text_component_builder.add_component(ConsoleText(index));
+            Engine::spawn(&text_component_builder.build());
         }
-    };
+        return;
+    }
 
-    let scared_distance = Vec2::new(aspect.width * 0.15, 0.);
-    let circle_distance = Vec2::new(aspect.width * 0.275, 0.);
-    let line_distance = Vec2::new(aspect.width * 0.375, 0.);
-    let center_point_vec2 = screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into());
-    let center_point_vec3 = center_point_vec2.extend(1.);
-    let center_point_vec3t = Vec3T {
-        x: center_point_vec3.x,
-        y: center_point_vec3.y,
-        z: center_point_vec3.z,
-    };
+    let console_buffer = console_buffer();
+    let generation = console_buffer.generation();
+    let lines = (console_overlay.shown_generation != Some(generation)).then(|| {
+        console_buffer
+            .last(CONSOLE_OVERLAY_LINES)
+            .map(ConsoleLine::to_overlay_string)
+            .collect::<Vec<_>>()
+    });
+    drop(console_buffer);
+    console_overlay.shown_generation = Some(generation);
+
+    console_text_query.for_each(|(text_render, console_text)| {
+        if let Some(lines) = &lines {
+            // Truncated silently, a warning would be logged into the buffer shown here and
+            // redraw the overlay with another long line
+            text_render.text =
+                str_to_u8_array(lines.get(console_text.0).map_or("", String::as_str)).0;
+        }
+        text_render.visible = console_overlay.visible;
+    });
+}
 
-    let time_passed = if time_passed_since_creation.is_empty() {
-        Engine::spawn(bundle!(
-            &MaterialTestObject,
-            &TimePassedSinceCreation::default()
-        ));
-        0.
-    } else {
-        let mut time_passed = 0.;
-        time_passed_since_creation.for_each(|time_passed_since_creation| {
-            *time_passed_since_creation += frame_constants.delta_time;
-            time_passed = ***time_passed_since_creation;
-        });
-        time_passed
-    };
+/// A [`Resource`] for the postprocess ordering overlay, see [`postprocess_inspector`]. `selected`
+/// indexes `WorldRenderManager::postprocesses`.
+#[derive(Debug, Default, Resource)]
+pub struct PostprocessInspector {
+    pub visible: bool,
+    selected: usize,
+}
 
-    draw_text_writer.write_builder(|builder| {
-        let flatbuffer_test_string = builder.create_string("This is a test");
-        let mut draw_text_builder = DrawTextBuilder::new(builder);
-        draw_text_builder.add_font_size(48.);
-        draw_text_builder.add_text(flatbuffer_test_string);
-        let red = 0.25 * time_passed.sin() + 0.75;
-        let green = 0.25 * time_passed.cos() + 0.75;
-        draw_text_builder.add_color(&void_public::event::graphics::Color::new(
-            red, green, 1., 1.,
-        ));
-        draw_text_builder.add_bounds(&Vec2T { x: 500., y: 500. }.pack());
-        draw_text_builder.add_text_alignment(TextAlignment::Center);
-        let transform = TransformT {
-            position: center_point_vec3t,
-            scale: Vec2T { x: 1., y: 1. },
-            ..Default::default()
-        };
-        draw_text_builder.add_transform(&transform.pack());
-        draw_text_builder.add_z(1.);
-        draw_text_builder.finish()
-    });
+/// Marker [`Component`] for a line of the [`PostprocessInspector`], `.0` is the line index from
+/// the top
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct PostprocessInspectorText(usize);
 
-    let starting_rotation_matrix = Mat2::from_angle(time_passed);
-    let mut rotation_matrix = starting_rotation_matrix;
-    let num_of_images = 5;
-    let image_shift_rotation_matrix = generate_equal_parts_rotation_matrix(num_of_images as f32);
-    for index in 0..num_of_images {
-        draw_rectangle_writer.write_builder(|builder| {
-            let mut draw_rectangle_builder = DrawRectangleBuilder::new(builder);
-            draw_rectangle_builder.add_asset_id(*scared_id);
-            let red = 0.25 * (index as f32).cos() + 0.75;
-            let green = 0.25 * (index as f32).sin() + 0.75;
-            draw_rectangle_builder.add_color(&void_public::event::graphics::Color::new(
-                red, green, 1., 1.,
-            ));
-            let position = center_point_vec3 + (rotation_matrix * scared_distance).extend(0.);
-            rotation_matrix *= image_shift_rotation_matrix;
-            let transform = TransformT {
-                position: Vec3T {
-                    x: position.x,
-                    y: position.y,
-                    z: position.z,
-                },
-                scale: Vec2T { x: 125., y: 125. },
-                rotation: (index as f32 + time_passed).sin(),
-                ..Default::default()
-            };
-            draw_rectangle_builder.add_transform(&transform.pack());
-            draw_rectangle_builder.finish()
-        });
+/// Removes every postprocess and adds them back with `from` and `to` swapped, keeping their
+/// current uniforms. `WorldRenderManager` applies postprocesses in the order they were added.
+fn swap_postprocesses(
+    from: usize,
+    to: usize,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+) -> local_error::Result<()> {
+    let mut postprocesses = world_render_manager
+        .postprocesses()
+        .iter()
+        .map(|postprocess| {
+            let material_id = *postprocess.material_id();
+            let material = gpu_interface
+                .material_manager
+                .get_material(material_id)
+                .ok_or_else(|| TestModuleError::AssetNotLoaded {
+                    asset_path: format!("material {material_id:?}"),
+                    reason: "the postprocess material was not found in the material manager"
+                        .to_string(),
+                })?;
+            Ok((material_id, material, postprocess.material_uniforms.clone()))
+        })
+        .collect::<local_error::Result<Vec<_>>>()?;
+    postprocesses.swap(from, to);
+
+    let material_ids = postprocesses
+        .iter()
+        .map(|(material_id, _, _)| *material_id)
+        .collect::<Vec<_>>();
+    world_render_manager.remove_postprocesses(&material_ids);
+    for (_, material, material_uniforms) in &postprocesses {
+        world_render_manager.add_or_update_postprocess(material, material_uniforms);
     }
 
-    rotation_matrix = starting_rotation_matrix;
-    let num_of_circles = 6;
-    let circle_shift_rotation_matrix = generate_equal_parts_rotation_matrix(num_of_circles as f32);
-    for index in 0..num_of_circles {
-        let position = center_point_vec2 + (rotation_matrix * circle_distance);
-        rotation_matrix *= circle_shift_rotation_matrix;
-        let r = 0.25 * (index as f32).sin() + 0.75;
-        let g = 0.25 * (index as f32).cos() + 0.75;
-        draw_circle_writer.write(
-            DrawCircleT {
-                position: Vec2T {
-                    x: position.x,
-                    y: position.y,
+    Ok(())
+}
+
+#[system]
+fn postprocess_inspector_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    view: &View,
+    postprocess_inspector: &mut PostprocessInspector,
+    world_render_manager: &mut WorldRenderManager,
+    mut inspector_text_query: Query<(&mut TextRender, &PostprocessInspectorText)>,
+) {
+    if inspector_text_query.is_empty() {
+        for index in 0..POSTPROCESS_INSPECTOR_LINES {
+            let y_percent =
+                ZeroToHundredPercent::new(0.9) - ZeroToHundredPercent::new(0.035) * index as f32;
+            let mut text_component_builder = create_new_text::<_, CustomText>(
+                CreateTextInput {
+                    text: "",
+                    visible: false,
+                    position: screen_space_coordinate_by_percent(aspect, 0.15.into(), y_percent)
+                        .extend(4500.),
+                    text_type: TextTypes::Custom(22.),
+                    ..Default::default()
                 },
-                z: 0.,
-                radius: 100.,
-                subdivisions: 32,
-                rotation: 0.,
-                color: ColorT { r, g, b: 1., a: 1. },
-            }
-            .pack(),
-        );
+                aspect,
+            );
+            text_component_builder.add_component(PostprocessInspectorText(index));
+            Engine::spawn(&text_component_builder.build());
+        }
+        return;
     }
 
-    rotation_matrix = starting_rotation_matrix;
-    let num_of_lines = 4;
-    let half_line_length = 35.;
-    let thickness = 20.;
-    let line_shift_rotation_matrix = generate_equal_parts_rotation_matrix(num_of_lines as f32);
-    for index in 0..num_of_lines {
-        let center_position = center_point_vec2 + (rotation_matrix * line_distance);
-        rotation_matrix *= line_shift_rotation_matrix;
-        let from_position = center_position - Vec2::new(half_line_length, 0.);
-        let to_position = center_position + Vec2::new(half_line_length, 0.);
-        let r = 0.25 * (index as f32).cos() + 0.75;
-        let g = 0.25 * (index as f32).sin() + 0.75;
-        draw_line_writer.write(
-            DrawLineT {
-                from: Vec2T {
-                    x: from_position.x,
-                    y: from_position.y,
-                },
-                to: Vec2T {
-                    x: to_position.x,
-                    y: to_position.y,
+    let postprocess_count = world_render_manager.postprocesses().len();
+    let in_postprocess_test =
+        matches!(view.view_state(), ViewState::Material((_, _))) && postprocess_count > 0;
+    if in_postprocess_test && is_postprocess_inspector_toggle_just_pressed(input_state) {
+        postprocess_inspector.visible = !postprocess_inspector.visible;
+    }
+    let visible = in_postprocess_test && postprocess_inspector.visible;
+
+    let lines = visible.then(|| {
+        postprocess_inspector.selected = postprocess_inspector
+            .selected
+            .min(postprocess_count.saturating_sub(1));
+
+        let up_pressed = is_up_just_pressed(input_state);
+        let down_pressed = is_down_just_pressed(input_state);
+        if up_pressed != down_pressed {
+            let selected = postprocess_inspector.selected;
+            if let Some(moved_to) = moved_index(selected, postprocess_count, up_pressed) {
+                if is_postprocess_move_modifier_pressed(input_state) {
+                    match swap_postprocesses(
+                        selected,
+                        moved_to,
+                        gpu_interface,
+                        world_render_manager,
+                    ) {
+                        Ok(()) => postprocess_inspector.selected = moved_to,
+                        Err(error) => error!("Could not move postprocess {selected}: {error}"),
+                    }
+                } else {
+                    postprocess_inspector.selected = moved_to;
+                }
+            }
+        }
+
+        let material_names = world_render_manager
+            .postprocesses()
+            .iter()
+            .map(|postprocess| {
+                let material_id = *postprocess.material_id();
+                gpu_interface
+                    .material_manager
+                    .get_material(material_id)
+                    .map_or_else(
+                        || format!("{material_id:?}"),
+                        |material| material.name().to_string(),
+                    )
+            })
+            .collect::<Vec<_>>();
+        inspector_lines(&material_names, postprocess_inspector.selected)
+    });
+
+    inspector_text_query.for_each(|(text_render, inspector_text)| {
+        if let Some(lines) = &lines {
+            text_render.text =
+                text_to_u8_array(lines.get(inspector_text.0).map_or("", String::as_str));
+        }
+        text_render.visible = visible;
+    });
+}
+
+/// A [`Resource`] for the WGSL overlay, see [`shader_source_viewer`]. The WGSL is generated when
+/// the overlay opens and kept until the running test's material changes.
+#[derive(Debug, Default, Resource)]
+pub struct ShaderSourceViewer {
+    pub visible: bool,
+    first_line: usize,
+    wgsl: Option<(MaterialId, String)>,
+}
+
+/// Marker [`Component`] for a line of the [`ShaderSourceViewer`], `.0` is the line index from
+/// the top
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct ShaderSourceViewerText(usize);
+
+#[system]
+fn shader_source_viewer_system(
+    aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    material_test_registry: &MaterialTestRegistry,
+    view: &View,
+    shader_source_viewer: &mut ShaderSourceViewer,
+    mut viewer_text_query: Query<(&mut TextRender, &ShaderSourceViewerText)>,
+) {
+    if viewer_text_query.is_empty() {
+        for index in 0..SHADER_SOURCE_VIEWER_LINES {
+            let y_percent =
+                ZeroToHundredPercent::new(0.92) - ZeroToHundredPercent::new(0.035) * index as f32;
+            let mut text_component_builder = create_new_text::<_, CustomText>(
+                CreateTextInput {
+                    text: "",
+                    visible: false,
+                    position: screen_space_coordinate_by_percent(aspect, 0.05.into(), y_percent)
+                        .extend(4500.),
+                    text_type: TextTypes::Custom(18.),
+                    ..Default::default()
                 },
-                z: 0.,
-                thickness,
-                color: ColorT { r, g, b: 1., a: 1. },
-            }
-            .pack(),
-        );
+                aspect,
+            );
+            text_component_builder.add_component(ShaderSourceViewerText(index));
+            Engine::spawn(&text_component_builder.build());
+        }
+        return;
+    }
+
+    let running_test = match view.view_state() {
+        ViewState::Material((material_test_id, name)) => material_test_registry
+            .get_by_id(*material_test_id)
+            .and_then(MaterialTestEntry::first_material_id)
+            .map(|material_id| (name, material_id)),
+        _ => None,
+    };
+    if running_test.is_some() && is_shader_source_toggle_just_pressed(input_state) {
+        shader_source_viewer.visible = !shader_source_viewer.visible;
     }
+    let visible = running_test.is_some() && shader_source_viewer.visible;
+
+    let lines = running_test.filter(|_| visible).map(|(name, material_id)| {
+        if shader_source_viewer
+            .wgsl
+            .as_ref()
+            .is_none_or(|(wgsl_material_id, _)| *wgsl_material_id != material_id)
+        {
+            let wgsl = gpu_interface
+                .material_manager
+                .generate_shader_text(material_id)
+                .unwrap_or_else(|error| format!("Could not generate WGSL: {error:?}"));
+            shader_source_viewer.wgsl = Some((material_id, wgsl));
+            shader_source_viewer.first_line = 0;
+        }
+        let Some((_, wgsl)) = &shader_source_viewer.wgsl else {
+            return vec![];
+        };
+
+        let page_lines = SHADER_SOURCE_PAGE_LINES as isize;
+        let scrolled_lines = if is_up_just_pressed(input_state) || is_scroll_up(input_state) {
+            -1
+        } else if is_down_just_pressed(input_state) || is_scroll_down(input_state) {
+            1
+        } else if is_page_up_just_pressed(input_state) {
+            -page_lines
+        } else if is_page_down_just_pressed(input_state) {
+            page_lines
+        } else {
+            0
+        };
+        shader_source_viewer.first_line = scrolled_first_line(
+            shader_source_viewer.first_line,
+            scrolled_lines,
+            wgsl.lines().count(),
+        );
+        viewer_lines(name, wgsl, shader_source_viewer.first_line)
+    });
+
+    viewer_text_query.for_each(|(text_render, viewer_text)| {
+        if let Some(lines) = &lines {
+            text_render.text =
+                text_to_u8_array(lines.get(viewer_text.0).map_or("", String::as_str));
+        }
+        text_render.visible = visible;
+    });
 }
 
-/// Currently this system uses non deterministic RNG code, once we have a RNG library in the Engine
-/// that portion should be replaced
-#[system_once]
-fn stress_test_startup_system(
+/// A [`Resource`] for the overlay comparing the running test's uniforms to their defaults, see
+/// [`mod@uniform_diff`]
+#[derive(Debug, Default, Resource)]
+pub struct UniformDiffOverlay {
+    pub visible: bool,
+    selected: usize,
+}
+
+/// Marker [`Component`] for a line of the [`UniformDiffOverlay`], `.0` is the line index from the
+/// top
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct UniformDiffText(usize);
+
+#[system]
+#[allow(clippy::too_many_arguments)]
+fn uniform_diff_system(
     aspect: &Aspect,
     gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    view: &View,
+    uniform_diff_overlay: &mut UniformDiffOverlay,
+    world_render_manager: &mut WorldRenderManager,
     material_test_query: Query<&MaterialTest>,
+    mut material_params_query: Query<(&mut MaterialParameters, &MaterialTestObject)>,
+    mut diff_text_query: Query<(&mut TextRender, &UniformDiffText)>,
 ) {
-    let Some(stress_test_material_test) = material_test_query
-        .iter()
-        .find(|material_test| material_test.name() == "stress_test")
-    else {
-        error!("Could not find stress_test material test");
-        return;
-    };
-    let mut materials_id_iter = stress_test_material_test.material_id_iter();
-    let Some(Some(desat_material_id)) = materials_id_iter.next() else {
-        error!("Could not find desat_material_id on stress_test");
-        return;
-    };
-    let Some(Some(pan_material_id)) = materials_id_iter.next() else {
-        error!("Could not find pan_material_id on stress_test");
-        return;
-    };
-    let Some(Some(default_sprite_material_id)) = materials_id_iter.next() else {
-        error!("Could not find default_sprite_material_id on stress_test");
+    if diff_text_query.is_empty() {
+        for index in 0..UNIFORM_DIFF_LINES {
+            let y_percent =
+                ZeroToHundredPercent::new(0.92) - ZeroToHundredPercent::new(0.035) * index as f32;
+            let mut text_component_builder = create_new_text::<_, CustomText>(
+                CreateTextInput {
+                    text: "",
+                    visible: false,
+                    position: screen_space_coordinate_by_percent(aspect, 0.55.into(), y_percent)
+                        .extend(4500.),
+                    text_type: TextTypes::Custom(20.),
+                    ..Default::default()
+                },
+                aspect,
+            );
+            text_component_builder.add_component(UniformDiffText(index));
+            Engine::spawn(&text_component_builder.build());
+        }
         return;
+    }
+
+    let material_test = match view.view_state() {
+        ViewState::Material((material_test_id, _)) => material_test_query
+            .iter()
+            .find(|material_test| material_test.id() == *material_test_id),
+        _ => None,
     };
-    let mut rng = thread_rng();
+    if material_test.is_some() && is_uniform_diff_toggle_just_pressed(input_state) {
+        uniform_diff_overlay.visible = !uniform_diff_overlay.visible;
+    }
+    let visible = material_test.is_some() && uniform_diff_overlay.visible;
 
-    let sprite_materials = [
-        gpu_interface
-            .material_manager
-            .get_material(default_sprite_material_id)
-            .unwrap(),
-        gpu_interface
-            .material_manager
-            .get_material(pan_material_id)
-            .unwrap(),
-        gpu_interface
+    let lines = material_test.filter(|_| visible).map(|material_test| {
+        let Some(Some(material_id)) = material_test.material_id_iter().next() else {
+            return vec![format!("{} has no material", material_test.name())];
+        };
+        let Some(default_uniforms) = gpu_interface
             .material_manager
-            .get_material(desat_material_id)
-            .unwrap(),
-    ];
+            .get_material(material_id)
+            .and_then(|material| material.generate_default_material_uniforms())
+        else {
+            return vec![format!(
+                "Could not generate the default uniforms of {}",
+                material_test.name()
+            )];
+        };
+        let Some(current_uniform_preset) = current_uniform_preset(
+            material_test,
+            material_id,
+            gpu_interface,
+            world_render_manager,
+            &mut material_params_query,
+        ) else {
+            return vec!["The test material is not on screen".to_string()];
+        };
+        let entries = uniform_diff(
+            &current_uniform_preset,
+            &uniform_preset_from_material_uniforms(&default_uniforms),
+        );
 
-    let scared_id = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/scared.png".into())
-        .unwrap()
-        .id();
+        let last_index = entries.len().min(UNIFORM_DIFF_LINES - 1).saturating_sub(1);
+        if is_up_just_pressed(input_state) {
+            uniform_diff_overlay.selected = uniform_diff_overlay.selected.saturating_sub(1);
+        } else if is_down_just_pressed(input_state) {
+            uniform_diff_overlay.selected += 1;
+        }
+        uniform_diff_overlay.selected = uniform_diff_overlay.selected.min(last_index);
 
-    for i in 0..32 {
-        let material = sprite_materials[i % sprite_materials.len()];
+        if is_uniform_reset_just_pressed(input_state) {
+            let reset_all = is_uniform_reset_all_modifier_pressed(input_state);
+            let uniform_preset = reset_preset(
+                &entries,
+                (!reset_all).then_some(uniform_diff_overlay.selected),
+            );
+            if uniform_preset.values.is_empty() {
+                toast::show("Already at the defaults", DEFAULT_TOAST_SECONDS);
+            } else {
+                apply_uniform_preset_to_material_test(
+                    &uniform_preset,
+                    material_test,
+                    material_id,
+                    gpu_interface,
+                    world_render_manager,
+                    &mut material_params_query,
+                );
+                let reset_names = uniform_preset
+                    .values
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                info!("Reset {reset_names} of {}", material_test.name());
+                toast::show(&format!("Reset {reset_names}"), DEFAULT_TOAST_SECONDS);
+            }
+        }
+        diff_lines(&entries, uniform_diff_overlay.selected)
+    });
 
-        let material_params = MaterialParameters::new(material.material_id())
-            .update_texture(&gpu_interface.material_manager, &("color_tex", &scared_id))
-            .unwrap()
-            .end_chain();
+    diff_text_query.for_each(|(text_render, diff_text)| {
+        if let Some(lines) = &lines {
+            text_render.text = text_to_u8_array(lines.get(diff_text.0).map_or("", String::as_str));
+        }
+        text_render.visible = visible;
+    });
+}
 
-        // This scales the velocity with the size of the window, using the
-        // width as a shorthand for that
-        let velocity_scalar = aspect.width * 0.15;
-        let velocity = Velocity {
-            direction: Vec3::new(
-                rng.gen_range(-velocity_scalar..velocity_scalar),
-                rng.gen_range(-velocity_scalar..velocity_scalar),
-                0.,
-            ),
-            rotation: rng.gen_range(-6.0..6.),
-        };
+/// A [`Resource`] for the entity and asset counts overlay, see [`entity_counts`]
+#[derive(Debug, Default, Resource)]
+pub struct EntityCountsOverlay {
+    pub visible: bool,
+    entity_counts: EntityCounts,
+    loaded_asset_counts: Option<LoadedAssetCounts>,
+    /// Leftover test objects are only flagged once the view has been out of a test for a frame,
+    /// despawns take effect the frame after the view changes
+    frames_outside_test: usize,
+}
 
-        let mut texture_component_builder = create_new_texture(
-            Vec3::new(
-                rng.gen_range(-1.0..1.) * aspect.width * 0.5,
-                rng.gen_range(-1.0..1.) * aspect.height * 0.5,
-                1.,
-            )
-            .into(),
-            Vec4::new(
-                rng.gen_range(0.5..3.0),
-                rng.gen_range(0.5..3.0),
-                rng.gen_range(0.5..3.0),
-                1.,
-            )
-            .into(),
-            scared_id,
-            Some(Vec2::new(
-                rng.gen_range(0.25..1.0) * aspect.width * 0.125,
-                rng.gen_range(0.25..1.0) * aspect.width * 0.125,
-            )),
-        );
-        texture_component_builder.add_components(bundle_for_builder!(
-            MaterialTestObject,
-            material_params,
-            velocity
-        ));
-        Engine::spawn(&texture_component_builder.build());
+/// Marker [`Component`] for a line of the [`EntityCountsOverlay`], `.0` is the line index from
+/// the top
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct EntityCountsText(usize);
+
+/// Counts the entities for [`entity_counts_overlay_system`], which can't read every
+/// [`TextRender`] while writing its own
+#[system]
+fn entity_counts_system(
+    view: &View,
+    entity_counts_overlay: &mut EntityCountsOverlay,
+    material_test_object_query: Query<&MaterialTestObject>,
+    text_render_query: Query<&TextRender>,
+    texture_render_query: Query<&TextureRender>,
+    material_params_query: Query<&MaterialParameters>,
+) {
+    if matches!(view.view_state(), ViewState::Material(_)) {
+        entity_counts_overlay.frames_outside_test = 0;
+    } else {
+        entity_counts_overlay.frames_outside_test += 1;
     }
-    set_system_enabled!(true, stress_test_system);
+    entity_counts_overlay.entity_counts = EntityCounts {
+        material_test_objects: material_test_object_query.len(),
+        text_renders: text_render_query.len(),
+        texture_renders: texture_render_query.len(),
+        material_parameters: material_params_query.len(),
+    };
 }
 
 #[system]
-fn stress_test_system(
+fn entity_counts_overlay_system(
     aspect: &Aspect,
-    frame_constants: &FrameConstants,
-    mut test_objects_query: Query<(
-        &MaterialTestObject,
-        &mut Transform,
-        &mut Velocity,
-        &mut MaterialParameters,
-    )>,
+    input_state: &InputState,
+    entity_counts_overlay: &mut EntityCountsOverlay,
+    mut entity_counts_text_query: Query<(&mut TextRender, &EntityCountsText)>,
 ) {
-    test_objects_query.for_each(|(_, transform, velocity, _)| {
-        transform
-            .position
-            .set(transform.position.get() + velocity.direction * frame_constants.delta_time);
-
-        let transform_position = transform.position.get();
-        if transform_position.x < -aspect.width * 0.5 && velocity.direction.x < 0.
-            || transform_position.x > aspect.width * 0.5 && velocity.direction.y > 0.
-        {
-            velocity.direction.x = -velocity.direction.x;
+    if entity_counts_text_query.is_empty() {
+        for index in 0..ENTITY_COUNTS_LINES {
+            let y_percent =
+                ZeroToHundredPercent::new(0.3) - ZeroToHundredPercent::new(0.035) * index as f32;
+            let mut text_component_builder = create_new_text::<_, CustomText>(
+                CreateTextInput {
+                    text: "",
+                    visible: false,
+                    position: screen_space_coordinate_by_percent(aspect, 0.15.into(), y_percent)
+                        .extend(4500.),
+                    text_type: TextTypes::Custom(20.),
+                    ..Default::default()
+                },
+                aspect,
+            );
+            text_component_builder.add_component(EntityCountsText(index));
+            Engine::spawn(&text_component_builder.build());
         }
+        return;
+    }
 
-        if transform_position.y < -aspect.height * 0.5 && velocity.direction.y < 0.
-            || transform_position.y > aspect.height * 0.5 && velocity.direction.y > 0.
-        {
-            velocity.direction.y = -velocity.direction.y;
-        }
+    if is_entity_counts_toggle_just_pressed(input_state) {
+        entity_counts_overlay.visible = !entity_counts_overlay.visible;
+    }
+    let lines = entity_counts_overlay.visible.then(|| {
+        entity_counts_lines(
+            &entity_counts_overlay.entity_counts,
+            entity_counts_overlay.loaded_asset_counts.as_ref(),
+            entity_counts_overlay.frames_outside_test > 1,
+        )
+    });
 
-        transform.rotation += velocity.rotation * frame_constants.delta_time;
+    entity_counts_text_query.for_each(|(text_render, entity_counts_text)| {
+        if let Some(lines) = &lines {
+            text_render.text =
+                text_to_u8_array(lines.get(entity_counts_text.0).map_or("", String::as_str));
+        }
+        text_render.visible = entity_counts_overlay.visible;
     });
 }
 
-fn invert_y_scared_distance(aspect: &Aspect) -> Vec2 {
-    Vec2::new(aspect.width * 0.3, 0.)
-}
+/// Frame times at or above this many seconds reach the top of the sparkline
+const PERF_OVERLAY_SPARKLINE_CEILING: f32 = 1. / 30.;
 
-#[system_once]
-fn invert_y_startup_system(
+#[system]
+fn perf_overlay_system(
     aspect: &Aspect,
-    gpu_interface: &GpuInterface,
-    world_render_manager: &mut WorldRenderManager,
-    material_test_query: Query<&mut MaterialTest>,
+    frame_constants: &FrameConstants,
+    input_state: &InputState,
+    test_clock: &TestClock,
+    view: &View,
+    perf_overlay: &mut PerfOverlay,
+    draw_line_writer: EventWriter<DrawLine>,
+    mut perf_overlay_texts: Query<(&mut TextRender, &PerfOverlayText)>,
 ) {
-    let scared_distance = invert_y_scared_distance(aspect);
-    let Some(material_test) = material_test_query
-        .iter()
-        .find(|material_test| material_test.name() == "invert_y")
-    else {
-        error!("Could not find invert_y material test");
-        return;
-    };
-    let Some(Some(material_id)) = material_test.material_id_iter().next() else {
-        error!("invert_y material test is missing expected material_id");
-        return;
-    };
+    perf_overlay
+        .frame_time_history
+        .push(frame_constants.delta_time);
 
-    let material = gpu_interface
-        .material_manager
-        .get_material(material_id)
-        .unwrap();
-    let material_uniforms = MaterialUniforms::empty(material_id);
+    if is_perf_overlay_toggle_just_pressed(input_state) {
+        perf_overlay.visible = !perf_overlay.visible;
+    }
 
-    world_render_manager.add_or_update_postprocess(material, &material_uniforms);
+    if !matches!(view.view_state(), ViewState::Material((_, _))) {
+        return;
+    }
 
-    let arrow_up_id = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/arrow_up.png".into())
-        .unwrap()
-        .id();
-    let scared_id = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/scared.png".into())
-        .unwrap()
-        .id();
+    let overlay_text = perf_overlay
+        .frame_time_history
+        .stats()
+        .map(|stats| {
+            format!(
+                "{}  time x{:.2}",
+                stats.to_overlay_string(frame_constants.frame_rate),
+                test_clock.time_scale
+            )
+        })
+        .unwrap_or_default();
+    if perf_overlay_texts.is_empty() {
+        let mut text_component_builder = create_new_text::<_, CustomText>(
+            CreateTextInput {
+                text: overlay_text,
+                visible: perf_overlay.visible,
+                position: screen_space_coordinate_by_percent(aspect, 0.2.into(), 0.975.into())
+                    .extend(4000.),
+                text_type: TextTypes::Custom(24.),
+                ..Default::default()
+            },
+            aspect,
+        );
+        text_component_builder
+            .add_components(bundle_for_builder!(MaterialTestObject, PerfOverlayText));
+        Engine::spawn(&text_component_builder.build());
+    } else {
+        perf_overlay_texts.for_each(|(text_render, _)| {
+            text_render.text = text_to_u8_array(&overlay_text);
+            text_render.visible = perf_overlay.visible;
+        });
+    }
 
-    let mut texture_component_builder = create_new_texture(
-        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
-            .extend(0.)
-            .into(),
-        *palette::WHITE,
-        arrow_up_id,
-        Some(Vec2::splat(aspect.width * 0.08)),
-    );
-    texture_component_builder.add_component(MaterialTestObject);
-    Engine::spawn(&texture_component_builder.build());
+    if !perf_overlay.visible {
+        return;
+    }
 
-    let mut texture_component_builder = create_new_texture(
-        scared_distance.extend(0.).into(),
-        *palette::WHITE,
-        scared_id,
-        Some(Vec2::splat(aspect.width * 0.11)),
+    let sparkline_origin = screen_space_coordinate_by_percent(aspect, 0.02.into(), 0.88.into());
+    let sparkline_size = Vec2::new(aspect.width * 0.25, aspect.height * 0.07);
+    let sparkline_points = sparkline_points(
+        &perf_overlay.frame_time_history,
+        sparkline_origin,
+        sparkline_size,
+        PERF_OVERLAY_SPARKLINE_CEILING,
     );
-    texture_component_builder.add_components(bundle_for_builder!(
-        MaterialTestObject,
-        TimePassedSinceCreation::default()
-    ));
-    Engine::spawn(&texture_component_builder.build());
-
-    let mut text_component_builder = create_new_text::<_, HeaderText>(CreateTextInput {
-        position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.7.into()).extend(0.),
-        text: "This is up",
-        ..Default::default()
-    });
-    text_component_builder.add_component(MaterialTestObject);
-    Engine::spawn(&text_component_builder.build());
-    set_system_enabled!(true, invert_y_system);
+    for points in sparkline_points.windows(2) {
+        draw_line_writer.write(
+            DrawLineT {
+                from: Vec2T {
+                    x: points[0].x,
+                    y: points[0].y,
+                },
+                to: Vec2T {
+                    x: points[1].x,
+                    y: points[1].y,
+                },
+                z: 4000.,
+                thickness: 2.,
+                color: ColorT {
+                    r: 0.4,
+                    g: 1.,
+                    b: 0.4,
+                    a: 1.,
+                },
+            }
+            .pack(),
+        );
+    }
 }
 
-#[system]
-fn invert_y_system(
-    aspect: &Aspect,
-    frame_constants: &FrameConstants,
-    mut texture_query: Query<(&mut Transform, &TextureRender, &mut TimePassedSinceCreation)>,
-) {
-    let scared_distance = invert_y_scared_distance(aspect);
-    texture_query.for_each(|(transform, _, time_passed_since_creation)| {
-        *time_passed_since_creation += frame_constants.delta_time;
-        let rotation_matrix = Mat2::from_angle(***time_passed_since_creation);
-        transform.position = (rotation_matrix * scared_distance).extend(0.).into();
-        transform.rotation += (***time_passed_since_creation).cos() / 8.;
-    });
+/// A [`Component`] allowing a sprite material test to A/B its material against
+/// [`DefaultMaterials::Sprite`]. Pressing M swaps the entity's [`MaterialParameters`] with the
+/// stashed set, so exactly what the shader changes can be eyeballed on the same sprite.
+///
+/// Systems that update uniforms every frame should go through
+/// [`MaterialAbToggle::test_material_parameters_mut`], so they keep updating the test material
+/// while the default sprite material is displayed.
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct MaterialAbToggle {
+    showing_default: bool,
+    stashed_material_params: MaterialParameters,
 }
 
-fn test_post_scared_distance(aspect: &Aspect) -> Vec2 {
-    Vec2::new(aspect.width * 0.3, 0.)
+impl MaterialAbToggle {
+    pub fn for_default_sprite(material_manager: &MaterialManager, texture_id: TextureId) -> Self {
+        let default_material_params =
+            MaterialParameters::new(DefaultMaterials::Sprite.material_id())
+                .update_texture(material_manager, &("color_tex", &texture_id))
+                .unwrap()
+                .end_chain();
+        Self {
+            showing_default: false,
+            stashed_material_params: default_material_params,
+        }
+    }
+
+    pub fn showing_default(&self) -> bool {
+        self.showing_default
+    }
+
+    pub fn toggle(&mut self, material_params: &mut MaterialParameters) {
+        std::mem::swap(&mut self.stashed_material_params, material_params);
+        self.showing_default = !self.showing_default;
+    }
+
+    /// Returns whichever [`MaterialParameters`] currently belong to the test material
+    pub fn test_material_parameters_mut<'a>(
+        &'a mut self,
+        material_params: &'a mut MaterialParameters,
+    ) -> &'a mut MaterialParameters {
+        if self.showing_default {
+            &mut self.stashed_material_params
+        } else {
+            material_params
+        }
+    }
 }
 
-#[system_once]
-fn test_post_startup_system(
-    aspect: &Aspect,
-    gpu_interface: &GpuInterface,
-    world_render_manager: &mut WorldRenderManager,
-    material_test_query: Query<&MaterialTest>,
-) {
-    let scared_distance = test_post_scared_distance(aspect);
-    let Some(material_test) = material_test_query
-        .iter()
-        .find(|material_test| material_test.name() == "test_post")
-    else {
-        error!("Could not find test_post material test");
-        return;
-    };
-    let Some(Some(material_id)) = material_test.material_id_iter().next() else {
-        error!("test_post material test is missing expected material_id");
-        return;
-    };
+/// The maximum number of key bindings a single [`TestControls`] can describe
+pub const TEST_CONTROLS_LIMIT: usize = 8;
 
-    let material = gpu_interface
-        .material_manager
-        .get_material(material_id)
-        .unwrap();
+/// A single key binding and what it does in a material test
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct TestControl {
+    key: [u8; 32],
+    #[serde(with = "BigArray")]
+    description: [u8; 96],
+}
 
-    let material_uniforms = MaterialUniforms::empty(material_id);
+impl Default for TestControl {
+    fn default() -> Self {
+        Self {
+            key: [0; 32],
+            description: [0; 96],
+        }
+    }
+}
 
-    world_render_manager.add_or_update_postprocess(material, &material_uniforms);
+impl TestControl {
+    pub fn new(key: &str, description: &str) -> Self {
+        Self {
+            key: text_to_u8_array(key),
+            description: text_to_u8_array(description),
+        }
+    }
 
-    let arrow_up_id = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/arrow_up.png".into())
-        .unwrap()
-        .id();
-    let scared_id = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/scared.png".into())
-        .unwrap()
-        .id();
+    pub fn key(&self) -> &str {
+        u8_array_to_str(&self.key).unwrap()
+    }
 
-    let mut texture_component_builder = create_new_texture(
-        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
-            .extend(0.)
-            .into(),
-        *palette::WHITE,
-        arrow_up_id,
-        Some(Vec2::splat(aspect.width * 0.08)),
-    );
-    texture_component_builder.add_component(MaterialTestObject);
-    Engine::spawn(&texture_component_builder.build());
+    pub fn description(&self) -> &str {
+        u8_array_to_str(&self.description).unwrap()
+    }
+}
 
-    let mut texture_component_builder = create_new_texture(
-        scared_distance.extend(0.).into(),
-        *palette::WHITE,
-        scared_id,
-        Some(Vec2::splat(aspect.width * 0.11)),
-    );
+/// A [`Component`] listing the key bindings of a material test. Startup systems spawn this with
+/// [`MaterialTestObject`] and [`test_controls_system`] renders it as a legend, followed by the keys
+/// every test shares from the key bindings.
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct TestControls {
+    controls: [TestControl; TEST_CONTROLS_LIMIT],
+}
 
-    texture_component_builder.add_components(bundle_for_builder!(
-        MaterialTestObject,
-        TimePassedSinceCreation::default()
-    ));
-    Engine::spawn(&texture_component_builder.build());
+impl TestControls {
+    pub fn new(controls: &[(&str, &str)]) -> Self {
+        Self {
+            controls: array_from_iterator(
+                controls
+                    .iter()
+                    .map(|(key, description)| TestControl::new(key, description)),
+            ),
+        }
+    }
 
-    let mut text_component_builder = create_new_text::<_, HeaderText>(CreateTextInput {
-        position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.7.into()).extend(0.),
-        text: "This is up",
-        ..Default::default()
-    });
-    text_component_builder.add_component(MaterialTestObject);
-    Engine::spawn(&text_component_builder.build());
-    set_system_enabled!(true, test_post_system);
+    pub fn iter(&self) -> impl Iterator<Item = &TestControl> {
+        self.controls
+            .iter()
+            .take_while(|test_control| !test_control.key().is_empty())
+    }
 }
 
+/// Marker [`Component`] for the text lines rendered from [`TestControls`]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct TestControlsText;
+
 #[system]
-fn test_post_system(
+fn test_controls_system(
     aspect: &Aspect,
-    frame_constants: &FrameConstants,
-    mut texture_query: Query<(&mut Transform, &TextureRender, &mut TimePassedSinceCreation)>,
+    input_state: &InputState,
+    view: &View,
+    test_controls_query: Query<&TestControls>,
+    mut test_controls_text_query: Query<(&mut TextRender, &TestControlsText)>,
 ) {
-    let scared_distance = test_post_scared_distance(aspect);
-    texture_query.for_each(|(transform, _, time_passed_since_creation)| {
-        *time_passed_since_creation += frame_constants.delta_time;
-        let rotation_matrix = Mat2::from_angle(***time_passed_since_creation);
-        transform.position = (rotation_matrix * scared_distance).extend(0.).into();
-        transform.rotation += (***time_passed_since_creation).cos() / 8.;
-    });
+    if !matches!(view.view_state(), ViewState::Material((_, _))) {
+        return;
+    }
+
+    if test_controls_text_query.is_empty() {
+        let mut lines = test_controls_query
+            .iter()
+            .flat_map(|test_controls| {
+                test_controls
+                    .iter()
+                    .map(|test_control| {
+                        format!("{}: {}", test_control.key(), test_control.description())
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        if lines.is_empty() {
+            return;
+        }
+        // The keys every test shares come from the loaded key bindings
+        lines.extend(InputAction::HELP_ACTIONS.into_iter().map(help_line));
+        lines.push("Ctrl+R: restart test".to_string());
+
+        let line_count = lines.len();
+        for (index, line) in lines.into_iter().enumerate() {
+            let y_percent = ZeroToHundredPercent::new(0.05)
+                + ZeroToHundredPercent::new(0.035) * (line_count - 1 - index) as f32;
+            let mut text_component_builder = create_new_text::<_, CustomText>(
+                CreateTextInput {
+                    text: line,
+                    position: screen_space_coordinate_by_percent(aspect, 0.85.into(), y_percent)
+                        .extend(4000.),
+                    text_type: TextTypes::Custom(24.),
+                    ..Default::default()
+                },
+                aspect,
+            );
+            text_component_builder
+                .add_components(bundle_for_builder!(MaterialTestObject, TestControlsText));
+            Engine::spawn(&text_component_builder.build());
+        }
+    } else if is_help_toggle_just_pressed(input_state) {
+        test_controls_text_query.for_each(|(text_render, _)| {
+            text_render.visible = !text_render.visible;
+        });
+    }
 }
 
-fn warp_scared_distance(aspect: &Aspect) -> Vec2 {
-    Vec2::new(aspect.width * 0.3, 0.)
+/// A [`Resource`] for the split view of sprite tests, see [`split_view`]. While it's on the test's
+/// sprites are hidden and mirrored by [`SplitViewSprite`] copies, which aren't
+/// [`MaterialTestObject`]s so the test's systems leave them alone. Copies of a sprite with the
+/// test's own material follow its uniforms, other variants keep their defaults, and every copy
+/// samples the sprite's texture untinted.
+#[derive(Debug, Default, Resource)]
+pub struct SplitView {
+    pub active: bool,
+    /// The running test's materials followed by the default sprite material
+    variant_materials: Vec<MaterialId>,
+    variants: SplitViewVariants,
+    /// Every test sprite the split view hid, the first [`SPLIT_VIEW_SPRITE_LIMIT`] are mirrored
+    sources: Vec<EntityId>,
+    /// How many of the sources have copies with the current variants
+    mirrored_count: usize,
+    labels_spawned: bool,
 }
 
-#[system_once]
-fn warp_startup_system(
+/// Marker [`Component`] for a [`SplitView`] copy of a test sprite
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct SplitViewSprite {
+    quadrant: usize,
+    /// The index of the mirrored sprite in [`SplitView`]'s sources
+    source_index: usize,
+}
+
+/// Marker [`Component`] for the text naming a [`SplitView`] quadrant's variant
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct SplitViewLabel;
+
+/// A mirrored sprite as the test left it this frame
+struct SplitViewSource {
+    position: Vec3,
+    scale: Vec2,
+    rotation: f32,
+    texture_id: TextureId,
+    material_id: MaterialId,
+    uniform_preset: Option<UniformPreset>,
+}
+
+fn despawn_split_view_copies(
+    split_view_sprite_query: &Query<(
+        &EntityId,
+        &mut Transform,
+        &mut MaterialParameters,
+        &SplitViewSprite,
+    )>,
+    split_view_label_query: &Query<(&EntityId, &SplitViewLabel)>,
+) {
+    split_view_sprite_query
+        .iter()
+        .for_each(|query_components_ref| {
+            let (entity_id, _, _, _) = query_components_ref.unpack();
+            Engine::despawn(**entity_id);
+        });
+    split_view_label_query
+        .iter()
+        .for_each(|query_components_ref| {
+            let (entity_id, _) = query_components_ref.unpack();
+            Engine::despawn(**entity_id);
+        });
+}
+
+/// Runs after the test systems, so the copies mirror where the test moved its sprites this frame
+#[system]
+#[allow(clippy::too_many_arguments)]
+fn split_view_system(
     aspect: &Aspect,
     gpu_interface: &GpuInterface,
-    world_render_manager: &mut WorldRenderManager,
+    input_state: &InputState,
+    uniform_entry: &UniformEntry,
+    view: &View,
+    split_view: &mut SplitView,
     material_test_query: Query<&MaterialTest>,
+    mut sprite_query: Query<(
+        &EntityId,
+        &Transform,
+        &mut TextureRender,
+        &MaterialParameters,
+        &MaterialTestObject,
+    )>,
+    mut split_view_sprite_query: Query<(
+        &EntityId,
+        &mut Transform,
+        &mut MaterialParameters,
+        &SplitViewSprite,
+    )>,
+    split_view_label_query: Query<(&EntityId, &SplitViewLabel)>,
 ) {
-    let scared_distance = warp_scared_distance(aspect);
-    let Some(material_test) = material_test_query
-        .iter()
-        .find(|material_test| material_test.name() == "warp")
-    else {
-        error!("Could not find warp material test");
-        return;
-    };
-    let Some(Some(material_id)) = material_test.material_id_iter().next() else {
-        error!("warp material test is missing expected material_id");
-        return;
+    let material_test = match view.view_state() {
+        ViewState::Material((material_test_id, _)) => material_test_query
+            .iter()
+            .find(|material_test| material_test.id() == *material_test_id),
+        _ => None,
     };
+    if is_split_view_toggle_just_pressed(input_state) {
+        match material_test {
+            Some(material_test) if *material_test.material_type() == MaterialType::Sprite => {
+                split_view.active = !split_view.active;
+                if split_view.active {
+                    let mut variant_materials = material_test
+                        .material_id_iter()
+                        .flatten()
+                        .collect::<Vec<_>>();
+                    let default_material_id = DefaultMaterials::Sprite.material_id();
+                    if !variant_materials.contains(&default_material_id) {
+                        variant_materials.push(default_material_id);
+                    }
+                    split_view.variants = SplitViewVariants::new(variant_materials.len());
+                    split_view.variant_materials = variant_materials;
+                }
+            }
+            Some(_) => toast::show(
+                "The split view only works in sprite tests",
+                DEFAULT_TOAST_SECONDS,
+            ),
+            None => {}
+        }
+    }
+    // Leaving the test turns the split view off, the test's sprites are despawned with it
+    if material_test.is_none() {
+        split_view.active = false;
+    }
 
-    let material = gpu_interface
-        .material_manager
-        .get_material(material_id)
-        .unwrap();
-    let material_uniforms = material.generate_default_material_uniforms().unwrap();
-
-    world_render_manager.add_or_update_postprocess(material, material_uniforms);
+    if !split_view.active {
+        if split_view.sources.is_empty() && !split_view.labels_spawned {
+            return;
+        }
+        despawn_split_view_copies(&split_view_sprite_query, &split_view_label_query);
+        sprite_query.for_each(|(entity_id, _, texture_render, _, _)| {
+            if split_view.sources.contains(entity_id) {
+                texture_render.visible = true;
+            }
+        });
+        *split_view = SplitView::default();
+        return;
+    }
 
-    let arrow_up_id = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/arrow_up.png".into())
-        .unwrap()
-        .id();
-    let scared_id = gpu_interface
-        .texture_asset_manager
-        .get_texture_by_path(&"textures/scared.png".into())
-        .unwrap()
-        .id();
+    if !uniform_entry.is_open() {
+        if let Some(quadrant) = split_view_quadrant_just_pressed(input_state) {
+            // Every copy and label is respawned with the new variants
+            split_view.variants.cycle(quadrant);
+            despawn_split_view_copies(&split_view_sprite_query, &split_view_label_query);
+            split_view.mirrored_count = 0;
+            split_view.labels_spawned = false;
+        }
+    }
 
-    let mut texture_component_builder = create_new_texture(
-        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into())
-            .extend(0.)
-            .into(),
-        *palette::WHITE,
-        arrow_up_id,
-        Some(Vec2::splat(aspect.width * 0.08)),
+    let material_manager = &gpu_interface.material_manager;
+    let mut mirrored_sources = Vec::<Option<SplitViewSource>>::new();
+    sprite_query.for_each(
+        |(entity_id, transform, texture_render, material_params, _)| {
+            let source_index = match split_view
+                .sources
+                .iter()
+                .position(|source| source == entity_id)
+            {
+                Some(source_index) => source_index,
+                // Sprites the test spawns or shows later are hidden and mirrored as well
+                None if texture_render.visible => {
+                    split_view.sources.push(*entity_id);
+                    split_view.sources.len() - 1
+                }
+                None => return,
+            };
+            texture_render.visible = false;
+            if source_index >= SPLIT_VIEW_SPRITE_LIMIT {
+                return;
+            }
+            if mirrored_sources.len() <= source_index {
+                mirrored_sources.resize_with(source_index + 1, || None);
+            }
+            let uniform_preset = material_manager
+                .get_material(material_params.material_id)
+                .and_then(|material| material.get_current_uniforms(&material_params.data).ok())
+                .map(|material_uniforms| uniform_preset_from_material_uniforms(&material_uniforms));
+            mirrored_sources[source_index] = Some(SplitViewSource {
+                position: transform.position.get(),
+                scale: transform.scale.get(),
+                rotation: transform.rotation,
+                texture_id: texture_render.texture_id,
+                material_id: material_params.material_id,
+                uniform_preset,
+            });
+        },
     );
-    texture_component_builder.add_component(MaterialTestObject);
-    Engine::spawn(&texture_component_builder.build());
 
-    let mut texture_component_builder = create_new_texture(
-        scared_distance.extend(0.).into(),
-        *palette::WHITE,
-        scared_id,
-        Some(Vec2::splat(aspect.width * 0.11)),
+    let screen_size = Vec2::new(aspect.width, aspect.height);
+    split_view_sprite_query.for_each(
+        |(entity_id, transform, material_params, split_view_sprite)| {
+            let Some(Some(source)) = mirrored_sources.get(split_view_sprite.source_index) else {
+                // The test despawned the sprite
+                Engine::despawn(**entity_id);
+                return;
+            };
+            let (position, scale) = quadrant_transform(
+                source.position.truncate(),
+                source.scale,
+                split_view_sprite.quadrant,
+                screen_size,
+            );
+            transform.position.set(position.extend(source.position.z));
+            transform.scale.set(scale);
+            transform.rotation = source.rotation;
+
+            let Some(uniform_preset) = source
+                .uniform_preset
+                .as_ref()
+                .filter(|_| material_params.material_id == source.material_id)
+            else {
+                return;
+            };
+            let mut material_uniforms = material_params
+                .as_material_uniforms(material_manager)
+                .unwrap();
+            apply_uniform_preset(uniform_preset, &mut material_uniforms);
+            material_params
+                .update_from_material_uniforms(&material_uniforms)
+                .unwrap();
+        },
     );
-    texture_component_builder.add_components(bundle_for_builder!(
-        MaterialTestObject,
-        TimePassedSinceCreation::default()
-    ));
-    Engine::spawn(&texture_component_builder.build());
 
-    let mut text_component_builder = create_new_text::<_, HeaderText>(CreateTextInput {
-        position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.7.into()).extend(0.),
-        text: "This is up",
-        ..Default::default()
-    });
-    text_component_builder.add_component(MaterialTestObject);
-    Engine::spawn(&text_component_builder.build());
-    set_system_enabled!(true, warp_system);
-}
-
-#[system]
-fn warp_system(
-    aspect: &Aspect,
-    frame_constants: &FrameConstants,
-    world_render_manager: &mut WorldRenderManager,
-    material_test_query: Query<&MaterialTest>,
-    mut texture_query: Query<(&mut Transform, &TextureRender, &mut TimePassedSinceCreation)>,
-) {
-    let scared_distance = warp_scared_distance(aspect);
-    let Some(material_test) = material_test_query
+    let mirror_count = split_view.sources.len().min(SPLIT_VIEW_SPRITE_LIMIT);
+    for (source_index, source) in mirrored_sources
         .iter()
-        .find(|material_test| material_test.name() == "warp")
-    else {
-        error!("Could not find warp material test");
-        return;
-    };
-    let Some(Some(material_id)) = material_test.material_id_iter().next() else {
-        error!("warp material test is missing expected material_id");
-        return;
-    };
+        .enumerate()
+        .take(mirror_count)
+        .skip(split_view.mirrored_count)
+    {
+        let Some(source) = source else {
+            continue;
+        };
+        for quadrant in 0..SPLIT_VIEW_QUADRANTS {
+            let material_id = split_view.variant_materials[split_view.variants.variant(quadrant)];
+            let (position, scale) = quadrant_transform(
+                source.position.truncate(),
+                source.scale,
+                quadrant,
+                screen_size,
+            );
+            let material_params = MaterialParameters::new(material_id)
+                .update_texture(material_manager, &("color_tex", &source.texture_id))
+                .map(|material_params| material_params.end_chain())
+                .unwrap_or_else(|_| MaterialParameters::new(material_id));
+            let split_view_sprite = SplitViewSprite {
+                quadrant,
+                source_index,
+            };
+            let mut texture_component_builder = create_new_texture(
+                position.extend(source.position.z).into(),
+                *palette::WHITE,
+                source.texture_id,
+                Some(scale),
+            );
+            texture_component_builder
+                .add_components(bundle_for_builder!(material_params, split_view_sprite));
+            Engine::spawn(&texture_component_builder.build());
+        }
+    }
+    split_view.mirrored_count = mirror_count;
+
+    if !split_view.labels_spawned {
+        for quadrant in 0..SPLIT_VIEW_QUADRANTS {
+            let material_id = split_view.variant_materials[split_view.variants.variant(quadrant)];
+            let material_name = material_manager.get_material(material_id).map_or_else(
+                || format!("{material_id:?}"),
+                |material| material.name().to_string(),
+            );
+            let mut text_component_builder = create_new_text::<_, CustomText>(
+                CreateTextInput {
+                    text: &format!("{}: {material_name}", quadrant + 1),
+                    position: quadrant_label_position(quadrant, screen_size).extend(4000.),
+                    text_type: TextTypes::Custom(aspect.height * 0.03),
+                    ..Default::default()
+                },
+                aspect,
+            );
+            text_component_builder.add_component(SplitViewLabel);
+            Engine::spawn(&text_component_builder.build());
+        }
+        split_view.labels_spawned = true;
+    }
+}
 
-    texture_query.for_each(|(transform, _, time_passed_since_creation)| {
-        *time_passed_since_creation += frame_constants.delta_time;
-        let rotation_matrix = Mat2::from_angle(***time_passed_since_creation);
-        transform.position = (rotation_matrix * scared_distance).extend(0.).into();
-        transform.rotation += (***time_passed_since_creation).cos() / 8.;
-    });
+/// A [`Resource`] for the backgrounds drawn behind material tests, see [`background`]
+#[derive(Debug, Default, Resource)]
+pub struct SceneBackground {
+    test_backgrounds: TestBackgrounds,
+    /// The test, background and screen size the [`BackgroundSprite`] was spawned for
+    shown: Option<(String, Background, Vec2)>,
+}
 
-    let current_material_uniforms = &mut world_render_manager
-        .get_postprocess_by_material_id_mut(material_id)
-        .unwrap()
-        .material_uniforms;
+/// Marker [`Component`] for the sprite drawing the [`SceneBackground`]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct BackgroundSprite;
 
-    let warp_factor = current_material_uniforms.get("param_0").unwrap();
+/// Behind the tests' sprites, which are drawn from z 0 up
+const BACKGROUND_Z: f32 = -100.;
 
-    let new_value = match warp_factor {
-        UniformValue::Array(_) => unreachable!(),
-        UniformValue::F32(uniform_var) => {
-            let current_value = uniform_var.current_value();
-            const INCREMENT_FACTOR: f32 = 0.0005;
-            current_value + INCREMENT_FACTOR
+/// The texture and tint drawing `background`, the checkerboard is uploaded the first time it's
+/// needed
+fn background_texture(
+    gpu_interface: &mut GpuInterface,
+    new_texture_event_writer: &EventWriter<NewTexture>,
+    background: Background,
+) -> local_error::Result<Option<(TextureId, Vec4)>> {
+    let (texture_id, color) = match background {
+        Background::None => return Ok(None),
+        Background::Solid(index) => (
+            require_texture(gpu_interface, BACKGROUND_SOLID_TEXTURE_PATH)?,
+            Vec4::from_array(BACKGROUND_COLORS[index].1),
+        ),
+        Background::Photo => (
+            require_texture(gpu_interface, BACKGROUND_PHOTO_TEXTURE_PATH)?,
+            Vec4::ONE,
+        ),
+        Background::Checker => {
+            let texture_asset_manager = &mut gpu_interface.texture_asset_manager;
+            let texture_id = match texture_asset_manager
+                .get_texture_by_path(&BACKGROUND_CHECKER_TEXTURE_PATH.into())
+            {
+                Some(texture) => texture.id(),
+                None => texture_asset_manager
+                    .load_texture_by_pixels(
+                        &BACKGROUND_CHECKER_TEXTURE_PATH.into(),
+                        BACKGROUND_CHECKER_SIZE,
+                        BACKGROUND_CHECKER_SIZE,
+                        &checker_pixels(BACKGROUND_CHECKER_SIZE),
+                        false,
+                        new_texture_event_writer,
+                    )
+                    .map_err(|error| TestModuleError::AssetNotLoaded {
+                        asset_path: BACKGROUND_CHECKER_TEXTURE_PATH.to_string(),
+                        reason: format!("{error:?}"),
+                    })?
+                    .id(),
+            };
+            (texture_id, Vec4::ONE)
         }
-        UniformValue::Vec4(_) => unreachable!(),
     };
-
-    current_material_uniforms
-        .update("param_0", new_value.into())
-        .unwrap();
+    Ok(Some((texture_id, color)))
 }
 
-#[derive(Debug, Component, serde::Deserialize, serde::Serialize)]
-pub struct FpsCounter;
-
+/// C cycles the running test's background. The background sprite is respawned whenever the test,
+/// its background or the screen size changes, and despawned outside of tests.
 #[system]
-fn fps_system(
+fn background_system(
     aspect: &Aspect,
-    frame_constants: &FrameConstants,
+    gpu_interface: &mut GpuInterface,
+    input_state: &InputState,
     view: &View,
-    mut fps_counters: Query<(&mut TextRender, &FpsCounter)>,
+    scene_background: &mut SceneBackground,
+    new_texture_event_writer: EventWriter<NewTexture>,
+    background_sprite_query: Query<(&EntityId, &BackgroundSprite)>,
 ) {
-    if matches!(view.view_state(), ViewState::Material((_, _))) {
-        let fps_text = format!("FPS: {}", frame_constants.frame_rate);
-        if fps_counters.is_empty() {
-            let mut text_component_builder = create_new_text::<_, CustomText>(CreateTextInput {
-                text: fps_text,
-                position: screen_space_coordinate_by_percent(aspect, 0.05.into(), 0.975.into())
-                    .extend(4000.),
-                text_type: TextTypes::Custom(24.),
-                ..Default::default()
-            });
-            text_component_builder
-                .add_components(bundle_for_builder!(MaterialTestObject, FpsCounter));
-            Engine::spawn(&text_component_builder.build());
-        } else {
-            fps_counters.for_each(|(text_render, _)| {
-                text_render.text = str_to_u8_array(&fps_text);
-            });
+    let test_name = match view.view_state() {
+        ViewState::Material((_, name)) => Some(name.to_string()),
+        _ => None,
+    };
+    if let Some(test_name) = &test_name {
+        if is_background_cycle_just_pressed(input_state) {
+            let background = scene_background.test_backgrounds.cycle(test_name);
+            toast::show(
+                &format!("Background: {}", background.name()),
+                DEFAULT_TOAST_SECONDS,
+            );
         }
     }
+
+    let screen_size = Vec2::new(aspect.width, aspect.height);
+    let wanted = test_name.map(|test_name| {
+        let background = scene_background.test_backgrounds.get(&test_name);
+        (test_name, background, screen_size)
+    });
+    if wanted == scene_background.shown {
+        return;
+    }
+    background_sprite_query
+        .iter()
+        .for_each(|query_components_ref| {
+            let (entity_id, _) = query_components_ref.unpack();
+            Engine::despawn(**entity_id);
+        });
+    let background = wanted
+        .as_ref()
+        .map_or(Background::None, |(_, background, _)| *background);
+    scene_background.shown = wanted;
+
+    let (texture_id, color) =
+        match background_texture(gpu_interface, &new_texture_event_writer, background) {
+            Ok(Some(background_texture)) => background_texture,
+            Ok(None) => return,
+            Err(error) => {
+                let message = format!("Can't draw the {} background: {error}", background.name());
+                error!("{message}");
+                toast::show(&message, DEFAULT_TOAST_SECONDS);
+                return;
+            }
+        };
+    let mut texture_component_builder = create_new_relative_texture(
+        Vec3::new(0., 0., BACKGROUND_Z).into(),
+        color.into(),
+        texture_id,
+        screen_size,
+        RelativeScale::OfScreen,
+    );
+    texture_component_builder.add_component(BackgroundSprite);
+    Engine::spawn(&texture_component_builder.build());
+}
+
+#[system]
+fn material_ab_toggle_system(
+    input_state: &InputState,
+    mut toggles: Query<(&mut MaterialAbToggle, &mut MaterialParameters)>,
+) {
+    if !is_material_toggle_just_pressed(input_state) {
+        return;
+    }
+
+    toggles.for_each(|(material_ab_toggle, material_params)| {
+        material_ab_toggle.toggle(material_params);
+    });
 }
 
-#[derive(Debug, Component, serde::Deserialize)]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
 /// Simple [`Component`] for capturing the TextureIds being loaded
 pub struct MaterialTextureAsset(TextureId);
 
@@ -1579,7 +8710,7 @@ impl MaterialTextureAsset {
     }
 }
 
-#[derive(Debug, Component, serde::Deserialize)]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
 /// Simple [`Component`] for capturing the TextIds being loaded
 pub struct MaterialTextAsset(TextId);
 
@@ -1599,7 +8730,7 @@ impl MaterialTextAsset {
     }
 }
 
-#[derive(Debug, Component, serde::Deserialize)]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
 /// Simple [`Component`] for capturing the Materials being loaded
 pub struct MaterialAsset(MaterialId);
 
@@ -1667,7 +8798,7 @@ impl Error for MaterialIdAlreadySet {}
 unsafe impl Sync for MaterialIdAlreadySet {}
 unsafe impl Send for MaterialIdAlreadySet {}
 
-#[derive(Debug, Component, serde::Deserialize)]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
 pub struct MaybeLoadedMaterial {
     material_type: MaterialType,
     material_id: Option<MaterialId>,
@@ -1724,7 +8855,7 @@ impl MaybeLoadedMaterial {
     }
 }
 
-#[derive(Debug, Component, serde::Deserialize)]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
 /// A [`Component`] for identifying useful information for running a material
 /// test as well as a bool indicating if it is active or not. The intent is that
 /// only one `MaterialTest` should be active at a time
@@ -1736,6 +8867,23 @@ pub struct MaterialTest {
     material_type: MaterialType,
     #[serde(with = "BigArray")]
     startup_system_name: [u8; 256],
+    /// All zeroes when the test has no teardown system
+    #[serde(with = "BigArray")]
+    teardown_system_name: [u8; 256],
+    /// The asset path the test's material was registered from, all zeroes for tests made of
+    /// several materials
+    #[serde(with = "BigArray")]
+    material_definition_path: [u8; 256],
+    /// Copied from [`TEST_DETAILS`](test_details::TEST_DETAILS), all zeroes for tests without
+    /// an entry
+    #[serde(with = "BigArray")]
+    description: [u8; DESCRIPTION_CAPACITY],
+    #[serde(with = "BigArray")]
+    author: [u8; AUTHOR_CAPACITY],
+    /// Comma separated, copied from [`TEST_DETAILS`](test_details::TEST_DETAILS) like the
+    /// description
+    #[serde(with = "BigArray")]
+    tags: [u8; TAGS_CAPACITY],
 }
 
 impl MaterialTest {
@@ -1746,14 +8894,61 @@ impl MaterialTest {
         material_type: &MaterialType,
         material_test_id_holder: &mut MaterialTestIdHolder,
     ) -> Self {
-        let name = material_test_id_holder.validate_new_name(desired_name);
+        let registered_test = material_test_id_holder.register_test(desired_name, *material_type);
+        let (name, truncated) = str_to_u8_array(registered_test.name.as_str());
+        if truncated {
+            error!(
+                "Material test name {} does not fit in {} bytes, it can't be found by name",
+                registered_test.name,
+                name.len()
+            );
+        }
+        let (description, author, tags) = match test_details(&registered_test.name) {
+            Some(test_details) => (
+                str_to_u8_array(test_details.description).0,
+                str_to_u8_array(test_details.author).0,
+                str_to_u8_array(&test_details.tags.join(",")).0,
+            ),
+            None => (
+                [0; DESCRIPTION_CAPACITY],
+                [0; AUTHOR_CAPACITY],
+                [0; TAGS_CAPACITY],
+            ),
+        };
         Self {
-            id: material_test_id_holder.get_next_id(),
+            id: registered_test.id,
             maybe_loaded_materials: array_from_iterator(maybe_loaded_materials.iter().cloned()),
             material_type: *material_type,
-            name: str_to_u8_array(name.as_str()),
+            name,
             startup_system_name: cstr_to_u8_array(startup_system),
+            teardown_system_name: [0; 256],
+            material_definition_path: [0; 256],
+            description,
+            author,
+            tags,
+        }
+    }
+
+    /// Sets a `#[system_once]` that is enabled when leaving the test, for cleanup beyond
+    /// despawning [`MaterialTestObject`]s and removing postprocesses
+    pub fn with_teardown_system(mut self, teardown_system: &CStr) -> Self {
+        self.teardown_system_name = cstr_to_u8_array(teardown_system);
+        self
+    }
+
+    pub fn with_material_definition_path(mut self, material_definition_path: &str) -> Self {
+        let (material_definition_path_array, truncated) = str_to_u8_array(material_definition_path);
+        if truncated {
+            error!(
+                "Material definition path {material_definition_path} of {} does not fit in {} \
+                 bytes, its TOML can't be read back",
+                self.name(),
+                material_definition_path_array.len()
+            );
+        } else {
+            self.material_definition_path = material_definition_path_array;
         }
+        self
     }
 
     pub fn id(&self) -> MaterialTestId {
@@ -1774,10 +8969,50 @@ impl MaterialTest {
         u8_array_to_str(&self.name).unwrap()
     }
 
+    pub fn description(&self) -> &str {
+        u8_array_to_str(&self.description).unwrap()
+    }
+
+    pub fn author(&self) -> &str {
+        u8_array_to_str(&self.author).unwrap()
+    }
+
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        u8_array_to_str(&self.tags)
+            .unwrap()
+            .split(',')
+            .filter(|tag| !tag.is_empty())
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags().any(|test_tag| test_tag == tag)
+    }
+
+    /// The description, followed by the author when one is recorded
+    pub fn details_text(&self) -> String {
+        match (self.description(), self.author()) {
+            (description, "") => description.to_string(),
+            (description, author) => format!("{description} (by {author})"),
+        }
+    }
+
     pub fn startup_system_name(&self) -> &CStr {
         u8_array_to_cstr(&self.startup_system_name).unwrap()
     }
 
+    pub fn teardown_system_name(&self) -> Option<&CStr> {
+        u8_array_to_cstr(&self.teardown_system_name)
+            .ok()
+            .filter(|teardown_system_name| !teardown_system_name.is_empty())
+    }
+
+    /// `None` for tests made of several materials, which have no single definition to read
+    pub fn material_definition_path(&self) -> Option<&str> {
+        u8_array_to_str(&self.material_definition_path)
+            .ok()
+            .filter(|material_definition_path| !material_definition_path.is_empty())
+    }
+
     pub fn update_maybe_loaded_materials(&mut self, text_id: TextId, material_id: MaterialId) {
         for maybe_loaded_material in &mut self.maybe_loaded_materials {
             if maybe_loaded_material.text_id() == MISSING_TEXT_ID
@@ -1796,103 +9031,470 @@ impl MaterialTest {
 }
 
 /// This is a marker [`Component`] intended to mark assets used in a Material Test that should be cleaned up when changing or clearing material tests
-#[derive(Debug, Component, serde::Deserialize)]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
 pub struct MaterialTestObject;
 
+/// A test name no registered test had when [`MaterialTestIdHolder::validate_new_name`] made it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UniqueTestName(String);
+
+impl UniqueTestName {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for UniqueTestName {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for UniqueTestName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A [`MaterialTest`] as recorded by the [`MaterialTestIdHolder`], so tests can be looked up by
+/// name before their entities are spawned
+#[derive(Clone, Debug)]
+pub struct RegisteredTest {
+    pub name: UniqueTestName,
+    pub material_type: MaterialType,
+    pub id: MaterialTestId,
+}
+
 /// A [`Resource`] for ensuring there are no id clashes with [`MaterialTest`]s
 #[derive(Debug, Default, Resource)]
 pub struct MaterialTestIdHolder {
     next_id: MaterialTestId,
-    taken_test_names: Vec<String>,
+    registered_tests: Vec<RegisteredTest>,
 }
 
 impl MaterialTestIdHolder {
+    /// Ids are never handed out twice, so entities still pointing at a removed test can't be
+    /// mistaken for a test registered after it
     pub fn get_next_id(&mut self) -> MaterialTestId {
         let next_id = self.next_id;
         self.next_id = next_id.increment_id();
         next_id
     }
 
-    pub fn validate_new_name(&mut self, desired_name: &str) -> String {
-        if self
-            .taken_test_names
-            .iter()
-            .any(|existing_name| existing_name == desired_name)
-        {
-            self.validate_new_name(format!("{desired_name}0").as_str())
-        } else {
-            let name = desired_name.to_string();
-            self.taken_test_names.push(name.clone());
-            name
+    /// `desired_name`, or `desired_name (2)`, `desired_name (3)` and so on when it collides with a
+    /// registered test. Only as many names as are registered can collide, so the last candidate
+    /// checked is always free.
+    pub fn validate_new_name(&self, desired_name: &str) -> UniqueTestName {
+        let mut name = desired_name.to_string();
+        for suffix in 2..self.registered_tests.len() + 2 {
+            if self.find_by_name(&name).is_none() {
+                break;
+            }
+            name = format!("{desired_name} ({suffix})");
         }
+        UniqueTestName(name)
+    }
+
+    pub fn register_test(
+        &mut self,
+        desired_name: &str,
+        material_type: MaterialType,
+    ) -> RegisteredTest {
+        let registered_test = RegisteredTest {
+            name: self.validate_new_name(desired_name),
+            material_type,
+            id: self.get_next_id(),
+        };
+        self.registered_tests.push(registered_test.clone());
+        registered_test
+    }
+
+    /// Deregisters the test named `name`, freeing its name so the test can be registered again,
+    /// e.g. after reloading it. The test comes back with a new id.
+    pub fn remove(&mut self, name: &str) -> Option<RegisteredTest> {
+        let index = self
+            .registered_tests
+            .iter()
+            .position(|registered_test| registered_test.name.as_str() == name)?;
+        Some(self.registered_tests.remove(index))
+    }
+
+    pub fn registered_tests(&self) -> &[RegisteredTest] {
+        &self.registered_tests
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &RegisteredTest> {
+        self.registered_tests.iter()
+    }
+
+    pub fn find_by_name(&self, name: &str) -> Option<&RegisteredTest> {
+        self.registered_tests
+            .iter()
+            .find(|registered_test| registered_test.name.as_str() == name)
+    }
+
+    pub fn id_for_name(&self, name: &str) -> Option<MaterialTestId> {
+        self.find_by_name(name)
+            .map(|registered_test| registered_test.id)
+    }
+
+    pub fn name_for_id(&self, id: MaterialTestId) -> Option<&str> {
+        self.registered_tests
+            .iter()
+            .find(|registered_test| registered_test.id == id)
+            .map(|registered_test| registered_test.name.as_str())
+    }
+}
+
+/// A [`MaterialTest`]'s materials as cached by the [`MaterialTestRegistry`]
+#[derive(Clone, Debug)]
+pub struct MaterialTestEntry {
+    pub name: String,
+    pub id: MaterialTestId,
+    pub material_type: MaterialType,
+    material_ids: Vec<Option<MaterialId>>,
+}
+
+impl MaterialTestEntry {
+    pub fn material_id_iter(&self) -> impl Iterator<Item = Option<MaterialId>> + use<'_> {
+        self.material_ids.iter().copied()
+    }
+
+    /// The material every single material test uses, `None` until it has loaded
+    pub fn first_material_id(&self) -> Option<MaterialId> {
+        self.material_id_iter().next().flatten()
     }
 }
 
+/// A [`Resource`] caching each [`MaterialTest`]'s [`MaterialId`]s as they load, so per frame
+/// systems can look their test up without scanning every [`MaterialTest`] by name. Populated by
+/// `handle_material_id_from_text_id_events`.
+#[derive(Debug, Default, Resource)]
+pub struct MaterialTestRegistry {
+    entries: HashMap<usize, MaterialTestEntry>,
+    ids_by_name: HashMap<String, MaterialTestId>,
+}
+
+impl MaterialTestRegistry {
+    pub fn update(&mut self, material_test: &MaterialTest) {
+        self.ids_by_name
+            .insert(material_test.name().to_string(), material_test.id());
+        self.entries.insert(
+            *material_test.id(),
+            MaterialTestEntry {
+                name: material_test.name().to_string(),
+                id: material_test.id(),
+                material_type: *material_test.material_type(),
+                material_ids: material_test.material_id_iter().collect(),
+            },
+        );
+    }
+
+    /// No [`MaterialTest`] has loaded a material yet
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.ids_by_name.keys().map(String::as_str)
+    }
+
+    pub fn get_by_name(&self, name: &str) -> Option<&MaterialTestEntry> {
+        self.ids_by_name
+            .get(name)
+            .and_then(|material_test_id| self.get_by_id(*material_test_id))
+    }
+
+    pub fn get_by_id(&self, material_test_id: MaterialTestId) -> Option<&MaterialTestEntry> {
+        self.entries.get(&*material_test_id)
+    }
+}
+
+/// The test's first material applied to scared.png, for the material selection thumbnails
+fn selection_thumbnail_material_parameters(
+    gpu_interface: &GpuInterface,
+    material_test: &MaterialTest,
+    texture_id: TextureId,
+) -> Option<MaterialParameters> {
+    let material_id = material_test.material_id_iter().next()??;
+    let material = gpu_interface.material_manager.get_material(material_id)?;
+    let material_params = match material
+        .generate_default_material_parameters()
+        .update_texture(&gpu_interface.material_manager, &("color_tex", &texture_id))
+    {
+        Ok(material_params) => material_params.end_chain(),
+        // Materials that don't sample `color_tex` keep their default textures
+        Err(_) => material.generate_default_material_parameters(),
+    };
+    Some(material_params)
+}
+
+/// Two columns of five tests fit between the header and the page indicator
+const MATERIAL_SELECTION_PAGE_SIZE: usize = 10;
+/// The font size of the underlined test's description, below the page indicator
+const MATERIAL_SELECTION_DETAILS_FONT_PERCENT: f32 = 0.025;
+
+fn material_selection_page(index: usize) -> usize {
+    index / MATERIAL_SELECTION_PAGE_SIZE
+}
+
+fn material_selection_page_count(material_test_count: usize) -> usize {
+    material_test_count
+        .div_ceil(MATERIAL_SELECTION_PAGE_SIZE)
+        .max(1)
+}
+
+/// Wraps `index` into `0..array_len`, counting back from the end for negative indices. Every index
+/// of an empty array wraps to 0.
 fn wrap_index(index: isize, array_len: usize) -> usize {
-    let len = array_len as isize;
-    (((index % len) + len) % len) as usize
+    if array_len == 0 {
+        return 0;
+    }
+    // Widened so neither `isize::MIN` nor lengths above `isize::MAX` overflow
+    (index as i128).rem_euclid(array_len as i128) as usize
 }
 
+#[allow(clippy::too_many_arguments)]
 #[system]
 fn handle_inputs(
     selectables_query: Query<(&TextRender, &Transform, &Color, &RegularText)>,
     mut underline_query: Query<(&EntityId, &mut Transform, &Color, &Underline)>,
+    interactive_text_query: Query<(&TextRender, &Transform, &InteractiveText)>,
     material_test_query: Query<&MaterialTest>,
     aspect: &Aspect,
     input_state: &InputState,
+    key_repeat_state: &KeyRepeatState,
+    press_timing_state: &PressTimingState,
+    test_usage_history: &mut TestUsageHistory,
+    touch_state: &TouchState,
+    uniform_entry: &UniformEntry,
     view_system: &mut View,
 ) {
+    let typing_filter = matches!(view_system.view_state(), ViewState::MaterialSelection(_))
+        && view_system.material_selection_filter.is_some();
+    if !typing_filter
+        && !matches!(view_system.view_state(), ViewState::Exiting)
+        && is_quit_just_pressed(input_state)
+    {
+        view_system.set_transition_to(TransitionTo::Exit);
+        return;
+    }
+
+    // A tap on a menu entry opens it, a tap anywhere else selects the underlined entry. Tests in
+    // the material selection are underlined by a tap and opened by a double-click instead.
+    let in_menu = matches!(
+        view_system.view_state(),
+        ViewState::MainView(_) | ViewState::MaterialSelection(_)
+    );
+    let entry_at = |position: Vec2| {
+        interactive_text_query
+            .iter()
+            .find_map(|query_components_ref| {
+                let (text_render, transform, interactive_text) = query_components_ref.unpack();
+                is_on_text(
+                    position,
+                    transform.position.get().truncate(),
+                    measure_text(
+                        u8_array_to_str(&text_render.text).unwrap(),
+                        text_render.font_size,
+                    ),
+                    text_render.font_size,
+                )
+                .then_some(interactive_text.0)
+            })
+    };
+    let cursor_position = input_state.mouse.cursor_position;
+    let double_clicked_transition = press_timing_state
+        .is_double_clicked(MouseButton::Left)
+        .then(|| {
+            entry_at(screen_to_world(
+                aspect,
+                Vec2::new(cursor_position.x, cursor_position.y),
+            ))
+        })
+        .flatten()
+        .filter(|_| in_menu);
+    let tapped_transition = touch_state
+        .tap_position()
+        .filter(|_| in_menu)
+        .and_then(entry_at);
+    let tapped_test = match (view_system.view_state(), tapped_transition) {
+        (ViewState::MaterialSelection(_), Some(TransitionTo::Material((_, material_test_id)))) => {
+            Some(material_test_id)
+        }
+        _ => None,
+    };
+    if let Some(transition_to) =
+        double_clicked_transition.or(tapped_transition.filter(|_| tapped_test.is_none()))
+    {
+        view_system.set_transition_to(transition_to);
+        return;
+    }
+    let tapped = touch_state.tap_position().is_some() && tapped_test.is_none();
+    let swipe = touch_state.swipe();
+
     match view_system.view_state() {
-        ViewState::Loading => {
-            // no inputs during loading
+        ViewState::Loading | ViewState::Exiting => {
+            // no inputs during loading or while exiting
         }
-        ViewState::MainView(material_types) => {
-            let left_pressed = is_left_just_pressed(input_state);
-            let right_pressed = is_right_just_pressed(input_state);
-            let select_pressed = is_select_just_pressed(input_state);
+        ViewState::MainView(main_view_selection) => {
+            if is_back_just_pressed(input_state) {
+                let Some(esc_transition) = view_system.esc_transition else {
+                    error!("esc transition must be set in MainView");
+                    return;
+                };
+                view_system.set_transition_to(esc_transition);
+                return;
+            }
+
+            let left_pressed = is_left_repeated(input_state, key_repeat_state)
+                || swipe == Some(SwipeDirection::Left);
+            let right_pressed = is_right_repeated(input_state, key_repeat_state)
+                || swipe == Some(SwipeDirection::Right);
+            let up_pressed = is_up_repeated(input_state, key_repeat_state);
+            let down_pressed = is_down_repeated(input_state, key_repeat_state);
+            let select_pressed = is_select_just_pressed(input_state) || tapped;
 
             if select_pressed {
-                view_system
-                    .set_transition_to(TransitionTo::MaterialSelection(*material_types, None));
+                match main_view_selection {
+                    MainViewSelection::ResumeLastTest => {
+                        let Some(resume_last_test) = &view_system.resume_last_test else {
+                            error!("Resume last test was selected without a last test to resume");
+                            return;
+                        };
+                        let transition_to = resume_last_test.transition_to;
+                        view_system.set_transition_to(transition_to);
+                    }
+                    MainViewSelection::MaterialType(material_type) => {
+                        view_system.set_transition_to(TransitionTo::MaterialSelection(
+                            *material_type,
+                            None,
+                        ));
+                    }
+                }
                 return;
             }
 
-            if left_pressed && right_pressed {
+            if (left_pressed && right_pressed) || (up_pressed && down_pressed) {
                 return;
             }
 
-            if left_pressed || right_pressed {
-                let new_material_type = match material_types {
-                    MaterialType::Sprite => MaterialType::PostProcessing,
-                    MaterialType::PostProcessing => MaterialType::Sprite,
-                };
-
-                view_system.view_state = ViewState::MainView(new_material_type);
+            let new_main_view_selection = match main_view_selection {
+                MainViewSelection::ResumeLastTest if up_pressed || down_pressed => {
+                    MainViewSelection::MaterialType(MaterialType::Sprite)
+                }
+                MainViewSelection::MaterialType(_)
+                    if (up_pressed || down_pressed) && view_system.resume_last_test.is_some() =>
+                {
+                    MainViewSelection::ResumeLastTest
+                }
+                MainViewSelection::MaterialType(material_type) if left_pressed || right_pressed => {
+                    MainViewSelection::MaterialType(match material_type {
+                        MaterialType::Sprite => MaterialType::PostProcessing,
+                        MaterialType::PostProcessing => MaterialType::Sprite,
+                    })
+                }
+                _ => return,
+            };
 
-                selectables_query
-                    .iter()
-                    .try_for_each(|query_components_ref| {
-                        let (text_render, transform, _, _) = query_components_ref.unpack();
-                        if u8_array_to_str(&text_render.text).unwrap()
-                            == title_from_material_type(&new_material_type)
-                        {
-                            if let Some(mut components) = underline_query.iter_mut().next() {
-                                let (_, underline_transform, _, _) = components.unpack();
-                                let underline_offset =
-                                    Vec3::new(0., *UNDERLINE_OFFSET_Y_PERCENT * aspect.height, 0.);
-                                underline_transform
-                                    .position
-                                    .set(transform.position.get() - underline_offset);
-                                return ControlFlow::Break(());
-                            }
+            let selected_title = match new_main_view_selection {
+                MainViewSelection::ResumeLastTest => view_system
+                    .resume_last_test
+                    .as_ref()
+                    .map(|resume_last_test| resume_last_test.title.clone())
+                    .unwrap_or_default(),
+                MainViewSelection::MaterialType(material_type) => {
+                    title_from_material_type(&material_type).to_string()
+                }
+            };
+            view_system.view_state = ViewState::MainView(new_main_view_selection);
+
+            selectables_query
+                .iter()
+                .try_for_each(|query_components_ref| {
+                    let (text_render, transform, _, _) = query_components_ref.unpack();
+                    if u8_array_to_str(&text_render.text).unwrap() == selected_title {
+                        if let Some(mut components) = underline_query.iter_mut().next() {
+                            let (_, underline_transform, _, _) = components.unpack();
+                            let underline_offset =
+                                Vec3::new(0., *UNDERLINE_OFFSET_Y_PERCENT * aspect.height, 0.);
+                            underline_transform
+                                .position
+                                .set(transform.position.get() - underline_offset);
+                            underline_transform.scale.set(underline_scale(
+                                Some(measure_text(&selected_title, text_render.font_size)),
+                                aspect,
+                            ));
+                            return ControlFlow::Break(());
                         }
+                    }
 
-                        ControlFlow::Continue(())
-                    });
-            }
+                    ControlFlow::Continue(())
+                });
         }
         ViewState::MaterialSelection((material_type, material_test_id, material_id_order)) => {
-            if is_back_just_pressed(input_state) {
+            if let Some(filter) = &view_system.material_selection_filter {
+                let material_type = *material_type;
+                let mut new_filter = filter.clone();
+                if is_filter_cancel_just_pressed(input_state) {
+                    view_system.material_selection_filter = None;
+                    view_system
+                        .set_transition_to(TransitionTo::MaterialSelection(material_type, None));
+                    return;
+                }
+                if is_filter_delete_just_pressed(input_state) {
+                    new_filter.pop();
+                }
+                new_filter.extend(filter_characters_just_pressed(input_state));
+                if &new_filter != filter {
+                    view_system.material_selection_filter = Some(new_filter);
+                    view_system
+                        .set_transition_to(TransitionTo::MaterialSelection(material_type, None));
+                    return;
+                }
+                // Enter selects the top match below, every other key is reserved for typing
+                if !input_state.keys[KeyCode::Enter].just_pressed() {
+                    return;
+                }
+            } else if is_filter_toggle_just_pressed(input_state) {
+                view_system.material_selection_filter = Some(String::new());
+                view_system
+                    .set_transition_to(TransitionTo::MaterialSelection(*material_type, None));
+                return;
+            } else if is_tag_next_just_pressed(input_state) {
+                let material_type = *material_type;
+                let available_tags = available_tags(
+                    material_test_query
+                        .iter()
+                        .filter(|material_test| *material_test.material_type() == material_type)
+                        .flat_map(|material_test| material_test.tags()),
+                );
+                view_system.material_selection_tag = next_tag(
+                    &available_tags,
+                    view_system.material_selection_tag.as_deref(),
+                )
+                .map(str::to_string);
+                view_system.set_transition_to(TransitionTo::MaterialSelection(material_type, None));
+                return;
+            } else if is_favorite_toggle_just_pressed(input_state) {
+                // The list is rebuilt so the test moves in or out of the favorites
+                if let Some(material_test) = material_test_id.and_then(|material_test_id| {
+                    material_test_query
+                        .iter()
+                        .find(|material_test| material_test.id() == material_test_id)
+                }) {
+                    test_usage_history.toggle_favorite(*material_type, material_test.name());
+                    view_system.set_transition_to(TransitionTo::MaterialSelection(
+                        *material_type,
+                        *material_test_id,
+                    ));
+                }
+                return;
+            }
+
+            if is_back_just_pressed(input_state) || swipe == Some(SwipeDirection::Down) {
                 let Some(esc_transition) = view_system.esc_transition else {
                     error!("esc transition must be set in MaterialSelection View");
                     return;
@@ -1901,22 +9503,29 @@ fn handle_inputs(
                 return;
             }
 
-            let select_pressed = is_select_just_pressed(input_state);
+            // Swiping sideways switches to the other material type's tests
+            if matches!(swipe, Some(SwipeDirection::Left | SwipeDirection::Right)) {
+                view_system.set_transition_to(TransitionTo::MaterialSelection(
+                    match material_type {
+                        MaterialType::Sprite => MaterialType::PostProcessing,
+                        MaterialType::PostProcessing => MaterialType::Sprite,
+                    },
+                    None,
+                ));
+                return;
+            }
+
+            let select_pressed = is_select_just_pressed(input_state) || tapped;
             if select_pressed && !material_id_order.is_empty() {
                 let material_test_id = material_test_id.unwrap();
                 view_system
                     .set_transition_to(TransitionTo::Material((*material_type, material_test_id)));
-                let material_test = material_test_query
-                    .iter()
-                    .find(|material_test| material_test.id() == material_test_id)
-                    .unwrap();
-                Engine::set_system_enabled(material_test.startup_system_name(), true, module_name);
                 return;
             }
 
             let (left_pressed, right_pressed) = {
-                let left_pressed = is_left_just_pressed(input_state);
-                let right_pressed = is_right_just_pressed(input_state);
+                let left_pressed = is_left_repeated(input_state, key_repeat_state);
+                let right_pressed = is_right_repeated(input_state, key_repeat_state);
 
                 if left_pressed && right_pressed {
                     (false, false)
@@ -1926,8 +9535,8 @@ fn handle_inputs(
             };
 
             let (up_pressed, down_pressed) = {
-                let up_pressed = is_up_just_pressed(input_state);
-                let down_pressed = is_down_just_pressed(input_state);
+                let up_pressed = is_up_repeated(input_state, key_repeat_state);
+                let down_pressed = is_down_repeated(input_state, key_repeat_state);
 
                 if up_pressed && down_pressed {
                     (false, false)
@@ -1936,8 +9545,49 @@ fn handle_inputs(
                 }
             };
 
+            let (page_up_pressed, page_down_pressed) = {
+                let page_up_pressed =
+                    is_page_up_just_pressed(input_state) || is_scroll_up(input_state);
+                // Swiping up pages through the tests like scrolling a list
+                let page_down_pressed = is_page_down_just_pressed(input_state)
+                    || is_scroll_down(input_state)
+                    || swipe == Some(SwipeDirection::Up);
+
+                if page_up_pressed && page_down_pressed {
+                    (false, false)
+                } else {
+                    (page_up_pressed, page_down_pressed)
+                }
+            };
+
+            if !material_id_order.is_empty() && (page_up_pressed || page_down_pressed) {
+                let current_index = material_id_order
+                    .iter()
+                    .position(|material_test_id_in_vec| {
+                        material_test_id_in_vec == &material_test_id.unwrap()
+                    })
+                    .unwrap();
+                let page_count = material_selection_page_count(material_id_order.len());
+                let page_shift = if page_up_pressed { -1 } else { 1 };
+                let new_page = wrap_index(
+                    material_selection_page(current_index) as isize + page_shift,
+                    page_count,
+                );
+                if new_page != material_selection_page(current_index) {
+                    view_system.set_transition_to(TransitionTo::MaterialSelection(
+                        *material_type,
+                        Some(material_id_order[new_page * MATERIAL_SELECTION_PAGE_SIZE]),
+                    ));
+                }
+                return;
+            }
+
             if !material_id_order.is_empty()
-                && (left_pressed || right_pressed || up_pressed || down_pressed)
+                && (left_pressed
+                    || right_pressed
+                    || up_pressed
+                    || down_pressed
+                    || tapped_test.is_some())
             {
                 let current_index = material_id_order
                     .iter()
@@ -1945,25 +9595,43 @@ fn handle_inputs(
                         material_test_id_in_vec == &material_test_id.unwrap()
                     })
                     .unwrap();
-                let index_shift = if left_pressed {
-                    -1
-                } else if right_pressed {
-                    1
-                } else {
-                    0
-                } + if up_pressed {
-                    -2
-                } else if down_pressed {
-                    2
-                } else {
-                    0
+                let tapped_index = tapped_test.and_then(|tapped_test| {
+                    material_id_order
+                        .iter()
+                        .position(|material_test_id_in_vec| *material_test_id_in_vec == tapped_test)
+                });
+                let new_index = match tapped_index {
+                    Some(tapped_index) => tapped_index,
+                    None => {
+                        let index_shift = if left_pressed {
+                            -1
+                        } else if right_pressed {
+                            1
+                        } else {
+                            0
+                        } + if up_pressed {
+                            -2
+                        } else if down_pressed {
+                            2
+                        } else {
+                            0
+                        };
+                        wrap_index(
+                            current_index as isize + index_shift,
+                            material_id_order.len(),
+                        )
+                    }
                 };
-                let new_index = wrap_index(
-                    current_index as isize + index_shift,
-                    material_id_order.len(),
-                );
                 let selected_material_test_id = material_id_order[new_index];
 
+                if material_selection_page(new_index) != material_selection_page(current_index) {
+                    view_system.set_transition_to(TransitionTo::MaterialSelection(
+                        *material_type,
+                        Some(selected_material_test_id),
+                    ));
+                    return;
+                }
+
                 let selected_material_test_ref = material_test_query
                     .iter()
                     .find(|material_test| material_test.id() == selected_material_test_id);
@@ -1979,7 +9647,7 @@ fn handle_inputs(
                     .try_for_each(|query_components_ref| {
                         let (text_render, transform, _, _) = query_components_ref.unpack();
                         if u8_array_to_str(&text_render.text).unwrap()
-                            == selected_material_test.name()
+                            == test_display_name(selected_material_test.name())
                         {
                             if let Some(mut components) = underline_query.iter_mut().next() {
                                 let (_, underline_transform, _, _) = components.unpack();
@@ -1988,6 +9656,13 @@ fn handle_inputs(
                                 underline_transform
                                     .position
                                     .set(transform.position.get() - underline_offset);
+                                underline_transform.scale.set(underline_scale(
+                                    Some(measure_text(
+                                        test_display_name(selected_material_test.name()),
+                                        text_render.font_size,
+                                    )),
+                                    aspect,
+                                ));
                                 return ControlFlow::Break(());
                             }
                         }
@@ -1996,8 +9671,37 @@ fn handle_inputs(
                     });
             }
         }
+        ViewState::Error(_) => {
+            if is_back_just_pressed(input_state)
+                || is_select_just_pressed(input_state)
+                || tapped
+                || swipe == Some(SwipeDirection::Down)
+            {
+                let Some(esc_transition) = view_system.esc_transition else {
+                    error!("esc transition must be set in the error view");
+                    return;
+                };
+                view_system.set_transition_to(esc_transition);
+            }
+        }
         ViewState::Material((material_test_id, material_test_name)) => {
-            if is_back_just_pressed(input_state) {
+            if is_force_reload_just_pressed(input_state) {
+                if let Some(material_test) = material_test_query
+                    .iter()
+                    .find(|material_test| material_test.id() == *material_test_id)
+                {
+                    info!("Restarting {material_test_name}");
+                    view_system.set_transition_to(TransitionTo::Material((
+                        *material_test.material_type(),
+                        *material_test_id,
+                    )));
+                    return;
+                }
+            }
+            // Escape and backspace edit the uniform entry field while it's open
+            if (is_back_just_pressed(input_state) || swipe == Some(SwipeDirection::Down))
+                && !uniform_entry.is_open()
+            {
                 let Some(esc_transition) = view_system.esc_transition else {
                     error!(
                         "Esc transition not set from material test {material_test_id} {material_test_name}. This is an error"
@@ -2010,6 +9714,273 @@ fn handle_inputs(
     }
 }
 
+/// Marker [`Component`] for the description of the test underlined in
+/// [`ViewState::MaterialSelection`], holding which test it describes
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct MaterialSelectionDetails {
+    material_test_id: MaterialTestId,
+}
+
+/// Shows the underlined test's description below the material selection list, respawning it
+/// whenever the underline moves to another test
+#[system]
+fn material_selection_details_system(
+    aspect: &Aspect,
+    view: &View,
+    material_test_query: Query<&MaterialTest>,
+    material_selection_details_query: Query<(&EntityId, &MaterialSelectionDetails)>,
+) {
+    let ViewState::MaterialSelection((_, Some(selected_material_test_id), _)) = view.view_state()
+    else {
+        return;
+    };
+    let mut shown = false;
+    material_selection_details_query
+        .iter()
+        .for_each(|query_components_ref| {
+            let (entity_id, material_selection_details) = query_components_ref.unpack();
+            if material_selection_details.material_test_id == *selected_material_test_id {
+                shown = true;
+            } else {
+                Engine::despawn(**entity_id);
+            }
+        });
+    if shown {
+        return;
+    }
+    let Some(material_test) = material_test_query
+        .iter()
+        .find(|material_test| material_test.id() == *selected_material_test_id)
+    else {
+        return;
+    };
+
+    let mut text_component_builder = create_new_text::<_, CustomText>(
+        CreateTextInput {
+            text: material_test.details_text(),
+            position: screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.04.into())
+                .extend(0.),
+            text_type: TextTypes::Custom(aspect.height * MATERIAL_SELECTION_DETAILS_FONT_PERCENT),
+            max_width: Some(aspect.width * 0.8),
+            ..Default::default()
+        },
+        aspect,
+    );
+    text_component_builder.add_components(bundle_for_builder!(
+        NonInteractiveText,
+        MaterialSelectionDetails {
+            material_test_id: *selected_material_test_id,
+        }
+    ));
+    Engine::spawn(&text_component_builder.build());
+}
+
+/// Logs `error` and replaces the current test with the error view, from which Escape returns to
+/// the menu
+pub fn show_error_view(view: &mut View, error: &TestModuleError) {
+    error!("{error}");
+    view.show_error(&error.to_string());
+}
+
+/// Covers the screen with the `iris_wipe` postprocess during [`TransitionStyle::Iris`]
+/// transitions. Returns false while its material hasn't loaded, so the fade is drawn instead.
+/// Once the iris reopens the postprocess is removed, unless the iris wipe test is running.
+fn update_iris_transition(
+    alpha: f32,
+    aspect: &Aspect,
+    view: &View,
+    material_test_registry: &MaterialTestRegistry,
+    gpu_interface: &GpuInterface,
+    world_render_manager: &mut WorldRenderManager,
+) -> bool {
+    let Some(iris_wipe_entry) = material_test_registry.get_by_name("iris_wipe") else {
+        return false;
+    };
+    let Some(material_id) = iris_wipe_entry.first_material_id() else {
+        return false;
+    };
+
+    if alpha <= 0. {
+        let in_iris_wipe_test = matches!(
+            view.view_state(),
+            ViewState::Material((material_test_id, _)) if *material_test_id == iris_wipe_entry.id
+        );
+        if !in_iris_wipe_test
+            && world_render_manager
+                .get_postprocess_by_material_id_mut(material_id)
+                .is_some()
+        {
+            world_render_manager.remove_postprocesses(&[material_id]);
+        }
+        return true;
+    }
+
+    if world_render_manager
+        .get_postprocess_by_material_id_mut(material_id)
+        .is_none()
+    {
+        let Some(material) = gpu_interface.material_manager.get_material(material_id) else {
+            return false;
+        };
+        let Some(material_uniforms) = material.generate_default_material_uniforms() else {
+            return false;
+        };
+        world_render_manager.add_or_update_postprocess(material, material_uniforms);
+    }
+    if let Some(postprocess) = world_render_manager.get_postprocess_by_material_id_mut(material_id)
+    {
+        postprocess
+            .material_uniforms
+            .update("radius", iris_radius(alpha).into())
+            .unwrap();
+        postprocess
+            .material_uniforms
+            .update("aspect_ratio", (aspect.width / aspect.height).into())
+            .unwrap();
+    }
+    true
+}
+
+#[allow(clippy::too_many_arguments)]
+#[system]
+fn view_transition_system(
+    draw_rectangle_writer: EventWriter<DrawRectangle>,
+    aspect: &Aspect,
+    frame_constants: &FrameConstants,
+    gpu_interface: &GpuInterface,
+    material_test_registry: &MaterialTestRegistry,
+    view: &mut View,
+    world_render_manager: &mut WorldRenderManager,
+) {
+    if view.fade.advance(frame_constants.delta_time) {
+        set_system_enabled!(true, view_system);
+    }
+
+    let alpha = view.fade.alpha();
+    if view.transition_style == TransitionStyle::Iris
+        && update_iris_transition(
+            alpha,
+            aspect,
+            view,
+            material_test_registry,
+            gpu_interface,
+            world_render_manager,
+        )
+    {
+        return;
+    }
+    if alpha <= 0. {
+        return;
+    }
+    let Some(white_texture) = gpu_interface
+        .texture_asset_manager
+        .get_texture_by_path(&"textures/white.png".into())
+    else {
+        return;
+    };
+    let white_id = white_texture.id();
+
+    let center = screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.5.into());
+    draw_rectangle_writer.write_builder(|builder| {
+        let mut draw_rectangle_builder = DrawRectangleBuilder::new(builder);
+        draw_rectangle_builder.add_asset_id(*white_id);
+        draw_rectangle_builder
+            .add_color(&void_public::event::graphics::Color::new(0., 0., 0., alpha));
+        let transform = TransformT {
+            position: Vec3T {
+                x: center.x,
+                y: center.y,
+                z: 5000.,
+            },
+            scale: Vec2T {
+                x: aspect.width,
+                y: aspect.height,
+            },
+            ..Default::default()
+        };
+        draw_rectangle_builder.add_transform(&transform.pack());
+        draw_rectangle_builder.finish()
+    });
+}
+
+/// A [`Resource`] for the color blindness simulation postprocess, see [`color_blind`]
+#[derive(Debug, Default, Resource)]
+pub struct ColorBlindSimulation {
+    mode: ColorBlindMode,
+    text_id: Option<TextId>,
+    /// Set once the material has loaded and compiled
+    material_id: Option<MaterialId>,
+}
+
+/// F8 cycles the color blindness simulation in any view. While it's on its postprocess is kept
+/// last, so it sees every other postprocess's output, and is added back after view transitions
+/// remove every postprocess.
+#[system]
+fn color_blind_system(
+    gpu_interface: &GpuInterface,
+    input_state: &InputState,
+    color_blind_simulation: &mut ColorBlindSimulation,
+    world_render_manager: &mut WorldRenderManager,
+) {
+    if is_color_blind_cycle_just_pressed(input_state) {
+        color_blind_simulation.mode = color_blind_simulation.mode.next();
+        let message = match color_blind_simulation.material_id {
+            Some(_) => format!("Color blindness: {}", color_blind_simulation.mode.name()),
+            None => "The color blindness simulation failed to load".to_string(),
+        };
+        toast::show(&message, DEFAULT_TOAST_SECONDS);
+    }
+    let Some(material_id) = color_blind_simulation.material_id else {
+        return;
+    };
+
+    let is_added = world_render_manager
+        .get_postprocess_by_material_id_mut(material_id)
+        .is_some();
+    let Some(matrix) = color_blind_simulation.mode.matrix() else {
+        if is_added {
+            world_render_manager.remove_postprocesses(&[material_id]);
+        }
+        return;
+    };
+    let is_last = world_render_manager
+        .postprocesses()
+        .last()
+        .is_some_and(|postprocess| *postprocess.material_id() == material_id);
+    if !is_last {
+        if is_added {
+            world_render_manager.remove_postprocesses(&[material_id]);
+        }
+        let Some(material) = gpu_interface.material_manager.get_material(material_id) else {
+            return;
+        };
+        let Some(material_uniforms) = material.generate_default_material_uniforms() else {
+            return;
+        };
+        world_render_manager.add_or_update_postprocess(material, material_uniforms);
+    }
+    if let Some(postprocess) = world_render_manager.get_postprocess_by_material_id_mut(material_id)
+    {
+        for (uniform_name, row) in COLOR_BLIND_ROW_UNIFORMS.into_iter().zip(matrix) {
+            postprocess
+                .material_uniforms
+                .update(uniform_name, Vec3::from_array(row).extend(0.).into())
+                .unwrap();
+        }
+    }
+}
+
+/// Quits on the frame after [`TransitionTo::Exit`], once the despawns and postprocess removals
+/// from the teardown have been applied
+#[system_once]
+fn exit_system(view: &View) {
+    if matches!(view.view_state(), ViewState::Exiting) {
+        info!("Exiting shader test module");
+        exit(0);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 #[system_once]
 fn view_system(
     interactive_text_query: Query<(&EntityId, &InteractiveText)>,
@@ -2017,6 +9988,8 @@ fn view_system(
     mut material_test_query: Query<&mut MaterialTest>,
     material_test_object_query: Query<(&EntityId, &MaterialTestObject)>,
     aspect: &Aspect,
+    gpu_interface: &GpuInterface,
+    test_usage_history: &mut TestUsageHistory,
     view_handler: &mut View,
     world_render_manager: &mut WorldRenderManager,
 ) {
@@ -2026,31 +9999,33 @@ fn view_system(
         &mut material_test_query,
         &material_test_object_query,
         aspect,
+        gpu_interface,
+        test_usage_history,
         world_render_manager,
     );
 }
 
 // Marker Components for Text
 
-#[derive(Debug, Component, serde::Deserialize)]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
 pub struct HeaderText;
 
-#[derive(Debug, Component, serde::Deserialize)]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
 pub struct RegularText;
 
-#[derive(Debug, Component, serde::Deserialize)]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
 pub struct CustomText;
 
-#[derive(Debug, Component, serde::Deserialize)]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
 pub struct Underline;
 
-#[derive(Debug, Component, serde::Deserialize)]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
 pub struct NonInteractiveText;
 
-#[derive(Debug, Component, serde::Deserialize)]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
 pub struct InteractiveText(TransitionTo);
 
-#[derive(Debug, Component, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
 pub struct TimePassedSinceCreation(f32);
 
 impl Default for TimePassedSinceCreation {
@@ -2086,6 +10061,26 @@ impl AddAssign<f32> for &mut TimePassedSinceCreation {
     }
 }
 
+pub const IMMEDIATE_MODE_BURST_STEP: usize = 1000;
+pub const IMMEDIATE_MODE_BURST_MAX: usize = 20000;
+
+/// Toggled with B in the immediate mode test, replaces the regular scene with thousands of draw
+/// events per frame to stress the immediate mode event path
+#[derive(Debug, Component, Deserialize, Serialize, serde::Deserialize, serde::Serialize)]
+pub struct ImmediateModeBurst {
+    pub enabled: bool,
+    pub primitive_count: usize,
+}
+
+impl Default for ImmediateModeBurst {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            primitive_count: 5 * IMMEDIATE_MODE_BURST_STEP,
+        }
+    }
+}
+
 impl InteractiveText {
     pub fn new(transition_to: TransitionTo) -> Self {
         Self(transition_to)
@@ -2107,22 +10102,89 @@ impl Deref for InteractiveText {
 /// * [`ViewState::MainView`] is the intended entry point, should display the different [`MaterialType`]s
 /// * [`ViewState::MaterialSelection`] is a selection view of tests grouped under the selected [`MaterialType`]s
 /// * [`ViewState::Material`] should display the selected Material Test
+/// * [`ViewState::Error`] replaces a test that failed to start, holding the error message
+/// * [`ViewState::Exiting`] happens for the frame between tearing everything down and quitting
 pub enum ViewState {
     #[default]
     Loading,
-    MainView(MaterialType),
+    MainView(MainViewSelection),
     /// The middle enum value is an optional selection of a starting MaterialTest.id and the last enum value is a list of all possible MaterialTest ids for the selected [`MaterialType`]
     MaterialSelection((MaterialType, Option<MaterialTestId>, Vec<MaterialTestId>)),
     Material((MaterialTestId, String)),
+    Error(String),
+    Exiting,
+}
+
+/// The entry underlined in [`ViewState::MainView`]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub enum MainViewSelection {
+    ResumeLastTest,
+    MaterialType(MaterialType),
+}
+
+/// A [`Resource`] holding the [`TestUsage`] behind the favorites and recent tests of
+/// [`ViewState::MaterialSelection`]. It's read from [`TEST_USAGE_PATH`] when first needed and
+/// written back after every change.
+#[derive(Debug, Default, Resource)]
+pub struct TestUsageHistory {
+    test_usage: Option<TestUsage>,
+}
+
+impl TestUsageHistory {
+    fn test_usage(&mut self) -> &mut TestUsage {
+        self.test_usage
+            .get_or_insert_with(|| TestUsage::read(TEST_USAGE_PATH))
+    }
+
+    pub fn record_open(&mut self, material_type: MaterialType, name: &str) {
+        self.test_usage()
+            .record_open(material_type, name, unix_seconds_now());
+        self.save();
+    }
+
+    pub fn toggle_favorite(&mut self, material_type: MaterialType, name: &str) {
+        self.test_usage().toggle_favorite(material_type, name);
+        self.save();
+    }
+
+    /// See [`TestUsage::grouped_order`]
+    pub fn grouped_order(
+        &mut self,
+        material_type: MaterialType,
+        names: &[&str],
+    ) -> Vec<(SelectionGroup, usize)> {
+        self.test_usage().grouped_order(material_type, names)
+    }
+
+    fn save(&self) {
+        let Some(test_usage) = &self.test_usage else {
+            return;
+        };
+        if let Err(error) = test_usage.write(TEST_USAGE_PATH) {
+            warn!("Could not save test usage to {TEST_USAGE_PATH}: {error}");
+        }
+    }
+}
+
+/// The "Resume last test" entry of [`ViewState::MainView`], read from [`LAST_TEST_PATH`]
+#[derive(Clone, Debug)]
+pub struct ResumeLastTest {
+    pub title: String,
+    pub transition_to: TransitionTo,
 }
 
-#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, serde::Deserialize)]
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Serialize, serde::Deserialize, serde::Serialize,
+)]
 pub enum TransitionTo {
     #[default]
     Loading,
     MainView,
     MaterialSelection(MaterialType, Option<MaterialTestId>),
     Material((MaterialType, MaterialTestId)),
+    /// The message is set with [`View::show_error`]
+    Error,
+    Exit,
 }
 
 #[derive(Debug, Resource)]
@@ -2131,6 +10193,16 @@ pub struct View {
     view_state: ViewState,
     pub esc_transition: Option<TransitionTo>,
     pub post_load_transition: Option<TransitionTo>,
+    /// When set, the material selection view only shows tests whose names contain this text
+    pub material_selection_filter: Option<String>,
+    /// When set, the material selection view only shows tests with this tag
+    pub material_selection_tag: Option<String>,
+    pub resume_last_test: Option<ResumeLastTest>,
+    pub fade: ViewFade,
+    pub transition_style: TransitionStyle,
+    error_message: Option<String>,
+    /// The error panel text of each test whose material doesn't compile
+    shader_errors: Vec<(MaterialTestId, MaterialId, String)>,
 }
 
 impl Default for View {
@@ -2140,6 +10212,13 @@ impl Default for View {
             view_state: ViewState::default(),
             esc_transition: None,
             post_load_transition: None,
+            material_selection_filter: None,
+            material_selection_tag: None,
+            resume_last_test: None,
+            fade: ViewFade::default(),
+            transition_style: TransitionStyle::default(),
+            error_message: None,
+            shader_errors: Vec::new(),
         }
     }
 }
@@ -2157,11 +10236,69 @@ impl View {
         self.transitioning_to.as_ref()
     }
 
+    /// The view changes once the screen has faded out, or right away when fading is turned off
     pub fn set_transition_to(&mut self, new_transitioning_to: TransitionTo) {
+        // An error wins over the transitions requested after it in the same frame, the test that
+        // failed asked for none of them
+        if matches!(self.transitioning_to, Some(TransitionTo::Error))
+            && !matches!(new_transitioning_to, TransitionTo::Error)
+        {
+            return;
+        }
+        // Opening a test whose material doesn't compile shows the compile error instead
+        let new_transitioning_to = match new_transitioning_to {
+            TransitionTo::Material((_, material_test_id)) => match self
+                .shader_errors
+                .iter()
+                .find(|(shader_error_test_id, _, _)| *shader_error_test_id == material_test_id)
+            {
+                Some((_, _, panel_text)) => {
+                    self.error_message = Some(panel_text.clone());
+                    TransitionTo::Error
+                }
+                None => new_transitioning_to,
+            },
+            _ => new_transitioning_to,
+        };
         self.transitioning_to = Some(new_transitioning_to);
-        set_system_enabled!(true, view_system);
+        if self.fade.is_enabled() {
+            self.fade.fade_out();
+        } else {
+            set_system_enabled!(true, view_system);
+        }
+    }
+
+    /// `None` clears the error of the test's material, e.g. once a fixed material definition is
+    /// loaded again
+    pub fn set_shader_error(
+        &mut self,
+        material_test_id: MaterialTestId,
+        material_id: MaterialId,
+        panel_text: Option<String>,
+    ) {
+        self.shader_errors
+            .retain(|(shader_error_test_id, shader_error_material_id, _)| {
+                *shader_error_test_id != material_test_id
+                    || *shader_error_material_id != material_id
+            });
+        if let Some(panel_text) = panel_text {
+            self.shader_errors
+                .push((material_test_id, material_id, panel_text));
+        }
+    }
+
+    pub fn has_shader_error(&self, material_id: MaterialId) -> bool {
+        self.shader_errors
+            .iter()
+            .any(|(_, shader_error_material_id, _)| *shader_error_material_id == material_id)
+    }
+
+    pub fn show_error(&mut self, message: &str) {
+        self.error_message = Some(message.to_string());
+        self.set_transition_to(TransitionTo::Error);
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn change_view(
         &mut self,
         interactive_text_query: &Query<(&EntityId, &InteractiveText)>,
@@ -2169,6 +10306,8 @@ impl View {
         material_test_query: &mut Query<&mut MaterialTest>,
         material_test_object_query: &Query<(&EntityId, &MaterialTestObject)>,
         aspect: &Aspect,
+        gpu_interface: &GpuInterface,
+        test_usage_history: &mut TestUsageHistory,
         world_render_manager: &mut WorldRenderManager,
     ) {
         let Some(ref transition_to) = self.transitioning_to else {
@@ -2193,14 +10332,25 @@ impl View {
                 Engine::despawn(**entity_id);
             });
 
+        // Teardown systems are enabled after the match below, since leaving a test turns off
+        // every material test system
+        let teardown_system_name = match &self.view_state {
+            ViewState::Material((material_test_id, _)) => material_test_query
+                .iter()
+                .find(|material_test| material_test.id() == *material_test_id)
+                .and_then(|material_test| material_test.teardown_system_name())
+                .map(CStr::to_owned),
+            _ => None,
+        };
+
         match transition_to {
             TransitionTo::Loading => {
                 self.esc_transition = None;
 
-                let mut text_component_builder =
-                    create_new_text::<_, HeaderText>(CreateTextInput {
-                        text: "Loading...",
-                        text_type: TextTypes::Header,
+                let mut text_component_builder = create_new_text::<_, HeaderText>(
+                    CreateTextInput {
+                        text: tr("menu.loading"),
+                        text_type: TextTypes::HeaderRelative(HEADER_FONT_HEIGHT_PERCENT),
                         position: screen_space_coordinate_by_percent(
                             aspect,
                             0.5.into(),
@@ -2208,12 +10358,16 @@ impl View {
                         )
                         .extend(0.),
                         ..Default::default()
-                    });
+                    },
+                    aspect,
+                );
                 text_component_builder.add_component(NonInteractiveText);
                 Engine::spawn(&text_component_builder.build());
             }
             TransitionTo::MainView => {
-                self.esc_transition = None;
+                self.esc_transition = Some(TransitionTo::Exit);
+                self.material_selection_filter = None;
+                self.material_selection_tag = None;
 
                 turn_off_material_test_systems();
 
@@ -2224,10 +10378,10 @@ impl View {
                     .collect::<Vec<_>>();
                 world_render_manager.remove_postprocesses(&postprocess_material_ids);
 
-                let mut text_component_builder =
-                    create_new_text::<_, HeaderText>(CreateTextInput {
-                        text: "Choose Material Type:",
-                        text_type: TextTypes::Header,
+                let mut text_component_builder = create_new_text::<_, HeaderText>(
+                    CreateTextInput {
+                        text: tr("menu.choose_material_type"),
+                        text_type: TextTypes::HeaderRelative(HEADER_FONT_HEIGHT_PERCENT),
                         position: screen_space_coordinate_by_percent(
                             aspect,
                             0.5.into(),
@@ -2235,28 +10389,32 @@ impl View {
                         )
                         .extend(0.),
                         ..Default::default()
-                    });
+                    },
+                    aspect,
+                );
                 text_component_builder.add_component(NonInteractiveText);
                 Engine::spawn(&text_component_builder.build());
 
                 let standard_material_text_position =
                     screen_space_coordinate_by_percent(aspect, 0.25.into(), 0.60.into()).extend(0.);
-                let mut text_component_builder =
-                    create_new_text::<_, RegularText>(CreateTextInput {
+                let mut text_component_builder = create_new_text::<_, RegularText>(
+                    CreateTextInput {
                         text: title_from_material_type(&MaterialType::Sprite),
-                        text_type: TextTypes::Regular,
+                        text_type: TextTypes::RegularRelative(REGULAR_FONT_HEIGHT_PERCENT),
                         position: standard_material_text_position,
                         ..Default::default()
-                    });
+                    },
+                    aspect,
+                );
                 text_component_builder.add_component(InteractiveText::new(
                     TransitionTo::MaterialSelection(MaterialType::Sprite, None),
                 ));
                 Engine::spawn(&text_component_builder.build());
 
-                let mut text_component_builder =
-                    create_new_text::<_, RegularText>(CreateTextInput {
+                let mut text_component_builder = create_new_text::<_, RegularText>(
+                    CreateTextInput {
                         text: title_from_material_type(&MaterialType::PostProcessing),
-                        text_type: TextTypes::Regular,
+                        text_type: TextTypes::RegularRelative(REGULAR_FONT_HEIGHT_PERCENT),
                         position: screen_space_coordinate_by_percent(
                             aspect,
                             0.75.into(),
@@ -2264,19 +10422,76 @@ impl View {
                         )
                         .extend(0.),
                         ..Default::default()
-                    });
+                    },
+                    aspect,
+                );
                 text_component_builder.add_component(InteractiveText::new(
                     TransitionTo::MaterialSelection(MaterialType::PostProcessing, None),
                 ));
                 Engine::spawn(&text_component_builder.build());
 
-                self.view_state = ViewState::MainView(MaterialType::Sprite);
+                self.resume_last_test = LastTest::read(LAST_TEST_PATH).and_then(|last_test| {
+                    material_test_query
+                        .iter()
+                        .find(|material_test| {
+                            *material_test.material_type() == last_test.material_type
+                                && material_test.name() == last_test.name
+                        })
+                        .map(|material_test| ResumeLastTest {
+                            title: format!(
+                                "{}: {}",
+                                tr("menu.resume_last_test"),
+                                test_display_name(&last_test.name)
+                            ),
+                            transition_to: TransitionTo::Material((
+                                last_test.material_type,
+                                material_test.id(),
+                            )),
+                        })
+                });
+
+                let (underlined_text_position, underlined_text) = if let Some(resume_last_test) =
+                    &self.resume_last_test
+                {
+                    let resume_last_test_text_position =
+                        screen_space_coordinate_by_percent(aspect, 0.5.into(), 0.45.into())
+                            .extend(0.);
+                    let mut text_component_builder = create_new_text::<_, RegularText>(
+                        CreateTextInput {
+                            text: resume_last_test.title.as_str(),
+                            text_type: TextTypes::RegularRelative(REGULAR_FONT_HEIGHT_PERCENT),
+                            position: resume_last_test_text_position,
+                            ..Default::default()
+                        },
+                        aspect,
+                    );
+                    text_component_builder
+                        .add_component(InteractiveText::new(resume_last_test.transition_to));
+                    Engine::spawn(&text_component_builder.build());
+
+                    self.view_state = ViewState::MainView(MainViewSelection::ResumeLastTest);
+                    (
+                        resume_last_test_text_position,
+                        resume_last_test.title.as_str(),
+                    )
+                } else {
+                    self.view_state =
+                        ViewState::MainView(MainViewSelection::MaterialType(MaterialType::Sprite));
+                    (
+                        standard_material_text_position,
+                        title_from_material_type(&MaterialType::Sprite),
+                    )
+                };
 
                 let underline_offset =
                     Vec3::new(0., *UNDERLINE_OFFSET_Y_PERCENT * aspect.height, 0.);
                 let mut underline_component_builder = create_underline(
-                    (standard_material_text_position - underline_offset).into(),
-                    None,
+                    (underlined_text_position - underline_offset).into(),
+                    Some(measure_text(
+                        underlined_text,
+                        TextTypes::RegularRelative(REGULAR_FONT_HEIGHT_PERCENT)
+                            .font_size(aspect.height),
+                    )),
                     aspect,
                 );
                 underline_component_builder.add_component(NonInteractiveText);
@@ -2294,10 +10509,10 @@ impl View {
                     .collect::<Vec<_>>();
                 world_render_manager.remove_postprocesses(&postprocess_material_ids);
 
-                let mut text_component_builder =
-                    create_new_text::<_, HeaderText>(CreateTextInput {
+                let mut text_component_builder = create_new_text::<_, HeaderText>(
+                    CreateTextInput {
                         text: title_from_material_type(material_type),
-                        text_type: TextTypes::Header,
+                        text_type: TextTypes::HeaderRelative(HEADER_FONT_HEIGHT_PERCENT),
                         position: screen_space_coordinate_by_percent(
                             aspect,
                             0.5.into(),
@@ -2305,54 +10520,245 @@ impl View {
                         )
                         .extend(0.),
                         ..Default::default()
-                    });
+                    },
+                    aspect,
+                );
                 text_component_builder.add_component(NonInteractiveText);
                 Engine::spawn(&text_component_builder.build());
 
-                let mut material_test_id_order = vec![];
-                let left_column_starting_position =
-                    screen_space_coordinate_by_percent(aspect, 0.25.into(), 0.6.into()).extend(0.);
-                let right_column_starting_position =
-                    screen_space_coordinate_by_percent(aspect, 0.75.into(), 0.6.into()).extend(0.);
-                material_test_query
+                let available_tags = available_tags(
+                    material_test_query
+                        .iter()
+                        .filter(|material_test| material_test.material_type() == material_type)
+                        .flat_map(|material_test| material_test.tags()),
+                );
+                // The other material type's tests may not have the tag
+                if self.material_selection_tag.as_deref().is_some_and(|tag| {
+                    !available_tags
+                        .iter()
+                        .any(|available_tag| *available_tag == tag)
+                }) {
+                    self.material_selection_tag = None;
+                }
+                let material_selection_filter = self.material_selection_filter.clone();
+                let material_selection_tag = self.material_selection_tag.clone();
+                let matches_filter = |material_test: &MaterialTest| {
+                    material_selection_filter
+                        .as_ref()
+                        .is_none_or(|filter| material_test.name().contains(filter.as_str()))
+                        && material_selection_tag
+                            .as_ref()
+                            .is_none_or(|tag| material_test.has_tag(tag))
+                };
+                // The tag row makes way for the filter being typed, which names the tag instead
+                if let Some(filter) = &material_selection_filter {
+                    let filter_label = match &material_selection_tag {
+                        Some(tag) => format!("{} [{tag}]", tr("menu.filter")),
+                        None => tr("menu.filter").to_string(),
+                    };
+                    let mut text_component_builder = create_new_text::<_, RegularText>(
+                        CreateTextInput {
+                            text: &format!("{filter_label}: {filter}_"),
+                            text_type: TextTypes::RegularRelative(REGULAR_FONT_HEIGHT_PERCENT),
+                            position: screen_space_coordinate_by_percent(
+                                aspect,
+                                0.5.into(),
+                                0.675.into(),
+                            )
+                            .extend(0.),
+                            ..Default::default()
+                        },
+                        aspect,
+                    );
+                    text_component_builder.add_component(NonInteractiveText);
+                    Engine::spawn(&text_component_builder.build());
+                } else if !available_tags.is_empty() {
+                    let mut text_component_builder = create_new_text::<_, CustomText>(
+                        CreateTextInput {
+                            text: &format!(
+                                "{} ({}): {}",
+                                tr("menu.tags"),
+                                bound_keys_text(InputAction::TagNext),
+                                tag_row_text(
+                                    tr("menu.all_tags"),
+                                    &available_tags,
+                                    material_selection_tag.as_deref()
+                                )
+                            ),
+                            text_type: TextTypes::Custom(
+                                aspect.height * MATERIAL_SELECTION_DETAILS_FONT_PERCENT,
+                            ),
+                            position: screen_space_coordinate_by_percent(
+                                aspect,
+                                0.5.into(),
+                                0.675.into(),
+                            )
+                            .extend(0.),
+                            ..Default::default()
+                        },
+                        aspect,
+                    );
+                    text_component_builder.add_component(NonInteractiveText);
+                    Engine::spawn(&text_component_builder.build());
+                }
+
+                let material_tests = material_test_query
                     .iter()
                     .filter(|material_test| material_test.material_type() == material_type)
-                    .enumerate()
-                    .for_each(|(index, material_test)| {
-                        material_test_id_order.push(material_test.id);
+                    .filter(|material_test| matches_filter(material_test))
+                    .collect::<Vec<_>>();
+                // Favorites and recent tests are listed ahead of the rest, while filtering the
+                // matches are listed as registered so Enter picks the first registered match
+                let grouped_order = if material_selection_filter.is_none() {
+                    let names = material_tests
+                        .iter()
+                        .map(|material_test| material_test.name())
+                        .collect::<Vec<_>>();
+                    test_usage_history.grouped_order(*material_type, &names)
+                } else {
+                    (0..material_tests.len())
+                        .map(|index| (SelectionGroup::Other, index))
+                        .collect()
+                };
+                let material_test_id_order = grouped_order
+                    .iter()
+                    .map(|(_, material_test_index)| material_tests[*material_test_index].id)
+                    .collect::<Vec<_>>();
+                let selected_index = (*specified_material_test_id)
+                    .and_then(|specified_material_test_id| {
+                        material_test_id_order.iter().position(|material_test_id| {
+                            *material_test_id == specified_material_test_id
+                        })
+                    })
+                    .unwrap_or(0);
+                let page = material_selection_page(selected_index);
+                let page_count = material_selection_page_count(material_test_id_order.len());
+                if page_count > 1 {
+                    let mut text_component_builder = create_new_text::<_, RegularText>(
+                        CreateTextInput {
+                            text: &format!(
+                                "{} {}/{page_count}  ({}/{})",
+                                tr("menu.page"),
+                                page + 1,
+                                bound_keys_text(InputAction::PageUp),
+                                bound_keys_text(InputAction::PageDown)
+                            ),
+                            text_type: TextTypes::RegularRelative(REGULAR_FONT_HEIGHT_PERCENT),
+                            position: screen_space_coordinate_by_percent(
+                                aspect,
+                                0.5.into(),
+                                0.1.into(),
+                            )
+                            .extend(0.),
+                            ..Default::default()
+                        },
+                        aspect,
+                    );
+                    text_component_builder.add_component(NonInteractiveText);
+                    Engine::spawn(&text_component_builder.build());
+                }
 
-                        let (quotient, remainder) = division_result(index, 2);
-                        let position = if remainder % 2 == 0 {
-                            left_column_starting_position
-                        } else {
-                            right_column_starting_position
-                        } - quotient as f32 * Vec3::new(0., 0.1 * aspect.height, 0.);
+                // Post processing materials can't be drawn on a sprite, so only sprite tests get
+                // thumbnails
+                let thumbnail_texture_id = if *material_type == MaterialType::Sprite {
+                    gpu_interface
+                        .texture_asset_manager
+                        .get_texture_by_path(&"textures/scared.png".into())
+                        .map(|texture| texture.id())
+                } else {
+                    None
+                };
+                let thumbnail_offset = Vec3::new(-0.18 * aspect.width, 0., 0.);
+
+                let grid_layout = GridLayout {
+                    first_cell_percent: Vec2::new(0.25, 0.6),
+                    columns: 2,
+                    cell_spacing_percent: Vec2::new(0.5, 0.1),
+                };
+                // Labels are only needed once some test is a favorite or was opened before
+                let grouped = grouped_order
+                    .first()
+                    .is_some_and(|(group, _)| *group != SelectionGroup::Other);
+                let group_label_offset = Vec3::new(0., 0.05 * aspect.height, 0.);
+                grouped_order
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| material_selection_page(*index) == page)
+                    .for_each(|(index, (group, material_test_index))| {
+                        let material_test = &material_tests[*material_test_index];
+                        let position = grid_layout
+                            .position(aspect, index % MATERIAL_SELECTION_PAGE_SIZE)
+                            .extend(0.);
+
+                        // A group is labelled above its first test, and again at the top of
+                        // each page it continues on
+                        if grouped
+                            && (index % MATERIAL_SELECTION_PAGE_SIZE == 0
+                                || grouped_order[index - 1].0 != *group)
+                        {
+                            let mut text_component_builder = create_new_text::<_, CustomText>(
+                                CreateTextInput {
+                                    text: tr(group.label_key()),
+                                    text_type: TextTypes::Custom(
+                                        aspect.height * MATERIAL_SELECTION_DETAILS_FONT_PERCENT,
+                                    ),
+                                    position: position + group_label_offset,
+                                    ..Default::default()
+                                },
+                                aspect,
+                            );
+                            text_component_builder.add_component(NonInteractiveText);
+                            Engine::spawn(&text_component_builder.build());
+                        }
 
-                        let mut text_component_builder =
-                            create_new_text::<_, RegularText>(CreateTextInput {
-                                text: u8_array_to_str(&material_test.name).unwrap(),
-                                text_type: TextTypes::Regular,
+                        let mut text_component_builder = create_new_text::<_, RegularText>(
+                            CreateTextInput {
+                                text: test_display_name(material_test.name()),
+                                text_type: TextTypes::RegularRelative(REGULAR_FONT_HEIGHT_PERCENT),
                                 position,
                                 ..Default::default()
-                            });
+                            },
+                            aspect,
+                        );
 
                         text_component_builder.add_component(InteractiveText::new(
                             TransitionTo::Material((*material_type, material_test.id)),
                         ));
                         Engine::spawn(&text_component_builder.build());
 
-                        let should_add_underline =
-                            if let Some(specified_material_test_id) = specified_material_test_id {
-                                specified_material_test_id == &material_test.id
-                            } else {
-                                index == 0
-                            };
-                        if should_add_underline {
+                        if let Some(material_params) =
+                            thumbnail_texture_id.and_then(|thumbnail_texture_id| {
+                                selection_thumbnail_material_parameters(
+                                    gpu_interface,
+                                    material_test,
+                                    thumbnail_texture_id,
+                                )
+                            })
+                        {
+                            let mut texture_component_builder = create_new_relative_texture(
+                                position + thumbnail_offset,
+                                *palette::WHITE,
+                                thumbnail_texture_id.unwrap(),
+                                Vec2::splat(aspect.height * 0.07),
+                                RelativeScale::OfHeight,
+                            );
+                            texture_component_builder.add_components(bundle_for_builder!(
+                                NonInteractiveText,
+                                material_params
+                            ));
+                            Engine::spawn(&texture_component_builder.build());
+                        }
+
+                        if index == selected_index {
                             let underline_offset =
                                 Vec3::new(0., *UNDERLINE_OFFSET_Y_PERCENT * aspect.height, 0.);
                             let mut underline_component_builder = create_underline(
                                 (position - underline_offset).into(),
-                                None,
+                                Some(measure_text(
+                                    test_display_name(material_test.name()),
+                                    TextTypes::RegularRelative(REGULAR_FONT_HEIGHT_PERCENT)
+                                        .font_size(aspect.height),
+                                )),
                                 aspect,
                             );
                             underline_component_builder.add_component(NonInteractiveText);
@@ -2362,11 +10768,7 @@ impl View {
 
                 self.view_state = ViewState::MaterialSelection((
                     *material_type,
-                    if let Some(specified_material_test) = specified_material_test_id {
-                        Some(*specified_material_test)
-                    } else {
-                        material_test_id_order.first().copied()
-                    },
+                    material_test_id_order.get(selected_index).copied(),
                     material_test_id_order,
                 ));
             }
@@ -2379,14 +10781,131 @@ impl View {
                     Some(*material_test_id),
                 ));
 
-                let name = material_test_query
+                // Tests can follow each other directly when a playlist is running, so the previous
+                // test's systems and postprocesses are cleaned up here as well
+                turn_off_material_test_systems();
+
+                let postprocess_material_ids = world_render_manager
+                    .postprocesses()
+                    .iter()
+                    .map(|post_process| *post_process.material_id())
+                    .collect::<Vec<_>>();
+                world_render_manager.remove_postprocesses(&postprocess_material_ids);
+
+                let material_test = material_test_query
                     .iter()
                     .find(|material_test_object| material_test_object.id() == *material_test_id)
-                    .unwrap()
-                    .name()
-                    .to_string();
+                    .unwrap();
+                Engine::set_system_enabled(material_test.startup_system_name(), true, module_name);
+                let name = material_test.name().to_string();
+                let last_test = LastTest {
+                    material_type: *material_type,
+                    name: name.clone(),
+                };
+                if let Err(error) = last_test.write(LAST_TEST_PATH) {
+                    warn!("Could not save the last test to {LAST_TEST_PATH}: {error}");
+                }
+                test_usage_history.record_open(*material_type, &name);
                 self.view_state = ViewState::Material((*material_test_id, name));
             }
+            TransitionTo::Error => {
+                // Going back returns to the failed test in its material selection
+                self.esc_transition = Some(match &self.view_state {
+                    ViewState::Material((material_test_id, _)) => material_test_query
+                        .iter()
+                        .find(|material_test| material_test.id() == *material_test_id)
+                        .map_or(TransitionTo::MainView, |material_test| {
+                            TransitionTo::MaterialSelection(
+                                *material_test.material_type(),
+                                Some(*material_test_id),
+                            )
+                        }),
+                    _ => TransitionTo::MainView,
+                });
+
+                turn_off_material_test_systems();
+
+                let postprocess_material_ids = world_render_manager
+                    .postprocesses()
+                    .iter()
+                    .map(|post_process| *post_process.material_id())
+                    .collect::<Vec<_>>();
+                world_render_manager.remove_postprocesses(&postprocess_material_ids);
+
+                let error_message = self.error_message.take().unwrap_or_default();
+
+                let mut text_component_builder = create_new_text::<_, HeaderText>(
+                    CreateTextInput {
+                        text: tr("menu.test_failed"),
+                        text_type: TextTypes::HeaderRelative(HEADER_FONT_HEIGHT_PERCENT),
+                        position: screen_space_coordinate_by_percent(
+                            aspect,
+                            0.5.into(),
+                            0.75.into(),
+                        )
+                        .extend(0.),
+                        ..Default::default()
+                    },
+                    aspect,
+                );
+                text_component_builder.add_component(NonInteractiveText);
+                Engine::spawn(&text_component_builder.build());
+
+                let mut text_component_builder = create_new_text::<_, RegularText>(
+                    CreateTextInput {
+                        text: &error_message,
+                        text_type: TextTypes::RegularRelative(REGULAR_FONT_HEIGHT_PERCENT),
+                        position: screen_space_coordinate_by_percent(
+                            aspect,
+                            0.5.into(),
+                            0.5.into(),
+                        )
+                        .extend(0.),
+                        max_width: Some(aspect.width * 0.8),
+                        ..Default::default()
+                    },
+                    aspect,
+                );
+                text_component_builder.add_component(NonInteractiveText);
+                Engine::spawn(&text_component_builder.build());
+
+                let mut text_component_builder = create_new_text::<_, RegularText>(
+                    CreateTextInput {
+                        text: tr("menu.back_to_menu"),
+                        text_type: TextTypes::RegularRelative(REGULAR_FONT_HEIGHT_PERCENT),
+                        position: screen_space_coordinate_by_percent(
+                            aspect,
+                            0.5.into(),
+                            0.2.into(),
+                        )
+                        .extend(0.),
+                        ..Default::default()
+                    },
+                    aspect,
+                );
+                text_component_builder.add_component(NonInteractiveText);
+                Engine::spawn(&text_component_builder.build());
+
+                self.view_state = ViewState::Error(error_message);
+            }
+            TransitionTo::Exit => {
+                self.esc_transition = None;
+
+                turn_off_material_test_systems();
+
+                let postprocess_material_ids = world_render_manager
+                    .postprocesses()
+                    .iter()
+                    .map(|post_process| *post_process.material_id())
+                    .collect::<Vec<_>>();
+                world_render_manager.remove_postprocesses(&postprocess_material_ids);
+
+                self.view_state = ViewState::Exiting;
+                set_system_enabled!(true, exit_system);
+            }
+        }
+        if let Some(teardown_system_name) = teardown_system_name {
+            Engine::set_system_enabled(&teardown_system_name, true, module_name);
         }
         self.clear_transitioning_to();
     }
@@ -2397,12 +10916,231 @@ include!(concat!(env!("OUT_DIR"), "/ffi.rs"));
 
 #[cfg(test)]
 mod test {
+    use std::{
+        fs,
+        num::NonZero,
+        path::{Path, PathBuf},
+        time::{Duration, Instant},
+    };
+
     use game_asset::{
         ecs_module::MaterialManager,
-        resource_managers::material_manager::{DEFAULT_SHADER_ID, DEFAULT_SHADER_TEXT},
+        resource_managers::material_manager::{
+            DEFAULT_SHADER_ID, DEFAULT_SHADER_TEXT, materials::MaterialType,
+        },
+    };
+    use proptest::prelude::*;
+    use snapshot::{Deserialize, Deserializer, Serialize, Serializer};
+    use void_public::{Vec2, material::MaterialId, text::TextId};
+
+    use crate::{
+        InteractiveText, MaterialTest, MaterialTestIdHolder, MaterialTestRegistry,
+        MaybeLoadedMaterial, PercentPosition, TestClock, TimePassedSinceCreation, TransitionTo,
+        math::layout::RelativeScale,
+        test_validation::{Profile, UniformLayoutMismatch, WgslLint, WgslValidator},
+        wrap_index,
     };
 
-    use crate::test_validation::WgslValidator;
+    /// `value` written with the engine's snapshot serializer
+    fn snapshot_bytes<T: Serialize>(value: &T) -> Vec<u8> {
+        let mut bytes = vec![];
+        value.serialize(&mut Serializer::new(&mut bytes)).unwrap();
+        bytes
+    }
+
+    fn snapshot_round_trip<T: Deserialize + Serialize>(value: &T) -> T {
+        let bytes = snapshot_bytes(value);
+        unsafe { T::deserialize(&mut Deserializer::new(bytes.as_slice())) }.unwrap()
+    }
+
+    #[test]
+    fn snapshot_mid_test_round_trip() {
+        let text_id = TextId(NonZero::new(7).unwrap());
+        let mut material_test_id_holder = MaterialTestIdHolder::default();
+        let mut material_test = MaterialTest::new(
+            "warp",
+            c"warp_startup_system",
+            &[MaybeLoadedMaterial::new(
+                MaterialType::PostProcessing,
+                text_id,
+            )],
+            &MaterialType::PostProcessing,
+            &mut material_test_id_holder,
+        )
+        .with_teardown_system(c"warp_teardown_system");
+        material_test.update_maybe_loaded_materials(text_id, MaterialId(3));
+        let interactive_text = InteractiveText::new(TransitionTo::Material((
+            MaterialType::PostProcessing,
+            material_test.id(),
+        )));
+        let time_passed = TimePassedSinceCreation::default() + 2.5;
+
+        let test_clock = TestClock {
+            paused: true,
+            time_scale: 0.25,
+            ..Default::default()
+        };
+
+        let restored_material_test = snapshot_round_trip(&material_test);
+        assert_eq!(restored_material_test.name(), "warp");
+        assert_eq!(
+            restored_material_test.teardown_system_name(),
+            Some(c"warp_teardown_system")
+        );
+        assert_eq!(
+            restored_material_test.material_id_iter().next(),
+            Some(Some(MaterialId(3)))
+        );
+        let restored_test_clock = snapshot_round_trip(&test_clock);
+        assert!(restored_test_clock.paused);
+        assert_eq!(restored_test_clock.time_scale, 0.25);
+        // Anything the snapshot drops would change the bytes written for the restored copy
+        assert_eq!(
+            snapshot_bytes(&restored_material_test),
+            snapshot_bytes(&material_test)
+        );
+        assert_eq!(
+            snapshot_bytes(&snapshot_round_trip(&interactive_text)),
+            snapshot_bytes(&interactive_text)
+        );
+        assert_eq!(
+            snapshot_bytes(&snapshot_round_trip(&time_passed)),
+            snapshot_bytes(&time_passed)
+        );
+        assert_eq!(
+            snapshot_bytes(&restored_test_clock),
+            snapshot_bytes(&test_clock)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn wrap_index_stays_in_bounds(index in any::<isize>(), array_len in 1..usize::MAX) {
+            let wrapped = wrap_index(index, array_len);
+            prop_assert!(wrapped < array_len);
+            prop_assert_eq!(
+                wrapped as i128,
+                (index as i128).rem_euclid(array_len as i128)
+            );
+        }
+
+        #[test]
+        fn validate_new_name_is_free_after_collision_chains(
+            desired_name in "[a-z]{1,8}",
+            chain_length in 0..64usize,
+            other_names in prop::collection::vec("[a-z]{1,8}( \\([0-9]{1,2}\\))?", 0..16),
+        ) {
+            let mut material_test_id_holder = MaterialTestIdHolder::default();
+            for name in (0..chain_length)
+                .map(|index| match index {
+                    0 => desired_name.clone(),
+                    _ => format!("{desired_name} ({})", index + 1),
+                })
+                .chain(other_names)
+            {
+                material_test_id_holder.register_test(&name, MaterialType::Sprite);
+            }
+            let name = material_test_id_holder.validate_new_name(&desired_name);
+            prop_assert!(name.starts_with(&desired_name));
+            prop_assert!(material_test_id_holder.find_by_name(&name).is_none());
+        }
+    }
+
+    #[test]
+    fn colliding_names_are_numbered() {
+        let mut material_test_id_holder = MaterialTestIdHolder::default();
+        let names = (0..3)
+            .map(|_| {
+                material_test_id_holder
+                    .register_test("warp", MaterialType::Sprite)
+                    .name
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names.iter().map(|name| name.as_str()).collect::<Vec<_>>(),
+            ["warp", "warp (2)", "warp (3)"]
+        );
+    }
+
+    #[test]
+    fn removed_tests_free_their_name_but_not_their_id() {
+        let mut material_test_id_holder = MaterialTestIdHolder::default();
+        let warp = material_test_id_holder.register_test("warp", MaterialType::Sprite);
+        let fire = material_test_id_holder.register_test("fire", MaterialType::PostProcessing);
+        assert_eq!(material_test_id_holder.id_for_name("warp"), Some(warp.id));
+        assert_eq!(material_test_id_holder.name_for_id(fire.id), Some("fire"));
+
+        let removed = material_test_id_holder.remove("warp").unwrap();
+        assert_eq!(removed.id, warp.id);
+        assert_eq!(material_test_id_holder.id_for_name("warp"), None);
+        assert_eq!(material_test_id_holder.name_for_id(warp.id), None);
+        assert!(material_test_id_holder.remove("warp").is_none());
+
+        // Re-registering doesn't get a suffix, but does get an id no other test has had
+        let reloaded = material_test_id_holder.register_test("warp", MaterialType::Sprite);
+        assert_eq!(reloaded.name.as_str(), "warp");
+        assert_ne!(reloaded.id, warp.id);
+        assert_ne!(reloaded.id, fire.id);
+        assert_eq!(material_test_id_holder.name_for_id(warp.id), None);
+        assert_eq!(
+            material_test_id_holder.name_for_id(reloaded.id),
+            Some("warp")
+        );
+        assert_eq!(
+            material_test_id_holder
+                .iter()
+                .map(|registered_test| registered_test.name.as_str())
+                .collect::<Vec<_>>(),
+            ["fire", "warp"]
+        );
+    }
+
+    #[test]
+    fn wrap_index_edge_cases() {
+        assert_eq!(wrap_index(-1, 3), 2);
+        assert_eq!(wrap_index(isize::MIN, 3), 1);
+        assert_eq!(wrap_index(isize::MAX, 1), 0);
+        assert_eq!(wrap_index(5, 0), 0);
+    }
+
+    #[test]
+    fn material_test_registry_caches_loaded_materials() {
+        let text_id = TextId(NonZero::new(11).unwrap());
+        let mut material_test_id_holder = MaterialTestIdHolder::default();
+        let mut material_test = MaterialTest::new(
+            "starfield",
+            c"starfield_startup_system",
+            &[MaybeLoadedMaterial::new(MaterialType::Sprite, text_id)],
+            &MaterialType::Sprite,
+            &mut material_test_id_holder,
+        );
+        let mut material_test_registry = MaterialTestRegistry::default();
+        assert!(material_test_registry.is_empty());
+        material_test_registry.update(&material_test);
+        assert!(!material_test_registry.is_empty());
+        assert!(
+            material_test_registry
+                .get_by_name("starfield")
+                .unwrap()
+                .first_material_id()
+                .is_none()
+        );
+
+        material_test.update_maybe_loaded_materials(text_id, MaterialId(5));
+        material_test_registry.update(&material_test);
+        let material_test_entry = material_test_registry
+            .get_by_id(material_test.id())
+            .unwrap();
+        assert_eq!(material_test_entry.name, "starfield");
+        assert_eq!(material_test_entry.material_type, MaterialType::Sprite);
+        assert_eq!(
+            material_test_entry
+                .first_material_id()
+                .map(|material_id| material_id.0),
+            Some(5)
+        );
+        assert!(material_test_registry.get_by_name("warp").is_none());
+    }
 
     #[test]
     fn validate_shader() {
@@ -2465,18 +11203,398 @@ mod test {
         );
     }
 
-    #[ignore]
     #[test]
-    // This is a helper function for outputing the shader string while developing a shader
-    fn output_shader_string() {
+    fn color_replacement_bindings() {
+        let mut validation = WgslValidator::default();
         let mut material_manager = MaterialManager::default();
-        let toml_string = include_str!("../assets/toml_materials/sprite/scrolling_color.toml");
-        let material_id = material_manager
-            .register_material_from_string(DEFAULT_SHADER_ID, "scrolling_color", toml_string)
+        let color_replacement_material_id = material_manager
+            .register_material_from_string(
+                DEFAULT_SHADER_ID,
+                "color_replacement",
+                include_str!("../assets/toml_materials/sprite/color_replacement.toml"),
+            )
+            .unwrap();
+        let wgsl = material_manager
+            .generate_shader_text(color_replacement_material_id)
             .unwrap();
-        let wgsl = material_manager.generate_shader_text(material_id).unwrap();
-        println!("{wgsl}");
-        // Failing this test otherwise std out is supressed
-        panic!();
+        let wgsl_metadata = validation.emit_wgsl_metadata(&wgsl).unwrap();
+        assert_eq!(
+            wgsl_metadata
+                .entry_point_meta_data_iter()
+                .map(|entry_point| entry_point.stage.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["vertex", "fragment"]
+        );
+        let color_tex = wgsl_metadata.global_variable("color_tex").unwrap();
+        let sampler_color_tex = wgsl_metadata.global_variable("sampler_color_tex").unwrap();
+        assert_eq!(color_tex.address_space, "handle");
+        assert_eq!(color_tex.type_name, "texture_2d<f32>");
+        assert_eq!(sampler_color_tex.type_name, "sampler");
+        assert!(color_tex.binding.is_some());
+        assert_ne!(color_tex.binding, sampler_color_tex.binding);
+        assert_eq!(
+            wgsl_metadata
+                .global_variable("global_uniforms")
+                .unwrap()
+                .address_space,
+            "uniform"
+        );
+        assert!(wgsl_metadata.colliding_bindings().is_empty());
+    }
+
+    #[test]
+    fn profiles_limit_capabilities() {
+        let wgsl = r#"
+@fragment
+fn fs_main(@builtin(primitive_index) primitive_index: u32) -> @location(0) vec4f {
+    return vec4f(f32(primitive_index));
+}
+"#;
+        assert!(
+            WgslValidator::with_profile(Profile::Native)
+                .validate_wgsl_string(wgsl)
+                .is_ok()
+        );
+        assert!(
+            WgslValidator::with_profile(Profile::WebGl2)
+                .validate_wgsl_string(wgsl)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn uniform_layout_mismatches_are_found() {
+        let wgsl = r#"
+struct SceneInstance {
+    model: vec4f,
+    speed: f32,
+    color: vec4f,
+    offsets: array<vec4f, 2>,
+}
+
+@group(0) @binding(0) var<uniform> scene_instance: SceneInstance;
+"#;
+        let mut validation = WgslValidator::default();
+        let check = |validation: &mut WgslValidator, toml_string: &str| {
+            validation.check_uniform_layout(toml_string, wgsl).unwrap()
+        };
+
+        assert!(
+            check(
+                &mut validation,
+                r#"
+[uniform_types]
+speed = { type = "f32", default = 0.1 }
+color = "vec4f"
+offsets = "array<vec4f, 2>"
+"#
+            )
+            .is_empty()
+        );
+        assert_eq!(
+            check(
+                &mut validation,
+                r#"
+[uniform_types]
+color = "f32"
+missing = "f32"
+speed = { type = "vec4f", default = [0.0, 0.0, 0.0, 1.0] }
+
+[uniform_ranges]
+speed = [0.0, 1.0]
+"#
+            ),
+            vec![
+                UniformLayoutMismatch::Missing {
+                    name: "missing".to_string()
+                },
+                UniformLayoutMismatch::Size {
+                    name: "speed".to_string(),
+                    declared: 16,
+                    wgsl: 4
+                },
+                UniformLayoutMismatch::Size {
+                    name: "color".to_string(),
+                    declared: 4,
+                    wgsl: 16
+                },
+            ]
+        );
+        // A member the TOML doesn't declare shifts the uniforms after it
+        assert_eq!(
+            check(
+                &mut validation,
+                r#"
+[uniform_types]
+speed = "f32"
+offsets = "array<vec4f, 2>"
+"#
+            ),
+            vec![UniformLayoutMismatch::Offset {
+                name: "offsets".to_string(),
+                expected: 32,
+                wgsl: 48
+            }]
+        );
+    }
+
+    /// Every `.toml` file under `directory` and its subdirectories, sorted
+    fn toml_files(directory: &Path) -> Vec<PathBuf> {
+        let mut toml_files = fs::read_dir(directory)
+            .unwrap_or_else(|error| panic!("Could not read {}: {error}", directory.display()))
+            .flat_map(|entry| {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    toml_files(&path)
+                } else if path
+                    .extension()
+                    .is_some_and(|extension| extension == "toml")
+                {
+                    vec![path]
+                } else {
+                    vec![]
+                }
+            })
+            .collect::<Vec<_>>();
+        toml_files.sort();
+        toml_files
+    }
+
+    /// Every TOML material in the assets along with its material type, TOML and generated WGSL
+    fn generated_toml_materials() -> Vec<(PathBuf, MaterialType, String, String)> {
+        let toml_materials_directory =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("assets/toml_materials");
+        let mut material_manager = MaterialManager::default();
+        let mut generated_toml_materials = vec![];
+        for path in toml_files(&toml_materials_directory) {
+            // The material type is the directory right below toml_materials
+            let material_type = match path
+                .strip_prefix(&toml_materials_directory)
+                .unwrap()
+                .components()
+                .next()
+                .and_then(|component| component.as_os_str().to_str())
+            {
+                Some("sprite") => MaterialType::Sprite,
+                Some("post_processing") => MaterialType::PostProcessing,
+                _ => panic!("{} is not in a material type directory", path.display()),
+            };
+            let name = path.file_stem().unwrap().to_str().unwrap();
+            let toml_string = fs::read_to_string(&path).unwrap();
+            let material_id = material_manager
+                .register_material_from_string(
+                    material_type.into_shader_template_id(),
+                    name,
+                    &toml_string,
+                )
+                .unwrap_or_else(|error| panic!("{} failed to register: {error:?}", path.display()));
+            let wgsl = material_manager
+                .generate_shader_text(material_id)
+                .unwrap_or_else(|error| {
+                    panic!("{} failed to generate WGSL: {error:?}", path.display())
+                });
+            generated_toml_materials.push((path, material_type, toml_string, wgsl));
+        }
+        assert!(!generated_toml_materials.is_empty(), "No materials found");
+        generated_toml_materials
+    }
+
+    #[test]
+    fn every_toml_material_is_valid_wgsl() {
+        let mut validation = WgslValidator::default();
+        let mut profile_validations =
+            Profile::ALL.map(|profile| (profile, WgslValidator::with_profile(profile)));
+        for (path, _, toml_string, wgsl) in generated_toml_materials() {
+            for (profile, profile_validation) in &mut profile_validations {
+                if let Err(error) = profile_validation.validate_wgsl_string(&wgsl) {
+                    panic!(
+                        "{} is not valid WGSL for {profile:?}: {error}",
+                        path.display()
+                    );
+                }
+            }
+            // The template's bindings and the material's textures must not share a slot
+            let colliding_bindings = validation
+                .emit_wgsl_metadata(&wgsl)
+                .unwrap()
+                .colliding_bindings();
+            assert!(
+                colliding_bindings.is_empty(),
+                "{} binds several globals to (group, binding) {colliding_bindings:?}",
+                path.display()
+            );
+            // Mismatches would only show up as garbage on screen
+            let uniform_layout_mismatches = validation
+                .check_uniform_layout(&toml_string, &wgsl)
+                .unwrap();
+            assert!(
+                uniform_layout_mismatches.is_empty(),
+                "{} uniforms don't match the WGSL: {}",
+                path.display(),
+                uniform_layout_mismatches
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+    }
+
+    #[cfg(feature = "cross_compile")]
+    #[test]
+    fn every_toml_material_cross_compiles() {
+        let mut validation = WgslValidator::default();
+        for (path, _, _, wgsl) in generated_toml_materials() {
+            if let Err(error) = validation.cross_compile(&wgsl) {
+                panic!("{} does not cross compile: {error}", path.display());
+            }
+        }
+    }
+
+    #[test]
+    fn lint_finds_unreachable_items() {
+        let wgsl = r#"
+const SCALE: f32 = 2.0;
+const UNUSED_SCALE: f32 = 3.0;
+
+@group(0) @binding(0) var<uniform> used_uniform: vec4f;
+@group(0) @binding(1) var<uniform> unused_uniform: vec4f;
+
+fn scaled(value: vec4f) -> vec4f {
+    return value * SCALE;
+}
+
+fn called_from_loop() {}
+
+fn dead_code() -> vec4f {
+    return unused_uniform;
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4f {
+    for (var i = 0; i < 2; i++) {
+        called_from_loop();
+    }
+    return scaled(used_uniform);
+}
+"#;
+        let lint = WgslValidator::default().lint(wgsl).unwrap();
+        assert_eq!(lint.unused_globals, vec!["unused_uniform".to_string()]);
+        assert_eq!(lint.unused_functions, vec!["dead_code".to_string()]);
+        assert_eq!(lint.unused_constants, vec!["UNUSED_SCALE".to_string()]);
+    }
+
+    #[test]
+    fn shader_templates_have_no_dead_code() {
+        let mut validation = WgslValidator::default();
+        // Template items only some materials use are fine, so only what every material of a
+        // type leaves unused is dead code of its template
+        let mut template_lints: Vec<(MaterialType, WgslLint)> = vec![];
+        for (path, material_type, _, wgsl) in generated_toml_materials() {
+            let lint = validation
+                .lint(&wgsl)
+                .unwrap_or_else(|error| panic!("{} failed to lint: {error}", path.display()));
+            match template_lints
+                .iter_mut()
+                .find(|(template_type, _)| *template_type == material_type)
+            {
+                Some((_, template_lint)) => template_lint.retain_common(&lint),
+                None => template_lints.push((material_type, lint)),
+            }
+        }
+        for (material_type, template_lint) in template_lints {
+            assert!(
+                template_lint.is_empty(),
+                "The {material_type:?} shader template has dead code: {template_lint:?}"
+            );
+        }
+    }
+
+    #[ignore]
+    #[test]
+    // This is a helper function for measuring how long every material takes to compile, so
+    // slower shader generation or templates show up. Run it in release with
+    // `cargo test --release bench_shader_compile_times -- --ignored --nocapture`
+    fn bench_shader_compile_times() {
+        const ITERATIONS: u32 = 100;
+        let mut validation = WgslValidator::default();
+        let mut totals = [Duration::ZERO; 3];
+        println!(
+            "{:<40} {:>12} {:>12} {:>12}",
+            "material", "register µs", "generate µs", "validate µs"
+        );
+        let micros = |duration: Duration| duration.as_secs_f64() * 1_000_000. / ITERATIONS as f64;
+        for (path, material_type, toml_string, _) in generated_toml_materials() {
+            let name = path.file_stem().unwrap().to_str().unwrap();
+            let mut durations = [Duration::ZERO; 3];
+            for _ in 0..ITERATIONS {
+                // Every iteration registers into an empty manager
+                let mut material_manager = MaterialManager::default();
+                let start = Instant::now();
+                let material_id = material_manager
+                    .register_material_from_string(
+                        material_type.into_shader_template_id(),
+                        name,
+                        &toml_string,
+                    )
+                    .unwrap();
+                let registered = Instant::now();
+                let wgsl = material_manager.generate_shader_text(material_id).unwrap();
+                let generated = Instant::now();
+                validation.validate_wgsl_string(&wgsl).unwrap();
+                durations[0] += registered - start;
+                durations[1] += generated - registered;
+                durations[2] += generated.elapsed();
+            }
+            println!(
+                "{:<40} {:>12.1} {:>12.1} {:>12.1}",
+                format!("{material_type:?}/{name}"),
+                micros(durations[0]),
+                micros(durations[1]),
+                micros(durations[2])
+            );
+            for (total, duration) in totals.iter_mut().zip(durations) {
+                *total += duration;
+            }
+        }
+        println!(
+            "{:<40} {:>12.1} {:>12.1} {:>12.1}",
+            "total",
+            micros(totals[0]),
+            micros(totals[1]),
+            micros(totals[2])
+        );
+    }
+
+    #[test]
+    fn relayout_keeps_screen_percentage_and_relative_scale() {
+        let previous_screen_size = Vec2::new(800., 600.);
+        let screen_size = Vec2::new(1600., 900.);
+        let mut percent_position = PercentPosition::with_relative_scale(RelativeScale::OfWidth);
+        assert_eq!(
+            percent_position.relayout(Vec2::new(200., -150.), previous_screen_size, screen_size),
+            Vec2::new(400., -225.)
+        );
+        assert_eq!(
+            percent_position.rescale(Vec2::splat(100.), previous_screen_size, screen_size),
+            Vec2::splat(200.)
+        );
+        // Moved by something else since the last relayout, so the percentage is captured again
+        assert_eq!(
+            percent_position.relayout(Vec2::new(0., 450.), screen_size, previous_screen_size),
+            Vec2::new(0., 300.)
+        );
+        assert_eq!(
+            percent_position.relayout(Vec2::new(0., 300.), previous_screen_size, screen_size),
+            Vec2::new(0., 450.)
+        );
+        assert_eq!(
+            PercentPosition::default().rescale(
+                Vec2::splat(100.),
+                previous_screen_size,
+                screen_size
+            ),
+            Vec2::splat(100.)
+        );
     }
 }
@@ -1,6 +1,75 @@
 //! Holds our local Error and Result types.
 
-use std::error::Error;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    str::Utf8Error,
+};
 
-pub type LocalError = Box<dyn Error + Send + Sync>;
-pub type Result<T> = core::result::Result<T, LocalError>;
+#[derive(Debug)]
+pub enum TestModuleError {
+    /// A texture or material definition could not be loaded, or isn't loaded yet
+    AssetNotLoaded {
+        asset_path: String,
+        reason: String,
+    },
+    MaterialTestMissing {
+        test_name: String,
+    },
+    /// The [`MaterialTest`](crate::MaterialTest) exists, but has no loaded material
+    MaterialMissing {
+        test_name: String,
+    },
+    Utf8(Utf8Error),
+    MissingNulTerminator,
+    UniformMissing {
+        uniform_name: String,
+        reason: String,
+    },
+    ViewStateInvalid(String),
+}
+
+impl Display for TestModuleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TestModuleError::AssetNotLoaded { asset_path, reason } => {
+                write!(f, "Asset {asset_path} is not loaded: {reason}")
+            }
+            TestModuleError::MaterialTestMissing { test_name } => {
+                write!(f, "Could not find {test_name} material test")
+            }
+            TestModuleError::MaterialMissing { test_name } => {
+                write!(f, "Could not find material id on {test_name}")
+            }
+            TestModuleError::Utf8(utf8_error) => write!(f, "Text is not valid UTF-8: {utf8_error}"),
+            TestModuleError::MissingNulTerminator => write!(
+                f,
+                "Could not find nul terminator on CStr represented as a u8 array"
+            ),
+            TestModuleError::UniformMissing {
+                uniform_name,
+                reason,
+            } => write!(f, "Could not set uniform {uniform_name}: {reason}"),
+            TestModuleError::ViewStateInvalid(message) => {
+                write!(f, "Invalid view state: {message}")
+            }
+        }
+    }
+}
+
+impl Error for TestModuleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TestModuleError::Utf8(utf8_error) => Some(utf8_error),
+            _ => None,
+        }
+    }
+}
+
+impl From<Utf8Error> for TestModuleError {
+    fn from(utf8_error: Utf8Error) -> Self {
+        TestModuleError::Utf8(utf8_error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, TestModuleError>;
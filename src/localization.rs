@@ -0,0 +1,128 @@
+//! Translations for user facing strings. Strings are looked up by key with [`tr`] in the locale
+//! picked with `--locale=<locale>`, falling back to English and then to the key itself. Locales
+//! are flat TOML files at `assets/locales/<locale>.toml` of `key = "value"` lines, where a
+//! `[table]` header prefixes the keys below it, so `starfield` under `[tests]` is looked up as
+//! `tests.starfield`.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use crate::flat_toml::{FlatTomlEntry, flat_toml_entries, parse_string};
+
+pub const LOCALE_ARG: &str = "--locale=";
+pub const LOCALES_DIRECTORY: &str = "assets/locales";
+/// English is compiled in, so every key resolves even when the locale files are missing
+const ENGLISH_TOML: &str = include_str!("../assets/locales/en.toml");
+
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+static ENGLISH: OnceLock<Locale> = OnceLock::new();
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Locale {
+    strings: HashMap<String, String>,
+}
+
+impl Locale {
+    /// Lines that aren't a table header or a `key = "value"` pair are skipped
+    pub fn from_toml_string(toml_string: &str) -> Self {
+        let strings = flat_toml_entries(toml_string)
+            .filter_map(Result::ok)
+            .filter_map(|FlatTomlEntry { table, key, value }| {
+                let key = if table.is_empty() {
+                    key.to_string()
+                } else {
+                    format!("{table}.{key}")
+                };
+                Some((key, parse_string(value)?))
+            })
+            .collect();
+        Self { strings }
+    }
+
+    /// A missing locale file is logged by the caller and English is used instead
+    pub fn read<P: AsRef<Path>>(path: P) -> Option<Self> {
+        Some(Self::from_toml_string(&fs::read_to_string(path).ok()?))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.strings.get(key).map(String::as_str)
+    }
+}
+
+/// Parses `--locale=<locale>`, e.g. `--locale=de`
+pub fn parse_locale_arg(arg: &str) -> Option<&str> {
+    arg.strip_prefix(LOCALE_ARG)
+        .filter(|locale| !locale.is_empty())
+}
+
+pub fn locale_path(locale: &str) -> PathBuf {
+    Path::new(LOCALES_DIRECTORY).join(format!("{locale}.toml"))
+}
+
+/// Only the first locale set is used, returns false if one was already set
+pub fn set_locale(locale: Locale) -> bool {
+    LOCALE.set(locale).is_ok()
+}
+
+/// The translation of `key` in the current locale, or in English if the locale doesn't have it
+pub fn translate(key: &str) -> Option<&'static str> {
+    LOCALE.get().and_then(|locale| locale.get(key)).or_else(|| {
+        ENGLISH
+            .get_or_init(|| Locale::from_toml_string(ENGLISH_TOML))
+            .get(key)
+    })
+}
+
+/// Like [`translate`], but untranslated keys are shown as they are
+pub fn tr(key: &str) -> &str {
+    translate(key).unwrap_or(key)
+}
+
+/// Tests without a translation are shown by their registered name
+pub fn test_display_name(test_name: &str) -> &str {
+    translate(&format!("tests.{test_name}")).unwrap_or(test_name)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::localization::{Locale, parse_locale_arg, tr};
+
+    #[test]
+    fn locale_toml_tables_prefix_keys() {
+        let locale = Locale::from_toml_string(
+            r#"
+# German
+title = "Shader Test"
+
+[menu]
+loading = "Wird geladen..."
+escaped = "Sagt \"Hallo\"\nZweite Zeile"
+broken = Wird geladen
+
+[tests]
+starfield = "星空"
+"#,
+        );
+        assert_eq!(locale.get("title"), Some("Shader Test"));
+        assert_eq!(locale.get("menu.loading"), Some("Wird geladen..."));
+        assert_eq!(
+            locale.get("menu.escaped"),
+            Some("Sagt \"Hallo\"\nZweite Zeile")
+        );
+        assert_eq!(locale.get("menu.broken"), None);
+        assert_eq!(locale.get("tests.starfield"), Some("星空"));
+        assert_eq!(locale.get("starfield"), None);
+    }
+
+    #[test]
+    fn english_is_the_fallback() {
+        assert_eq!(tr("menu.loading"), "Loading...");
+        assert_eq!(tr("menu.not_a_key"), "menu.not_a_key");
+        assert_eq!(parse_locale_arg("--locale=ja"), Some("ja"));
+        assert_eq!(parse_locale_arg("--locale="), None);
+    }
+}
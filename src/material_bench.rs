@@ -0,0 +1,150 @@
+//! Helpers for `--bench-materials`, which runs the stress test once per sprite material with the
+//! same sprite count and seed, records the average frame time of each and writes them ranked from
+//! the most to the least expensive.
+
+use std::{fs, io, path::Path};
+
+pub const BENCH_MATERIALS_ARG: &str = "--bench-materials";
+/// Every material is benchmarked with this many sprites
+pub const MATERIAL_BENCH_SPRITE_COUNT: usize = 2000;
+/// Frames right after the sprites are spawned aren't recorded, pipelines may still be warming up
+pub const MATERIAL_BENCH_WARMUP_SECONDS: f32 = 1.;
+pub const MATERIAL_BENCH_MEASURE_SECONDS: f32 = 5.;
+pub const MATERIAL_BENCH_REPORT_PATH: &str = "material_bench_report.csv";
+
+pub fn is_bench_materials_arg(arg: &str) -> bool {
+    arg == BENCH_MATERIALS_ARG
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaterialBenchResult {
+    pub name: String,
+    pub average_frame_time: f32,
+}
+
+/// Steps through `materials`, `M` identifies the material the stress test spawns its sprites with
+#[derive(Debug)]
+pub struct MaterialBench<M> {
+    materials: Vec<(String, M)>,
+    elapsed: f32,
+    frame_time_total: f32,
+    frame_count: usize,
+    results: Vec<MaterialBenchResult>,
+}
+
+impl<M: Copy> MaterialBench<M> {
+    /// `materials` are each material's name in the report and its id
+    pub fn new(materials: Vec<(String, M)>) -> Self {
+        Self {
+            materials,
+            elapsed: 0.,
+            frame_time_total: 0.,
+            frame_count: 0,
+            results: vec![],
+        }
+    }
+
+    /// The material being benchmarked, `None` once every material has been
+    pub fn current_material(&self) -> Option<(&str, M)> {
+        self.materials
+            .get(self.results.len())
+            .map(|(name, material)| (name.as_str(), *material))
+    }
+
+    pub fn material_count(&self) -> usize {
+        self.materials.len()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current_material().is_none()
+    }
+
+    pub fn results(&self) -> &[MaterialBenchResult] {
+        &self.results
+    }
+
+    /// Records a frame of the current material, returning true when it is done and the sprites
+    /// should be respawned with the next one
+    pub fn record_frame(&mut self, delta_time: f32) -> bool {
+        if self.is_finished() {
+            return false;
+        }
+        self.elapsed += delta_time;
+        if self.elapsed <= MATERIAL_BENCH_WARMUP_SECONDS {
+            return false;
+        }
+        self.frame_time_total += delta_time;
+        self.frame_count += 1;
+        if self.elapsed < MATERIAL_BENCH_WARMUP_SECONDS + MATERIAL_BENCH_MEASURE_SECONDS {
+            return false;
+        }
+
+        let (name, _) = &self.materials[self.results.len()];
+        self.results.push(MaterialBenchResult {
+            name: name.clone(),
+            average_frame_time: self.frame_time_total / self.frame_count as f32,
+        });
+        self.elapsed = 0.;
+        self.frame_time_total = 0.;
+        self.frame_count = 0;
+        true
+    }
+
+    /// The results so far, the most expensive material first
+    pub fn to_csv(&self) -> String {
+        let mut ranked_results = self.results.iter().collect::<Vec<_>>();
+        ranked_results.sort_by(|a, b| b.average_frame_time.total_cmp(&a.average_frame_time));
+        ranked_results.into_iter().enumerate().fold(
+            "rank,material,average_frame_time_ms,average_fps\n".to_string(),
+            |mut accumulator, (index, result)| {
+                accumulator.push_str(&format!(
+                    "{},{},{:.3},{:.1}\n",
+                    index + 1,
+                    result.name,
+                    result.average_frame_time * 1000.,
+                    1. / result.average_frame_time
+                ));
+                accumulator
+            },
+        )
+    }
+
+    pub fn write_report<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_csv())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::material_bench::{
+        MATERIAL_BENCH_MEASURE_SECONDS, MATERIAL_BENCH_WARMUP_SECONDS, MaterialBench,
+        is_bench_materials_arg,
+    };
+
+    #[test]
+    fn materials_are_benchmarked_in_turn_and_ranked() {
+        assert!(is_bench_materials_arg("--bench-materials"));
+        assert!(!is_bench_materials_arg("--bench-materials=5"));
+
+        let mut material_bench =
+            MaterialBench::new(vec![("desat".to_string(), 1), ("blur".to_string(), 2)]);
+        for frame_time in [0.25, 0.5] {
+            // The warmup frames are slow but not recorded
+            assert!(!material_bench.record_frame(MATERIAL_BENCH_WARMUP_SECONDS));
+            let frame_count = (MATERIAL_BENCH_MEASURE_SECONDS / frame_time).round() as usize;
+            let finished_frames = (0..frame_count)
+                .filter(|_| material_bench.record_frame(frame_time))
+                .count();
+            assert_eq!(finished_frames, 1);
+        }
+        assert!(material_bench.is_finished());
+        assert!(!material_bench.record_frame(0.01));
+
+        assert_eq!(
+            material_bench.to_csv(),
+            "rank,material,average_frame_time_ms,average_fps\n\
+             1,blur,500.000,2.0\n\
+             2,desat,250.000,4.0\n"
+        );
+    }
+}
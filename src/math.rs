@@ -2,11 +2,13 @@
 
 use std::{
     f32::consts::PI,
-    ops::{Deref, Div, Rem},
+    ops::{Add, Deref, Div, Mul, Rem, Sub},
 };
 
 use void_public::{Aspect, Mat2, Vec2};
 
+pub mod layout;
+
 pub fn division_result<T: Copy + Div<Output = T> + Rem<Output = T>>(
     dividend: T,
     divisor: T,
@@ -27,6 +29,44 @@ pub fn generate_equal_parts_rotation_matrix(rotation_split_factor: f32) -> Mat2
     Mat2::from_angle(2. * PI * (1. / (rotation_split_factor)))
 }
 
+/// Points along an arc of `radius` around `center`, starting at `start_angle` and sweeping
+/// `sweep_angle` radians counter clockwise. The arc is split into `subdivisions` straight
+/// segments, so `subdivisions + 1` points are returned.
+pub fn arc_points(
+    center: Vec2,
+    radius: f32,
+    start_angle: f32,
+    sweep_angle: f32,
+    subdivisions: usize,
+) -> Vec<Vec2> {
+    let subdivisions = subdivisions.max(1);
+    (0..=subdivisions)
+        .map(|index| {
+            let angle = start_angle + sweep_angle * index as f32 / subdivisions as f32;
+            center + Vec2::new(angle.cos(), angle.sin()) * radius
+        })
+        .collect()
+}
+
+/// Points along an Archimedean spiral around `center`, growing from the center out to
+/// `max_radius` over `turns` revolutions, split into `segments` straight segments.
+pub fn spiral_points(
+    center: Vec2,
+    max_radius: f32,
+    turns: f32,
+    segments: usize,
+    starting_angle: f32,
+) -> Vec<Vec2> {
+    let segments = segments.max(1);
+    (0..=segments)
+        .map(|index| {
+            let progress = index as f32 / segments as f32;
+            let angle = starting_angle + 2. * PI * turns * progress;
+            center + Vec2::new(angle.cos(), angle.sin()) * max_radius * progress
+        })
+        .collect()
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
 pub struct ZeroToHundredPercent(f32);
 
@@ -59,6 +99,81 @@ impl Deref for ZeroToHundredPercent {
     }
 }
 
+impl ZeroToHundredPercent {
+    /// `numerator / denominator` as a percent, a zero denominator gives 0%
+    pub fn from_fraction(numerator: f32, denominator: f32) -> Self {
+        if denominator == 0. {
+            Self(0.)
+        } else {
+            Self::new(numerator / denominator)
+        }
+    }
+
+    /// The remaining percent, 30% becomes 70%
+    pub fn inverse(self) -> Self {
+        Self(1. - self.0)
+    }
+}
+
+impl Add for ZeroToHundredPercent {
+    type Output = ZeroToHundredPercent;
+
+    /// Saturates at 100%
+    fn add(self, right_hand_side: Self) -> Self::Output {
+        Self::new(self.0 + right_hand_side.0)
+    }
+}
+
+impl Sub for ZeroToHundredPercent {
+    type Output = ZeroToHundredPercent;
+
+    /// Saturates at 0%
+    fn sub(self, right_hand_side: Self) -> Self::Output {
+        Self::new(self.0 - right_hand_side.0)
+    }
+}
+
+impl Mul<f32> for ZeroToHundredPercent {
+    type Output = ZeroToHundredPercent;
+
+    /// Saturates at 0% and 100%
+    fn mul(self, right_hand_side: f32) -> Self::Output {
+        Self::new(self.0 * right_hand_side)
+    }
+}
+
+/// Converts a cursor position in pixels, measured from the top left of the window with y going
+/// down, to world space, where the origin is the center of the screen and y goes up
+pub fn screen_to_world(aspect: &Aspect, cursor_px: Vec2) -> Vec2 {
+    Vec2::new(
+        cursor_px.x - aspect.width / 2.,
+        aspect.height / 2. - cursor_px.y,
+    )
+}
+
+/// The inverse of [`screen_to_world`]
+pub fn world_to_screen(aspect: &Aspect, world_position: Vec2) -> Vec2 {
+    Vec2::new(
+        world_position.x + aspect.width / 2.,
+        aspect.height / 2. - world_position.y,
+    )
+}
+
+/// Converts a cursor position in pixels to the uvs of a sprite covering the whole screen, which
+/// also start at the top left
+pub fn screen_to_uv(aspect: &Aspect, cursor_px: Vec2) -> Vec2 {
+    Vec2::new(cursor_px.x / aspect.width, cursor_px.y / aspect.height)
+}
+
+/// Converts a world position to the uvs of a sprite centered on `sprite_center`, which start at
+/// its top left
+pub fn world_to_sprite_uv(world_position: Vec2, sprite_center: Vec2, sprite_size: Vec2) -> Vec2 {
+    Vec2::new(
+        0.5 + (world_position.x - sprite_center.x) / sprite_size.x,
+        0.5 - (world_position.y - sprite_center.y) / sprite_size.y,
+    )
+}
+
 pub fn screen_space_coordinate_by_percent(
     aspect: &Aspect,
     x_percent: ZeroToHundredPercent,
@@ -72,3 +187,43 @@ pub fn screen_space_coordinate_by_percent(
         -half_height + *y_percent * aspect.height,
     )
 }
+
+#[cfg(test)]
+mod test {
+    use void_public::Vec2;
+
+    use crate::math::{ZeroToHundredPercent, world_to_sprite_uv};
+
+    #[test]
+    fn zero_to_hundred_percent_arithmetic_saturates() {
+        let percent = ZeroToHundredPercent::new(0.25);
+        assert_eq!(*(percent + 0.5.into()), 0.75);
+        assert_eq!(*(percent + 0.9.into()), 1.);
+        assert_eq!(*(percent - 0.5.into()), 0.);
+        assert_eq!(*(percent * 2.), 0.5);
+        assert_eq!(*(percent * 8.), 1.);
+        assert_eq!(*(percent * -1.), 0.);
+        assert_eq!(*percent.inverse(), 0.75);
+        assert_eq!(*ZeroToHundredPercent::from_fraction(1., 4.), 0.25);
+        assert_eq!(*ZeroToHundredPercent::from_fraction(5., 4.), 1.);
+        assert_eq!(*ZeroToHundredPercent::from_fraction(1., 0.), 0.);
+    }
+
+    #[test]
+    fn sprite_uvs_start_at_the_top_left() {
+        let sprite_center = Vec2::new(100., 50.);
+        let sprite_size = Vec2::new(200., 100.);
+        assert_eq!(
+            world_to_sprite_uv(sprite_center, sprite_center, sprite_size),
+            Vec2::new(0.5, 0.5)
+        );
+        assert_eq!(
+            world_to_sprite_uv(Vec2::new(0., 100.), sprite_center, sprite_size),
+            Vec2::new(0., 0.)
+        );
+        assert_eq!(
+            world_to_sprite_uv(Vec2::new(200., 0.), sprite_center, sprite_size),
+            Vec2::new(1., 1.)
+        );
+    }
+}
@@ -0,0 +1,215 @@
+//! Layout helpers for placing things on screen relative to an [`Anchor`], or as rows and grids
+//! of evenly spaced items. Positions are worked out in screen percentages, where (0, 0) is the
+//! bottom left and (1, 1) is the top right, and then converted to world space with the
+//! [`Aspect`].
+
+use void_public::{Aspect, Vec2};
+
+use crate::math::division_result;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Anchor {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    CenterLeft,
+    Center,
+    CenterRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+impl Anchor {
+    /// The screen percentage of the anchor point
+    pub fn percent(self) -> Vec2 {
+        match self {
+            Anchor::TopLeft => Vec2::new(0., 1.),
+            Anchor::TopCenter => Vec2::new(0.5, 1.),
+            Anchor::TopRight => Vec2::new(1., 1.),
+            Anchor::CenterLeft => Vec2::new(0., 0.5),
+            Anchor::Center => Vec2::new(0.5, 0.5),
+            Anchor::CenterRight => Vec2::new(1., 0.5),
+            Anchor::BottomLeft => Vec2::new(0., 0.),
+            Anchor::BottomCenter => Vec2::new(0.5, 0.),
+            Anchor::BottomRight => Vec2::new(1., 0.),
+        }
+    }
+}
+
+/// Which screen size a quad sized relative to the screen follows when the window is resized
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    snapshot::Deserialize,
+    snapshot::Serialize,
+)]
+pub enum RelativeScale {
+    /// Both axes keep their fraction of the screen width, e.g. squares sized from the width
+    OfWidth,
+    /// Both axes keep their fraction of the screen height, like relative font sizes
+    OfHeight,
+    /// Each axis keeps its fraction of the matching screen axis, e.g. full screen quads
+    OfScreen,
+}
+
+impl RelativeScale {
+    /// Returns `scale`, sized for `previous_screen_size`, sized for `screen_size`
+    pub fn rescale(self, scale: Vec2, previous_screen_size: Vec2, screen_size: Vec2) -> Vec2 {
+        let ratio = screen_size / previous_screen_size;
+        scale
+            * match self {
+                RelativeScale::OfWidth => Vec2::splat(ratio.x),
+                RelativeScale::OfHeight => Vec2::splat(ratio.y),
+                RelativeScale::OfScreen => ratio,
+            }
+    }
+}
+
+/// Unlike [`screen_space_coordinate_by_percent`](crate::math::screen_space_coordinate_by_percent)
+/// percentages are not clamped, so positions just off screen can be expressed
+pub fn percent_to_screen_space(aspect: &Aspect, percent: Vec2) -> Vec2 {
+    percent_to_position(percent, Vec2::new(aspect.width, aspect.height))
+}
+
+/// The world position of a screen percentage on a screen of `screen_size`
+pub fn percent_to_position(percent: Vec2, screen_size: Vec2) -> Vec2 {
+    (percent - Vec2::splat(0.5)) * screen_size
+}
+
+/// The inverse of [`percent_to_position`]
+pub fn position_to_percent(position: Vec2, screen_size: Vec2) -> Vec2 {
+    position / screen_size + Vec2::splat(0.5)
+}
+
+/// The position of `anchor` moved by `percent_offset` of the screen and then by `pixel_offset`
+pub fn anchored_position(
+    aspect: &Aspect,
+    anchor: Anchor,
+    percent_offset: Vec2,
+    pixel_offset: Vec2,
+) -> Vec2 {
+    percent_to_screen_space(aspect, anchor.percent() + percent_offset) + pixel_offset
+}
+
+/// The screen percentages of `count` items `spacing_percent` apart in a horizontal row centered
+/// on `center_percent`
+pub fn row_percents(center_percent: Vec2, spacing_percent: f32, count: usize) -> Vec<Vec2> {
+    let half_span = (count.max(1) - 1) as f32 * 0.5;
+    (0..count)
+        .map(|index| center_percent + Vec2::new(spacing_percent * (index as f32 - half_span), 0.))
+        .collect()
+}
+
+pub fn row_positions(
+    aspect: &Aspect,
+    center_percent: Vec2,
+    spacing_percent: f32,
+    count: usize,
+) -> Vec<Vec2> {
+    row_percents(center_percent, spacing_percent, count)
+        .into_iter()
+        .map(|percent| percent_to_screen_space(aspect, percent))
+        .collect()
+}
+
+/// Items filled left to right, then top to bottom, starting from the top left cell
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridLayout {
+    pub first_cell_percent: Vec2,
+    pub columns: usize,
+    /// Horizontal distance between columns and vertical distance between rows
+    pub cell_spacing_percent: Vec2,
+}
+
+impl GridLayout {
+    pub fn cell_percent(&self, index: usize) -> Vec2 {
+        let (row, column) = division_result(index, self.columns.max(1));
+        self.first_cell_percent
+            + Vec2::new(
+                column as f32 * self.cell_spacing_percent.x,
+                -(row as f32) * self.cell_spacing_percent.y,
+            )
+    }
+
+    pub fn position(&self, aspect: &Aspect, index: usize) -> Vec2 {
+        percent_to_screen_space(aspect, self.cell_percent(index))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use void_public::Vec2;
+
+    use crate::math::layout::{
+        Anchor, GridLayout, RelativeScale, percent_to_position, position_to_percent, row_percents,
+    };
+
+    #[test]
+    fn anchors_and_rows() {
+        assert_eq!(Anchor::TopRight.percent(), Vec2::new(1., 1.));
+        assert_eq!(Anchor::BottomCenter.percent(), Vec2::new(0.5, 0.));
+        assert_eq!(
+            row_percents(Vec2::new(0.5, 0.5), 0.25, 4),
+            vec![
+                Vec2::new(0.125, 0.5),
+                Vec2::new(0.375, 0.5),
+                Vec2::new(0.625, 0.5),
+                Vec2::new(0.875, 0.5),
+            ]
+        );
+        assert_eq!(
+            row_percents(Vec2::new(0.5, 0.9), 0.15, 1),
+            vec![Vec2::new(0.5, 0.9)]
+        );
+        assert!(row_percents(Vec2::new(0.5, 0.9), 0.15, 0).is_empty());
+    }
+
+    #[test]
+    fn grid_fills_rows_first() {
+        let grid_layout = GridLayout {
+            first_cell_percent: Vec2::new(0.25, 0.75),
+            columns: 2,
+            cell_spacing_percent: Vec2::new(0.5, 0.125),
+        };
+        assert_eq!(grid_layout.cell_percent(0), Vec2::new(0.25, 0.75));
+        assert_eq!(grid_layout.cell_percent(1), Vec2::new(0.75, 0.75));
+        assert_eq!(grid_layout.cell_percent(4), Vec2::new(0.25, 0.5));
+    }
+
+    #[test]
+    fn percent_and_position_round_trip() {
+        let screen_size = Vec2::new(800., 600.);
+        assert_eq!(
+            percent_to_position(Vec2::new(0.25, 1.), screen_size),
+            Vec2::new(-200., 300.)
+        );
+        assert_eq!(
+            position_to_percent(Vec2::new(-200., 300.), screen_size),
+            Vec2::new(0.25, 1.)
+        );
+    }
+
+    #[test]
+    fn relative_scales_follow_their_screen_axis() {
+        let previous_screen_size = Vec2::new(800., 600.);
+        let screen_size = Vec2::new(1600., 900.);
+        let scale = Vec2::new(200., 100.);
+        assert_eq!(
+            RelativeScale::OfWidth.rescale(scale, previous_screen_size, screen_size),
+            Vec2::new(400., 200.)
+        );
+        assert_eq!(
+            RelativeScale::OfHeight.rescale(scale, previous_screen_size, screen_size),
+            Vec2::new(300., 150.)
+        );
+        assert_eq!(
+            RelativeScale::OfScreen.rescale(scale, previous_screen_size, screen_size),
+            Vec2::new(400., 150.)
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! The bursts drawn by the `particles` test. The TOML material format has no array uniforms, so
+//! the material declares [`PARTICLE_BURST_SLOTS`] `vec4f` uniforms, `burst_0` and up, and gathers
+//! them into an array in the shader. Each burst's seed expands to [`PARTICLES_PER_BURST`]
+//! particles in the fragment shader, which loops over every particle for every pixel.
+
+/// How many bursts fit in the material's uniforms, a new burst replaces the oldest once all are
+/// in use
+pub const PARTICLE_BURST_SLOTS: usize = 16;
+/// The uniform holding each slot's burst
+pub const PARTICLE_BURST_UNIFORM_NAMES: [&str; PARTICLE_BURST_SLOTS] = [
+    "burst_0", "burst_1", "burst_2", "burst_3", "burst_4", "burst_5", "burst_6", "burst_7",
+    "burst_8", "burst_9", "burst_10", "burst_11", "burst_12", "burst_13", "burst_14", "burst_15",
+];
+/// Must match the particle loop in particles.toml
+pub const PARTICLES_PER_BURST: usize = 32;
+/// Must match the `lifetime` uniform's default in particles.toml
+pub const PARTICLE_LIFETIME_SECONDS: f32 = 2.;
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    snapshot::Deserialize,
+    snapshot::Serialize,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+pub struct ParticleBurst {
+    /// The burst's center in screen uvs
+    pub uv: [f32; 2],
+    pub spawn_time: f32,
+    pub seed: f32,
+}
+
+/// The bursts in each uniform slot, `next_slot` is the one the next burst is written to
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    snapshot::Deserialize,
+    snapshot::Serialize,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+pub struct ParticleBursts {
+    slots: [Option<ParticleBurst>; PARTICLE_BURST_SLOTS],
+    next_slot: usize,
+}
+
+impl ParticleBursts {
+    /// Returns whether a live burst had to be replaced
+    pub fn spawn(&mut self, particle_burst: ParticleBurst) -> bool {
+        let replaced = self.slots[self.next_slot].is_some_and(|replaced_burst| {
+            particle_burst.spawn_time - replaced_burst.spawn_time < PARTICLE_LIFETIME_SECONDS
+        });
+        self.slots[self.next_slot] = Some(particle_burst);
+        self.next_slot = (self.next_slot + 1) % PARTICLE_BURST_SLOTS;
+        replaced
+    }
+
+    pub fn live_burst_count(&self, time: f32) -> usize {
+        self.slots
+            .iter()
+            .flatten()
+            .filter(|particle_burst| time - particle_burst.spawn_time < PARTICLE_LIFETIME_SECONDS)
+            .count()
+    }
+
+    /// The `burst_<slot>` uniforms, `[uv.x, uv.y, age, seed]`. Empty slots have an age of -1,
+    /// which the shader skips along with bursts older than their lifetime.
+    pub fn uniform_values(&self, time: f32) -> [[f32; 4]; PARTICLE_BURST_SLOTS] {
+        self.slots.map(|slot| match slot {
+            Some(particle_burst) => [
+                particle_burst.uv[0],
+                particle_burst.uv[1],
+                time - particle_burst.spawn_time,
+                particle_burst.seed,
+            ],
+            None => [0., 0., -1., 0.],
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::particles::{
+        PARTICLE_BURST_SLOTS, PARTICLE_LIFETIME_SECONDS, ParticleBurst, ParticleBursts,
+    };
+
+    fn particle_burst(spawn_time: f32) -> ParticleBurst {
+        ParticleBurst {
+            uv: [0.25, 0.75],
+            spawn_time,
+            seed: 3.,
+        }
+    }
+
+    #[test]
+    fn bursts_expire_after_their_lifetime() {
+        let mut particle_bursts = ParticleBursts::default();
+        assert!(!particle_bursts.spawn(particle_burst(1.)));
+        assert_eq!(particle_bursts.live_burst_count(2.), 1);
+        assert_eq!(
+            particle_bursts.live_burst_count(1. + PARTICLE_LIFETIME_SECONDS),
+            0
+        );
+
+        let uniform_values = particle_bursts.uniform_values(1.5);
+        assert_eq!(uniform_values[0], [0.25, 0.75, 0.5, 3.]);
+        assert_eq!(uniform_values[1], [0., 0., -1., 0.]);
+    }
+
+    #[test]
+    fn full_slots_replace_the_oldest_burst() {
+        let mut particle_bursts = ParticleBursts::default();
+        for slot in 0..PARTICLE_BURST_SLOTS {
+            assert!(!particle_bursts.spawn(particle_burst(slot as f32 * 0.01)));
+        }
+        assert!(particle_bursts.spawn(particle_burst(1.)));
+        assert_eq!(particle_bursts.uniform_values(1.)[0][2], 0.);
+        assert_eq!(particle_bursts.live_burst_count(1.), PARTICLE_BURST_SLOTS);
+
+        // The second slot's burst has expired, so reusing it replaces nothing live
+        assert!(!particle_bursts.spawn(particle_burst(5.)));
+    }
+}
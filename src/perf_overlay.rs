@@ -0,0 +1,162 @@
+//! Helpers for tracking recent frame times and laying out the performance overlay.
+
+use void_public::Vec2;
+
+/// How many of the most recent frames are kept for the stats and the sparkline
+pub const FRAME_TIME_HISTORY_LENGTH: usize = 240;
+pub const PERF_OVERLAY_ARG: &str = "--perf-overlay=";
+
+/// Parses `--perf-overlay=on` or `--perf-overlay=off`, whether the overlay starts shown
+pub fn parse_perf_overlay_arg(arg: &str) -> Option<bool> {
+    match arg.strip_prefix(PERF_OVERLAY_ARG)? {
+        "on" => Some(true),
+        "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// A ring buffer of the most recent frame times, in seconds
+#[derive(Debug)]
+pub struct FrameTimeHistory {
+    frame_times: [f32; FRAME_TIME_HISTORY_LENGTH],
+    next_index: usize,
+    len: usize,
+}
+
+impl Default for FrameTimeHistory {
+    fn default() -> Self {
+        Self {
+            frame_times: [0.; FRAME_TIME_HISTORY_LENGTH],
+            next_index: 0,
+            len: 0,
+        }
+    }
+}
+
+impl FrameTimeHistory {
+    pub fn push(&mut self, frame_time: f32) {
+        self.frame_times[self.next_index] = frame_time;
+        self.next_index = (self.next_index + 1) % FRAME_TIME_HISTORY_LENGTH;
+        self.len = (self.len + 1).min(FRAME_TIME_HISTORY_LENGTH);
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Iterates from the oldest to the newest frame time
+    pub fn iter(&self) -> impl Iterator<Item = f32> + use<'_> {
+        let oldest_index =
+            (self.next_index + FRAME_TIME_HISTORY_LENGTH - self.len) % FRAME_TIME_HISTORY_LENGTH;
+        (0..self.len).map(move |offset| {
+            self.frame_times[(oldest_index + offset) % FRAME_TIME_HISTORY_LENGTH]
+        })
+    }
+
+    pub fn stats(&self) -> Option<FrameTimeStats> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut sorted_frame_times = self.iter().collect::<Vec<_>>();
+        sorted_frame_times.sort_by(f32::total_cmp);
+
+        let p99_index = ((sorted_frame_times.len() as f32 * 0.99).ceil() as usize).max(1) - 1;
+        Some(FrameTimeStats {
+            min: sorted_frame_times[0],
+            avg: sorted_frame_times.iter().sum::<f32>() / sorted_frame_times.len() as f32,
+            max: sorted_frame_times[sorted_frame_times.len() - 1],
+            p99: sorted_frame_times[p99_index],
+        })
+    }
+}
+
+/// Frame time statistics, in seconds
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrameTimeStats {
+    pub min: f32,
+    pub avg: f32,
+    pub max: f32,
+    pub p99: f32,
+}
+
+impl FrameTimeStats {
+    pub fn to_overlay_string(&self, frame_rate: f32) -> String {
+        format!(
+            "FPS: {frame_rate:.0}  min {:.1}  avg {:.1}  max {:.1}  p99 {:.1} ms",
+            self.min * 1000.,
+            self.avg * 1000.,
+            self.max * 1000.,
+            self.p99 * 1000.,
+        )
+    }
+}
+
+/// Lays out the frame times as sparkline points inside the rectangle starting at `origin`
+/// (bottom left) with `size`. Frame times at or above `ceiling` touch the top of the rectangle.
+pub fn sparkline_points(
+    frame_time_history: &FrameTimeHistory,
+    origin: Vec2,
+    size: Vec2,
+    ceiling: f32,
+) -> Vec<Vec2> {
+    let step = size.x / (FRAME_TIME_HISTORY_LENGTH - 1) as f32;
+    frame_time_history
+        .iter()
+        .enumerate()
+        .map(|(index, frame_time)| {
+            let height = (frame_time / ceiling).clamp(0., 1.) * size.y;
+            origin + Vec2::new(index as f32 * step, height)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::perf_overlay::{
+        FRAME_TIME_HISTORY_LENGTH, FrameTimeHistory, parse_perf_overlay_arg,
+    };
+
+    #[test]
+    fn perf_overlay_arg_parsing() {
+        assert_eq!(parse_perf_overlay_arg("--perf-overlay=off"), Some(false));
+        assert_eq!(parse_perf_overlay_arg("--perf-overlay=on"), Some(true));
+        assert_eq!(parse_perf_overlay_arg("--perf-overlay=maybe"), None);
+    }
+
+    #[test]
+    fn frame_time_history_wraps_and_keeps_newest() {
+        let mut frame_time_history = FrameTimeHistory::default();
+        assert!(frame_time_history.stats().is_none());
+
+        for frame in 0..FRAME_TIME_HISTORY_LENGTH + 10 {
+            frame_time_history.push(frame as f32);
+        }
+
+        assert_eq!(frame_time_history.len(), FRAME_TIME_HISTORY_LENGTH);
+        assert_eq!(frame_time_history.iter().next(), Some(10.));
+        assert_eq!(
+            frame_time_history.iter().last(),
+            Some((FRAME_TIME_HISTORY_LENGTH + 9) as f32)
+        );
+    }
+
+    #[test]
+    fn frame_time_stats_include_hitches() {
+        let mut frame_time_history = FrameTimeHistory::default();
+        for _ in 0..99 {
+            frame_time_history.push(0.01);
+        }
+        frame_time_history.push(0.1);
+
+        let stats = frame_time_history.stats().unwrap();
+        assert_eq!(stats.min, 0.01);
+        assert_eq!(stats.max, 0.1);
+        assert_eq!(stats.p99, 0.01);
+        assert!((stats.avg - 0.0109).abs() < 0.0001);
+    }
+}
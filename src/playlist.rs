@@ -0,0 +1,41 @@
+//! Helpers for queueing several tests on the command line, e.g. `warp:10 starfield:15`, which
+//! are then played one after another for the given number of seconds each.
+
+use game_asset::resource_managers::material_manager::materials::MaterialType;
+
+use crate::MaterialTestId;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlaylistEntry {
+    pub material_type: MaterialType,
+    pub material_test_id: MaterialTestId,
+    pub duration_seconds: f32,
+}
+
+/// Parses `<test name>:<seconds>`, the duration must be positive
+pub fn parse_playlist_arg(arg: &str) -> Option<(&str, f32)> {
+    let (test_name, duration_seconds) = arg.split_once(':')?;
+    let duration_seconds = duration_seconds
+        .parse::<f32>()
+        .ok()
+        .filter(|duration_seconds| *duration_seconds > 0.)?;
+    (!test_name.is_empty()).then_some((test_name, duration_seconds))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::playlist::parse_playlist_arg;
+
+    #[test]
+    fn playlist_arg_parsing() {
+        assert_eq!(parse_playlist_arg("warp:10"), Some(("warp", 10.)));
+        assert_eq!(
+            parse_playlist_arg("starfield:2.5"),
+            Some(("starfield", 2.5))
+        );
+        assert_eq!(parse_playlist_arg("warp"), None);
+        assert_eq!(parse_playlist_arg("warp:"), None);
+        assert_eq!(parse_playlist_arg("warp:-1"), None);
+        assert_eq!(parse_playlist_arg(":10"), None);
+    }
+}
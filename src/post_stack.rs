@@ -0,0 +1,110 @@
+//! The layers of the `post_stack` test, which runs several postprocesses at once to check that
+//! `WorldRenderManager` applies them in the order they were added. [`PostStackLayers`] tracks
+//! which layers are on and the order they're added in, `post_stack_system` re-adds the
+//! postprocesses whenever either changes.
+
+use std::fmt::Write;
+
+pub const POST_STACK_LAYER_COUNT: usize = 3;
+/// The postprocess materials of the `post_stack` test, in the order of its material list
+pub const POST_STACK_LAYER_NAMES: [&str; POST_STACK_LAYER_COUNT] =
+    ["invert_y", "vignette", "grain"];
+/// The layer whose `time` uniform is advanced every frame
+pub const POST_STACK_GRAIN_LAYER: usize = 2;
+
+/// Layers are indices into [`POST_STACK_LAYER_NAMES`], `order` lists every layer once, first
+/// applied first
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    snapshot::Deserialize,
+    snapshot::Serialize,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+pub struct PostStackLayers {
+    order: [usize; POST_STACK_LAYER_COUNT],
+    enabled: [bool; POST_STACK_LAYER_COUNT],
+}
+
+impl Default for PostStackLayers {
+    fn default() -> Self {
+        Self {
+            order: [0, 1, 2],
+            enabled: [true; POST_STACK_LAYER_COUNT],
+        }
+    }
+}
+
+impl PostStackLayers {
+    /// Layers out of range are ignored
+    pub fn toggle(&mut self, layer: usize) {
+        if let Some(enabled) = self.enabled.get_mut(layer) {
+            *enabled = !*enabled;
+        }
+    }
+
+    /// Moves the first applied layer to the end of the stack
+    pub fn rotate(&mut self) {
+        self.order.rotate_left(1);
+    }
+
+    /// The enabled layers, in the order their postprocesses are added
+    pub fn active_layers(&self) -> impl Iterator<Item = usize> + use<'_> {
+        self.order
+            .iter()
+            .copied()
+            .filter(|layer| self.enabled[*layer])
+    }
+
+    /// The stack in order, with each layer's toggle key, e.g. `1 invert_y > 2 vignette (off)`
+    pub fn status_text(&self) -> String {
+        let mut status_text = String::new();
+        for (position, layer) in self.order.iter().enumerate() {
+            if position > 0 {
+                status_text.push_str(" > ");
+            }
+            write!(
+                status_text,
+                "{} {}",
+                layer + 1,
+                POST_STACK_LAYER_NAMES[*layer]
+            )
+            .unwrap();
+            if !self.enabled[*layer] {
+                status_text.push_str(" (off)");
+            }
+        }
+        status_text
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::post_stack::PostStackLayers;
+
+    #[test]
+    fn toggled_layers_keep_their_place_in_the_order() {
+        let mut post_stack_layers = PostStackLayers::default();
+        post_stack_layers.toggle(1);
+        post_stack_layers.toggle(5);
+        assert_eq!(
+            post_stack_layers.active_layers().collect::<Vec<_>>(),
+            vec![0, 2]
+        );
+
+        post_stack_layers.rotate();
+        post_stack_layers.toggle(1);
+        assert_eq!(
+            post_stack_layers.active_layers().collect::<Vec<_>>(),
+            vec![1, 2, 0]
+        );
+        post_stack_layers.toggle(0);
+        assert_eq!(
+            post_stack_layers.status_text(),
+            "2 vignette > 3 grain > 1 invert_y (off)"
+        );
+    }
+}
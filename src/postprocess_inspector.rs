@@ -0,0 +1,62 @@
+//! An overlay listing the running test's postprocesses in the order `WorldRenderManager` applies
+//! them. I toggles it in any test with postprocesses, Up/Down select an entry and Shift+Up/Down
+//! move it, after which `postprocess_inspector_system` re-adds the postprocesses in the new order.
+
+/// The overlay's text lines, a header and one line per postprocess
+pub const POSTPROCESS_INSPECTOR_LINES: usize = 9;
+
+/// The index `index` moves to when moved one step up or down a list of `len` entries, `None` at
+/// either end
+pub fn moved_index(index: usize, len: usize, up: bool) -> Option<usize> {
+    if up {
+        index.checked_sub(1)
+    } else {
+        (index + 1 < len).then_some(index + 1)
+    }
+}
+
+/// The overlay text, `material_names` are in execution order and the selected one is marked
+pub fn inspector_lines(material_names: &[String], selected: usize) -> Vec<String> {
+    let mut lines = vec!["Postprocesses, first applied first:".to_string()];
+    if material_names.is_empty() {
+        lines.push("  none".to_string());
+    }
+    lines.extend(
+        material_names
+            .iter()
+            .enumerate()
+            .take(POSTPROCESS_INSPECTOR_LINES - 1)
+            .map(|(index, material_name)| {
+                let marker = if index == selected { '>' } else { ' ' };
+                format!("{marker} {} {material_name}", index + 1)
+            }),
+    );
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use crate::postprocess_inspector::{inspector_lines, moved_index};
+
+    #[test]
+    fn entries_stop_moving_at_the_ends() {
+        assert_eq!(moved_index(0, 3, true), None);
+        assert_eq!(moved_index(1, 3, true), Some(0));
+        assert_eq!(moved_index(1, 3, false), Some(2));
+        assert_eq!(moved_index(2, 3, false), None);
+    }
+
+    #[test]
+    fn selected_postprocess_is_marked() {
+        let material_names = ["invert_y".to_string(), "grain".to_string()];
+        assert_eq!(
+            inspector_lines(&material_names, 1),
+            vec![
+                "Postprocesses, first applied first:",
+                "  1 invert_y",
+                "> 2 grain"
+            ]
+        );
+        assert_eq!(inspector_lines(&[], 0)[1], "  none");
+    }
+}
@@ -0,0 +1,114 @@
+//! Saves and restores the uniforms of the running material test, so parameter combinations found
+//! interactively survive a restart. Each slot is a flat TOML file of `name = value` lines stored
+//! at `presets/<test name>/<slot>.toml`.
+
+use std::{
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::flat_toml::{FlatTomlEntry, flat_toml_entries, parse_f32_list};
+
+pub const PRESETS_DIRECTORY: &str = "presets";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PresetValue {
+    F32(f32),
+    Vec4([f32; 4]),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UniformPreset {
+    pub values: Vec<(String, PresetValue)>,
+}
+
+pub fn preset_path(test_name: &str, slot: usize) -> PathBuf {
+    Path::new(PRESETS_DIRECTORY)
+        .join(test_name)
+        .join(format!("{slot}.toml"))
+}
+
+impl UniformPreset {
+    pub fn to_toml_string(&self) -> String {
+        self.values
+            .iter()
+            .fold(String::new(), |mut toml_string, (name, value)| {
+                match value {
+                    PresetValue::F32(value) => writeln!(toml_string, "{name} = {value:?}"),
+                    PresetValue::Vec4([x, y, z, w]) => {
+                        writeln!(toml_string, "{name} = [{x:?}, {y:?}, {z:?}, {w:?}]")
+                    }
+                }
+                .unwrap();
+                toml_string
+            })
+    }
+
+    /// Only the subset of TOML written by [`UniformPreset::to_toml_string`] is understood, blank
+    /// lines and `#` comments are skipped
+    pub fn from_toml_string(toml_string: &str) -> Option<Self> {
+        let values = flat_toml_entries(toml_string)
+            .map(|entry| {
+                let FlatTomlEntry { key, value, .. } = entry.ok()?;
+                Some((key.to_string(), parse_preset_value(value)?))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self { values })
+    }
+
+    /// A missing or malformed file just means the slot is empty
+    pub fn read<P: AsRef<Path>>(path: P) -> Option<Self> {
+        Self::from_toml_string(&fs::read_to_string(path).ok()?)
+    }
+
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, self.to_toml_string())
+    }
+}
+
+fn parse_preset_value(value: &str) -> Option<PresetValue> {
+    if !value.starts_with('[') {
+        return value.parse::<f32>().ok().map(PresetValue::F32);
+    }
+    Some(PresetValue::Vec4(parse_f32_list(value)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use crate::presets::{PresetValue, UniformPreset, preset_path};
+
+    #[test]
+    fn uniform_preset_toml_round_trip() {
+        let uniform_preset = UniformPreset {
+            values: vec![
+                ("speed".to_string(), PresetValue::F32(0.1)),
+                (
+                    "cold_star_color".to_string(),
+                    PresetValue::Vec4([0.6055, 0.6875, 1., 1.]),
+                ),
+            ],
+        };
+        assert_eq!(
+            UniformPreset::from_toml_string(&uniform_preset.to_toml_string()),
+            Some(uniform_preset)
+        );
+        assert_eq!(
+            UniformPreset::from_toml_string("# starfield\n\nspeed = 2\n"),
+            Some(UniformPreset {
+                values: vec![("speed".to_string(), PresetValue::F32(2.))],
+            })
+        );
+        assert_eq!(UniformPreset::from_toml_string("speed = fast\n"), None);
+        assert_eq!(UniformPreset::from_toml_string("color = [1, 2]\n"), None);
+        assert_eq!(
+            preset_path("starfield", 3),
+            Path::new("presets/starfield/3.toml")
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! CPU side pixel generation for the `procedural_texture` test, which rebuilds an RGBA8 buffer
+//! every few frames and uploads it as a dynamic texture.
+
+/// The texture is this many pixels wide and high
+pub const PROCEDURAL_TEXTURE_SIZE: usize = 64;
+/// Seconds between uploads, each upload advances the pattern's animation by one step
+pub const PROCEDURAL_TEXTURE_UPLOAD_SECONDS: f32 = 0.1;
+/// Pixels per checkerboard cell
+const CHECKER_CELL_SIZE: usize = 8;
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    snapshot::Deserialize,
+    snapshot::Serialize,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+pub enum ProceduralPattern {
+    #[default]
+    Checkerboard,
+    Gradient,
+    Noise,
+}
+
+impl ProceduralPattern {
+    pub fn next(self) -> Self {
+        match self {
+            ProceduralPattern::Checkerboard => ProceduralPattern::Gradient,
+            ProceduralPattern::Gradient => ProceduralPattern::Noise,
+            ProceduralPattern::Noise => ProceduralPattern::Checkerboard,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ProceduralPattern::Checkerboard => "checkerboard",
+            ProceduralPattern::Gradient => "gradient",
+            ProceduralPattern::Noise => "noise",
+        }
+    }
+}
+
+/// A `size` by `size` RGBA8 buffer, rows from the top. `step` animates the pattern, the
+/// checkerboard scrolls, the gradient cycles and the noise is reseeded.
+pub fn generate_pixels(pattern: ProceduralPattern, size: usize, step: u32) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(size * size * 4);
+    for y in 0..size {
+        for x in 0..size {
+            let rgb = match pattern {
+                ProceduralPattern::Checkerboard => {
+                    let scrolled_x = x + step as usize;
+                    if (scrolled_x / CHECKER_CELL_SIZE + y / CHECKER_CELL_SIZE) % 2 == 0 {
+                        [255, 255, 255]
+                    } else {
+                        [32, 32, 32]
+                    }
+                }
+                ProceduralPattern::Gradient => {
+                    let offset = (step as usize * 4) % 256;
+                    [
+                        ((x * 255 / size.max(1) + offset) % 256) as u8,
+                        (y * 255 / size.max(1)) as u8,
+                        (255 - offset) as u8,
+                    ]
+                }
+                ProceduralPattern::Noise => {
+                    let value = (hash(x as u32, y as u32, step) >> 24) as u8;
+                    [value, value, value]
+                }
+            };
+            pixels.extend_from_slice(&rgb);
+            pixels.push(255);
+        }
+    }
+    pixels
+}
+
+/// A cheap integer hash, good enough for visual noise
+fn hash(x: u32, y: u32, seed: u32) -> u32 {
+    let mut value = x
+        .wrapping_mul(0x27d4_eb2d)
+        .wrapping_add(y.wrapping_mul(0x1656_67b1))
+        .wrapping_add(seed.wrapping_mul(0x9e37_79b9));
+    value ^= value >> 15;
+    value = value.wrapping_mul(0x85eb_ca6b);
+    value ^= value >> 13;
+    value
+}
+
+#[cfg(test)]
+mod test {
+    use crate::procedural_texture::{ProceduralPattern, generate_pixels};
+
+    #[test]
+    fn checkerboard_alternates_and_scrolls() {
+        let pixels = generate_pixels(ProceduralPattern::Checkerboard, 16, 0);
+        assert_eq!(pixels.len(), 16 * 16 * 4);
+        assert_eq!(&pixels[0..4], &[255, 255, 255, 255]);
+        assert_eq!(&pixels[8 * 4..8 * 4 + 4], &[32, 32, 32, 255]);
+
+        let scrolled_pixels = generate_pixels(ProceduralPattern::Checkerboard, 16, 8);
+        assert_eq!(&scrolled_pixels[0..4], &[32, 32, 32, 255]);
+    }
+
+    #[test]
+    fn noise_changes_with_the_step() {
+        assert_eq!(
+            generate_pixels(ProceduralPattern::Noise, 8, 3),
+            generate_pixels(ProceduralPattern::Noise, 8, 3)
+        );
+        assert_ne!(
+            generate_pixels(ProceduralPattern::Noise, 8, 3),
+            generate_pixels(ProceduralPattern::Noise, 8, 4)
+        );
+        assert_eq!(
+            ProceduralPattern::Noise.next(),
+            ProceduralPattern::Checkerboard
+        );
+    }
+}
@@ -0,0 +1,10 @@
+//! Helpers for seeding the deterministic RNG used by material tests.
+
+pub const SEED_ARG: &str = "--seed=";
+/// The seed used when none is passed on the command line, so runs are reproducible by default
+pub const DEFAULT_TEST_RNG_SEED: u64 = 0x5EED;
+
+/// Parses `--seed=<u64>`
+pub fn parse_seed_arg(arg: &str) -> Option<u64> {
+    arg.strip_prefix(SEED_ARG)?.parse::<u64>().ok()
+}
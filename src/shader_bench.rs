@@ -0,0 +1,161 @@
+//! Times the shader pipeline of every test's materials with `--bench-shaders[=<iterations>]`.
+//! Once every material has loaded, each one's WGSL is generated, parsed and validated the given
+//! number of times, the mean of each step is printed as a table and the module exits. Slowdowns in
+//! `MaterialManager::generate_shader_text` or heavier templates show up without a profiler.
+
+use std::time::{Duration, Instant};
+
+use game_asset::ecs_module::MaterialManager;
+use naga::{
+    front::wgsl::parse_str,
+    valid::{Capabilities, ValidationFlags, Validator},
+};
+
+use crate::shader_dump::{DumpedTest, shader_file_path};
+
+pub const BENCH_SHADERS_ARG: &str = "--bench-shaders";
+pub const DEFAULT_BENCH_SHADERS_ITERATIONS: u32 = 100;
+
+/// The iteration count of `--bench-shaders` or `--bench-shaders=<iterations>`
+pub fn parse_bench_shaders_arg(arg: &str) -> Option<u32> {
+    let iterations = arg.strip_prefix(BENCH_SHADERS_ARG)?;
+    if iterations.is_empty() {
+        return Some(DEFAULT_BENCH_SHADERS_ITERATIONS);
+    }
+    iterations
+        .strip_prefix('=')?
+        .parse()
+        .ok()
+        .filter(|iterations| *iterations > 0)
+}
+
+/// The mean time of each step for one material
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShaderBenchRow {
+    /// The material's dump path without the extension, e.g. `sprite/starfield`
+    pub name: String,
+    pub generate: Duration,
+    pub parse: Duration,
+    pub validate: Duration,
+}
+
+/// Generates, parses and validates every test's WGSL `iterations` times. Fails on the first
+/// material that doesn't make it through, since its times wouldn't mean anything.
+pub fn bench_all_shaders(
+    material_manager: &MaterialManager,
+    tests: &[DumpedTest<'_>],
+    iterations: u32,
+) -> Result<Vec<ShaderBenchRow>, String> {
+    let mut rows = vec![];
+    for test in tests {
+        for (index, material_id) in test.material_ids.iter().enumerate() {
+            let name = shader_file_path(
+                test.material_type,
+                test.name,
+                index,
+                test.material_ids.len(),
+            )
+            .with_extension("")
+            .display()
+            .to_string();
+            let mut totals = [Duration::ZERO; 3];
+            for _ in 0..iterations {
+                let start = Instant::now();
+                let wgsl = material_manager
+                    .generate_shader_text(*material_id)
+                    .map_err(|error| format!("Could not generate WGSL for {name}: {error:?}"))?;
+                let generated = Instant::now();
+                let module = parse_str(&wgsl)
+                    .map_err(|error| format!("{name}: {}", error.emit_to_string(&wgsl)))?;
+                let parsed = Instant::now();
+                Validator::new(ValidationFlags::all(), Capabilities::all())
+                    .validate(&module)
+                    .map_err(|error| format!("{name}: {}", error.emit_to_string(&wgsl)))?;
+                totals[0] += generated - start;
+                totals[1] += parsed - generated;
+                totals[2] += parsed.elapsed();
+            }
+            let [generate, parse, validate] = totals.map(|total| total / iterations);
+            rows.push(ShaderBenchRow {
+                name,
+                generate,
+                parse,
+                validate,
+            });
+        }
+    }
+    Ok(rows)
+}
+
+/// The rows in microseconds, slowest total first, followed by the sum of every row
+pub fn bench_table(rows: &[ShaderBenchRow]) -> String {
+    let micros = |duration: Duration| duration.as_secs_f64() * 1_000_000.;
+    let line = |name: &str, generate: Duration, parse: Duration, validate: Duration| {
+        format!(
+            "{name:<40} {:>12.1} {:>12.1} {:>12.1} {:>12.1}\n",
+            micros(generate),
+            micros(parse),
+            micros(validate),
+            micros(generate + parse + validate)
+        )
+    };
+    let mut sorted_rows = rows.iter().collect::<Vec<_>>();
+    sorted_rows.sort_by_key(|row| std::cmp::Reverse(row.generate + row.parse + row.validate));
+
+    let mut table = format!(
+        "{:<40} {:>12} {:>12} {:>12} {:>12}\n",
+        "material", "generate µs", "parse µs", "validate µs", "total µs"
+    );
+    for row in &sorted_rows {
+        table.push_str(&line(&row.name, row.generate, row.parse, row.validate));
+    }
+    let sum = |step: fn(&ShaderBenchRow) -> Duration| rows.iter().map(step).sum::<Duration>();
+    table.push_str(&line(
+        "total",
+        sum(|row| row.generate),
+        sum(|row| row.parse),
+        sum(|row| row.validate),
+    ));
+    table
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::shader_bench::{
+        DEFAULT_BENCH_SHADERS_ITERATIONS, ShaderBenchRow, bench_table, parse_bench_shaders_arg,
+    };
+
+    #[test]
+    fn bench_shaders_arg() {
+        assert_eq!(
+            parse_bench_shaders_arg("--bench-shaders"),
+            Some(DEFAULT_BENCH_SHADERS_ITERATIONS)
+        );
+        assert_eq!(parse_bench_shaders_arg("--bench-shaders=20"), Some(20));
+        assert_eq!(parse_bench_shaders_arg("--bench-shaders=0"), None);
+        assert_eq!(parse_bench_shaders_arg("--bench-shaders=many"), None);
+        assert_eq!(parse_bench_shaders_arg("--bench-shadersx"), None);
+        assert_eq!(parse_bench_shaders_arg("--bench-materials"), None);
+    }
+
+    #[test]
+    fn bench_table_lists_the_slowest_first() {
+        let row = |name: &str, micros: u64| ShaderBenchRow {
+            name: name.to_string(),
+            generate: Duration::from_micros(micros),
+            parse: Duration::from_micros(micros * 2),
+            validate: Duration::from_micros(micros * 3),
+        };
+        let table = bench_table(&[row("sprite/fire", 10), row("post_processing/warp", 20)]);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("material"));
+        assert!(lines[1].starts_with("post_processing/warp"));
+        assert!(lines[1].ends_with("120.0"));
+        assert!(lines[2].starts_with("sprite/fire"));
+        assert!(lines[3].starts_with("total"));
+        assert!(lines[3].ends_with("180.0"));
+    }
+}
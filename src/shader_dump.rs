@@ -0,0 +1,109 @@
+//! Writes the WGSL generated for every test's materials to a directory with
+//! `--dump-shaders=<directory>`, so shader authors can read and diff the generated code without
+//! running a test.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use game_asset::{
+    ecs_module::MaterialManager, resource_managers::material_manager::materials::MaterialType,
+};
+use void_public::material::MaterialId;
+
+use crate::last_test::material_type_to_file_string;
+
+pub const DUMP_SHADERS_ARG: &str = "--dump-shaders=";
+
+pub fn parse_dump_shaders_arg(arg: &str) -> Option<PathBuf> {
+    arg.strip_prefix(DUMP_SHADERS_ARG)
+        .filter(|directory| !directory.is_empty())
+        .map(PathBuf::from)
+}
+
+/// A test's material below the dump directory, e.g. `sprite/starfield.wgsl`. Tests with several
+/// materials get a numbered file per material, `sprite/particles_0.wgsl` and so on.
+pub fn shader_file_path(
+    material_type: MaterialType,
+    test_name: &str,
+    index: usize,
+    material_count: usize,
+) -> PathBuf {
+    let file_name = if material_count > 1 {
+        format!("{test_name}_{index}.wgsl")
+    } else {
+        format!("{test_name}.wgsl")
+    };
+    Path::new(material_type_to_file_string(material_type)).join(file_name)
+}
+
+/// The material test a dump is made from, its loaded materials in the test's order
+pub struct DumpedTest<'a> {
+    pub material_type: MaterialType,
+    pub name: &'a str,
+    pub material_ids: Vec<MaterialId>,
+}
+
+/// Writes every test's generated WGSL below `directory`, creating it as needed, and returns how
+/// many files were written
+pub fn dump_all_shaders<P: AsRef<Path>>(
+    material_manager: &MaterialManager,
+    tests: &[DumpedTest<'_>],
+    directory: P,
+) -> io::Result<usize> {
+    let directory = directory.as_ref();
+    let mut written_count = 0;
+    for test in tests {
+        for (index, material_id) in test.material_ids.iter().enumerate() {
+            let wgsl = material_manager
+                .generate_shader_text(*material_id)
+                .map_err(|error| {
+                    io::Error::other(format!(
+                        "Could not generate WGSL for {}: {error:?}",
+                        test.name
+                    ))
+                })?;
+            let path = directory.join(shader_file_path(
+                test.material_type,
+                test.name,
+                index,
+                test.material_ids.len(),
+            ));
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, wgsl)?;
+            written_count += 1;
+        }
+    }
+    Ok(written_count)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use game_asset::resource_managers::material_manager::materials::MaterialType;
+
+    use crate::shader_dump::{parse_dump_shaders_arg, shader_file_path};
+
+    #[test]
+    fn dump_shaders_arg_and_file_paths() {
+        assert_eq!(
+            parse_dump_shaders_arg("--dump-shaders=out/wgsl"),
+            Some(PathBuf::from("out/wgsl"))
+        );
+        assert_eq!(parse_dump_shaders_arg("--dump-shaders="), None);
+        assert_eq!(parse_dump_shaders_arg("--list"), None);
+
+        assert_eq!(
+            shader_file_path(MaterialType::Sprite, "starfield", 0, 1),
+            PathBuf::from("sprite/starfield.wgsl")
+        );
+        assert_eq!(
+            shader_file_path(MaterialType::PostProcessing, "warp", 1, 2),
+            PathBuf::from("post_processing/warp_1.wgsl")
+        );
+    }
+}
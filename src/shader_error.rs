@@ -0,0 +1,213 @@
+//! Turns naga's WGSL errors into the text of the error panel shown for a test whose material
+//! doesn't compile, so a broken material definition points at the offending line instead of
+//! leaving its pipeline stuck loading.
+
+use std::{error::Error, fmt::Display};
+
+use naga::{
+    WithSpan,
+    front::wgsl::{ParseError, parse_str},
+    valid::{Capabilities, ValidationError, ValidationFlags, Validator},
+};
+
+#[derive(Debug)]
+pub enum WgslError {
+    ValidationErr {
+        source: String,
+        error: WithSpan<ValidationError>,
+        message: String,
+    },
+    ParserErr {
+        message: String,
+        line: Option<usize>,
+        position: Option<usize>,
+    },
+    #[cfg(feature = "cross_compile")]
+    BackendErr {
+        backend: &'static str,
+        message: String,
+    },
+}
+
+impl WgslError {
+    pub fn from_parse_error(error: &ParseError, source: &str) -> Self {
+        let message = error.emit_to_string(source);
+        if let Some(location) = error.location(source) {
+            Self::ParserErr {
+                message,
+                line: Some(location.line_number as usize),
+                position: Some(location.line_position as usize),
+            }
+        } else {
+            Self::ParserErr {
+                message,
+                line: None,
+                position: None,
+            }
+        }
+    }
+
+    /// The 1-based line and position the error points at, when naga knows it
+    pub fn location(&self) -> Option<(usize, usize)> {
+        match self {
+            WgslError::ValidationErr { source, error, .. } => {
+                error.location(source).map(|location| {
+                    (
+                        location.line_number as usize,
+                        location.line_position as usize,
+                    )
+                })
+            }
+            WgslError::ParserErr { line, position, .. } => Some(((*line)?, (*position)?)),
+            #[cfg(feature = "cross_compile")]
+            WgslError::BackendErr { .. } => None,
+        }
+    }
+}
+
+impl Display for WgslError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            WgslError::ValidationErr {
+                source,
+                error,
+                message,
+            } => {
+                write!(
+                    f,
+                    "Error validating WGSL. Error: {}, message: {}",
+                    error.emit_to_string(source),
+                    message
+                )
+            }
+            WgslError::ParserErr {
+                message,
+                line,
+                position,
+            } => {
+                let line_string = match line {
+                    Some(line) => line.to_string(),
+                    None => "not found".to_string(),
+                };
+                let position_string = match position {
+                    Some(position) => position.to_string(),
+                    None => "not found".to_string(),
+                };
+                write!(
+                    f,
+                    "Error parsing WGSL on ln {line_string} pos {position_string} : {message}"
+                )
+            }
+            #[cfg(feature = "cross_compile")]
+            WgslError::BackendErr { backend, message } => {
+                write!(f, "Error emitting {backend}: {message}")
+            }
+        }
+    }
+}
+
+impl Error for WgslError {}
+
+/// What the error panel shows about a shader that doesn't compile
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShaderError {
+    pub summary: String,
+    /// The 1-based line and position in the generated WGSL
+    pub location: Option<(usize, usize)>,
+    /// The WGSL line at the location, trimmed
+    pub source_line: Option<String>,
+}
+
+impl ShaderError {
+    /// `source` is the WGSL the error was found in
+    pub fn from_wgsl_error(error: &WgslError, source: &str) -> Self {
+        // naga's reports start with an `error: <summary>` line followed by the annotated source
+        let summary = match error {
+            WgslError::ValidationErr { message, .. } | WgslError::ParserErr { message, .. } => {
+                let first_line = message.lines().next().unwrap_or_default();
+                first_line
+                    .strip_prefix("error: ")
+                    .unwrap_or(first_line)
+                    .to_string()
+            }
+            #[cfg(feature = "cross_compile")]
+            WgslError::BackendErr { .. } => error.to_string(),
+        };
+        let location = error.location();
+        Self {
+            summary,
+            location,
+            source_line: location.and_then(|(line, _)| {
+                source
+                    .lines()
+                    .nth(line.saturating_sub(1))
+                    .map(|source_line| source_line.trim().to_string())
+            }),
+        }
+    }
+
+    pub fn panel_text(&self, test_name: &str) -> String {
+        let mut panel_text = format!("{test_name}: {}", self.summary);
+        if let Some((line, position)) = self.location {
+            panel_text.push_str(&format!("\nln {line} pos {position}"));
+            if let Some(source_line) = &self.source_line {
+                panel_text.push_str(&format!(": {source_line}"));
+            }
+        }
+        panel_text
+    }
+}
+
+/// Parses and validates generated WGSL with every capability, the engine decides what the
+/// adapter actually supports
+pub fn check_wgsl(source: &str) -> Result<(), ShaderError> {
+    let error = match parse_str(source) {
+        Ok(module) => {
+            match Validator::new(ValidationFlags::all(), Capabilities::all()).validate(&module) {
+                Ok(_) => return Ok(()),
+                Err(error) => {
+                    let message = error.emit_to_string(source);
+                    WgslError::ValidationErr {
+                        source: source.to_string(),
+                        error,
+                        message,
+                    }
+                }
+            }
+        }
+        Err(error) => WgslError::from_parse_error(&error, source),
+    };
+    Err(ShaderError::from_wgsl_error(&error, source))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shader_error::{ShaderError, check_wgsl};
+
+    #[test]
+    fn panel_text_points_at_the_line() {
+        let shader_error = ShaderError {
+            summary: "no definition in scope for identifier: `speeed`".to_string(),
+            location: Some((12, 21)),
+            source_line: Some("let offset = uv.y * speeed;".to_string()),
+        };
+        assert_eq!(
+            shader_error.panel_text("starfield"),
+            "starfield: no definition in scope for identifier: `speeed`\n\
+             ln 12 pos 21: let offset = uv.y * speeed;"
+        );
+        assert_eq!(
+            ShaderError {
+                location: None,
+                source_line: None,
+                ..shader_error
+            }
+            .panel_text("starfield"),
+            "starfield: no definition in scope for identifier: `speeed`"
+        );
+
+        let shader_error = check_wgsl("fn f() -> f32 {\n    return missing;\n}\n").unwrap_err();
+        assert_eq!(shader_error.location.map(|(line, _)| line), Some(2));
+        assert_eq!(shader_error.source_line.as_deref(), Some("return missing;"));
+    }
+}
@@ -0,0 +1,78 @@
+//! An overlay showing the WGSL generated for the running test's material, with line numbers. F10
+//! toggles it, Up/Down and the scroll wheel scroll a line and Page Up/Page Down a page.
+
+/// The overlay's text lines, a header and the visible WGSL lines
+pub const SHADER_SOURCE_VIEWER_LINES: usize = 24;
+/// WGSL lines visible at once, below the header
+pub const SHADER_SOURCE_PAGE_LINES: usize = SHADER_SOURCE_VIEWER_LINES - 1;
+/// Longer lines are cut off so they fit on screen
+pub const SHADER_SOURCE_LINE_CHARS: usize = 100;
+
+/// The first visible line after scrolling `lines` lines down, negative for up, kept so the last
+/// page stays full
+pub fn scrolled_first_line(first_line: usize, lines: isize, line_count: usize) -> usize {
+    first_line
+        .saturating_add_signed(lines)
+        .min(line_count.saturating_sub(SHADER_SOURCE_PAGE_LINES))
+}
+
+/// The overlay text for `wgsl` from `first_line` on, each line numbered from 1 and right
+/// aligned so the numbers line up
+pub fn viewer_lines(test_name: &str, wgsl: &str, first_line: usize) -> Vec<String> {
+    let line_count = wgsl.lines().count();
+    let last_line = (first_line + SHADER_SOURCE_PAGE_LINES).min(line_count);
+    let number_width = line_count.to_string().len();
+    let mut lines = vec![format!(
+        "WGSL of {test_name}, lines {}-{last_line} of {line_count}:",
+        (first_line + 1).min(last_line)
+    )];
+    lines.extend(
+        wgsl.lines()
+            .enumerate()
+            .skip(first_line)
+            .take(SHADER_SOURCE_PAGE_LINES)
+            .map(|(index, line)| {
+                let line = line
+                    .chars()
+                    .take(SHADER_SOURCE_LINE_CHARS)
+                    .collect::<String>();
+                format!("{:>number_width$} {line}", index + 1)
+            }),
+    );
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shader_source_viewer::{
+        SHADER_SOURCE_PAGE_LINES, SHADER_SOURCE_VIEWER_LINES, scrolled_first_line, viewer_lines,
+    };
+
+    #[test]
+    fn scrolling_stops_at_the_last_page() {
+        assert_eq!(scrolled_first_line(0, -1, 100), 0);
+        assert_eq!(scrolled_first_line(0, 1, 100), 1);
+        assert_eq!(
+            scrolled_first_line(70, SHADER_SOURCE_PAGE_LINES as isize, 100),
+            100 - SHADER_SOURCE_PAGE_LINES
+        );
+        assert_eq!(scrolled_first_line(0, 5, 10), 0);
+    }
+
+    #[test]
+    fn lines_are_numbered() {
+        let wgsl = (1..=30)
+            .map(|line| format!("line {line}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let lines = viewer_lines("warp", &wgsl, 7);
+        assert_eq!(lines.len(), SHADER_SOURCE_VIEWER_LINES);
+        assert_eq!(lines[0], "WGSL of warp, lines 8-30 of 30:");
+        assert_eq!(lines[1], " 8 line 8");
+        assert_eq!(lines[SHADER_SOURCE_PAGE_LINES], "30 line 30");
+        assert_eq!(
+            viewer_lines("warp", "", 0),
+            vec!["WGSL of warp, lines 0-0 of 0:"]
+        );
+    }
+}
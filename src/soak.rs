@@ -0,0 +1,219 @@
+//! Helpers for the `--soak` mode, which drives the module with random key presses and view
+//! transitions for a long time and samples the frame rate and entity counts, to catch slow leaks
+//! and menu state machine corner cases. `--soak=<minutes>` sets how long it runs and
+//! `--soak=<minutes>,<actions per second>` how often it acts as well.
+
+use std::{fs, io, path::Path};
+
+use crate::{entity_counts::EntityCounts, input_bindings::InputAction};
+
+pub const SOAK_ARG: &str = "--soak";
+pub const SOAK_DEFAULT_MINUTES: f32 = 60.;
+pub const SOAK_DEFAULT_ACTIONS_PER_SECOND: f32 = 4.;
+/// On average one action in this many is a view transition instead of a key press
+pub const SOAK_TRANSITION_ONE_IN: u32 = 20;
+pub const SOAK_SAMPLE_SECONDS: f32 = 10.;
+pub const SOAK_REPORT_PATH: &str = "soak_report.csv";
+/// Actions the soak never presses, quitting ends the soak
+pub const SOAK_SKIPPED_ACTIONS: [InputAction; 1] = [InputAction::Quit];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SoakConfig {
+    pub duration_seconds: f32,
+    pub actions_per_second: f32,
+}
+
+/// Parses `--soak`, `--soak=<minutes>` or `--soak=<minutes>,<actions per second>`
+pub fn parse_soak_arg(arg: &str) -> Option<SoakConfig> {
+    let remainder = arg.strip_prefix(SOAK_ARG)?;
+    let mut soak_config = SoakConfig {
+        duration_seconds: SOAK_DEFAULT_MINUTES * 60.,
+        actions_per_second: SOAK_DEFAULT_ACTIONS_PER_SECOND,
+    };
+    if remainder.is_empty() {
+        return Some(soak_config);
+    }
+    let remainder = remainder.strip_prefix('=')?;
+    let (minutes, actions_per_second) = match remainder.split_once(',') {
+        Some((minutes, actions_per_second)) => (minutes, Some(actions_per_second)),
+        None => (remainder, None),
+    };
+    let positive = |value: &str| value.parse::<f32>().ok().filter(|value| *value > 0.);
+    soak_config.duration_seconds = positive(minutes)? * 60.;
+    if let Some(actions_per_second) = actions_per_second {
+        soak_config.actions_per_second = positive(actions_per_second)?;
+    }
+    Some(soak_config)
+}
+
+/// The actions the soak picks its key presses from
+pub fn soak_actions() -> impl Iterator<Item = InputAction> {
+    InputAction::ALL
+        .into_iter()
+        .filter(|input_action| !SOAK_SKIPPED_ACTIONS.contains(input_action))
+}
+
+/// What the soak does this frame
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SoakTick {
+    pub actions: usize,
+    /// A sample should be recorded with [`Soak::record_sample`]
+    pub sample: bool,
+    pub finished: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SoakSample {
+    pub elapsed_seconds: f32,
+    /// Averaged since the previous sample
+    pub average_frame_time: f32,
+    pub entity_counts: EntityCounts,
+}
+
+#[derive(Debug)]
+pub struct Soak {
+    config: SoakConfig,
+    elapsed: f32,
+    action_progress: f32,
+    sample_elapsed: f32,
+    sample_frame_count: usize,
+    samples: Vec<SoakSample>,
+}
+
+impl Soak {
+    pub fn new(config: SoakConfig) -> Self {
+        Self {
+            config,
+            elapsed: 0.,
+            action_progress: 0.,
+            sample_elapsed: 0.,
+            sample_frame_count: 0,
+            samples: vec![],
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.config.duration_seconds
+    }
+
+    pub fn samples(&self) -> &[SoakSample] {
+        &self.samples
+    }
+
+    pub fn advance(&mut self, delta_time: f32) -> SoakTick {
+        if self.is_finished() {
+            return SoakTick::default();
+        }
+        self.elapsed += delta_time;
+        self.sample_elapsed += delta_time;
+        self.sample_frame_count += 1;
+
+        self.action_progress += delta_time * self.config.actions_per_second;
+        let actions = self.action_progress.floor();
+        self.action_progress -= actions;
+
+        let finished = self.is_finished();
+        SoakTick {
+            actions: actions as usize,
+            sample: self.sample_elapsed >= SOAK_SAMPLE_SECONDS || finished,
+            finished,
+        }
+    }
+
+    pub fn record_sample(&mut self, entity_counts: EntityCounts) {
+        self.samples.push(SoakSample {
+            elapsed_seconds: self.elapsed,
+            average_frame_time: self.sample_elapsed / self.sample_frame_count.max(1) as f32,
+            entity_counts,
+        });
+        self.sample_elapsed = 0.;
+        self.sample_frame_count = 0;
+    }
+
+    pub fn to_csv(&self) -> String {
+        self.samples.iter().fold(
+            "elapsed_seconds,average_frame_time_ms,material_test_objects,text_renders,\
+             texture_renders,material_parameters\n"
+                .to_string(),
+            |mut accumulator, sample| {
+                accumulator.push_str(&format!(
+                    "{:.0},{:.3},{},{},{},{}\n",
+                    sample.elapsed_seconds,
+                    sample.average_frame_time * 1000.,
+                    sample.entity_counts.material_test_objects,
+                    sample.entity_counts.text_renders,
+                    sample.entity_counts.texture_renders,
+                    sample.entity_counts.material_parameters
+                ));
+                accumulator
+            },
+        )
+    }
+
+    pub fn write_report<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_csv())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        entity_counts::EntityCounts,
+        input_bindings::InputAction,
+        soak::{
+            SOAK_DEFAULT_ACTIONS_PER_SECOND, SOAK_SAMPLE_SECONDS, Soak, SoakConfig, parse_soak_arg,
+            soak_actions,
+        },
+    };
+
+    #[test]
+    fn soak_arg_parsing() {
+        assert_eq!(
+            parse_soak_arg("--soak=90"),
+            Some(SoakConfig {
+                duration_seconds: 5400.,
+                actions_per_second: SOAK_DEFAULT_ACTIONS_PER_SECOND,
+            })
+        );
+        assert_eq!(
+            parse_soak_arg("--soak=1,10"),
+            Some(SoakConfig {
+                duration_seconds: 60.,
+                actions_per_second: 10.,
+            })
+        );
+        assert!(parse_soak_arg("--soak").is_some());
+        assert_eq!(parse_soak_arg("--soak=0"), None);
+        assert_eq!(parse_soak_arg("--soak=5,fast"), None);
+        assert_eq!(parse_soak_arg("--soaking"), None);
+        assert!(!soak_actions().any(|input_action| input_action == InputAction::Quit));
+    }
+
+    #[test]
+    fn soak_acts_samples_and_finishes() {
+        let mut soak = Soak::new(SoakConfig {
+            duration_seconds: SOAK_SAMPLE_SECONDS * 2.,
+            actions_per_second: 2.,
+        });
+        let mut actions = 0;
+        let mut samples = 0;
+        let mut finished = false;
+        while !finished {
+            let soak_tick = soak.advance(0.25);
+            actions += soak_tick.actions;
+            if soak_tick.sample {
+                samples += 1;
+                soak.record_sample(EntityCounts {
+                    text_renders: samples,
+                    ..Default::default()
+                });
+            }
+            finished = soak_tick.finished;
+        }
+        assert_eq!(actions, 40);
+        assert_eq!(samples, 2);
+        assert!(soak.is_finished());
+        assert_eq!(soak.advance(0.25).actions, 0);
+        assert_eq!(soak.to_csv().lines().nth(1), Some("10,250.000,0,1,0,0"));
+    }
+}
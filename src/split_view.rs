@@ -0,0 +1,103 @@
+//! Helpers for the split view of sprite tests, which draws the running test four times, once per
+//! quadrant of the screen, each with a different variant from the test's materials so they can be
+//! compared side by side. The engine renders a single viewport, so the test's sprites are hidden
+//! and mirrored by scaled down copies instead, one per quadrant.
+
+use void_public::Vec2;
+
+pub const SPLIT_VIEW_QUADRANTS: usize = 4;
+/// Sprites past this many are hidden without being mirrored, every mirrored sprite has a copy per
+/// quadrant updated each frame
+pub const SPLIT_VIEW_SPRITE_LIMIT: usize = 250;
+
+/// Which variant each quadrant shows, quadrants are numbered left to right from the top left
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SplitViewVariants {
+    variants: [usize; SPLIT_VIEW_QUADRANTS],
+    variant_count: usize,
+}
+
+impl SplitViewVariants {
+    /// Spreads the first variants over the quadrants, wrapping when there are fewer than four
+    pub fn new(variant_count: usize) -> Self {
+        Self {
+            variants: std::array::from_fn(|quadrant| quadrant % variant_count.max(1)),
+            variant_count,
+        }
+    }
+
+    pub fn variant(&self, quadrant: usize) -> usize {
+        self.variants[quadrant]
+    }
+
+    /// Steps `quadrant` to the next variant, wrapping around to the first
+    pub fn cycle(&mut self, quadrant: usize) {
+        self.variants[quadrant] = (self.variants[quadrant] + 1) % self.variant_count.max(1);
+    }
+}
+
+/// The center of `quadrant` in world space, where the origin is the center of the screen
+fn quadrant_center(quadrant: usize, screen_size: Vec2) -> Vec2 {
+    let column = (quadrant % 2) as f32;
+    let row = (quadrant / 2) as f32;
+    Vec2::new(column - 0.5, 0.5 - row) * screen_size * 0.5
+}
+
+/// Where something at `position` with `scale` on the full screen is drawn in `quadrant`
+pub fn quadrant_transform(
+    position: Vec2,
+    scale: Vec2,
+    quadrant: usize,
+    screen_size: Vec2,
+) -> (Vec2, Vec2) {
+    (
+        quadrant_center(quadrant, screen_size) + position * 0.5,
+        scale * 0.5,
+    )
+}
+
+/// Near the top left corner of `quadrant`, where its variant is labelled
+pub fn quadrant_label_position(quadrant: usize, screen_size: Vec2) -> Vec2 {
+    quadrant_center(quadrant, screen_size) + Vec2::new(-0.125, 0.2) * screen_size
+}
+
+#[cfg(test)]
+mod test {
+    use void_public::Vec2;
+
+    use crate::split_view::{SplitViewVariants, quadrant_label_position, quadrant_transform};
+
+    #[test]
+    fn variants_wrap_per_quadrant() {
+        let mut split_view_variants = SplitViewVariants::new(3);
+        assert_eq!(
+            (0..4)
+                .map(|quadrant| split_view_variants.variant(quadrant))
+                .collect::<Vec<_>>(),
+            [0, 1, 2, 0]
+        );
+        split_view_variants.cycle(2);
+        assert_eq!(split_view_variants.variant(2), 0);
+        split_view_variants.cycle(3);
+        assert_eq!(split_view_variants.variant(3), 1);
+        assert_eq!(SplitViewVariants::new(0).variant(3), 0);
+    }
+
+    #[test]
+    fn quadrants_shrink_the_screen_into_each_corner() {
+        let screen_size = Vec2::new(800., 600.);
+        assert_eq!(
+            quadrant_transform(Vec2::ZERO, Vec2::splat(100.), 0, screen_size),
+            (Vec2::new(-200., 150.), Vec2::splat(50.))
+        );
+        // The full screen's top right corner lands on the bottom right quadrant's
+        assert_eq!(
+            quadrant_transform(Vec2::new(400., 300.), Vec2::ONE, 3, screen_size).0,
+            Vec2::new(400., 0.)
+        );
+        assert_eq!(
+            quadrant_label_position(1, screen_size),
+            Vec2::new(100., 270.)
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! The command line, merged with config.toml, parsed once at startup. `materials_setup` fills it
+//! in and each setup system after it reads the options it owns, so no system scans the raw
+//! arguments itself. Test names are only resolved once every test has been registered.
+
+use std::path::PathBuf;
+
+use crate::{
+    fuzzy::closest_match,
+    input_handlers::{KeyRepeat, parse_key_repeat_arg},
+    input_replay::{parse_record_input_arg, parse_replay_input_arg},
+    localization::parse_locale_arg,
+    material_bench::is_bench_materials_arg,
+    perf_overlay::parse_perf_overlay_arg,
+    playlist::parse_playlist_arg,
+    rng::parse_seed_arg,
+    shader_bench::parse_bench_shaders_arg,
+    shader_dump::parse_dump_shaders_arg,
+    soak::{SoakConfig, parse_soak_arg},
+    stress_ramp::parse_stress_ramp_arg,
+    teardown_check::is_check_teardown_arg,
+    view_transitions::{TransitionStyle, parse_fade_arg, parse_transition_arg},
+};
+
+/// Prints every registered test grouped by material type and exits
+pub const LIST_ARG: &str = "--list";
+/// The test both `--stress-ramp` and `--bench-materials` run
+pub const STRESS_TEST_NAME: &str = "stress_test";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StartupArgs {
+    pub list: bool,
+    pub locale: Option<String>,
+    pub bench_shaders: Option<u32>,
+    pub dump_shaders: Option<PathBuf>,
+    pub replay_input: Option<PathBuf>,
+    pub record_input: Option<PathBuf>,
+    pub key_repeat: Option<KeyRepeat>,
+    pub fade_seconds: Option<f32>,
+    pub transition_style: Option<TransitionStyle>,
+    pub seed: Option<u64>,
+    pub soak: Option<SoakConfig>,
+    pub perf_overlay: Option<bool>,
+    pub stress_ramp_seconds: Option<f32>,
+    pub bench_materials: bool,
+    pub check_teardown: bool,
+    /// `<test name>:<seconds>` entries in command line order, not yet checked against the tests
+    pub playlist: Vec<(String, f32)>,
+    /// The first positional argument, lowercased
+    pub test_name: Option<String>,
+}
+
+impl StartupArgs {
+    /// Parses `args` as returned by `std::env::args`, skipping the program name
+    pub fn parse(args: &[String]) -> Self {
+        let args = args.get(1..).unwrap_or_default();
+        Self {
+            list: args.iter().any(|arg| arg == LIST_ARG),
+            locale: args
+                .iter()
+                .find_map(|arg| parse_locale_arg(arg))
+                .map(str::to_string),
+            bench_shaders: args.iter().find_map(|arg| parse_bench_shaders_arg(arg)),
+            dump_shaders: args.iter().find_map(|arg| parse_dump_shaders_arg(arg)),
+            replay_input: args.iter().find_map(|arg| parse_replay_input_arg(arg)),
+            record_input: args.iter().find_map(|arg| parse_record_input_arg(arg)),
+            key_repeat: args.iter().find_map(|arg| parse_key_repeat_arg(arg)),
+            fade_seconds: args.iter().find_map(|arg| parse_fade_arg(arg)),
+            transition_style: args.iter().find_map(|arg| parse_transition_arg(arg)),
+            seed: args.iter().find_map(|arg| parse_seed_arg(arg)),
+            soak: args.iter().find_map(|arg| parse_soak_arg(arg)),
+            perf_overlay: args.iter().find_map(|arg| parse_perf_overlay_arg(arg)),
+            stress_ramp_seconds: args.iter().find_map(|arg| parse_stress_ramp_arg(arg)),
+            bench_materials: args.iter().any(|arg| is_bench_materials_arg(arg)),
+            check_teardown: args.iter().any(|arg| is_check_teardown_arg(arg)),
+            playlist: args
+                .iter()
+                .filter_map(|arg| parse_playlist_arg(arg))
+                .map(|(test_name, duration_seconds)| (test_name.to_string(), duration_seconds))
+                .collect(),
+            test_name: args
+                .iter()
+                .find(|arg| !arg.starts_with("--") && parse_playlist_arg(arg).is_none())
+                .map(|test_name| test_name.to_lowercase()),
+        }
+    }
+
+    /// The test to open once everything has loaded, unless a playlist takes over. The stress test
+    /// modes always open the stress test.
+    pub fn requested_test_name(&self) -> Option<&str> {
+        if self.stress_ramp_seconds.is_some() || self.bench_materials {
+            Some(STRESS_TEST_NAME)
+        } else {
+            self.test_name.as_deref()
+        }
+    }
+
+    /// Whether the playlist is tried before [`Self::requested_test_name`], the stress test modes
+    /// ignore it
+    pub fn plays_playlist(&self) -> bool {
+        self.stress_ramp_seconds.is_none() && !self.bench_materials && !self.playlist.is_empty()
+    }
+}
+
+/// The warning for a test name that doesn't match any registered test
+pub fn unknown_test_message<'a>(
+    test_name: &str,
+    registered_names: impl IntoIterator<Item = &'a str>,
+) -> String {
+    match closest_match(test_name, registered_names) {
+        Some(closest_name) => format!("Unknown test {test_name}, did you mean {closest_name}?"),
+        None => format!("Unknown test {test_name}, run with {LIST_ARG} to see every test"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::startup_args::{STRESS_TEST_NAME, StartupArgs, unknown_test_message};
+
+    fn parse(args: &[&str]) -> StartupArgs {
+        let args = ["shader_test_module"]
+            .iter()
+            .chain(args)
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>();
+        StartupArgs::parse(&args)
+    }
+
+    #[test]
+    fn startup_args_are_parsed_once() {
+        let startup_args = parse(&[
+            "--seed=7",
+            "Warp",
+            "--dump-shaders=out",
+            "starfield:10",
+            "fire",
+        ]);
+        assert_eq!(startup_args.seed, Some(7));
+        assert_eq!(startup_args.dump_shaders, Some(PathBuf::from("out")));
+        assert_eq!(startup_args.playlist, vec![("starfield".to_string(), 10.)]);
+        assert_eq!(startup_args.test_name.as_deref(), Some("warp"));
+        assert!(!startup_args.list);
+        assert_eq!(parse(&[]), StartupArgs::default());
+    }
+
+    #[test]
+    fn stress_test_modes_take_precedence() {
+        let startup_args = parse(&["warp", "starfield:10"]);
+        assert_eq!(startup_args.requested_test_name(), Some("warp"));
+        assert!(startup_args.plays_playlist());
+
+        let startup_args = parse(&["warp", "starfield:10", "--bench-materials"]);
+        assert_eq!(startup_args.requested_test_name(), Some(STRESS_TEST_NAME));
+        assert!(!startup_args.plays_playlist());
+    }
+
+    #[test]
+    fn unknown_test_message_suggests_the_closest_name() {
+        assert_eq!(
+            unknown_test_message("wrap", ["warp", "fire"]),
+            "Unknown test wrap, did you mean warp?"
+        );
+        assert_eq!(
+            unknown_test_message("lighthouse", ["warp", "fire"]),
+            "Unknown test lighthouse, run with --list to see every test"
+        );
+    }
+}
@@ -0,0 +1,133 @@
+//! Helpers for the stress test's scripted ramp mode, which doubles the sprite count at a fixed
+//! interval and records the average frame time of every step.
+
+use std::{fs, io, path::Path};
+
+pub const STRESS_RAMP_ARG: &str = "--stress-ramp";
+pub const STRESS_RAMP_DEFAULT_STEP_SECONDS: f32 = 5.;
+/// The ramp stops after this many steps, 100 sprites doubled 10 times is 51200 sprites
+pub const STRESS_RAMP_MAX_STEPS: usize = 10;
+pub const STRESS_RAMP_REPORT_PATH: &str = "stress_ramp_report.csv";
+
+/// Parses `--stress-ramp` or `--stress-ramp=<seconds per step>`
+pub fn parse_stress_ramp_arg(arg: &str) -> Option<f32> {
+    let remainder = arg.strip_prefix(STRESS_RAMP_ARG)?;
+    if remainder.is_empty() {
+        return Some(STRESS_RAMP_DEFAULT_STEP_SECONDS);
+    }
+    remainder
+        .strip_prefix('=')?
+        .parse::<f32>()
+        .ok()
+        .filter(|step_seconds| *step_seconds > 0.)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StressRampStep {
+    pub sprite_count: usize,
+    pub average_frame_time: f32,
+}
+
+#[derive(Debug)]
+pub struct StressRamp {
+    step_seconds: f32,
+    step_elapsed: f32,
+    step_frame_time_total: f32,
+    step_frame_count: usize,
+    steps: Vec<StressRampStep>,
+}
+
+impl StressRamp {
+    pub fn new(step_seconds: f32) -> Self {
+        Self {
+            step_seconds,
+            step_elapsed: 0.,
+            step_frame_time_total: 0.,
+            step_frame_count: 0,
+            steps: vec![],
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.steps.len() >= STRESS_RAMP_MAX_STEPS
+    }
+
+    pub fn steps(&self) -> &[StressRampStep] {
+        &self.steps
+    }
+
+    /// Records a frame, returning the finished step once `step_seconds` have elapsed
+    pub fn record_frame(&mut self, delta_time: f32, sprite_count: usize) -> Option<StressRampStep> {
+        if self.is_finished() {
+            return None;
+        }
+
+        self.step_elapsed += delta_time;
+        self.step_frame_time_total += delta_time;
+        self.step_frame_count += 1;
+
+        if self.step_elapsed < self.step_seconds {
+            return None;
+        }
+
+        let step = StressRampStep {
+            sprite_count,
+            average_frame_time: self.step_frame_time_total / self.step_frame_count as f32,
+        };
+        self.steps.push(step);
+        self.step_elapsed = 0.;
+        self.step_frame_time_total = 0.;
+        self.step_frame_count = 0;
+        Some(step)
+    }
+
+    pub fn to_csv(&self) -> String {
+        self.steps.iter().enumerate().fold(
+            "step,sprite_count,average_frame_time_ms,average_fps\n".to_string(),
+            |mut accumulator, (index, step)| {
+                accumulator.push_str(&format!(
+                    "{index},{},{:.3},{:.1}\n",
+                    step.sprite_count,
+                    step.average_frame_time * 1000.,
+                    1. / step.average_frame_time
+                ));
+                accumulator
+            },
+        )
+    }
+
+    pub fn write_report<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_csv())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::stress_ramp::{STRESS_RAMP_DEFAULT_STEP_SECONDS, StressRamp, parse_stress_ramp_arg};
+
+    #[test]
+    fn stress_ramp_arg_parsing() {
+        assert_eq!(
+            parse_stress_ramp_arg("--stress-ramp"),
+            Some(STRESS_RAMP_DEFAULT_STEP_SECONDS)
+        );
+        assert_eq!(parse_stress_ramp_arg("--stress-ramp=2.5"), Some(2.5));
+        assert_eq!(parse_stress_ramp_arg("--stress-ramp=0"), None);
+        assert_eq!(parse_stress_ramp_arg("--stress-rampage"), None);
+        assert_eq!(parse_stress_ramp_arg("stress_test"), None);
+    }
+
+    #[test]
+    fn stress_ramp_records_average_frame_time_per_step() {
+        let mut stress_ramp = StressRamp::new(1.);
+        assert_eq!(stress_ramp.record_frame(0.25, 100), None);
+        assert_eq!(stress_ramp.record_frame(0.25, 100), None);
+        let step = stress_ramp.record_frame(0.5, 100).unwrap();
+        assert_eq!(step.sprite_count, 100);
+        assert!((step.average_frame_time - 1. / 3.).abs() < f32::EPSILON);
+        assert_eq!(
+            stress_ramp.to_csv(),
+            "step,sprite_count,average_frame_time_ms,average_fps\n0,100,333.333,3.0\n"
+        );
+    }
+}
@@ -0,0 +1,118 @@
+//! A debug mode, enabled with `--check-teardown`, checking that leaving a material test cleaned up
+//! after it. A couple of frames after the view leaves a test, once despawns and the teardown
+//! system have run, leftover test objects, postprocesses and test systems `View::change_view`
+//! can't turn off are logged and toasted.
+
+pub const CHECK_TEARDOWN_ARG: &str = "--check-teardown";
+/// Frames between leaving a test and checking, despawns take effect a frame later and teardown
+/// systems run the frame after the view changed
+pub const TEARDOWN_CHECK_DELAY_FRAMES: usize = 2;
+
+pub fn is_check_teardown_arg(arg: &str) -> bool {
+    arg == CHECK_TEARDOWN_ARG
+}
+
+/// Waits for the view to leave a test and says when to check it
+#[derive(Debug, Default)]
+pub struct TeardownCheck {
+    running_test: Option<String>,
+    /// The test left and the frames until it is checked
+    pending: Option<(String, usize)>,
+}
+
+impl TeardownCheck {
+    /// Called every frame with the test the view is running, returns the test to check once it
+    /// has been left for [`TEARDOWN_CHECK_DELAY_FRAMES`]
+    pub fn update(&mut self, running_test: Option<&str>) -> Option<String> {
+        if self.running_test.as_deref() != running_test {
+            if let Some(left_test) = self.running_test.take() {
+                self.pending = Some((left_test, TEARDOWN_CHECK_DELAY_FRAMES));
+            }
+            self.running_test = running_test.map(str::to_string);
+        }
+        // Starting another test right away leaves nothing to check, its objects are expected
+        if self.running_test.is_some() {
+            self.pending = None;
+        }
+
+        let (_, frames_left) = self.pending.as_mut()?;
+        *frames_left = frames_left.saturating_sub(1);
+        if *frames_left > 0 {
+            return None;
+        }
+        self.pending.take().map(|(left_test, _)| left_test)
+    }
+}
+
+/// What a test left behind
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TeardownLeaks {
+    pub test_objects: usize,
+    /// The postprocesses still applied, named after the test owning their material
+    pub postprocesses: Vec<String>,
+    /// Tests whose systems aren't registered, so leaving a test doesn't turn them off
+    pub unregistered_tests: Vec<String>,
+}
+
+impl TeardownLeaks {
+    pub fn is_empty(&self) -> bool {
+        self.test_objects == 0
+            && self.postprocesses.is_empty()
+            && self.unregistered_tests.is_empty()
+    }
+
+    pub fn report(&self, left_test: &str) -> String {
+        let mut offenders = vec![];
+        if self.test_objects > 0 {
+            offenders.push(format!("{} MaterialTestObject entities", self.test_objects));
+        }
+        if !self.postprocesses.is_empty() {
+            offenders.push(format!("postprocesses {}", self.postprocesses.join(", ")));
+        }
+        if !self.unregistered_tests.is_empty() {
+            offenders.push(format!(
+                "systems of {} never turned off",
+                self.unregistered_tests.join(", ")
+            ));
+        }
+        format!("Leaving {left_test} left {}", offenders.join("; "))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::teardown_check::{TEARDOWN_CHECK_DELAY_FRAMES, TeardownCheck, TeardownLeaks};
+
+    #[test]
+    fn left_tests_are_checked_after_the_delay() {
+        let mut teardown_check = TeardownCheck::default();
+        assert_eq!(teardown_check.update(None), None);
+        assert_eq!(teardown_check.update(Some("warp")), None);
+        for _ in 1..TEARDOWN_CHECK_DELAY_FRAMES {
+            assert_eq!(teardown_check.update(None), None);
+        }
+        assert_eq!(teardown_check.update(None), Some("warp".to_string()));
+        assert_eq!(teardown_check.update(None), None);
+
+        // Switching straight to another test isn't checked
+        teardown_check.update(Some("warp"));
+        for _ in 0..TEARDOWN_CHECK_DELAY_FRAMES {
+            assert_eq!(teardown_check.update(Some("fire")), None);
+        }
+    }
+
+    #[test]
+    fn leaks_are_reported() {
+        assert!(TeardownLeaks::default().is_empty());
+        let teardown_leaks = TeardownLeaks {
+            test_objects: 3,
+            postprocesses: vec!["warp".to_string()],
+            unregistered_tests: vec!["fire".to_string()],
+        };
+        assert_eq!(
+            teardown_leaks.report("warp"),
+            "Leaving warp left 3 MaterialTestObject entities; postprocesses warp; systems of \
+             fire never turned off"
+        );
+    }
+}
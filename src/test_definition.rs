@@ -0,0 +1,98 @@
+//! Records the systems of every material test, so `turn_off_material_test_systems` can turn
+//! them all off without a hand kept list. Each test implements [`MaterialTestSystems`] with
+//! [`material_test_systems!`] next to its systems in `lib.rs`.
+
+use std::{
+    ffi::CStr,
+    iter,
+    sync::{Mutex, PoisonError},
+};
+
+/// A material test's systems, implemented with [`material_test_systems!`]
+pub trait MaterialTestSystems {
+    const STARTUP_SYSTEM: &'static CStr;
+    /// Enabled by the startup system once the test is set up
+    const SYSTEMS: &'static [&'static CStr];
+    const TEARDOWN_SYSTEM: Option<&'static CStr> = None;
+}
+
+/// Implements [`MaterialTestSystems`] from the system function names, e.g.
+/// `material_test_systems!(FireTest, fire_startup_system, [fire_system]);`
+macro_rules! material_test_systems {
+    ($material_test:ty, $startup_system:ident, [$($system:ident),* $(,)?] $(, $teardown_system:ident)?) => {
+        impl $crate::test_definition::MaterialTestSystems for $material_test {
+            const STARTUP_SYSTEM: &'static ::std::ffi::CStr =
+                $crate::test_definition::system_name(concat!(stringify!($startup_system), "\0"));
+            const SYSTEMS: &'static [&'static ::std::ffi::CStr] = &[$(
+                $crate::test_definition::system_name(concat!(stringify!($system), "\0"))
+            ),*];
+            $(
+                const TEARDOWN_SYSTEM: Option<&'static ::std::ffi::CStr> = Some(
+                    $crate::test_definition::system_name(concat!(stringify!($teardown_system), "\0")),
+                );
+            )?
+        }
+    };
+}
+pub(crate) use material_test_systems;
+
+/// Every registered test's startup and per frame systems, turned off whenever a test ends
+static TEST_SYSTEMS: Mutex<Vec<&'static CStr>> = Mutex::new(Vec::new());
+
+/// Called when a test is registered, so its systems can't be left out of
+/// `turn_off_material_test_systems`. Teardown systems are left alone, they run after the others
+/// are turned off.
+pub fn register_test_systems<T: MaterialTestSystems>() {
+    let mut test_systems = TEST_SYSTEMS.lock().unwrap_or_else(PoisonError::into_inner);
+    for system in iter::once(T::STARTUP_SYSTEM).chain(T::SYSTEMS.iter().copied()) {
+        if !test_systems.contains(&system) {
+            test_systems.push(system);
+        }
+    }
+}
+
+pub fn registered_test_systems() -> Vec<&'static CStr> {
+    TEST_SYSTEMS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// A nul terminated system name as the [`CStr`] `Engine::set_system_enabled` takes
+pub const fn system_name(name_with_nul: &'static str) -> &'static CStr {
+    match CStr::from_bytes_with_nul(name_with_nul.as_bytes()) {
+        Ok(system_name) => system_name,
+        Err(_) => panic!("System names must end in a single nul"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_definition::{
+        MaterialTestSystems, register_test_systems, registered_test_systems,
+    };
+
+    struct ExampleTest;
+
+    material_test_systems!(
+        ExampleTest,
+        example_startup_system,
+        [example_system],
+        example_teardown_system
+    );
+
+    #[test]
+    fn test_systems_are_registered_once() {
+        assert_eq!(ExampleTest::STARTUP_SYSTEM, c"example_startup_system");
+        assert_eq!(
+            ExampleTest::TEARDOWN_SYSTEM,
+            Some(c"example_teardown_system")
+        );
+        register_test_systems::<ExampleTest>();
+        register_test_systems::<ExampleTest>();
+        assert_eq!(
+            registered_test_systems(),
+            vec![c"example_startup_system", c"example_system"]
+        );
+    }
+}
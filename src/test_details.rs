@@ -0,0 +1,284 @@
+//! What each material test demonstrates, shown under the material selection list for the
+//! underlined test, and the tags the list can be filtered by.
+//! [`MaterialTest::new`](crate::MaterialTest::new) copies a test's details in by name, so a test
+//! missing from [`TEST_DETAILS`] is listed without a description or tags.
+
+/// The longest description a [`MaterialTest`](crate::MaterialTest) stores, in bytes
+pub const DESCRIPTION_CAPACITY: usize = 256;
+/// The longest author a [`MaterialTest`](crate::MaterialTest) stores, in bytes
+pub const AUTHOR_CAPACITY: usize = 64;
+/// The longest comma separated tag list a [`MaterialTest`](crate::MaterialTest) stores, in bytes
+pub const TAGS_CAPACITY: usize = 64;
+/// Every tag a test can have, in the order the material selection view lists them
+pub const TEST_TAGS: &[&str] = &[
+    "color",
+    "distortion",
+    "texture",
+    "lighting",
+    "animation",
+    "compositing",
+    "text",
+    "perf",
+];
+
+#[derive(Debug, PartialEq)]
+pub struct TestDetails {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Empty when the test's author isn't recorded
+    pub author: &'static str,
+    /// Each one of [`TEST_TAGS`]
+    pub tags: &'static [&'static str],
+}
+
+const fn details(
+    name: &'static str,
+    description: &'static str,
+    tags: &'static [&'static str],
+) -> TestDetails {
+    TestDetails {
+        name,
+        description,
+        author: "",
+        tags,
+    }
+}
+
+pub const TEST_DETAILS: &[TestDetails] = &[
+    details(
+        "invert_y",
+        "Flips the whole frame upside down in a postprocess.",
+        &["distortion"],
+    ),
+    details(
+        "test_post",
+        "Tints the whole frame, the simplest postprocess that samples the scene.",
+        &["color"],
+    ),
+    details(
+        "warp",
+        "Waves the frame sideways with a time driven sine offset.",
+        &["distortion", "animation"],
+    ),
+    details(
+        "hud_exclusion",
+        "Keeps a HUD bar out of a warp postprocess by masking its screen rectangle.",
+        &["distortion", "compositing"],
+    ),
+    details(
+        "iris_wipe",
+        "A circular reveal driven by a radius uniform, also used by --transition=iris.",
+        &["animation", "compositing"],
+    ),
+    details(
+        "mirror_floor",
+        "Reflects the scene below a floor line, with fade and ripple uniforms.",
+        &["distortion"],
+    ),
+    details(
+        "post_stack",
+        "Runs several postprocesses at once to check the order they're applied in.",
+        &["compositing"],
+    ),
+    details(
+        "channel_inspector",
+        "Shows one channel of a texture at a time as grayscale.",
+        &["color", "texture"],
+    ),
+    details(
+        "color_replacement",
+        "Swaps one exact color of a sprite for another.",
+        &["color"],
+    ),
+    details(
+        "desat_sprite",
+        "Desaturates a sprite with a luminance dot product.",
+        &["color"],
+    ),
+    details(
+        "pan_sprite",
+        "Offsets a sprite's uvs with a sine of its height and tints it blue.",
+        &["color", "distortion"],
+    ),
+    details(
+        "scrolling_color",
+        "Scrolls a rainbow across a sprite at a uniform driven speed.",
+        &["color", "animation"],
+    ),
+    details(
+        "starfield",
+        "Layers background stars, a milky way and scrolling foreground stars from two textures.",
+        &["texture", "animation"],
+    ),
+    details(
+        "fire",
+        "Animates flames from a noise texture.",
+        &["texture", "animation"],
+    ),
+    details(
+        "lens_flare",
+        "Draws a lens flare over the whole screen that follows the mouse.",
+        &["lighting"],
+    ),
+    details(
+        "day_night",
+        "Cycles a sprite's tint, brightness and saturation through a day and night.",
+        &["color", "animation"],
+    ),
+    details(
+        "parallax",
+        "Tiles one texture at several scales and speeds for parallax layers.",
+        &["texture", "animation"],
+    ),
+    details(
+        "pixel_upscale",
+        "Compares nearest and smoothed upscaling of pixel art at several sizes.",
+        &["texture"],
+    ),
+    details(
+        "stress_test",
+        "Spawns sprites in batches to find where the frame rate drops.",
+        &["perf"],
+    ),
+    details(
+        "immediate_mode_test",
+        "Draws rectangles each frame with DrawRectangle events instead of entities.",
+        &["perf"],
+    ),
+    details(
+        "procedural_texture",
+        "Uploads pixels generated on the CPU as a dynamic texture every few frames.",
+        &["texture", "perf"],
+    ),
+    details(
+        "atlas",
+        "Selects cells of an atlas texture through uv offset and scale uniforms.",
+        &["texture"],
+    ),
+    details(
+        "mip_inspector",
+        "Draws a checker texture at shrinking scales with filtering, mip bias and mip tint controls.",
+        &["texture"],
+    ),
+    details(
+        "texture_formats",
+        "Loads PNGs in several encodings and shows each channel, flagging formats that fail.",
+        &["texture"],
+    ),
+    details(
+        "nine_slice",
+        "Stretches a bordered panel at several sizes while its corners keep their size.",
+        &["texture"],
+    ),
+    details(
+        "msdf_text",
+        "Renders glyphs from a distance field atlas next to TextRender at matching sizes.",
+        &["text"],
+    ),
+    details(
+        "normal_light",
+        "Lights a sprite from a normal map with a light that follows the mouse.",
+        &["lighting"],
+    ),
+    details(
+        "splat_blend",
+        "Blends three textures through a mask, binding four textures in one material.",
+        &["texture", "compositing"],
+    ),
+    details(
+        "particles",
+        "Draws hundreds of particles in one quad from burst seeds held in uniforms.",
+        &["animation", "perf"],
+    ),
+];
+
+pub fn test_details(name: &str) -> Option<&'static TestDetails> {
+    TEST_DETAILS
+        .iter()
+        .find(|test_details| test_details.name == name)
+}
+
+/// The tags any of `test_tags` has, each once in [`TEST_TAGS`] order
+pub fn available_tags<'a>(test_tags: impl IntoIterator<Item = &'a str>) -> Vec<&'static str> {
+    let test_tags = test_tags.into_iter().collect::<Vec<_>>();
+    TEST_TAGS
+        .iter()
+        .copied()
+        .filter(|tag| test_tags.contains(tag))
+        .collect()
+}
+
+/// The tag after `selected`, `None` for every test after the last one
+pub fn next_tag(available_tags: &[&'static str], selected: Option<&str>) -> Option<&'static str> {
+    let next_index = selected
+        .and_then(|selected| available_tags.iter().position(|tag| *tag == selected))
+        .map_or(0, |index| index + 1);
+    available_tags.get(next_index).copied()
+}
+
+/// The tags in a row with the selected one bracketed, `all_label` standing for no tag
+pub fn tag_row_text(all_label: &str, available_tags: &[&str], selected: Option<&str>) -> String {
+    let bracket = |label: &str, is_selected: bool| {
+        if is_selected {
+            format!("[{label}]")
+        } else {
+            label.to_string()
+        }
+    };
+    std::iter::once(bracket(all_label, selected.is_none()))
+        .chain(
+            available_tags
+                .iter()
+                .map(|tag| bracket(tag, selected == Some(*tag))),
+        )
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::test_details::{
+        AUTHOR_CAPACITY, DESCRIPTION_CAPACITY, TAGS_CAPACITY, TEST_DETAILS, TEST_TAGS,
+        available_tags, next_tag, tag_row_text, test_details,
+    };
+
+    #[test]
+    fn test_details_fit_and_are_unique() {
+        let mut names = HashSet::new();
+        for test_details in TEST_DETAILS {
+            assert!(names.insert(test_details.name), "{}", test_details.name);
+            // Room is left for the nul terminator
+            assert!(test_details.description.len() < DESCRIPTION_CAPACITY);
+            assert!(test_details.author.len() < AUTHOR_CAPACITY);
+            assert!(test_details.tags.join(",").len() < TAGS_CAPACITY);
+            assert!(
+                test_details.tags.iter().all(|tag| TEST_TAGS.contains(tag)),
+                "{}",
+                test_details.name
+            );
+        }
+        assert_eq!(
+            test_details("atlas").map(|test_details| test_details.name),
+            Some("atlas")
+        );
+        assert_eq!(test_details("missing"), None);
+    }
+
+    #[test]
+    fn tags_cycle_through_the_available_ones() {
+        let available_tags = available_tags(["perf", "color", "perf"]);
+        assert_eq!(available_tags, ["color", "perf"]);
+        assert_eq!(next_tag(&available_tags, None), Some("color"));
+        assert_eq!(next_tag(&available_tags, Some("color")), Some("perf"));
+        assert_eq!(next_tag(&available_tags, Some("perf")), None);
+        assert_eq!(next_tag(&available_tags, Some("text")), Some("color"));
+        assert_eq!(next_tag(&[], None), None);
+        assert_eq!(
+            tag_row_text("all", &available_tags, Some("perf")),
+            "all  color  [perf]"
+        );
+        assert_eq!(tag_row_text("all", &[], None), "[all]");
+    }
+}
@@ -0,0 +1,287 @@
+//! Persists how often and how recently each test was opened, along with the tests marked as
+//! favorites with F, so the material selection view can list favorites and recently opened tests
+//! ahead of the rest.
+
+use std::{
+    cmp::Reverse,
+    fs, io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use game_asset::resource_managers::material_manager::materials::MaterialType;
+
+use crate::last_test::{material_type_from_file_string, material_type_to_file_string};
+
+pub const TEST_USAGE_PATH: &str = "test_usage.txt";
+/// How many recently opened tests are listed, two rows of the selection grid
+pub const RECENT_TEST_COUNT: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SelectionGroup {
+    Favorites,
+    Recent,
+    /// Every test that isn't a favorite or recently opened
+    Other,
+}
+
+impl SelectionGroup {
+    /// The key of the group's label in the locale files
+    pub fn label_key(self) -> &'static str {
+        match self {
+            SelectionGroup::Favorites => "menu.favorites",
+            SelectionGroup::Recent => "menu.recent",
+            SelectionGroup::Other => "menu.other_tests",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestUsageEntry {
+    pub material_type: MaterialType,
+    pub name: String,
+    pub open_count: u32,
+    /// Seconds since the Unix epoch, 0 for a test that was favorited but never opened
+    pub last_opened: u64,
+    pub favorite: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TestUsage {
+    entries: Vec<TestUsageEntry>,
+}
+
+impl TestUsage {
+    fn entry(&self, material_type: MaterialType, name: &str) -> Option<&TestUsageEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.material_type == material_type && entry.name == name)
+    }
+
+    fn entry_mut(&mut self, material_type: MaterialType, name: &str) -> &mut TestUsageEntry {
+        let index = match self
+            .entries
+            .iter()
+            .position(|entry| entry.material_type == material_type && entry.name == name)
+        {
+            Some(index) => index,
+            None => {
+                self.entries.push(TestUsageEntry {
+                    material_type,
+                    name: name.to_string(),
+                    open_count: 0,
+                    last_opened: 0,
+                    favorite: false,
+                });
+                self.entries.len() - 1
+            }
+        };
+        &mut self.entries[index]
+    }
+
+    /// `now` is in seconds since the Unix epoch, see [`unix_seconds_now`]
+    pub fn record_open(&mut self, material_type: MaterialType, name: &str, now: u64) {
+        let entry = self.entry_mut(material_type, name);
+        entry.open_count = entry.open_count.saturating_add(1);
+        entry.last_opened = now;
+    }
+
+    /// Returns whether the test is a favorite afterwards
+    pub fn toggle_favorite(&mut self, material_type: MaterialType, name: &str) -> bool {
+        let entry = self.entry_mut(material_type, name);
+        entry.favorite = !entry.favorite;
+        entry.favorite
+    }
+
+    pub fn is_favorite(&self, material_type: MaterialType, name: &str) -> bool {
+        self.entry(material_type, name)
+            .is_some_and(|entry| entry.favorite)
+    }
+
+    /// `names` are one material type's tests in registration order, the result indexes into them.
+    /// Favorites come first, most opened first, then the [`RECENT_TEST_COUNT`] most recently
+    /// opened tests that aren't favorites, then every other test in registration order. Each
+    /// test is listed once, so the selection can still be found by test id.
+    pub fn grouped_order(
+        &self,
+        material_type: MaterialType,
+        names: &[&str],
+    ) -> Vec<(SelectionGroup, usize)> {
+        let entries = names
+            .iter()
+            .map(|name| self.entry(material_type, name))
+            .collect::<Vec<_>>();
+
+        let mut favorites = (0..names.len())
+            .filter(|index| entries[*index].is_some_and(|entry| entry.favorite))
+            .collect::<Vec<_>>();
+        favorites.sort_by_key(|index| Reverse(entries[*index].map_or(0, |entry| entry.open_count)));
+
+        let mut recent = (0..names.len())
+            .filter(|index| {
+                entries[*index].is_some_and(|entry| !entry.favorite && entry.last_opened > 0)
+            })
+            .collect::<Vec<_>>();
+        recent.sort_by_key(|index| Reverse(entries[*index].map_or(0, |entry| entry.last_opened)));
+        recent.truncate(RECENT_TEST_COUNT);
+
+        let other = (0..names.len())
+            .filter(|index| !favorites.contains(index) && !recent.contains(index))
+            .collect::<Vec<_>>();
+
+        favorites
+            .into_iter()
+            .map(|index| (SelectionGroup::Favorites, index))
+            .chain(
+                recent
+                    .into_iter()
+                    .map(|index| (SelectionGroup::Recent, index)),
+            )
+            .chain(
+                other
+                    .into_iter()
+                    .map(|index| (SelectionGroup::Other, index)),
+            )
+            .collect()
+    }
+
+    /// One tab separated line per test, the material type, name, open count, last opened time
+    /// and 1 for favorites or 0 otherwise
+    pub fn to_file_string(&self) -> String {
+        self.entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{}\t{}\t{}\t{}\t{}\n",
+                    material_type_to_file_string(entry.material_type),
+                    entry.name,
+                    entry.open_count,
+                    entry.last_opened,
+                    u8::from(entry.favorite)
+                )
+            })
+            .collect()
+    }
+
+    /// Malformed lines are skipped
+    pub fn from_file_string(file_string: &str) -> Self {
+        let entries = file_string
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.trim().split('\t');
+                let material_type = material_type_from_file_string(fields.next()?)?;
+                let name = fields.next().filter(|name| !name.is_empty())?;
+                let open_count = fields.next()?.parse().ok()?;
+                let last_opened = fields.next()?.parse().ok()?;
+                let favorite = match fields.next()? {
+                    "1" => true,
+                    "0" => false,
+                    _ => return None,
+                };
+                Some(TestUsageEntry {
+                    material_type,
+                    name: name.to_string(),
+                    open_count,
+                    last_opened,
+                    favorite,
+                })
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// A missing file just means no test has been opened yet
+    pub fn read<P: AsRef<Path>>(path: P) -> Self {
+        fs::read_to_string(path)
+            .map(|file_string| Self::from_file_string(&file_string))
+            .unwrap_or_default()
+    }
+
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_file_string())
+    }
+}
+
+pub fn unix_seconds_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod test {
+    use game_asset::resource_managers::material_manager::materials::MaterialType;
+
+    use crate::test_usage::{RECENT_TEST_COUNT, SelectionGroup, TestUsage};
+
+    #[test]
+    fn favorites_and_recent_tests_come_first_once() {
+        let names = [
+            "atlas",
+            "fire",
+            "starfield",
+            "nine_slice",
+            "particles",
+            "msdf_text",
+        ];
+        let mut test_usage = TestUsage::default();
+        test_usage.record_open(MaterialType::Sprite, "fire", 10);
+        test_usage.record_open(MaterialType::Sprite, "starfield", 20);
+        test_usage.record_open(MaterialType::Sprite, "starfield", 30);
+        test_usage.record_open(MaterialType::Sprite, "nine_slice", 25);
+        test_usage.record_open(MaterialType::PostProcessing, "atlas", 40);
+        assert!(test_usage.toggle_favorite(MaterialType::Sprite, "starfield"));
+        assert!(test_usage.toggle_favorite(MaterialType::Sprite, "msdf_text"));
+
+        assert_eq!(
+            test_usage.grouped_order(MaterialType::Sprite, &names),
+            vec![
+                (SelectionGroup::Favorites, 2),
+                (SelectionGroup::Favorites, 5),
+                (SelectionGroup::Recent, 3),
+                (SelectionGroup::Recent, 1),
+                (SelectionGroup::Other, 0),
+                (SelectionGroup::Other, 4),
+            ]
+        );
+
+        assert!(!test_usage.toggle_favorite(MaterialType::Sprite, "msdf_text"));
+        assert!(!test_usage.is_favorite(MaterialType::Sprite, "msdf_text"));
+        assert!(!test_usage.is_favorite(MaterialType::PostProcessing, "starfield"));
+    }
+
+    #[test]
+    fn recent_tests_are_limited() {
+        let names = ["a", "b", "c", "d", "e", "f"];
+        let mut test_usage = TestUsage::default();
+        for (index, name) in names.iter().enumerate() {
+            test_usage.record_open(MaterialType::Sprite, name, index as u64 + 1);
+        }
+        let grouped_order = test_usage.grouped_order(MaterialType::Sprite, &names);
+        assert_eq!(
+            grouped_order
+                .iter()
+                .filter(|(group, _)| *group == SelectionGroup::Recent)
+                .count(),
+            RECENT_TEST_COUNT
+        );
+        assert_eq!(grouped_order[0], (SelectionGroup::Recent, 5));
+        assert_eq!(grouped_order[RECENT_TEST_COUNT], (SelectionGroup::Other, 0));
+    }
+
+    #[test]
+    fn test_usage_file_string_round_trip() {
+        let mut test_usage = TestUsage::default();
+        test_usage.record_open(MaterialType::PostProcessing, "warp", 1_700_000_000);
+        test_usage.toggle_favorite(MaterialType::Sprite, "fire");
+        assert_eq!(
+            TestUsage::from_file_string(&test_usage.to_file_string()),
+            test_usage
+        );
+        assert_eq!(
+            TestUsage::from_file_string("sprite\tfire\t1\n mesh\twarp\t1\t2\t0\n"),
+            TestUsage::default()
+        );
+    }
+}
@@ -1,18 +1,55 @@
 //! Helpers mostly related to analyzing WGSL for tests.
 
-use std::{error::Error, fmt::Display};
+use std::fmt::Display;
 
 use naga::{
-    WithSpan,
-    front::wgsl::{ParseError, parse_str},
-    valid::{Capabilities, ValidationError, ValidationFlags, Validator},
+    AddressSpace, Block, Expression, Function, Handle, ShaderStage, Statement, TypeInner,
+    front::wgsl::parse_str,
+    valid::{Capabilities, ValidationFlags, Validator},
+};
+#[cfg(feature = "cross_compile")]
+use naga::{
+    back::{glsl, msl, spv},
+    proc::BoundsCheckPolicies,
 };
 use serde::{Deserialize, Serialize};
 
+use crate::shader_error::WgslError;
+
+/// The targets shaders can be validated for, from least to most capable
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Profile {
+    /// WebGL2 through GLSL ES 3.0, which supports none of naga's optional capabilities
+    WebGl2,
+    /// WebGPU and downlevel native adapters, which only add cube map arrays
+    Downlevel,
+    /// Everything naga can validate
+    Native,
+}
+
+impl Profile {
+    pub const ALL: [Profile; 3] = [Profile::WebGl2, Profile::Downlevel, Profile::Native];
+
+    pub fn capabilities(self) -> Capabilities {
+        match self {
+            Profile::WebGl2 => Capabilities::empty(),
+            Profile::Downlevel => Capabilities::CUBE_ARRAY_TEXTURES,
+            Profile::Native => Capabilities::all(),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct WgslValidator(Validator);
 
 impl WgslValidator {
+    pub fn with_profile(profile: Profile) -> Self {
+        Self(Validator::new(
+            ValidationFlags::all(),
+            profile.capabilities(),
+        ))
+    }
+
     pub fn emit_wgsl_metadata<S: AsRef<str>>(
         &mut self,
         shader_string: S,
@@ -36,7 +73,15 @@ impl WgslValidator {
                 .iter()
                 .fold(vec![], |mut accumulator, (_, global_variable)| {
                     if let Some(name) = &global_variable.name {
-                        accumulator.push(name.clone());
+                        accumulator.push(GlobalVariableMetaData {
+                            name: name.clone(),
+                            binding: global_variable
+                                .binding
+                                .as_ref()
+                                .map(|binding| (binding.group, binding.binding)),
+                            address_space: address_space_name(global_variable.space).to_string(),
+                            type_name: global_variable.ty.to_wgsl(&module.to_ctx()),
+                        });
                     }
                     accumulator
                 });
@@ -77,7 +122,16 @@ impl WgslValidator {
         let entry_points = module
             .entry_points
             .iter()
-            .map(|entry_point| entry_point.name.clone())
+            .map(|entry_point| EntryPointMetaData {
+                name: entry_point.name.clone(),
+                stage: match entry_point.stage {
+                    ShaderStage::Vertex => "vertex",
+                    ShaderStage::Fragment => "fragment",
+                    ShaderStage::Compute => "compute",
+                }
+                .to_string(),
+                workgroup_size: entry_point.workgroup_size,
+            })
             .collect();
 
         Ok(WgslMetaData {
@@ -91,6 +145,156 @@ impl WgslValidator {
         })
     }
 
+    /// Cross-checks the uniforms declared in a material TOML against the struct of the generated
+    /// shader holding them, the one with the most of their names. Empty when the names, sizes
+    /// and offsets all match.
+    pub fn check_uniform_layout<S: AsRef<str>>(
+        &mut self,
+        toml_string: &str,
+        shader_string: S,
+    ) -> Result<Vec<UniformLayoutMismatch>, WgslError> {
+        let shader_string = shader_string.as_ref();
+        let module = parse_str(shader_string)
+            .map_err(|error| WgslError::from_parse_error(&error, shader_string))?;
+        let declared_uniforms = declared_uniforms(toml_string);
+        if declared_uniforms.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let structs = module
+            .types
+            .iter()
+            .filter_map(|(_, wgsl_type)| match &wgsl_type.inner {
+                TypeInner::Struct { members, .. } => Some(
+                    members
+                        .iter()
+                        .filter_map(|member| {
+                            Some(StructMemberLayout {
+                                name: member.name.clone()?,
+                                offset: member.offset,
+                                size: module.types[member.ty].inner.size(module.to_ctx()),
+                            })
+                        })
+                        .collect::<Vec<_>>(),
+                ),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        // A struct missing some uniforms still beats none, so the missing ones get reported
+        let members = structs
+            .iter()
+            .max_by_key(|members| {
+                declared_uniforms
+                    .iter()
+                    .filter(|uniform| members.iter().any(|member| member.name == uniform.name))
+                    .count()
+            })
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        Ok(uniform_layout_mismatches(&declared_uniforms, members))
+    }
+
+    /// Reports the named globals, functions and constants no entry point reaches, directly or
+    /// through the functions it calls. Constants only used to initialize other constants count
+    /// as unused, naga folds them away.
+    pub fn lint<S: AsRef<str>>(&mut self, shader_string: S) -> Result<WgslLint, WgslError> {
+        let shader_string = shader_string.as_ref();
+        let module = parse_str(shader_string)
+            .map_err(|error| WgslError::from_parse_error(&error, shader_string))?;
+
+        let mut used_globals = vec![];
+        let mut used_constants = vec![];
+        let mut used_functions = vec![];
+        let mut functions_to_visit = vec![];
+        let mut visit = |function: &Function, functions_to_visit: &mut Vec<Handle<Function>>| {
+            for (_, expression) in function.expressions.iter() {
+                match expression {
+                    Expression::GlobalVariable(handle) => used_globals.push(*handle),
+                    Expression::Constant(handle) => used_constants.push(*handle),
+                    _ => {}
+                }
+            }
+            called_functions(&function.body, functions_to_visit);
+        };
+        for entry_point in &module.entry_points {
+            visit(&entry_point.function, &mut functions_to_visit);
+        }
+        while let Some(handle) = functions_to_visit.pop() {
+            if !used_functions.contains(&handle) {
+                used_functions.push(handle);
+                visit(&module.functions[handle], &mut functions_to_visit);
+            }
+        }
+
+        Ok(WgslLint {
+            unused_globals: module
+                .global_variables
+                .iter()
+                .filter(|(handle, _)| !used_globals.contains(handle))
+                .filter_map(|(_, global_variable)| global_variable.name.clone())
+                .collect(),
+            unused_functions: module
+                .functions
+                .iter()
+                .filter(|(handle, _)| !used_functions.contains(handle))
+                .filter_map(|(_, function)| function.name.clone())
+                .collect(),
+            unused_constants: module
+                .constants
+                .iter()
+                .filter(|(handle, _)| !used_constants.contains(handle))
+                .filter_map(|(_, constant)| constant.name.clone())
+                .collect(),
+        })
+    }
+
+    /// Emits the validated module with naga's SPIR-V, GLSL and MSL backends, catching WGSL that
+    /// validates but a target can't compile. GLSL is written once per entry point.
+    #[cfg(feature = "cross_compile")]
+    pub fn cross_compile<S: AsRef<str>>(&mut self, shader_string: S) -> Result<(), WgslError> {
+        let shader_string = shader_string.as_ref();
+        let module = parse_str(shader_string)
+            .map_err(|error| WgslError::from_parse_error(&error, shader_string))?;
+        let info = self.0.validate(&module).map_err(|error| {
+            let message = error.emit_to_string(shader_string);
+            WgslError::ValidationErr {
+                source: shader_string.to_string(),
+                error,
+                message,
+            }
+        })?;
+        let backend_error =
+            |backend: &'static str, message: String| WgslError::BackendErr { backend, message };
+
+        spv::write_vec(&module, &info, &spv::Options::default(), None)
+            .map_err(|error| backend_error("SPIR-V", error.to_string()))?;
+        for entry_point in &module.entry_points {
+            let mut glsl_string = String::new();
+            glsl::Writer::new(
+                &mut glsl_string,
+                &module,
+                &info,
+                &glsl::Options::default(),
+                &glsl::PipelineOptions {
+                    shader_stage: entry_point.stage,
+                    entry_point: entry_point.name.clone(),
+                    multiview: None,
+                },
+                BoundsCheckPolicies::default(),
+            )
+            .and_then(|mut writer| writer.write())
+            .map_err(|error| backend_error("GLSL", format!("{}: {error}", entry_point.name)))?;
+        }
+        msl::write_string(
+            &module,
+            &info,
+            &msl::Options::default(),
+            &msl::PipelineOptions::default(),
+        )
+        .map_err(|error| backend_error("MSL", error.to_string()))?;
+        Ok(())
+    }
+
     pub fn validate_wgsl_string<S: AsRef<str>>(
         &mut self,
         shader_string: S,
@@ -114,7 +318,273 @@ impl WgslValidator {
 
 impl Default for WgslValidator {
     fn default() -> Self {
-        Self(Validator::new(ValidationFlags::all(), Capabilities::all()))
+        Self::with_profile(Profile::Native)
+    }
+}
+
+/// Pushes every function called in `block`, including nested blocks
+fn called_functions(block: &Block, functions: &mut Vec<Handle<Function>>) {
+    for statement in block.iter() {
+        match statement {
+            Statement::Call { function, .. } => functions.push(*function),
+            Statement::Block(block) => called_functions(block, functions),
+            Statement::If { accept, reject, .. } => {
+                called_functions(accept, functions);
+                called_functions(reject, functions);
+            }
+            Statement::Switch { cases, .. } => {
+                for case in cases {
+                    called_functions(&case.body, functions);
+                }
+            }
+            Statement::Loop {
+                body, continuing, ..
+            } => {
+                called_functions(body, functions);
+                called_functions(continuing, functions);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The WGSL keyword of an address space, e.g. `uniform`, textures and samplers are `handle`
+fn address_space_name(address_space: AddressSpace) -> &'static str {
+    match address_space {
+        AddressSpace::Function => "function",
+        AddressSpace::Private => "private",
+        AddressSpace::WorkGroup => "workgroup",
+        AddressSpace::Uniform => "uniform",
+        AddressSpace::Storage { .. } => "storage",
+        AddressSpace::Handle => "handle",
+        AddressSpace::PushConstant => "push_constant",
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GlobalVariableMetaData {
+    pub name: String,
+    /// The `@group` and `@binding` indices, `None` for globals that aren't resources
+    pub binding: Option<(u32, u32)>,
+    pub address_space: String,
+    /// As written in WGSL, e.g. `texture_2d<f32>`
+    pub type_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EntryPointMetaData {
+    pub name: String,
+    /// `vertex`, `fragment` or `compute`
+    pub stage: String,
+    /// All zeros for anything but compute entry points
+    pub workgroup_size: [u32; 3],
+}
+
+/// A uniform from the `[uniform_types]` table of a material TOML
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeclaredUniform {
+    pub name: String,
+    /// `f32`, `vec4f` or `array<vec4f, N>`
+    pub type_name: String,
+}
+
+impl DeclaredUniform {
+    /// Size and alignment in bytes with std140 style padding, vectors and arrays are 16 byte
+    /// aligned. `None` for types materials can't declare.
+    pub fn size_and_alignment(&self) -> Option<(u32, u32)> {
+        match self.type_name.as_str() {
+            "f32" => Some((4, 4)),
+            "vec4f" => Some((16, 16)),
+            type_name => {
+                let count = type_name
+                    .strip_prefix("array<")?
+                    .strip_suffix('>')?
+                    .split_once(',')
+                    .filter(|(element, _)| element.trim() == "vec4f")?
+                    .1
+                    .trim()
+                    .parse::<u32>()
+                    .ok()?;
+                Some((16 * count, 16))
+            }
+        }
+    }
+}
+
+/// The uniforms of a material TOML in declaration order, both `name = "f32"` and
+/// `name = { type = "f32", default = 1.0 }` declarations. Lines that aren't declarations are
+/// skipped, the shader generation reports those.
+pub fn declared_uniforms(toml_string: &str) -> Vec<DeclaredUniform> {
+    toml_string
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| *line != "[uniform_types]")
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .filter_map(|line| {
+            let (name, value) = line.split_once('=')?;
+            let value = value.trim();
+            let type_name = match value.strip_prefix('{') {
+                Some(table) => {
+                    table
+                        .split_once("type")?
+                        .1
+                        .trim_start()
+                        .strip_prefix('=')?
+                        .trim_start()
+                        .strip_prefix('"')?
+                        .split_once('"')?
+                        .0
+                }
+                None => value.strip_prefix('"')?.strip_suffix('"')?,
+            };
+            Some(DeclaredUniform {
+                name: name.trim().to_string(),
+                type_name: type_name.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StructMemberLayout {
+    pub name: String,
+    /// In bytes from the start of the struct
+    pub offset: u32,
+    pub size: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum UniformLayoutMismatch {
+    /// Declared in the TOML but not a member of the struct
+    Missing {
+        name: String,
+    },
+    UnknownType {
+        name: String,
+        type_name: String,
+    },
+    Size {
+        name: String,
+        declared: u32,
+        wgsl: u32,
+    },
+    /// The member isn't where std140 style packing of the uniforms before it puts it, e.g. an
+    /// undeclared member sits between them or the padding differs
+    Offset {
+        name: String,
+        expected: u32,
+        wgsl: u32,
+    },
+}
+
+impl Display for UniformLayoutMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UniformLayoutMismatch::Missing { name } => {
+                write!(f, "Uniform {name} is not in the WGSL struct")
+            }
+            UniformLayoutMismatch::UnknownType { name, type_name } => {
+                write!(f, "Uniform {name} has unknown type {type_name}")
+            }
+            UniformLayoutMismatch::Size {
+                name,
+                declared,
+                wgsl,
+            } => write!(
+                f,
+                "Uniform {name} is declared with {declared} bytes but has {wgsl} in WGSL"
+            ),
+            UniformLayoutMismatch::Offset {
+                name,
+                expected,
+                wgsl,
+            } => write!(
+                f,
+                "Uniform {name} is expected at byte {expected} but is at {wgsl} in WGSL"
+            ),
+        }
+    }
+}
+
+/// Compares the declared uniforms to the struct members. The engine arranges the uniforms in
+/// its own order, so they are expected packed back to back in the order the struct lists them,
+/// starting wherever the first one is.
+pub fn uniform_layout_mismatches(
+    declared_uniforms: &[DeclaredUniform],
+    members: &[StructMemberLayout],
+) -> Vec<UniformLayoutMismatch> {
+    let mut mismatches = declared_uniforms
+        .iter()
+        .filter(|uniform| !members.iter().any(|member| member.name == uniform.name))
+        .map(|uniform| UniformLayoutMismatch::Missing {
+            name: uniform.name.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    let mut expected_offset = None;
+    for member in members {
+        let Some(uniform) = declared_uniforms
+            .iter()
+            .find(|uniform| uniform.name == member.name)
+        else {
+            continue;
+        };
+        let Some((size, alignment)) = uniform.size_and_alignment() else {
+            mismatches.push(UniformLayoutMismatch::UnknownType {
+                name: uniform.name.clone(),
+                type_name: uniform.type_name.clone(),
+            });
+            // Without a size there is nothing to expect the next uniform's offset from
+            expected_offset = None;
+            continue;
+        };
+        if member.size != size {
+            mismatches.push(UniformLayoutMismatch::Size {
+                name: uniform.name.clone(),
+                declared: size,
+                wgsl: member.size,
+            });
+        }
+        let expected = expected_offset
+            .unwrap_or(member.offset)
+            .next_multiple_of(alignment);
+        if member.offset != expected {
+            mismatches.push(UniformLayoutMismatch::Offset {
+                name: uniform.name.clone(),
+                expected,
+                wgsl: member.offset,
+            });
+        }
+        expected_offset = Some(member.offset + size);
+    }
+    mismatches
+}
+
+/// Named items of a shader that no entry point uses, see [`WgslValidator::lint`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WgslLint {
+    pub unused_globals: Vec<String>,
+    pub unused_functions: Vec<String>,
+    pub unused_constants: Vec<String>,
+}
+
+impl WgslLint {
+    pub fn is_empty(&self) -> bool {
+        self.unused_globals.is_empty()
+            && self.unused_functions.is_empty()
+            && self.unused_constants.is_empty()
+    }
+
+    /// Keeps only the items unused in `other` as well, e.g. to find what a shader template
+    /// declares that none of its materials use
+    pub fn retain_common(&mut self, other: &WgslLint) {
+        self.unused_globals
+            .retain(|name| other.unused_globals.contains(name));
+        self.unused_functions
+            .retain(|name| other.unused_functions.contains(name));
+        self.unused_constants
+            .retain(|name| other.unused_constants.contains(name));
     }
 }
 
@@ -124,9 +594,9 @@ pub struct WgslMetaData {
     special_types: Vec<String>,
     constants: Vec<String>,
     overrides: Vec<String>,
-    global_variables: Vec<String>,
+    global_variables: Vec<GlobalVariableMetaData>,
     functions: Vec<String>,
-    entry_points: Vec<String>,
+    entry_points: Vec<EntryPointMetaData>,
 }
 
 impl WgslMetaData {
@@ -143,84 +613,43 @@ impl WgslMetaData {
         self.overrides.iter().map(|value| value.as_str())
     }
     pub fn global_variables_iter(&self) -> impl Iterator<Item = &'_ str> {
-        self.global_variables.iter().map(|value| value.as_str())
+        self.global_variables
+            .iter()
+            .map(|value| value.name.as_str())
+    }
+    pub fn global_variable_meta_data_iter(
+        &self,
+    ) -> impl Iterator<Item = &'_ GlobalVariableMetaData> {
+        self.global_variables.iter()
+    }
+    pub fn global_variable(&self, name: &str) -> Option<&GlobalVariableMetaData> {
+        self.global_variables
+            .iter()
+            .find(|value| value.name == name)
+    }
+    /// Every `(group, binding)` pair used by more than one global variable
+    pub fn colliding_bindings(&self) -> Vec<(u32, u32)> {
+        let mut bindings = self
+            .global_variables
+            .iter()
+            .filter_map(|value| value.binding)
+            .collect::<Vec<_>>();
+        bindings.sort_unstable();
+        let mut colliding_bindings = bindings
+            .windows(2)
+            .filter(|pair| pair[0] == pair[1])
+            .map(|pair| pair[0])
+            .collect::<Vec<_>>();
+        colliding_bindings.dedup();
+        colliding_bindings
     }
     pub fn functions_iter(&self) -> impl Iterator<Item = &'_ str> {
         self.functions.iter().map(|value| value.as_str())
     }
     pub fn entry_points_iter(&self) -> impl Iterator<Item = &'_ str> {
-        self.entry_points.iter().map(|value| value.as_str())
-    }
-}
-
-#[derive(Debug)]
-pub enum WgslError {
-    ValidationErr {
-        source: String,
-        error: WithSpan<ValidationError>,
-        message: String,
-    },
-    ParserErr {
-        message: String,
-        line: Option<usize>,
-        position: Option<usize>,
-    },
-}
-
-impl WgslError {
-    pub fn from_parse_error(error: &ParseError, source: &str) -> Self {
-        let message = error.emit_to_string(source);
-        if let Some(location) = error.location(source) {
-            Self::ParserErr {
-                message,
-                line: Some(location.line_number as usize),
-                position: Some(location.line_position as usize),
-            }
-        } else {
-            Self::ParserErr {
-                message,
-                line: None,
-                position: None,
-            }
-        }
+        self.entry_points.iter().map(|value| value.name.as_str())
     }
-}
-
-impl Display for WgslError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self {
-            WgslError::ValidationErr {
-                source,
-                error,
-                message,
-            } => {
-                write!(
-                    f,
-                    "Error validating WGSL. Error: {}, message: {}",
-                    error.emit_to_string(source),
-                    message
-                )
-            }
-            WgslError::ParserErr {
-                message,
-                line,
-                position,
-            } => {
-                let line_string = match line {
-                    Some(line) => line.to_string(),
-                    None => "not found".to_string(),
-                };
-                let position_string = match position {
-                    Some(position) => position.to_string(),
-                    None => "not found".to_string(),
-                };
-                write!(
-                    f,
-                    "Error parsing WGSL on ln {line_string} pos {position_string} : {message}"
-                )
-            }
-        }
+    pub fn entry_point_meta_data_iter(&self) -> impl Iterator<Item = &'_ EntryPointMetaData> {
+        self.entry_points.iter()
     }
 }
-
-impl Error for WgslError {}
@@ -1,10 +1,11 @@
 //! Helpers for generating entities with the proper components to create text.
 
-use std::{ffi::CStr, str::from_utf8};
+use std::{ffi::CStr, panic::Location, str::from_utf8, sync::Mutex};
 
 use game_asset::resource_managers::material_manager::materials::MaterialType;
+use log::warn;
 use void_public::{
-    Component, ComponentBuilder, Transform, Vec3, bundle_for_builder,
+    Aspect, Component, ComponentBuilder, Transform, Vec3, bundle_for_builder,
     colors::{Color, palette},
     graphics::TextRender,
     linalg::{Vec2, Vec4},
@@ -12,15 +13,25 @@ use void_public::{
 };
 
 use crate::{
-    CustomText, HeaderText, RegularText,
-    local_error::{LocalError, Result},
+    CustomText, HeaderText, PercentPosition, RegularText, RelativeFontSize,
+    local_error::{Result, TestModuleError},
+    localization::tr,
+    math::ZeroToHundredPercent,
 };
 
-pub const fn title_from_material_type(material_type: &MaterialType) -> &str {
-    match material_type {
-        MaterialType::Sprite => "Sprite Material",
-        MaterialType::PostProcessing => "Post Processing Material",
-    }
+/// Roughly the fixed [`TextTypes::Header`] size on a 1080p screen
+pub const HEADER_FONT_HEIGHT_PERCENT: ZeroToHundredPercent = ZeroToHundredPercent::new(0.12);
+/// Roughly the fixed [`TextTypes::Regular`] size on a 1080p screen
+pub const REGULAR_FONT_HEIGHT_PERCENT: ZeroToHundredPercent = ZeroToHundredPercent::new(0.06);
+/// The approximate height of a line of text as a multiple of the font size. The renderer spaces
+/// lines itself, this is only used to size bounds and to stack text.
+pub const LINE_HEIGHT_EM: f32 = 1.2;
+
+pub fn title_from_material_type(material_type: &MaterialType) -> &'static str {
+    tr(match material_type {
+        MaterialType::Sprite => "menu.sprite_material",
+        MaterialType::PostProcessing => "menu.post_processing_material",
+    })
 }
 
 #[derive(Debug)]
@@ -28,14 +39,28 @@ pub enum TextTypes {
     Header,
     Regular,
     Custom(f32),
+    /// A header sized as a percentage of the screen height, resized along with the window
+    HeaderRelative(ZeroToHundredPercent),
+    /// Regular text sized as a percentage of the screen height, resized along with the window
+    RegularRelative(ZeroToHundredPercent),
 }
 
 impl TextTypes {
-    pub const fn font_size(&self) -> f32 {
+    pub fn font_size(&self, screen_height: f32) -> f32 {
         match &self {
             TextTypes::Header => 128.,
             TextTypes::Regular => 64.,
             TextTypes::Custom(font_size) => *font_size,
+            TextTypes::HeaderRelative(percent_of_height)
+            | TextTypes::RegularRelative(percent_of_height) => **percent_of_height * screen_height,
+        }
+    }
+
+    pub const fn percent_of_height(&self) -> Option<ZeroToHundredPercent> {
+        match &self {
+            TextTypes::HeaderRelative(percent_of_height)
+            | TextTypes::RegularRelative(percent_of_height) => Some(*percent_of_height),
+            TextTypes::Header | TextTypes::Regular | TextTypes::Custom(_) => None,
         }
     }
 }
@@ -51,31 +76,118 @@ pub fn cstr_to_u8_array<const N: usize>(cstr: &CStr) -> [u8; N] {
     output_array
 }
 
-pub fn str_to_u8_array<const N: usize>(str: &str) -> [u8; N] {
+/// Strings longer than `N` bytes are cut at the last character that fits, so the array is always
+/// valid UTF-8. The returned bool is true when the string was truncated.
+pub fn str_to_u8_array<const N: usize>(str: &str) -> ([u8; N], bool) {
     let mut output_array = [0; N];
-    str.as_bytes()
-        .iter()
-        .take(N)
-        .enumerate()
-        .for_each(|(index, byte)| output_array[index] = *byte);
+    let mut length = str.len().min(N);
+    while !str.is_char_boundary(length) {
+        length -= 1;
+    }
+    output_array[..length].copy_from_slice(&str.as_bytes()[..length]);
+    (output_array, length < str.len())
+}
+
+/// The call sites of [`text_to_u8_array`] that have already logged a truncation
+static TRUNCATING_CALLERS: Mutex<Vec<&'static Location<'static>>> = Mutex::new(Vec::new());
+
+/// [`str_to_u8_array`] for text that is only displayed, where showing the start of a string that
+/// doesn't fit is better than showing nothing. Most callers update their text every frame, so
+/// truncation is only logged the first time for each call site.
+#[track_caller]
+pub fn text_to_u8_array<const N: usize>(text: &str) -> [u8; N] {
+    let (output_array, truncated) = str_to_u8_array(text);
+    if truncated && first_truncation_at(Location::caller()) {
+        warn!(
+            "Text at {} does not fit in {N} bytes and was truncated: {text}",
+            Location::caller()
+        );
+    }
     output_array
 }
 
+fn first_truncation_at(caller: &'static Location<'static>) -> bool {
+    let mut truncating_callers = TRUNCATING_CALLERS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if truncating_callers.contains(&caller) {
+        return false;
+    }
+    truncating_callers.push(caller);
+    true
+}
+
 pub fn u8_array_to_str(u8_slice: &[u8]) -> Result<&str> {
-    from_utf8(u8_slice)
-        .map(|str| str.trim_matches('\0'))
-        .map_err(|err| err.into())
+    Ok(from_utf8(u8_slice)?.trim_matches('\0'))
 }
 
 pub fn u8_array_to_cstr(u8_slice: &[u8]) -> Result<&CStr> {
-    let nul_position = u8_slice.iter().position(|b| *b == 0).ok_or::<LocalError>(
-        "Could not find nul terminator on CStr represented as a u8 array".into(),
-    )?;
+    let nul_position = u8_slice
+        .iter()
+        .position(|b| *b == 0)
+        .ok_or(TestModuleError::MissingNulTerminator)?;
     let cstr_slice = &u8_slice[..=nul_position];
 
     unsafe { Ok(CStr::from_bytes_with_nul_unchecked(cstr_slice)) }
 }
 
+/// The approximate advance of `character` as a fraction of the font size. The engine doesn't
+/// expose font metrics, so text is measured with these.
+fn glyph_width_em(character: char) -> f32 {
+    match character {
+        ' ' | '.' | ',' | ':' | ';' | '\'' | '!' | '|' | 'i' | 'j' | 'l' | 'I' => 0.3,
+        'm' | 'w' | 'M' | 'W' => 0.85,
+        // CJK, Hangul and fullwidth forms
+        '\u{2E80}'..='\u{9FFF}' | '\u{AC00}'..='\u{D7AF}' | '\u{FF00}'..='\u{FFEF}' => 1.,
+        character if character.is_uppercase() || character.is_ascii_digit() => 0.65,
+        _ => 0.55,
+    }
+}
+
+/// The approximate rendered width of a single line of `text`
+pub fn measure_text(text: &str, font_size: f32) -> f32 {
+    text.chars().map(glyph_width_em).sum::<f32>() * font_size
+}
+
+/// Breaks `text` into lines no wider than `max_width` at spaces, words wider than `max_width` on
+/// their own are split. Existing newlines are kept.
+pub fn wrap_text(text: &str, max_width: f32, font_size: f32) -> Vec<String> {
+    let mut lines = vec![];
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            for word_chunk in split_word_to_width(word, max_width, font_size) {
+                if line.is_empty() {
+                    line = word_chunk;
+                } else if measure_text(&format!("{line} {word_chunk}"), font_size) > max_width {
+                    lines.push(std::mem::replace(&mut line, word_chunk));
+                } else {
+                    line.push(' ');
+                    line.push_str(&word_chunk);
+                }
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+fn split_word_to_width(word: &str, max_width: f32, font_size: f32) -> Vec<String> {
+    let mut word_chunks = vec![];
+    let mut word_chunk = String::new();
+    for character in word.chars() {
+        if !word_chunk.is_empty()
+            && measure_text(&word_chunk, font_size) + glyph_width_em(character) * font_size
+                > max_width
+        {
+            word_chunks.push(std::mem::take(&mut word_chunk));
+        }
+        word_chunk.push(character);
+    }
+    word_chunks.push(word_chunk);
+    word_chunks
+}
+
 #[derive(Debug)]
 pub struct CreateTextInput<S: AsRef<str>> {
     pub text: S,
@@ -85,6 +197,8 @@ pub struct CreateTextInput<S: AsRef<str>> {
     pub position: Vec3,
     pub color: Vec4,
     pub text_type: TextTypes,
+    /// Wraps the text onto several lines no wider than this, and sizes `bounds_size` to fit them
+    pub max_width: Option<f32>,
 }
 
 impl<S: AsRef<str> + Default> Default for CreateTextInput<S> {
@@ -97,12 +211,15 @@ impl<S: AsRef<str> + Default> Default for CreateTextInput<S> {
             position: Vec3::new(0., 0., 0.),
             color: *palette::WHITE,
             text_type: TextTypes::Regular,
+            max_width: None,
         }
     }
 }
 
+#[track_caller]
 pub fn create_new_text<S: AsRef<str>, TextType: Component>(
     create_text_input: CreateTextInput<S>,
+    aspect: &Aspect,
 ) -> ComponentBuilder {
     let CreateTextInput {
         text,
@@ -112,13 +229,29 @@ pub fn create_new_text<S: AsRef<str>, TextType: Component>(
         position,
         color,
         text_type,
+        max_width,
     } = create_text_input;
-    let text = str_to_u8_array(text.as_ref());
+    let font_size = text_type.font_size(aspect.height);
+    let (text, bounds_size) = match max_width {
+        Some(max_width) => {
+            let lines = wrap_text(text.as_ref(), max_width, font_size);
+            let widest_line = lines
+                .iter()
+                .map(|line| measure_text(line, font_size))
+                .fold(0., f32::max);
+            let bounds_size = void_public::Vec2::new(
+                widest_line.min(max_width),
+                lines.len() as f32 * font_size * LINE_HEIGHT_EM,
+            );
+            (text_to_u8_array(&lines.join("\n")), bounds_size.into())
+        }
+        None => (text_to_u8_array(text.as_ref()), bounds_size),
+    };
     let text_render = TextRender {
         text,
         visible,
         bounds_size,
-        font_size: text_type.font_size(),
+        font_size,
         alignment,
     };
     let transform = Transform {
@@ -127,10 +260,19 @@ pub fn create_new_text<S: AsRef<str>, TextType: Component>(
     };
     let color = Color::from(color);
     let mut component_builder: ComponentBuilder =
-        bundle_for_builder!(text_render, transform, color).into();
+        bundle_for_builder!(text_render, transform, color, PercentPosition::default()).into();
+    if let Some(percent_of_height) = text_type.percent_of_height() {
+        component_builder.add_component(RelativeFontSize {
+            percent_of_height: *percent_of_height,
+        });
+    }
     match text_type {
-        TextTypes::Header => component_builder.add_component(HeaderText),
-        TextTypes::Regular => component_builder.add_component(RegularText),
+        TextTypes::Header | TextTypes::HeaderRelative(_) => {
+            component_builder.add_component(HeaderText)
+        }
+        TextTypes::Regular | TextTypes::RegularRelative(_) => {
+            component_builder.add_component(RegularText)
+        }
         TextTypes::Custom(_) => component_builder.add_component(CustomText),
     }
     component_builder
@@ -138,14 +280,72 @@ pub fn create_new_text<S: AsRef<str>, TextType: Component>(
 
 #[cfg(test)]
 mod test {
-    use crate::text::{str_to_u8_array, u8_array_to_str};
+    use std::panic::Location;
+
+    use crate::{
+        local_error::TestModuleError,
+        text::{
+            first_truncation_at, measure_text, str_to_u8_array, u8_array_to_cstr, u8_array_to_str,
+            wrap_text,
+        },
+    };
 
     #[test]
     fn u8_array_isnt_padded_when_converted_back_to_str() {
         let test_str = "hello";
         assert_eq!(test_str, "hello");
 
-        let test_u8_array = str_to_u8_array::<256>(test_str);
+        let (test_u8_array, _) = str_to_u8_array::<256>(test_str);
         assert_eq!(u8_array_to_str(&test_u8_array).unwrap(), test_str);
     }
+
+    #[test]
+    fn u8_array_converts_to_cstr_and_reports_malformed_arrays() {
+        let (test_u8_array, truncated) = str_to_u8_array::<256>("hello");
+        assert!(!truncated);
+        assert_eq!(u8_array_to_cstr(&test_u8_array).unwrap(), c"hello");
+        assert!(matches!(
+            u8_array_to_cstr(b"hello"),
+            Err(TestModuleError::MissingNulTerminator)
+        ));
+        assert!(matches!(
+            u8_array_to_str(&[0xFF, 0]),
+            Err(TestModuleError::Utf8(_))
+        ));
+    }
+
+    #[test]
+    fn u8_array_truncation_keeps_utf8_characters_whole() {
+        // "é" is 2 bytes and "€" is 3, so only "aé" fits in 4 bytes
+        let (test_u8_array, truncated) = str_to_u8_array::<4>("aé€");
+        assert!(truncated);
+        assert_eq!(test_u8_array, [b'a', 0xC3, 0xA9, 0]);
+        assert_eq!(u8_array_to_str(&test_u8_array).unwrap(), "aé");
+    }
+
+    #[test]
+    fn truncation_is_logged_once_per_call_site() {
+        let caller = Location::caller();
+        assert!(first_truncation_at(caller));
+        assert!(!first_truncation_at(caller));
+        assert!(first_truncation_at(Location::caller()));
+    }
+
+    #[test]
+    fn long_text_wraps_at_spaces() {
+        // 11 px per lowercase letter and 6 px per space at a font size of 20
+        assert_eq!(
+            wrap_text("aaa aaa aaaaaaaaa", 80., 20.),
+            vec!["aaa aaa", "aaaaaaa", "aa"]
+        );
+        assert_eq!(wrap_text("ab\nc", 80., 20.), vec!["ab", "c"]);
+        assert_eq!(wrap_text("", 80., 20.), vec![""]);
+    }
+
+    #[test]
+    fn narrow_and_wide_glyphs_are_measured_differently() {
+        assert!((measure_text("Wil", 20.) - 29.).abs() < 0.001);
+        assert!(measure_text("iiii", 20.) < measure_text("mmmm", 20.));
+        assert_eq!(measure_text("", 20.), 0.);
+    }
 }
@@ -0,0 +1,149 @@
+//! The edit state of an on screen, single line text field, with a cursor, backspace and character
+//! entry. The system owning a field feeds it key presses and draws it as a `TextRender` with a
+//! blinking underline under the cursor.
+
+/// How long the cursor stays visible, and then hidden, while blinking
+pub const CURSOR_BLINK_SECONDS: f32 = 0.5;
+
+/// `cursor` is a character index, the cursor sits before the character at that index
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextInput {
+    text: String,
+    cursor: usize,
+    blink_elapsed: f32,
+}
+
+impl TextInput {
+    /// The cursor starts at the end of `text`
+    pub fn new(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            cursor: text.chars().count(),
+            blink_elapsed: 0.,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// The text before the cursor, for positioning the cursor underline
+    pub fn text_before_cursor(&self) -> &str {
+        &self.text[..self.byte_index(self.cursor)]
+    }
+
+    pub fn insert(&mut self, character: char) {
+        let byte_index = self.byte_index(self.cursor);
+        self.text.insert(byte_index, character);
+        self.cursor += 1;
+        self.reset_blink();
+    }
+
+    /// Removes the character before the cursor
+    pub fn backspace(&mut self) {
+        if self.cursor == 0 {
+            return;
+        }
+        self.cursor -= 1;
+        let byte_index = self.byte_index(self.cursor);
+        self.text.remove(byte_index);
+        self.reset_blink();
+    }
+
+    /// Removes the character after the cursor
+    pub fn delete(&mut self) {
+        if self.cursor == self.text.chars().count() {
+            return;
+        }
+        let byte_index = self.byte_index(self.cursor);
+        self.text.remove(byte_index);
+        self.reset_blink();
+    }
+
+    pub fn move_cursor_left(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+        self.reset_blink();
+    }
+
+    pub fn move_cursor_right(&mut self) {
+        self.cursor = (self.cursor + 1).min(self.text.chars().count());
+        self.reset_blink();
+    }
+
+    pub fn advance_blink(&mut self, delta_time: f32) {
+        self.blink_elapsed = (self.blink_elapsed + delta_time) % (CURSOR_BLINK_SECONDS * 2.);
+    }
+
+    pub fn is_cursor_visible(&self) -> bool {
+        self.blink_elapsed < CURSOR_BLINK_SECONDS
+    }
+
+    pub fn parse_f32(&self) -> Option<f32> {
+        self.text
+            .trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|value| value.is_finite())
+    }
+
+    /// Editing keeps the cursor visible, so it doesn't blink out from under the typing
+    fn reset_blink(&mut self) {
+        self.blink_elapsed = 0.;
+    }
+
+    fn byte_index(&self, character_index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(character_index)
+            .map_or(self.text.len(), |(byte_index, _)| byte_index)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::text_input::{CURSOR_BLINK_SECONDS, TextInput};
+
+    #[test]
+    fn editing_around_the_cursor() {
+        let mut text_input = TextInput::new("0.5");
+        assert_eq!(text_input.cursor(), 3);
+        text_input.backspace();
+        text_input.insert('7');
+        text_input.insert('5');
+        assert_eq!(text_input.text(), "0.75");
+        assert_eq!(text_input.parse_f32(), Some(0.75));
+
+        text_input.move_cursor_left();
+        text_input.move_cursor_left();
+        text_input.move_cursor_left();
+        text_input.insert('-');
+        assert_eq!(text_input.text(), "0-.75");
+        assert_eq!(text_input.text_before_cursor(), "0-");
+        assert_eq!(text_input.parse_f32(), None);
+        text_input.backspace();
+        text_input.delete();
+        assert_eq!(text_input.text(), "075");
+
+        for _ in 0..5 {
+            text_input.move_cursor_left();
+        }
+        text_input.backspace();
+        assert_eq!(text_input.cursor(), 0);
+        assert_eq!(text_input.text(), "075");
+    }
+
+    #[test]
+    fn cursor_blinks_until_edited() {
+        let mut text_input = TextInput::new("");
+        assert!(text_input.is_cursor_visible());
+        text_input.advance_blink(CURSOR_BLINK_SECONDS * 1.5);
+        assert!(!text_input.is_cursor_visible());
+        text_input.insert('1');
+        assert!(text_input.is_cursor_visible());
+        assert_eq!(TextInput::new("inf").parse_f32(), None);
+    }
+}
@@ -7,6 +7,8 @@ use void_public::{
     linalg::{Vec3, Vec4},
 };
 
+use crate::{PercentPosition, math::layout::RelativeScale};
+
 const DEFAULT_SCALE: f32 = 100.;
 
 pub fn create_new_texture(
@@ -14,6 +16,40 @@ pub fn create_new_texture(
     color: Vec4,
     texture_id: TextureId,
     scale: Option<Vec2>,
+) -> ComponentBuilder {
+    texture_builder(
+        position,
+        color,
+        texture_id,
+        scale.unwrap_or(Vec2::splat(DEFAULT_SCALE)),
+        PercentPosition::default(),
+    )
+}
+
+/// [`create_new_texture`] for quads sized as a fraction of the screen, which keep that fraction
+/// when the window is resized
+pub fn create_new_relative_texture(
+    position: Vec3,
+    color: Vec4,
+    texture_id: TextureId,
+    scale: Vec2,
+    relative_scale: RelativeScale,
+) -> ComponentBuilder {
+    texture_builder(
+        position,
+        color,
+        texture_id,
+        scale,
+        PercentPosition::with_relative_scale(relative_scale),
+    )
+}
+
+fn texture_builder(
+    position: Vec3,
+    color: Vec4,
+    texture_id: TextureId,
+    scale: Vec2,
+    percent_position: PercentPosition,
 ) -> ComponentBuilder {
     let texture_render = TextureRender {
         texture_id,
@@ -21,8 +57,90 @@ pub fn create_new_texture(
     };
     let transform = Transform {
         position,
-        scale: scale.unwrap_or(Vec2::splat(DEFAULT_SCALE)).into(),
+        scale: scale.into(),
         ..Default::default()
     };
-    bundle_for_builder!(texture_render, transform, Color::from(color)).into()
+    bundle_for_builder!(
+        texture_render,
+        transform,
+        Color::from(color),
+        percent_position
+    )
+    .into()
+}
+
+/// The `uv_offset` and `uv_scale` uniforms selecting one cell of an atlas texture, in atlas uvs
+/// starting at the top left
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AtlasCellUniforms {
+    pub uv_offset: Vec2,
+    pub uv_scale: Vec2,
+}
+
+/// Cells are counted row by row from the top left, `index` wraps around past the last cell
+pub fn atlas_cell_uniforms(rows: usize, cols: usize, index: usize) -> AtlasCellUniforms {
+    let rows = rows.max(1);
+    let cols = cols.max(1);
+    let index = index % (rows * cols);
+    let uv_scale = Vec2::new(1. / cols as f32, 1. / rows as f32);
+    AtlasCellUniforms {
+        uv_offset: Vec2::new((index % cols) as f32, (index / cols) as f32) * uv_scale,
+        uv_scale,
+    }
+}
+
+/// The texel sampled `position` world units along one axis of a nine slice quad, the CPU side of
+/// the `nine_slice` material. The first and last `border_size` units show `slice_border` texels
+/// unstretched, the middle stretches over the rest of the texture. Borders wider than half the
+/// quad shrink to fit.
+pub fn nine_slice_texel(
+    position: f32,
+    quad_length: f32,
+    border_size: f32,
+    texture_length: f32,
+    slice_border: f32,
+) -> f32 {
+    let border_size = border_size.min(quad_length * 0.5);
+    if position < border_size {
+        position / border_size * slice_border
+    } else if position > quad_length - border_size {
+        texture_length - (quad_length - position) / border_size * slice_border
+    } else {
+        let middle_length = (quad_length - 2. * border_size).max(f32::EPSILON);
+        slice_border
+            + (position - border_size) / middle_length * (texture_length - 2. * slice_border)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use void_public::Vec2;
+
+    use crate::texture::{atlas_cell_uniforms, nine_slice_texel};
+
+    #[test]
+    fn atlas_cells_are_counted_from_the_top_left() {
+        let first_cell = atlas_cell_uniforms(4, 4, 0);
+        assert_eq!(first_cell.uv_offset, Vec2::new(0., 0.));
+        assert_eq!(first_cell.uv_scale, Vec2::new(0.25, 0.25));
+
+        let cell = atlas_cell_uniforms(2, 4, 6);
+        assert_eq!(cell.uv_offset, Vec2::new(0.5, 0.5));
+        assert_eq!(cell.uv_scale, Vec2::new(0.25, 0.5));
+        assert_eq!(atlas_cell_uniforms(2, 4, 14), cell);
+    }
+
+    #[test]
+    fn nine_slice_borders_keep_their_size() {
+        // A 48 texel panel with 12 texel borders, drawn 200 units long with 24 unit borders
+        assert_eq!(nine_slice_texel(12., 200., 24., 48., 12.), 6.);
+        assert_eq!(nine_slice_texel(24., 200., 24., 48., 12.), 12.);
+        assert_eq!(nine_slice_texel(100., 200., 24., 48., 12.), 24.);
+        assert_eq!(nine_slice_texel(176., 200., 24., 48., 12.), 36.);
+        assert_eq!(nine_slice_texel(188., 200., 24., 48., 12.), 42.);
+
+        // Too small for both borders, each gets half the quad
+        assert_eq!(nine_slice_texel(5., 20., 24., 48., 12.), 6.);
+        assert_eq!(nine_slice_texel(15., 20., 24., 48., 12.), 42.);
+    }
 }
@@ -0,0 +1,122 @@
+//! Transient messages stacked in the bottom right corner of the screen, for feedback like saved
+//! presets and failed loads that would otherwise only reach the console. [`show`] can be called
+//! from anywhere and queues the message, `toast_system` takes the queue each frame, spawns
+//! the text, keeps the stack in order and fades each toast out before despawning it.
+
+use std::sync::Mutex;
+
+use void_public::Vec2;
+
+use crate::text::LINE_HEIGHT_EM;
+
+pub const DEFAULT_TOAST_SECONDS: f32 = 2.5;
+/// Toasts fade out over their last this many seconds
+pub const TOAST_FADE_SECONDS: f32 = 0.5;
+/// Older toasts are dropped once the stack is this tall
+pub const MAX_VISIBLE_TOASTS: usize = 5;
+pub const TOAST_FONT_SIZE: f32 = 24.;
+/// Pixels between the stack and the screen edges, and between toasts
+const TOAST_MARGIN: f32 = 16.;
+
+/// A message waiting for `toast_system`
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingToast {
+    pub message: String,
+    pub duration_seconds: f32,
+}
+
+static PENDING_TOASTS: Mutex<Vec<PendingToast>> = Mutex::new(Vec::new());
+
+pub fn show(message: &str, duration_seconds: f32) {
+    PENDING_TOASTS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(PendingToast {
+            message: message.to_string(),
+            duration_seconds,
+        });
+}
+
+/// Empties the queue, oldest first
+pub fn take_pending() -> Vec<PendingToast> {
+    std::mem::take(
+        &mut *PENDING_TOASTS
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()),
+    )
+}
+
+pub fn toast_alpha(remaining_seconds: f32) -> f32 {
+    (remaining_seconds / TOAST_FADE_SECONDS).clamp(0., 1.)
+}
+
+/// The size of a toast's text, as `(width, line count)`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ToastSize {
+    pub width: f32,
+    pub lines: usize,
+}
+
+/// The pixel offsets of each toast's center from the bottom right corner of the screen, `sizes`
+/// are ordered newest first and the newest toast is at the bottom
+pub fn toast_stack_offsets(sizes: &[ToastSize]) -> Vec<Vec2> {
+    let line_height = TOAST_FONT_SIZE * LINE_HEIGHT_EM;
+    let mut bottom = TOAST_MARGIN;
+    sizes
+        .iter()
+        .map(|toast_size| {
+            let height = toast_size.lines.max(1) as f32 * line_height;
+            let offset = Vec2::new(
+                -(TOAST_MARGIN + toast_size.width * 0.5),
+                bottom + height * 0.5,
+            );
+            bottom += height + TOAST_MARGIN * 0.5;
+            offset
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use void_public::Vec2;
+
+    use crate::toast::{
+        TOAST_FADE_SECONDS, ToastSize, show, take_pending, toast_alpha, toast_stack_offsets,
+    };
+
+    #[test]
+    fn pending_toasts_are_taken_in_order() {
+        show("first", 1.);
+        show("second", 2.);
+        let messages = take_pending()
+            .into_iter()
+            .map(|pending_toast| pending_toast.message)
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["first", "second"]);
+        assert!(take_pending().is_empty());
+    }
+
+    #[test]
+    fn toasts_fade_at_the_end() {
+        assert_eq!(toast_alpha(2.), 1.);
+        assert_eq!(toast_alpha(TOAST_FADE_SECONDS * 0.5), 0.5);
+        assert_eq!(toast_alpha(-1.), 0.);
+    }
+
+    #[test]
+    fn newest_toast_is_at_the_bottom_of_the_stack() {
+        // 16 px margin, 28.8 px lines and 8 px between toasts
+        let offsets = toast_stack_offsets(&[
+            ToastSize {
+                width: 100.,
+                lines: 1,
+            },
+            ToastSize {
+                width: 40.,
+                lines: 2,
+            },
+        ]);
+        assert!((offsets[0] - Vec2::new(-66., 16. + 14.4)).length() < 0.001);
+        assert!((offsets[1] - Vec2::new(-36., 16. + 28.8 + 8. + 28.8)).length() < 0.001);
+    }
+}
@@ -7,15 +7,27 @@ use void_public::{
     linalg::Vec3,
 };
 
-use crate::{Underline, math::ZeroToHundredPercent};
+use crate::{
+    PercentPosition, Underline,
+    math::{ZeroToHundredPercent, layout::RelativeScale},
+};
 
 pub const UNDERLINE_OFFSET_Y_PERCENT: ZeroToHundredPercent = ZeroToHundredPercent::new(0.05);
 pub const UNDERLINE_HEIGHT_Y_PERCENT: ZeroToHundredPercent = ZeroToHundredPercent::new(0.005);
 pub const UNDERLINE_DEFAULT_WIDTH_X_PERCENT: ZeroToHundredPercent = ZeroToHundredPercent::new(0.15);
 
+/// The underline scale for text `text_width` wide, see [`measure_text`](crate::text::measure_text).
+/// The default width is used when the width of the text isn't known.
+pub fn underline_scale(text_width: Option<f32>, aspect: &Aspect) -> Vec2 {
+    Vec2::new(
+        text_width.unwrap_or(*UNDERLINE_DEFAULT_WIDTH_X_PERCENT * aspect.width),
+        *UNDERLINE_HEIGHT_Y_PERCENT * aspect.height,
+    )
+}
+
 pub fn create_underline(
     position: Vec3,
-    width_percent: Option<ZeroToHundredPercent>,
+    text_width: Option<f32>,
     aspect: &Aspect,
 ) -> ComponentBuilder {
     let texture_render = TextureRender {
@@ -24,13 +36,23 @@ pub fn create_underline(
     };
     let transform = Transform {
         position,
-        scale: Vec2::new(
-            *width_percent.unwrap_or(UNDERLINE_DEFAULT_WIDTH_X_PERCENT) * aspect.width,
-            *UNDERLINE_HEIGHT_Y_PERCENT * aspect.height,
-        )
-        .into(),
+        scale: underline_scale(text_width, aspect).into(),
         ..Default::default()
     };
     let color = palette::WHITE;
-    bundle_for_builder!(texture_render, transform, color, Underline).into()
+    // Measured text follows the screen height like relative font sizes do, the default width
+    // follows the screen width
+    let relative_scale = if text_width.is_some() {
+        RelativeScale::OfHeight
+    } else {
+        RelativeScale::OfScreen
+    };
+    bundle_for_builder!(
+        texture_render,
+        transform,
+        color,
+        Underline,
+        PercentPosition::with_relative_scale(relative_scale)
+    )
+    .into()
 }
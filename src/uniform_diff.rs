@@ -0,0 +1,153 @@
+//! An overlay listing the running test's uniforms next to their material defaults, marking the
+//! ones that changed. U toggles it, Up/Down select a uniform, X resets the selected uniform to its
+//! default and Shift+X resets all of them.
+
+use crate::presets::{PresetValue, UniformPreset};
+
+/// The overlay's text lines, a header and one line per uniform
+pub const UNIFORM_DIFF_LINES: usize = 16;
+/// Values closer than this to their default count as unchanged
+const UNCHANGED_EPSILON: f32 = 1e-5;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UniformDiffEntry {
+    pub name: String,
+    pub current: PresetValue,
+    pub default: PresetValue,
+}
+
+impl UniformDiffEntry {
+    pub fn is_changed(&self) -> bool {
+        match (&self.current, &self.default) {
+            (PresetValue::F32(current), PresetValue::F32(default)) => {
+                (current - default).abs() > UNCHANGED_EPSILON
+            }
+            (PresetValue::Vec4(current), PresetValue::Vec4(default)) => current
+                .iter()
+                .zip(default)
+                .any(|(current, default)| (current - default).abs() > UNCHANGED_EPSILON),
+            _ => true,
+        }
+    }
+}
+
+/// Pairs every default uniform with its current value, in the defaults' order. Uniforms missing
+/// from `current` are left out.
+pub fn uniform_diff(current: &UniformPreset, defaults: &UniformPreset) -> Vec<UniformDiffEntry> {
+    defaults
+        .values
+        .iter()
+        .filter_map(|(name, default)| {
+            let (_, current) = current
+                .values
+                .iter()
+                .find(|(current_name, _)| current_name == name)?;
+            Some(UniformDiffEntry {
+                name: name.clone(),
+                current: *current,
+                default: *default,
+            })
+        })
+        .collect()
+}
+
+fn format_value(value: &PresetValue) -> String {
+    match value {
+        PresetValue::F32(value) => format!("{value:.3}"),
+        PresetValue::Vec4([x, y, z, w]) => format!("[{x:.2}, {y:.2}, {z:.2}, {w:.2}]"),
+    }
+}
+
+/// The overlay text, changed uniforms are marked with `*` and the selected one with `>`
+pub fn diff_lines(entries: &[UniformDiffEntry], selected: usize) -> Vec<String> {
+    let changed_count = entries.iter().filter(|entry| entry.is_changed()).count();
+    let mut lines = vec![format!(
+        "Uniforms, {changed_count} of {} changed from default:",
+        entries.len()
+    )];
+    if entries.is_empty() {
+        lines.push("  none".to_string());
+    }
+    lines.extend(
+        entries
+            .iter()
+            .enumerate()
+            .take(UNIFORM_DIFF_LINES - 1)
+            .map(|(index, entry)| {
+                let marker = if index == selected { '>' } else { ' ' };
+                if entry.is_changed() {
+                    format!(
+                        "{marker} * {} {} (default {})",
+                        entry.name,
+                        format_value(&entry.current),
+                        format_value(&entry.default)
+                    )
+                } else {
+                    format!("{marker}   {} {}", entry.name, format_value(&entry.current))
+                }
+            }),
+    );
+    lines
+}
+
+/// The defaults of the changed uniforms, only the one at `index` when given, to apply as a preset
+pub fn reset_preset(entries: &[UniformDiffEntry], index: Option<usize>) -> UniformPreset {
+    UniformPreset {
+        values: entries
+            .iter()
+            .enumerate()
+            .filter(|(entry_index, entry)| {
+                entry.is_changed() && index.is_none_or(|index| index == *entry_index)
+            })
+            .map(|(_, entry)| (entry.name.clone(), entry.default))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        presets::{PresetValue, UniformPreset},
+        uniform_diff::{diff_lines, reset_preset, uniform_diff},
+    };
+
+    fn preset(values: &[(&str, PresetValue)]) -> UniformPreset {
+        UniformPreset {
+            values: values
+                .iter()
+                .map(|(name, value)| (name.to_string(), *value))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn changed_uniforms_are_marked_and_reset() {
+        let defaults = preset(&[
+            ("speed", PresetValue::F32(0.1)),
+            ("star_number", PresetValue::F32(100.)),
+            ("cold_star_color", PresetValue::Vec4([0.5, 0.5, 1., 1.])),
+        ]);
+        let current = preset(&[
+            ("cold_star_color", PresetValue::Vec4([0.5, 0.5, 1., 1.])),
+            ("speed", PresetValue::F32(0.5)),
+            ("star_number", PresetValue::F32(100.)),
+        ]);
+        let entries = uniform_diff(&current, &defaults);
+
+        assert_eq!(
+            diff_lines(&entries, 1),
+            vec![
+                "Uniforms, 1 of 3 changed from default:",
+                "  * speed 0.500 (default 0.100)",
+                ">   star_number 100.000",
+                "    cold_star_color [0.50, 0.50, 1.00, 1.00]",
+            ]
+        );
+        assert_eq!(
+            reset_preset(&entries, None),
+            preset(&[("speed", PresetValue::F32(0.1))])
+        );
+        assert_eq!(reset_preset(&entries, Some(1)), UniformPreset::default());
+        assert_eq!(diff_lines(&[], 0)[1], "  none");
+    }
+}
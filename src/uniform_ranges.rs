@@ -0,0 +1,97 @@
+//! Reads the `[uniform_ranges]` table of a material TOML, which declares the values a uniform can
+//! sensibly take so the randomize hotkey doesn't produce garbage and the mouse wheel steps through
+//! them at a useful rate, e.g.
+//!
+//! ```toml
+//! [uniform_ranges]
+//! speed = [0.0, 1.0]
+//! cold_star_color = [0.0, 1.0]
+//! ```
+//!
+//! Vec4 uniforms use the same range for every component. Uniforms without a range, like the ones
+//! driven by time, are never randomized.
+
+use crate::flat_toml::{FlatTomlEntry, flat_toml_entries, parse_f32_list};
+
+const UNIFORM_RANGES_TABLE: &str = "uniform_ranges";
+/// How many mouse wheel steps it takes to scroll a uniform across its whole range
+pub const SCROLL_STEPS_PER_RANGE: f32 = 100.;
+/// The mouse wheel step of uniforms without a range
+pub const UNRANGED_SCROLL_STEP: f32 = 0.01;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UniformRange {
+    pub min: f32,
+    pub max: f32,
+}
+
+/// Malformed lines and ranges with `min` above `max` are skipped
+pub fn parse_uniform_ranges(toml_string: &str) -> Vec<(String, UniformRange)> {
+    flat_toml_entries(toml_string)
+        .filter_map(Result::ok)
+        .filter(|entry| entry.table == UNIFORM_RANGES_TABLE)
+        .filter_map(|FlatTomlEntry { key, value, .. }| {
+            let [min, max] = parse_f32_list(value)?.try_into().ok()?;
+            (min <= max).then_some((key.to_string(), UniformRange { min, max }))
+        })
+        .collect()
+}
+
+/// `value` moved by `steps` mouse wheel steps, snapped to the step grid so repeated scrolling
+/// doesn't accumulate rounding errors, and kept inside `range`
+pub fn scrolled_uniform_value(value: f32, steps: f32, range: Option<UniformRange>) -> f32 {
+    let (origin, step) = match range {
+        Some(range) if range.max > range.min => {
+            (range.min, (range.max - range.min) / SCROLL_STEPS_PER_RANGE)
+        }
+        _ => (0., UNRANGED_SCROLL_STEP),
+    };
+    let scrolled_value = origin + (((value - origin) / step).round() + steps) * step;
+    match range {
+        Some(range) => scrolled_value.clamp(range.min, range.max),
+        None => scrolled_value,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::uniform_ranges::{UniformRange, parse_uniform_ranges, scrolled_uniform_value};
+
+    #[test]
+    fn uniform_ranges_table_parsing() {
+        let toml_string = r#"
+[uniform_types]
+speed = { type = "f32", default = 0.1 }
+
+[uniform_ranges]
+speed = [0.0, 1.5]
+star_number = [300.0, 10.0]
+cold_star_color = [0, 1]
+broken = 0.5
+
+[texture_descs]
+random = "nearest"
+"#;
+        assert_eq!(
+            parse_uniform_ranges(toml_string),
+            vec![
+                ("speed".to_string(), UniformRange { min: 0., max: 1.5 }),
+                (
+                    "cold_star_color".to_string(),
+                    UniformRange { min: 0., max: 1. }
+                ),
+            ]
+        );
+        assert!(parse_uniform_ranges("[uniform_types]\nspeed = [0.0, 1.0]\n").is_empty());
+    }
+
+    #[test]
+    fn scrolling_steps_through_the_range() {
+        let range = Some(UniformRange { min: 2., max: 4. });
+        assert!((scrolled_uniform_value(3., 1., range) - 3.02).abs() < 1e-5);
+        assert!((scrolled_uniform_value(3.013, -1., range) - 3.).abs() < 1e-5);
+        assert_eq!(scrolled_uniform_value(4., 1., range), 4.);
+        assert_eq!(scrolled_uniform_value(2., -1., range), 2.);
+        assert!((scrolled_uniform_value(0.5, -2., None) - 0.48).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,161 @@
+//! Keyframed uniform animation. A test describes how a uniform changes over time as a list of
+//! [`UniformKeyframe`]s instead of hand rolling the increments in its per-frame system. Only f32
+//! uniforms are animated.
+
+use log::warn;
+
+use crate::text::{str_to_u8_array, u8_array_to_str};
+
+/// How the value moves from the previous keyframe to the keyframe carrying the easing
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    snapshot::Deserialize,
+    snapshot::Serialize,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+pub enum Easing {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    /// Holds the previous value until the keyframe is reached
+    Step,
+}
+
+impl Easing {
+    /// Maps the linear progress `t`, from 0 to 1, between two keyframes to the eased progress
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0., 1.);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1. - (1. - t) * (1. - t),
+            Easing::EaseInOut => t * t * (3. - 2. * t),
+            Easing::Step => {
+                if t < 1. {
+                    0.
+                } else {
+                    1.
+                }
+            }
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    snapshot::Deserialize,
+    snapshot::Serialize,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+pub struct UniformKeyframe {
+    pub time: f32,
+    uniform_name: [u8; 32],
+    pub value: f32,
+    pub easing: Easing,
+}
+
+impl UniformKeyframe {
+    pub fn new(time: f32, uniform_name: &str, value: f32, easing: Easing) -> Self {
+        let (uniform_name_array, truncated) = str_to_u8_array(uniform_name);
+        if truncated {
+            warn!("Uniform name {uniform_name} is too long for a keyframe, it won't be animated");
+        }
+        Self {
+            time,
+            uniform_name: uniform_name_array,
+            value,
+            easing,
+        }
+    }
+
+    pub fn uniform_name(&self) -> &str {
+        u8_array_to_str(&self.uniform_name).unwrap()
+    }
+}
+
+/// The time of the last keyframe
+pub fn timeline_duration<'a>(keyframes: impl IntoIterator<Item = &'a UniformKeyframe>) -> f32 {
+    keyframes
+        .into_iter()
+        .map(|keyframe| keyframe.time)
+        .fold(0., f32::max)
+}
+
+/// The value of `uniform_name` at `time`, holding the first and last keyframe values outside of
+/// the keyframed range. Keyframes are expected in time order.
+pub fn evaluate_uniform<'a>(
+    keyframes: impl IntoIterator<Item = &'a UniformKeyframe>,
+    uniform_name: &str,
+    time: f32,
+) -> Option<f32> {
+    let mut previous_keyframe: Option<&UniformKeyframe> = None;
+    for keyframe in keyframes
+        .into_iter()
+        .filter(|keyframe| keyframe.uniform_name() == uniform_name)
+    {
+        if time < keyframe.time {
+            let Some(previous_keyframe) = previous_keyframe else {
+                return Some(keyframe.value);
+            };
+            let progress =
+                (time - previous_keyframe.time) / (keyframe.time - previous_keyframe.time);
+            return Some(
+                previous_keyframe.value
+                    + (keyframe.value - previous_keyframe.value) * keyframe.easing.apply(progress),
+            );
+        }
+        previous_keyframe = Some(keyframe);
+    }
+    previous_keyframe.map(|keyframe| keyframe.value)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::uniform_timeline::{Easing, UniformKeyframe, evaluate_uniform, timeline_duration};
+
+    #[test]
+    fn easing_endpoints_and_midpoints() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0.), 0.);
+            assert_eq!(easing.apply(1.), 1.);
+        }
+        assert_eq!(Easing::EaseIn.apply(0.5), 0.25);
+        assert_eq!(Easing::EaseOut.apply(0.5), 0.75);
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert_eq!(Easing::Step.apply(0.99), 0.);
+    }
+
+    #[test]
+    fn uniform_evaluation_between_keyframes() {
+        let keyframes = [
+            UniformKeyframe::new(1., "param_0", 0., Easing::Linear),
+            UniformKeyframe::new(2., "speed", 5., Easing::Linear),
+            UniformKeyframe::new(3., "param_0", 1., Easing::Linear),
+            UniformKeyframe::new(4., "param_0", 0., Easing::EaseIn),
+        ];
+        assert_eq!(timeline_duration(&keyframes), 4.);
+        assert_eq!(evaluate_uniform(&keyframes, "param_0", 0.), Some(0.));
+        assert_eq!(evaluate_uniform(&keyframes, "param_0", 2.), Some(0.5));
+        assert_eq!(evaluate_uniform(&keyframes, "param_0", 3.5), Some(0.75));
+        assert_eq!(evaluate_uniform(&keyframes, "param_0", 10.), Some(0.));
+        assert_eq!(evaluate_uniform(&keyframes, "speed", 0.), Some(5.));
+        assert_eq!(evaluate_uniform(&keyframes, "intensity", 0.), None);
+    }
+}
@@ -0,0 +1,180 @@
+//! Fade-out/fade-in between view states. The screen fades to black, the view changes while it is
+//! covered, then the new view fades back in. With `--transition=iris` the same timing closes and
+//! opens the `iris_wipe` postprocess instead.
+
+pub const FADE_ARG: &str = "--fade=";
+pub const DEFAULT_FADE_SECONDS: f32 = 0.2;
+
+/// Parses `--fade=<seconds>`, where `--fade=0` turns fading off
+pub fn parse_fade_arg(arg: &str) -> Option<f32> {
+    arg.strip_prefix(FADE_ARG)?
+        .parse::<f32>()
+        .ok()
+        .filter(|fade_seconds| *fade_seconds >= 0.)
+}
+
+pub const TRANSITION_ARG: &str = "--transition=";
+
+/// How the screen is covered while the view changes
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TransitionStyle {
+    /// A black fullscreen quad
+    #[default]
+    Fade,
+    /// The `iris_wipe` postprocess, falling back to [`TransitionStyle::Fade`] until it has loaded
+    Iris,
+}
+
+/// Parses `--transition=fade` or `--transition=iris`
+pub fn parse_transition_arg(arg: &str) -> Option<TransitionStyle> {
+    match arg.strip_prefix(TRANSITION_ARG)? {
+        "fade" => Some(TransitionStyle::Fade),
+        "iris" => Some(TransitionStyle::Iris),
+        _ => None,
+    }
+}
+
+/// The iris wipe's `radius` uniform covering the screen as much as [`ViewFade::alpha`] would,
+/// 1 is fully open and 0 fully closed. Eased, so the iris slows down as it closes.
+pub fn iris_radius(alpha: f32) -> f32 {
+    let open = 1. - alpha.clamp(0., 1.);
+    open * (2. - open)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FadePhase {
+    Idle,
+    FadingOut { elapsed: f32 },
+    FadingIn { elapsed: f32 },
+}
+
+/// Tracks the alpha of the fullscreen quad covering view changes, `duration` is the length of
+/// each half of the fade
+#[derive(Debug)]
+pub struct ViewFade {
+    duration: f32,
+    phase: FadePhase,
+}
+
+impl Default for ViewFade {
+    fn default() -> Self {
+        Self::new(DEFAULT_FADE_SECONDS)
+    }
+}
+
+impl ViewFade {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            phase: FadePhase::Idle,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.duration > 0.
+    }
+
+    /// Starts covering the screen, a fade in that is still running reverses from its current alpha
+    pub fn fade_out(&mut self) {
+        self.phase = match self.phase {
+            FadePhase::Idle => FadePhase::FadingOut { elapsed: 0. },
+            FadePhase::FadingIn { elapsed } => FadePhase::FadingOut {
+                elapsed: (self.duration - elapsed).max(0.),
+            },
+            fading_out @ FadePhase::FadingOut { .. } => fading_out,
+        };
+    }
+
+    /// Returns true on the frame the screen becomes fully covered, which is when the view should
+    /// change
+    pub fn advance(&mut self, delta_time: f32) -> bool {
+        match self.phase {
+            FadePhase::Idle => false,
+            FadePhase::FadingOut { elapsed } => {
+                let elapsed = elapsed + delta_time;
+                if elapsed >= self.duration {
+                    self.phase = FadePhase::FadingIn { elapsed: 0. };
+                    true
+                } else {
+                    self.phase = FadePhase::FadingOut { elapsed };
+                    false
+                }
+            }
+            FadePhase::FadingIn { elapsed } => {
+                let elapsed = elapsed + delta_time;
+                self.phase = if elapsed >= self.duration {
+                    FadePhase::Idle
+                } else {
+                    FadePhase::FadingIn { elapsed }
+                };
+                false
+            }
+        }
+    }
+
+    pub fn alpha(&self) -> f32 {
+        match self.phase {
+            FadePhase::Idle => 0.,
+            FadePhase::FadingOut { elapsed } => (elapsed / self.duration).clamp(0., 1.),
+            FadePhase::FadingIn { elapsed } => (1. - elapsed / self.duration).clamp(0., 1.),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::view_transitions::{
+        TransitionStyle, ViewFade, iris_radius, parse_fade_arg, parse_transition_arg,
+    };
+
+    #[test]
+    fn fade_arg_parsing() {
+        assert_eq!(parse_fade_arg("--fade=0.5"), Some(0.5));
+        assert_eq!(parse_fade_arg("--fade=0"), Some(0.));
+        assert_eq!(parse_fade_arg("--fade=-1"), None);
+        assert_eq!(parse_fade_arg("--fade"), None);
+    }
+
+    #[test]
+    fn view_fade_covers_then_reveals() {
+        let mut view_fade = ViewFade::new(1.);
+        assert_eq!(view_fade.alpha(), 0.);
+
+        view_fade.fade_out();
+        assert!(!view_fade.advance(0.5));
+        assert_eq!(view_fade.alpha(), 0.5);
+        assert!(view_fade.advance(0.5));
+        assert_eq!(view_fade.alpha(), 1.);
+        assert!(!view_fade.advance(0.25));
+        assert_eq!(view_fade.alpha(), 0.75);
+
+        // A new transition during the fade in reverses from the current alpha
+        view_fade.fade_out();
+        assert_eq!(view_fade.alpha(), 0.75);
+        assert!(view_fade.advance(0.25));
+        assert!(!view_fade.advance(1.));
+        assert_eq!(view_fade.alpha(), 0.);
+    }
+
+    #[test]
+    fn transition_arg_parsing() {
+        assert_eq!(
+            parse_transition_arg("--transition=iris"),
+            Some(TransitionStyle::Iris)
+        );
+        assert_eq!(
+            parse_transition_arg("--transition=fade"),
+            Some(TransitionStyle::Fade)
+        );
+        assert_eq!(parse_transition_arg("--transition=wipe"), None);
+        assert_eq!(parse_transition_arg("--fade=0.5"), None);
+    }
+
+    #[test]
+    fn iris_closes_as_the_fade_covers() {
+        assert_eq!(iris_radius(0.), 1.);
+        assert_eq!(iris_radius(1.), 0.);
+        assert_eq!(iris_radius(0.5), 0.75);
+        assert_eq!(iris_radius(2.), 0.);
+    }
+}